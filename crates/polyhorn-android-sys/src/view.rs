@@ -73,6 +73,17 @@ impl View {
             );
         }
     }
+
+    pub fn remove_view(&mut self, env: &Env, view: &View) {
+        unsafe {
+            env.call_method(
+                self.reference.as_object(),
+                "removeView",
+                "(Landroid/view/View;)V",
+                &[JValue::Object(view.as_reference().as_object()).into()],
+            );
+        }
+    }
 }
 
 impl Object for View {
@@ -1,9 +1,12 @@
 //! This crate implements Polyhorn for Android.
 
 pub use polyhorn_core::{
-    render, Context, ContextProvider, Key, Link, Receiver, Reference, Sender, State,
+    log_renders, observe_renders, render, render_with_executor, stop_observing_renders, untracked,
+    Computed, Context, ContextProvider, Executor, History, HistoryOptions, Key, Link,
+    LocalExecutor, PropsDiff, Receiver, Reference, RenderReason, RenderRecord, Sender, Signal,
+    Slot, State, TestExecutor, TokioExecutor,
 };
-pub use polyhorn_ui::{assets, color, font, geometry, layout, linalg, styles};
+pub use polyhorn_ui::{assets, color, font, geometry, layout, linalg, physics, styles};
 pub use polyhorn_ui_macros::render;
 
 pub mod components;
@@ -14,14 +17,18 @@ pub mod raw;
 /// Re-exports of hooks provided by Polyhorn Core and Polyhorn UI.
 pub mod hooks {
     pub use polyhorn_core::{
-        use_async, use_context, use_effect, use_id, use_reference, use_state, UseAsync, UseContext,
-        UseEffect, UseReference,
+        use_async, use_context, use_effect, use_history, use_id, use_imperative_handle,
+        use_machine, use_reference, use_state, use_worker, UseAsync, UseContext, UseEffect,
+        UseHistory, UseImperativeHandle, UseMachine, UseReference, UseWorker,
     };
     pub use polyhorn_ui::hooks::*;
 }
 
 use raw::Platform;
 
+/// Polyhorn core children type that is specialized for the Android platform.
+pub type Children = polyhorn_core::Children<Platform>;
+
 /// Polyhorn core element type that is specialized for the iOS platform.
 pub type Element = polyhorn_core::Element<Platform>;
 
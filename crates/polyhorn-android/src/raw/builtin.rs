@@ -2,6 +2,7 @@ use polyhorn_android_sys::{ImageView, View};
 use polyhorn_ui::geometry::{Dimension, Size};
 use polyhorn_ui::layout::LayoutNode;
 use polyhorn_ui::styles::{FlexDirection, Position, Relative, ViewStyle};
+use std::any::Any;
 
 use super::{Container, Environment, OpaqueContainer, Platform};
 
@@ -84,6 +85,10 @@ impl polyhorn_core::Builtin<Platform> for Builtin {
             _ => {}
         }
     }
+
+    fn as_any(&self) -> Option<&dyn Any> {
+        Some(self)
+    }
 }
 
 impl Container for polyhorn_android_sys::Activity {
@@ -1,12 +1,28 @@
+use as_any::AsAny;
+use polyhorn_core::PropsDiff;
+use std::any::Any;
 use std::rc::Rc;
 
 use super::Platform;
 use crate::{Element, Manager};
 
 /// Platform-specific component trait.
-pub trait Component: 'static {
+pub trait Component: AsAny {
     /// Render function that must be implemented by components.
     fn render(&self, manager: &mut Manager) -> Element;
+
+    /// Returns the name of this component that is used in render diagnostics.
+    fn type_name(&self) -> &'static str {
+        std::any::type_name::<Self>()
+    }
+
+    /// Compares the props of this component with the props of its previous
+    /// render. Components whose props implement `Debug` and / or `PartialEq`
+    /// can implement this with `PropsDiff::compare` to show up with the props
+    /// that changed in render diagnostics.
+    fn diff_props(&self, _previous: &dyn Any) -> Option<PropsDiff> {
+        None
+    }
 }
 
 /// Opaque reference counted wrapper around a component.
@@ -25,12 +41,27 @@ impl AsRef<dyn Component> for OpaqueComponent {
 /// `polyhorn_core::Component<polyhorn_android::Platform>`, but that's not yet
 /// possible.
 mod machinery {
-    use super::{Component, Element, Manager, OpaqueComponent, Platform, Rc};
+    use super::{Component, Element, Manager, OpaqueComponent, Platform, PropsDiff, Rc};
 
     impl polyhorn_core::Component<Platform> for OpaqueComponent {
         fn render(&self, manager: &mut Manager) -> Element {
             self.0.render(manager)
         }
+
+        fn downcast_ref<T>(&self) -> Option<&T>
+        where
+            T: 'static,
+        {
+            self.0.as_ref().as_any().downcast_ref::<T>()
+        }
+
+        fn type_name(&self) -> &'static str {
+            self.0.type_name()
+        }
+
+        fn diff_props(&self, previous: &Self) -> Option<PropsDiff> {
+            self.0.diff_props(previous.0.as_ref().as_any())
+        }
     }
 
     impl<T> From<T> for OpaqueComponent
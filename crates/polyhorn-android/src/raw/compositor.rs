@@ -1,5 +1,6 @@
 use polyhorn_android_sys::Runnable;
 use polyhorn_core::{Command, Composition};
+use serde::{Deserialize, Serialize};
 use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::{Arc, Mutex};
 
@@ -47,7 +48,7 @@ impl polyhorn_core::Compositor<Platform> for Compositor {
 }
 
 /// An opaque ID for containers that can be shared between threads.
-#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
 pub struct ContainerID(usize);
 
 /// Concrete implementation of a command buffer that can buffer commands before
@@ -106,4 +106,32 @@ impl polyhorn_core::Container<Platform> for OpaqueContainer {
 
         self.2.unmount();
     }
+
+    fn reorder(&mut self, children: &mut [&mut OpaqueContainer], environment: &mut Environment) {
+        // The layout tree can only append children, so we move the given
+        // children to the end in their new order.
+        if let Some(parent) = self.content_layout().or_else(|| self.layout()) {
+            let mut layouter = parent.layouter().write().unwrap();
+
+            for child in children.iter() {
+                if let Some(child) = child.layout() {
+                    layouter
+                        .flexbox_mut()
+                        .remove_child(parent.node(), child.node());
+                    layouter.add_child(parent.node(), child.node());
+                }
+            }
+        }
+
+        // Views can't be added to a view group that they're already in, so we
+        // remove them before adding them again in their new order.
+        if let Some(mut parent) = self.2.to_view() {
+            for child in children.iter() {
+                if let Some(view) = child.container().to_view() {
+                    parent.remove_view(environment.env(), &view);
+                    parent.add_view(environment.env(), &view);
+                }
+            }
+        }
+    }
 }
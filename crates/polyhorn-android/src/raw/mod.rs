@@ -5,6 +5,7 @@ mod container;
 mod convert;
 mod environment;
 mod logger;
+mod payload;
 mod platform;
 
 pub use builtin::Builtin;
@@ -14,6 +15,7 @@ pub use container::{Container, OpaqueContainer};
 pub use convert::Convert;
 pub use environment::Environment;
 pub use logger::AndroidLogger;
+pub use payload::Payload;
 pub use platform::Platform;
 
 pub mod jni {
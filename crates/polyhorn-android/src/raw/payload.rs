@@ -0,0 +1,45 @@
+use polyhorn_ui::styles::ViewStyle;
+use serde::{Deserialize, Serialize};
+
+use super::{Builtin, Platform};
+
+/// Serializable representation of a builtin that can be recorded and
+/// replayed (see `Renderer::record`).
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum Payload {
+    ImageView(ViewStyle),
+    KeyboardAvoidingView,
+    Label,
+    ScrollView,
+    TextInput,
+    View(ViewStyle),
+    Window,
+}
+
+impl polyhorn_core::Payload<Platform> for Payload {
+    type Builtin = Builtin;
+
+    fn encode(builtin: &Builtin) -> Payload {
+        match *builtin {
+            Builtin::ImageView(style) => Payload::ImageView(style),
+            Builtin::KeyboardAvoidingView => Payload::KeyboardAvoidingView,
+            Builtin::Label => Payload::Label,
+            Builtin::ScrollView => Payload::ScrollView,
+            Builtin::TextInput => Payload::TextInput,
+            Builtin::View(style) => Payload::View(style),
+            Builtin::Window => Payload::Window,
+        }
+    }
+
+    fn decode(self) -> Builtin {
+        match self {
+            Payload::ImageView(style) => Builtin::ImageView(style),
+            Payload::KeyboardAvoidingView => Builtin::KeyboardAvoidingView,
+            Payload::Label => Builtin::Label,
+            Payload::ScrollView => Builtin::ScrollView,
+            Payload::TextInput => Builtin::TextInput,
+            Payload::View(style) => Builtin::View(style),
+            Payload::Window => Builtin::Window,
+        }
+    }
+}
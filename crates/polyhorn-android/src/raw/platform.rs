@@ -1,6 +1,7 @@
 use polyhorn_android_sys::{Activity, Object, Thread};
-use polyhorn_core::EventLoop;
+use polyhorn_core::{EventLoop, Executor, TokioExecutor};
 use polyhorn_ui::layout::LayoutTree;
+use std::sync::Arc;
 
 use super::{
     AndroidLogger, CommandBuffer, Compositor, ContainerID, Environment, OpaqueComponent,
@@ -18,13 +19,21 @@ impl polyhorn_core::Platform for Platform {
     type ContainerID = ContainerID;
     type Environment = Environment;
 
-    fn with_compositor<F>(mut container: Self::Container, task: F) -> polyhorn_core::Disposable
+    fn default_executor() -> Arc<dyn Executor> {
+        Arc::new(TokioExecutor::new())
+    }
+
+    fn with_compositor<F>(
+        mut container: Self::Container,
+        executor: Arc<dyn Executor>,
+        task: F,
+    ) -> polyhorn_core::Disposable
     where
         F: FnOnce(Self::ContainerID, Self::Compositor, EventLoop) -> polyhorn_core::Disposable
             + Send
             + 'static,
     {
-        use std::sync::{Arc, RwLock};
+        use std::sync::RwLock;
 
         let _ = log::set_logger(&AndroidLogger);
         log::set_max_level(log::LevelFilter::max());
@@ -44,14 +53,8 @@ impl polyhorn_core::Platform for Platform {
             let mut compositor = Compositor::new(environment);
             let id = compositor.track(container);
 
-            let mut runtime = tokio::runtime::Runtime::new().unwrap();
-            runtime.block_on(async move {
-                let (evloop, handler) = EventLoop::new();
-                let _compositor = compositor.clone();
-                let _result = task(id, compositor, evloop);
-
-                handler.main().await;
-            })
+            let _compositor = compositor.clone();
+            EventLoop::run(executor, move |evloop| task(id, compositor, evloop));
         })
         .start(&env);
 
@@ -0,0 +1,239 @@
+use criterion::{criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion};
+use polyhorn_core::{
+    use_reference, use_state, Builtin, CommandBuffer, Component, Compositor, Container, Disposable,
+    Element, EventLoop, EventLoopHandler, Executor, Instance, Key, LocalExecutor, Manager,
+    Platform, Renderer,
+};
+use std::cell::Cell;
+use std::rc::Rc;
+use std::sync::Arc;
+
+/// Platform that discards all commands, so that the benchmarks only measure
+/// the cost of reconciliation.
+enum BenchPlatform {}
+
+impl Platform for BenchPlatform {
+    type ContainerID = usize;
+    type Container = BenchContainer;
+    type Component = OpaqueComponent;
+    type Compositor = BenchCompositor;
+    type CommandBuffer = BenchCommandBuffer;
+    type Environment = ();
+
+    fn with_compositor<F>(
+        _container: BenchContainer,
+        _executor: Arc<dyn Executor>,
+        _task: F,
+    ) -> Disposable
+    where
+        F: FnOnce(usize, BenchCompositor, EventLoop) -> Disposable + Send + 'static,
+    {
+        unimplemented!("Benchmarks construct a renderer directly.")
+    }
+}
+
+struct BenchContainer;
+
+impl Container<BenchPlatform> for BenchContainer {
+    fn mount(&mut self, _container: &mut BenchContainer, _environment: &mut ()) {}
+
+    fn unmount(&mut self) {}
+
+    fn reorder(&mut self, _children: &mut [&mut BenchContainer], _environment: &mut ()) {}
+}
+
+#[derive(Default)]
+struct BenchCompositor {
+    counter: Rc<Cell<usize>>,
+}
+
+impl Compositor<BenchPlatform> for BenchCompositor {
+    fn buffer(&self) -> BenchCommandBuffer {
+        BenchCommandBuffer {
+            counter: self.counter.clone(),
+        }
+    }
+}
+
+struct BenchCommandBuffer {
+    counter: Rc<Cell<usize>>,
+}
+
+impl CommandBuffer<BenchPlatform> for BenchCommandBuffer {
+    fn mount<F>(&mut self, _parent_id: usize, _initializer: F) -> usize
+    where
+        F: FnOnce(&mut BenchContainer, &mut ()) -> BenchContainer + Send + 'static,
+    {
+        let id = self.counter.get();
+        self.counter.set(id + 1);
+        id
+    }
+
+    fn mutate<F>(&mut self, _ids: &[usize], _mutator: F)
+    where
+        F: FnOnce(&mut [&mut BenchContainer], &mut ()) + Send + 'static,
+    {
+    }
+
+    fn unmount(&mut self, _id: usize) {}
+
+    fn layout(&mut self) {}
+
+    fn commit(self) {}
+}
+
+struct Leaf;
+
+impl Builtin<BenchPlatform> for Leaf {
+    fn instantiate(&self, _parent: &mut BenchContainer, _environment: &mut ()) -> BenchContainer {
+        BenchContainer
+    }
+
+    fn update(&self, _container: &mut BenchContainer, _environment: &mut ()) {}
+}
+
+trait BenchComponent {
+    fn render(&self, manager: &mut Manager<BenchPlatform>) -> Element<BenchPlatform>;
+}
+
+#[derive(Clone)]
+struct OpaqueComponent(Rc<dyn BenchComponent>);
+
+impl Component<BenchPlatform> for OpaqueComponent {
+    fn render(&self, manager: &mut Manager<BenchPlatform>) -> Element<BenchPlatform> {
+        self.0.render(manager)
+    }
+}
+
+/// List of rows. The number of rows and their contents are read from shared
+/// cells, so that the benchmarks can change them between renders.
+struct List {
+    rows: Rc<Cell<usize>>,
+    generation: Rc<Cell<usize>>,
+}
+
+impl BenchComponent for List {
+    fn render(&self, _manager: &mut Manager<BenchPlatform>) -> Element<BenchPlatform> {
+        let generation = self.generation.get();
+
+        Element::fragment(
+            Key::new(()),
+            (0..self.rows.get())
+                .map(|index| {
+                    Element::new(
+                        Key::new(index),
+                        OpaqueComponent(Rc::new(Row {
+                            value: generation + index,
+                        })),
+                        Element::empty(),
+                    )
+                })
+                .collect(),
+        )
+    }
+}
+
+struct Row {
+    value: usize,
+}
+
+impl BenchComponent for Row {
+    fn render(&self, manager: &mut Manager<BenchPlatform>) -> Element<BenchPlatform> {
+        let _selected = use_state!(manager, false);
+        let _value = use_reference!(manager, self.value);
+
+        Element::builtin(Key::new(()), Leaf, Element::empty(), None)
+    }
+}
+
+struct Tree {
+    renderer: Rc<Renderer<BenchPlatform>>,
+    root: Instance<BenchPlatform>,
+    rows: Rc<Cell<usize>>,
+    generation: Rc<Cell<usize>>,
+
+    // The renderer queues effects onto the event loop, which fails once the
+    // handler has been dropped.
+    _handler: EventLoopHandler,
+}
+
+fn mount(rows: usize) -> Tree {
+    let rows = Rc::new(Cell::new(rows));
+    let generation = Rc::new(Cell::new(0));
+
+    let (evloop, handler) = EventLoop::new(Arc::new(LocalExecutor::new()));
+    let renderer = Renderer::new(BenchCompositor::default(), evloop);
+    let root = renderer.render(
+        Element::new(
+            Key::new(()),
+            OpaqueComponent(Rc::new(List {
+                rows: rows.clone(),
+                generation: generation.clone(),
+            })),
+            Element::empty(),
+        ),
+        0,
+    );
+
+    Tree {
+        renderer,
+        root,
+        rows,
+        generation,
+        _handler: handler,
+    }
+}
+
+const SIZES: [usize; 2] = [100, 1000];
+
+fn bench_mount(c: &mut Criterion) {
+    let mut group = c.benchmark_group("mount");
+
+    for &size in SIZES.iter() {
+        group.bench_with_input(BenchmarkId::from_parameter(size), &size, |b, &size| {
+            b.iter(|| mount(size))
+        });
+    }
+
+    group.finish();
+}
+
+fn bench_update(c: &mut Criterion) {
+    let mut group = c.benchmark_group("update");
+
+    for &size in SIZES.iter() {
+        group.bench_with_input(BenchmarkId::from_parameter(size), &size, |b, &size| {
+            let tree = mount(size);
+
+            b.iter(|| {
+                tree.generation.set(tree.generation.get() + 1);
+                tree.renderer.rerender(&tree.root);
+            })
+        });
+    }
+
+    group.finish();
+}
+
+fn bench_unmount(c: &mut Criterion) {
+    let mut group = c.benchmark_group("unmount");
+
+    for &size in SIZES.iter() {
+        group.bench_with_input(BenchmarkId::from_parameter(size), &size, |b, &size| {
+            b.iter_batched(
+                || mount(size),
+                |tree| {
+                    tree.rows.set(0);
+                    tree.renderer.rerender(&tree.root);
+                    tree
+                },
+                BatchSize::LargeInput,
+            )
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_mount, bench_update, bench_unmount);
+criterion_main!(benches);
@@ -0,0 +1,185 @@
+use std::ops;
+
+/// Generational index into an arena. An index remains valid until the value
+/// it refers to is removed. After that, the slot may be reused but lookups
+/// with the old index will fail because the generation no longer matches.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Index {
+    slot: u32,
+    generation: u32,
+}
+
+enum Slot<T> {
+    Occupied {
+        generation: u32,
+        value: T,
+    },
+    Vacant {
+        generation: u32,
+        next_free: Option<u32>,
+    },
+}
+
+/// Arena that stores values in a contiguous vector and reuses the slots of
+/// removed values.
+pub struct Arena<T> {
+    slots: Vec<Slot<T>>,
+    free: Option<u32>,
+}
+
+impl<T> Arena<T> {
+    /// Returns a new empty arena.
+    pub fn new() -> Arena<T> {
+        Arena {
+            slots: vec![],
+            free: None,
+        }
+    }
+
+    /// Inserts the value that is returned by the given closure into a free
+    /// slot (or a new slot if there are no free slots) and returns its index.
+    /// The closure receives the index that the value will be stored at, which
+    /// is useful for values that need to refer to themselves.
+    pub fn insert_with<F>(&mut self, op: F) -> Index
+    where
+        F: FnOnce(Index) -> T,
+    {
+        match self.free {
+            Some(slot) => {
+                let entry = &mut self.slots[slot as usize];
+
+                let (generation, next_free) = match *entry {
+                    Slot::Vacant {
+                        generation,
+                        next_free,
+                    } => (generation, next_free),
+                    Slot::Occupied { .. } => unreachable!("Free list points to occupied slot."),
+                };
+
+                let index = Index { slot, generation };
+
+                *entry = Slot::Occupied {
+                    generation,
+                    value: op(index),
+                };
+
+                self.free = next_free;
+
+                index
+            }
+            None => {
+                let index = Index {
+                    slot: self.slots.len() as u32,
+                    generation: 0,
+                };
+
+                let value = op(index);
+                self.slots.push(Slot::Occupied {
+                    generation: 0,
+                    value,
+                });
+
+                index
+            }
+        }
+    }
+
+    /// Removes the value at the given index (if it is still present) and
+    /// returns it.
+    pub fn remove(&mut self, index: Index) -> Option<T> {
+        let entry = self.slots.get_mut(index.slot as usize)?;
+
+        match entry {
+            Slot::Occupied { generation, .. } if *generation == index.generation => {}
+            _ => return None,
+        }
+
+        let vacant = Slot::Vacant {
+            generation: index.generation.wrapping_add(1),
+            next_free: self.free,
+        };
+
+        self.free = Some(index.slot);
+
+        match std::mem::replace(entry, vacant) {
+            Slot::Occupied { value, .. } => Some(value),
+            Slot::Vacant { .. } => unreachable!(),
+        }
+    }
+
+    /// Returns a reference to the value at the given index.
+    pub fn get(&self, index: Index) -> Option<&T> {
+        match self.slots.get(index.slot as usize)? {
+            Slot::Occupied { generation, value } if *generation == index.generation => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns a mutable reference to the value at the given index.
+    pub fn get_mut(&mut self, index: Index) -> Option<&mut T> {
+        match self.slots.get_mut(index.slot as usize)? {
+            Slot::Occupied { generation, value } if *generation == index.generation => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns a boolean that indicates if the given index still refers to a
+    /// value in this arena.
+    pub fn contains(&self, index: Index) -> bool {
+        self.get(index).is_some()
+    }
+}
+
+impl<T> ops::Index<Index> for Arena<T> {
+    type Output = T;
+
+    fn index(&self, index: Index) -> &T {
+        self.get(index)
+            .expect("Index refers to a value that has been removed.")
+    }
+}
+
+impl<T> ops::IndexMut<Index> for Arena<T> {
+    fn index_mut(&mut self, index: Index) -> &mut T {
+        self.get_mut(index)
+            .expect("Index refers to a value that has been removed.")
+    }
+}
+
+impl<T> Default for Arena<T> {
+    fn default() -> Self {
+        Arena::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Arena;
+
+    #[test]
+    fn test_arena() {
+        let mut arena = Arena::new();
+
+        let a = arena.insert_with(|_| "a");
+        let b = arena.insert_with(|_| "b");
+
+        assert_eq!(arena.remove(a), Some("a"));
+        assert_eq!(arena.remove(a), None);
+        assert_eq!(arena.get(a), None);
+
+        // The slot of `a` is reused, but with a new generation.
+        let c = arena.insert_with(|_| "c");
+        assert_ne!(a, c);
+        assert_eq!(arena.get(a), None);
+        assert_eq!(arena.get(c), Some(&"c"));
+        assert_eq!(arena.get(b), Some(&"b"));
+
+        assert!(arena.contains(b));
+        assert!(!arena.contains(a));
+
+        // Values receive their own index.
+        let mut indices = Arena::new();
+        let d = indices.insert_with(|index| index);
+        assert_eq!(indices.get(d), Some(&d));
+    }
+}
@@ -1,3 +1,5 @@
+use std::any::Any;
+
 use super::Platform;
 
 pub trait Builtin<P>: Send + Sync
@@ -11,4 +13,18 @@ where
     ) -> P::Container;
 
     fn update(&self, container: &mut P::Container, environment: &mut P::Environment);
+
+    /// Returns this builtin as `Any` so that it can be encoded into a
+    /// serializable payload (see `Payload`). Builtins that return `None` are
+    /// not included in recordings.
+    fn as_any(&self) -> Option<&dyn Any> {
+        None
+    }
+
+    /// This function should return the type name of the concrete builtin. It
+    /// is used to decide if an existing container can be updated with this
+    /// builtin or should be replaced.
+    fn type_name(&self) -> &'static str {
+        std::any::type_name::<Self>()
+    }
 }
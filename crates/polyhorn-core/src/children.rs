@@ -0,0 +1,207 @@
+use super::{Component, Element, Platform};
+
+/// Key of the fragment that wraps the contents of a named slot. Slots are
+/// usually created by `poly!` (e.g. `<slot:header>...</slot:header>`) and can be
+/// retrieved with `Children::slot`.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Slot(pub &'static str);
+
+/// Flattened list of the children that were passed to a component. Named slots
+/// are kept separately and can be retrieved by their name.
+pub struct Children<P>
+where
+    P: Platform + ?Sized,
+{
+    elements: Vec<Element<P>>,
+    slots: Vec<Element<P>>,
+}
+
+impl<P> Children<P>
+where
+    P: Platform + ?Sized,
+{
+    /// Returns the children contained in the given element. Nested fragments
+    /// are flattened, with the exception of named slots.
+    pub fn new(element: &Element<P>) -> Children<P> {
+        fn collect<P>(element: &Element<P>, children: &mut Children<P>)
+        where
+            P: Platform + ?Sized,
+        {
+            match element {
+                Element::Fragment(fragment) if fragment.key.downcast_ref::<Slot>().is_some() => {
+                    children.slots.push(element.clone())
+                }
+                Element::Fragment(fragment) => fragment
+                    .elements
+                    .iter()
+                    .for_each(|element| collect(element, children)),
+                element => children.elements.push(element.clone()),
+            }
+        }
+
+        let mut children = Children {
+            elements: vec![],
+            slots: vec![],
+        };
+
+        collect(element, &mut children);
+
+        children
+    }
+
+    /// Returns the number of children, excluding named slots.
+    pub fn count(&self) -> usize {
+        self.elements.len()
+    }
+
+    /// Returns a boolean that indicates if there are no children, excluding
+    /// named slots.
+    pub fn is_empty(&self) -> bool {
+        self.elements.is_empty()
+    }
+
+    /// Returns an iterator over all children, excluding named slots.
+    pub fn iter(&self) -> impl Iterator<Item = &Element<P>> {
+        self.elements.iter()
+    }
+
+    /// Returns the only child or `None` if there are either no or multiple
+    /// children (excluding named slots).
+    pub fn only(&self) -> Option<&Element<P>> {
+        match self.elements.as_slice() {
+            [element] => Some(element),
+            _ => None,
+        }
+    }
+
+    /// Maps each child to a new element. The resulting elements inherit the
+    /// key of the child they were derived from, so that elements that are
+    /// created at the same call site do not collide.
+    pub fn map<F>(&self, mut op: F) -> Element<P>
+    where
+        F: FnMut(&Element<P>, usize) -> Element<P>,
+    {
+        self.elements
+            .iter()
+            .enumerate()
+            .map(|(index, element)| {
+                let result = op(element, index);
+
+                match element {
+                    Element::String(_) => result,
+                    element => result.with_key(element.key().clone()),
+                }
+            })
+            .collect::<Vec<_>>()
+            .into()
+    }
+
+    /// Returns the children that are instances of the given component type.
+    pub fn filter_by_type<C>(&self) -> Children<P>
+    where
+        C: 'static,
+    {
+        Children {
+            elements: self
+                .elements
+                .iter()
+                .filter(|element| match element {
+                    Element::Component(element) => element.component.downcast_ref::<C>().is_some(),
+                    _ => false,
+                })
+                .cloned()
+                .collect(),
+            slots: vec![],
+        }
+    }
+
+    /// Returns the contents of the slot with the given name (if it exists).
+    pub fn slot(&self, name: &str) -> Option<&Element<P>> {
+        self.slots.iter().find(|element| {
+            element
+                .key()
+                .downcast_ref::<Slot>()
+                .map(|slot| slot.0 == name)
+                .unwrap_or_default()
+        })
+    }
+}
+
+impl<P> From<Children<P>> for Element<P>
+where
+    P: Platform + ?Sized,
+{
+    fn from(children: Children<P>) -> Self {
+        children.elements.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Children, Slot};
+    use crate::testing::{Bar, Foo, TestPlatform};
+    use crate::{Element, Key};
+
+    type TestElement = Element<TestPlatform>;
+
+    fn children() -> TestElement {
+        Element::fragment(
+            Key::new(1),
+            vec![
+                Element::new(Key::new("a"), Foo.into(), Element::empty()),
+                Element::fragment(
+                    Key::new(2),
+                    vec![
+                        Element::new(Key::new("b"), Bar.into(), Element::empty()),
+                        Element::string("Hello"),
+                    ],
+                ),
+                Element::slot("header", vec![Element::string("Header")]),
+            ],
+        )
+    }
+
+    #[test]
+    fn test_count() {
+        let children = Children::new(&children());
+        assert_eq!(children.count(), 3);
+        assert!(children.only().is_none());
+
+        let only = Children::new(&Element::<TestPlatform>::string("Only"));
+        assert!(only.only().is_some());
+    }
+
+    #[test]
+    fn test_map_preserves_keys() {
+        let children = Children::new(&children());
+
+        let mapped = children.map(|_, index| {
+            Element::fragment(Key::new(()), vec![Element::string(&index.to_string())])
+        });
+
+        let keys = (0..3)
+            .map(|index| mapped.at(index).unwrap().key().clone())
+            .collect::<Vec<_>>();
+
+        assert_eq!(keys, vec![Key::new("a"), Key::new("b"), Key::new(())]);
+    }
+
+    #[test]
+    fn test_filter_by_type() {
+        let children = Children::new(&children());
+        let foos = children.filter_by_type::<Foo>();
+
+        assert_eq!(foos.count(), 1);
+        assert_eq!(foos.only().unwrap().key(), &Key::new("a"));
+        assert_eq!(children.filter_by_type::<Bar>().count(), 1);
+    }
+
+    #[test]
+    fn test_slot() {
+        let children = Children::new(&children());
+
+        let header = children.slot("header").unwrap();
+        assert_eq!(header.key(), &Key::new(Slot("header")));
+        assert!(children.slot("footer").is_none());
+    }
+}
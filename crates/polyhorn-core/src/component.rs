@@ -1,8 +1,31 @@
-use super::{Element, Manager, Platform};
+use super::{Element, Manager, Platform, PropsDiff};
 
 pub trait Component<P>: Clone
 where
     P: Platform + ?Sized,
 {
     fn render(&self, manager: &mut Manager<P>) -> Element<P>;
+
+    /// This function should return a reference to the concrete component if
+    /// it is of the given type. Platforms that wrap components in an opaque
+    /// type should implement this to support filtering children by type.
+    fn downcast_ref<T>(&self) -> Option<&T>
+    where
+        T: 'static,
+    {
+        None
+    }
+
+    /// This function should return the type name of the concrete component.
+    /// It is used to identify components in render diagnostics.
+    fn type_name(&self) -> &'static str {
+        std::any::type_name::<Self>()
+    }
+
+    /// This function should compare the props of this component with the props
+    /// of the given previous render. It is used by render diagnostics to report
+    /// which props changed when a parent re-renders.
+    fn diff_props(&self, _previous: &Self) -> Option<PropsDiff> {
+        None
+    }
 }
@@ -7,4 +7,9 @@ where
     fn mount(&mut self, container: &mut P::Container, environment: &mut P::Environment);
 
     fn unmount(&mut self);
+
+    /// Moves the given children, which are already mounted onto this
+    /// container, into the given order. This is used when replaying
+    /// recorded operations (see `Replayer`).
+    fn reorder(&mut self, children: &mut [&mut P::Container], environment: &mut P::Environment);
 }
@@ -33,10 +33,24 @@ impl ContextTree {
         self.insert_raw(value);
     }
 
-    pub fn insert_raw(&self, value: Rc<dyn Any>) {
+    /// This function inserts the given value and returns the value of the same
+    /// type that was previously stored in this branch (if any).
+    pub fn insert_raw(&self, value: Rc<dyn Any>) -> Option<Rc<dyn Any>> {
         self.values
             .borrow_mut()
-            .insert(value.as_ref().type_id(), value);
+            .insert(value.as_ref().type_id(), value)
+    }
+
+    /// This function returns the number of values in this branch (excluding
+    /// values of its parents).
+    pub fn len(&self) -> usize {
+        self.values.borrow().len()
+    }
+
+    /// This function returns a boolean that indicates if this branch does not
+    /// contain any values (excluding values of its parents).
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
     }
 
     pub fn get_flat<T>(&self) -> Option<Rc<T>>
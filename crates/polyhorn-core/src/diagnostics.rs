@@ -0,0 +1,429 @@
+//! Opt-in diagnostics that explain why components re-render. Once enabled
+//! (either with `observe_renders` or `log_renders`), each render of a
+//! component produces a `RenderRecord` that lists the reasons for that render.
+
+use std::any::Any;
+use std::fmt::{self, Debug, Display};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, RwLock};
+
+type Observer = Arc<dyn Fn(&RenderRecord) + Send + Sync>;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static OBSERVER: RwLock<Option<Observer>> = RwLock::new(None);
+
+/// Log target that `log_renders` emits render records to.
+const LOG_TARGET: &str = "polyhorn::render";
+
+/// Starts invoking the given observer for each render of a component. This
+/// replaces any previously installed observer.
+pub fn observe_renders<F>(observer: F)
+where
+    F: Fn(&RenderRecord) + Send + Sync + 'static,
+{
+    *OBSERVER.write().unwrap() = Some(Arc::new(observer));
+    ENABLED.store(true, Ordering::SeqCst);
+}
+
+/// Starts logging each render of a component to the `polyhorn::render` log
+/// target (at the debug level).
+pub fn log_renders() {
+    observe_renders(|record| log::debug!(target: LOG_TARGET, "{}", record))
+}
+
+/// Stops observing renders.
+pub fn stop_observing_renders() {
+    ENABLED.store(false, Ordering::SeqCst);
+    *OBSERVER.write().unwrap() = None;
+}
+
+/// Returns a boolean that indicates if render diagnostics are enabled. When
+/// disabled, the renderer does not keep track of render reasons at all.
+pub(crate) fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+pub(crate) fn emit(record: &RenderRecord) {
+    let observer = OBSERVER.read().unwrap().clone();
+
+    if let Some(observer) = observer {
+        observer(record);
+    }
+}
+
+/// Describes a single render of a component.
+#[derive(Clone, Debug)]
+pub struct RenderRecord {
+    /// ID of the instance that rendered.
+    pub instance_id: usize,
+
+    /// Type name of the component that rendered.
+    pub component: &'static str,
+
+    /// Reasons for this render. This list is empty if the render was queued by
+    /// a change that has already been processed by an earlier render.
+    pub reasons: Vec<RenderReason>,
+}
+
+impl Display for RenderRecord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} (#{}) rendered", self.component, self.instance_id)?;
+
+        for (index, reason) in self.reasons.iter().enumerate() {
+            match index {
+                0 => write!(f, " because {}", reason)?,
+                _ => write!(f, " and {}", reason)?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Reason for a render of a component.
+#[derive(Clone, Debug)]
+pub enum RenderReason {
+    /// The component was rendered for the first time.
+    Mount,
+
+    /// A state of the component was replaced. The state ID corresponds to the
+    /// order in which states are created by the component.
+    StateChanged {
+        state_id: usize,
+        type_name: &'static str,
+    },
+
+    /// A signal (or computed value) that the component read during its
+    /// previous render changed.
+    SignalChanged { type_name: &'static str },
+
+    /// A re-render was queued explicitly (e.g. through `Link::queue_rerender`).
+    Queued,
+
+    /// The value of a context that the component uses was replaced by one of
+    /// its ancestors.
+    ContextChanged { type_name: &'static str },
+
+    /// The parent of the component re-rendered. If the component can compare
+    /// its props, this contains the result of that comparison.
+    ParentRendered { props: Option<PropsDiff> },
+}
+
+impl Display for RenderReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RenderReason::Mount => write!(f, "it was mounted"),
+            RenderReason::StateChanged {
+                state_id,
+                type_name,
+            } => write!(f, "state #{} ({}) changed", state_id, type_name),
+            RenderReason::SignalChanged { type_name } => {
+                write!(f, "signal {} changed", type_name)
+            }
+            RenderReason::Queued => write!(f, "a re-render was queued"),
+            RenderReason::ContextChanged { type_name } => {
+                write!(f, "context {} changed", type_name)
+            }
+            RenderReason::ParentRendered { props: None } => write!(f, "its parent rendered"),
+            RenderReason::ParentRendered { props: Some(props) } => {
+                write!(f, "its parent rendered ({})", props)
+            }
+        }
+    }
+}
+
+/// Result of comparing the props of a component with the props it previously
+/// rendered with.
+#[derive(Clone, Debug, PartialEq)]
+pub enum PropsDiff {
+    /// The props are equal, so this render could have been avoided.
+    Unchanged,
+
+    /// The props differ. This contains the props that differ if they could be
+    /// determined, and is empty otherwise.
+    Changed(Vec<PropChange>),
+}
+
+impl PropsDiff {
+    /// Compares props using their `PartialEq` implementation. Returns `None` if
+    /// the previous props are of a different type.
+    pub fn partial_eq<T>(previous: &dyn Any, current: &T) -> Option<PropsDiff>
+    where
+        T: PartialEq + 'static,
+    {
+        let previous = previous.downcast_ref::<T>()?;
+
+        Some(match previous == current {
+            true => PropsDiff::Unchanged,
+            false => PropsDiff::Changed(vec![]),
+        })
+    }
+
+    /// Compares props using their (pretty printed) `Debug` representation,
+    /// which also reveals which props differ. Returns `None` if the previous
+    /// props are of a different type.
+    pub fn debug<T>(previous: &dyn Any, current: &T) -> Option<PropsDiff>
+    where
+        T: Debug + 'static,
+    {
+        let previous = format!("{:#?}", previous.downcast_ref::<T>()?);
+        let current = format!("{:#?}", current);
+
+        if previous == current {
+            return Some(PropsDiff::Unchanged);
+        }
+
+        Some(PropsDiff::Changed(changes(&previous, &current)))
+    }
+
+    /// Compares props using their `PartialEq` implementation and uses their
+    /// `Debug` representation to determine which props differ. Returns `None`
+    /// if the previous props are of a different type.
+    pub fn compare<T>(previous: &dyn Any, current: &T) -> Option<PropsDiff>
+    where
+        T: Debug + PartialEq + 'static,
+    {
+        match PropsDiff::partial_eq(previous, current)? {
+            PropsDiff::Unchanged => Some(PropsDiff::Unchanged),
+            PropsDiff::Changed(_) => match PropsDiff::debug(previous, current)? {
+                PropsDiff::Unchanged => Some(PropsDiff::Changed(vec![])),
+                diff => Some(diff),
+            },
+        }
+    }
+}
+
+impl Display for PropsDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PropsDiff::Unchanged => write!(f, "props are unchanged"),
+            PropsDiff::Changed(changes) if changes.is_empty() => write!(f, "props changed"),
+            PropsDiff::Changed(changes) => {
+                write!(f, "props changed: ")?;
+
+                for (index, change) in changes.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, ", ")?;
+                    }
+
+                    write!(f, "{}", change)?;
+                }
+
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Single prop that differs between two renders, along with its `Debug`
+/// representation before and after.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PropChange {
+    pub name: String,
+    pub previous: String,
+    pub current: String,
+}
+
+impl Display for PropChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {} -> {}", self.name, self.previous, self.current)
+    }
+}
+
+/// Returns the props that differ between the two given pretty printed `Debug`
+/// representations, or an empty list if these cannot be parsed.
+fn changes(previous: &str, current: &str) -> Vec<PropChange> {
+    match (fields(previous), fields(current)) {
+        (Some(previous), Some(current)) if previous.len() == current.len() => previous
+            .into_iter()
+            .zip(current)
+            .filter(|(previous, current)| previous != current)
+            .map(|((name, previous), (_, current))| PropChange {
+                name,
+                previous,
+                current,
+            })
+            .collect(),
+        _ => vec![],
+    }
+}
+
+/// Splits a pretty printed `Debug` representation of a struct into its
+/// fields. Fields of tuple structs are named after their index.
+fn fields(debug: &str) -> Option<Vec<(String, String)>> {
+    let mut lines = debug.lines();
+
+    let named = match lines.next()? {
+        header if header.ends_with('{') => true,
+        header if header.ends_with('(') => false,
+        _ => return Some(vec![]),
+    };
+
+    let mut fields: Vec<(String, String)> = vec![];
+
+    for line in lines {
+        match line.strip_prefix("    ") {
+            // Fields are indented by exactly one level.
+            Some(field) if !field.starts_with(' ') => {
+                let (name, value) = match named {
+                    true => {
+                        let mut parts = field.splitn(2, ": ");
+                        (parts.next()?.to_owned(), parts.next()?.to_owned())
+                    }
+                    false => (fields.len().to_string(), field.to_owned()),
+                };
+
+                fields.push((name, value));
+            }
+
+            // Anything that is indented further continues the last field.
+            Some(continuation) => {
+                let (_, value) = fields.last_mut()?;
+                value.push('\n');
+                value.push_str(continuation);
+            }
+
+            // This is the closing brace or parenthesis.
+            None => break,
+        }
+    }
+
+    for (_, value) in fields.iter_mut() {
+        if value.ends_with(',') {
+            value.pop();
+        }
+    }
+
+    Some(fields)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+    use std::sync::{Arc, Mutex};
+
+    use super::{observe_renders, stop_observing_renders, PropChange, PropsDiff, RenderReason};
+    use crate::testing::{render, rerender, TestComponent, TestPlatform};
+    use crate::{use_state, Element, Key, Manager, State, WeakLink};
+
+    #[derive(Debug, PartialEq)]
+    struct Props {
+        title: String,
+        count: usize,
+    }
+
+    #[test]
+    fn test_props_diff() {
+        let previous = Props {
+            title: "Hello".to_owned(),
+            count: 1,
+        };
+
+        let current = Props {
+            title: "Hello".to_owned(),
+            count: 2,
+        };
+
+        assert_eq!(
+            PropsDiff::compare(&previous, &current),
+            Some(PropsDiff::Changed(vec![PropChange {
+                name: "count".to_owned(),
+                previous: "1".to_owned(),
+                current: "2".to_owned(),
+            }]))
+        );
+
+        assert_eq!(
+            PropsDiff::compare(&current, &current),
+            Some(PropsDiff::Unchanged)
+        );
+
+        assert_eq!(PropsDiff::partial_eq(&42, &current), None);
+    }
+
+    struct Counter {
+        state: Rc<RefCell<Option<State<usize>>>>,
+    }
+
+    impl TestComponent for Counter {
+        fn render(&self, manager: &mut Manager<TestPlatform>) -> Element<TestPlatform> {
+            let state = use_state!(manager, 0);
+            self.state.replace(Some(state));
+
+            Element::new(
+                Key::new(()),
+                Label {
+                    value: *state.get(manager),
+                }
+                .into(),
+                Element::empty(),
+            )
+        }
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct Label {
+        value: usize,
+    }
+
+    impl TestComponent for Label {
+        fn render(&self, _manager: &mut Manager<TestPlatform>) -> Element<TestPlatform> {
+            Element::empty()
+        }
+
+        fn diff_props(&self, previous: &dyn std::any::Any) -> Option<PropsDiff> {
+            PropsDiff::compare(previous, self)
+        }
+    }
+
+    #[test]
+    fn test_render_reasons() {
+        let records = Arc::new(Mutex::new(vec![]));
+
+        observe_renders({
+            let records = records.clone();
+            move |record| {
+                if record.component.ends_with("Counter") || record.component.ends_with("Label") {
+                    records.lock().unwrap().push(record.to_string());
+                }
+            }
+        });
+
+        let state = Rc::new(RefCell::new(None));
+
+        let (renderer, instance, _handler) = render(Element::new(
+            Key::new(()),
+            Counter {
+                state: state.clone(),
+            }
+            .into(),
+            Element::empty(),
+        ));
+
+        let memory = instance.memory();
+        state
+            .borrow()
+            .unwrap()
+            .replace(&WeakLink::new(&instance, &memory), 1);
+        drop(memory);
+
+        rerender(&renderer, &instance);
+        stop_observing_renders();
+
+        let records = records.lock().unwrap();
+        assert_eq!(records.len(), 4);
+        assert!(records[0].ends_with("because it was mounted"));
+        assert!(records[2].ends_with("because state #0 (usize) changed"));
+        assert!(records[3].ends_with("because its parent rendered (props changed: value: 0 -> 1)"));
+
+        let reason = RenderReason::ParentRendered {
+            props: Some(PropsDiff::Unchanged),
+        };
+
+        assert_eq!(
+            reason.to_string(),
+            "its parent rendered (props are unchanged)"
+        );
+    }
+}
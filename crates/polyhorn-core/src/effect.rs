@@ -1,12 +1,10 @@
-use std::rc::Rc;
-
 use super::{Instance, Link, Memory, Platform};
 
 pub struct Effect<P>
 where
     P: Platform + ?Sized,
 {
-    instance: Rc<Instance<P>>,
+    instance: Instance<P>,
     closure: Box<dyn FnOnce(&EffectLink<P>)>,
 }
 
@@ -14,7 +12,7 @@ impl<P> Effect<P>
 where
     P: Platform + ?Sized,
 {
-    pub fn new<F>(instance: &Rc<Instance<P>>, closure: F) -> Effect<P>
+    pub fn new<F>(instance: &Instance<P>, closure: F) -> Effect<P>
     where
         F: FnOnce(&EffectLink<P>) + 'static,
     {
@@ -24,7 +22,7 @@ where
         }
     }
 
-    pub fn instance(&self) -> &Rc<Instance<P>> {
+    pub fn instance(&self) -> &Instance<P> {
         &self.instance
     }
 
@@ -37,7 +35,7 @@ pub struct LayoutEffect<P>
 where
     P: Platform + ?Sized,
 {
-    instance: Rc<Instance<P>>,
+    instance: Instance<P>,
     closure: Box<dyn FnOnce(&EffectLink<P>, &mut P::CommandBuffer)>,
 }
 
@@ -45,7 +43,7 @@ impl<P> LayoutEffect<P>
 where
     P: Platform + ?Sized,
 {
-    pub fn new<F>(instance: &Rc<Instance<P>>, closure: F) -> LayoutEffect<P>
+    pub fn new<F>(instance: &Instance<P>, closure: F) -> LayoutEffect<P>
     where
         F: FnOnce(&EffectLink<P>, &mut P::CommandBuffer) + 'static,
     {
@@ -55,7 +53,7 @@ where
         }
     }
 
-    pub fn instance(&self) -> &Rc<Instance<P>> {
+    pub fn instance(&self) -> &Instance<P> {
         &self.instance
     }
 
@@ -68,7 +66,7 @@ pub struct EffectLink<'a, P>
 where
     P: Platform + ?Sized,
 {
-    instance: &'a Rc<Instance<P>>,
+    instance: &'a Instance<P>,
     memory: &'a Memory,
 }
 
@@ -76,7 +74,7 @@ impl<'a, P> EffectLink<'a, P>
 where
     P: Platform + ?Sized,
 {
-    pub fn new(instance: &'a Rc<Instance<P>>, memory: &'a Memory) -> EffectLink<'a, P> {
+    pub fn new(instance: &'a Instance<P>, memory: &'a Memory) -> EffectLink<'a, P> {
         EffectLink { instance, memory }
     }
 }
@@ -87,7 +85,7 @@ where
 {
     type Platform = P;
 
-    fn instance(&self) -> &Rc<Instance<P>> {
+    fn instance(&self) -> &Instance<P> {
         self.instance
     }
 
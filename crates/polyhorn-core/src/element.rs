@@ -2,7 +2,7 @@ use std::any::Any;
 use std::rc::Rc;
 use std::sync::Arc;
 
-use super::{Builtin, Key, Platform, WeakReference};
+use super::{Builtin, Component, Key, Platform, Slot, WeakReference};
 
 pub struct ElementBuiltin<P>
 where
@@ -56,6 +56,7 @@ where
 {
     pub key: Key,
     pub value: Rc<dyn Any>,
+    pub type_name: &'static str,
     pub children: Box<Element<P>>,
 }
 
@@ -67,6 +68,7 @@ where
         ElementContext {
             key: self.key.clone(),
             value: self.value.clone(),
+            type_name: self.type_name,
             children: self.children.clone(),
         }
     }
@@ -143,6 +145,7 @@ where
         Element::Context(ElementContext {
             key,
             value,
+            type_name: std::any::type_name::<T>(),
             children,
         })
     }
@@ -156,6 +159,12 @@ where
         Element::Fragment(ElementFragment { key, elements })
     }
 
+    /// Returns a fragment with the given elements that is recognized as a
+    /// named slot by `Children::slot`.
+    pub fn slot(name: &'static str, elements: Vec<Element<P>>) -> Element<P> {
+        Element::fragment(Key::new(Slot(name)), elements)
+    }
+
     pub fn string(value: &str) -> Element<P> {
         Element::String(value.to_owned())
     }
@@ -170,6 +179,61 @@ where
         }
     }
 
+    /// Returns this element with the given key. Strings do not have a key and
+    /// are returned unchanged.
+    pub fn with_key(mut self, key: Key) -> Element<P> {
+        match &mut self {
+            Element::Builtin(builtin) => builtin.key = key,
+            Element::Component(component) => component.key = key,
+            Element::Context(context) => context.key = key,
+            Element::Fragment(fragment) => fragment.key = key,
+            Element::String(_) => {}
+        }
+
+        self
+    }
+
+    /// Returns a boolean that indicates if an instance of this element can be
+    /// re-rendered with the given element. This is the case if both elements
+    /// are of the same kind and, for builtins, components and contexts, of the
+    /// same type. Otherwise, the instance must be replaced.
+    pub fn reconciles_with(&self, other: &Element<P>) -> bool {
+        match (self, other) {
+            (Element::Builtin(a), Element::Builtin(b)) => {
+                a.builtin.type_name() == b.builtin.type_name()
+            }
+            (Element::Component(a), Element::Component(b)) => {
+                a.component.type_name() == b.component.type_name()
+            }
+            (Element::Context(a), Element::Context(b)) => {
+                a.value.as_ref().type_id() == b.value.as_ref().type_id()
+            }
+            (Element::Fragment(_), Element::Fragment(_)) => true,
+            _ => false,
+        }
+    }
+
+    /// Moves the children out of this element and returns them (if any) along
+    /// with the remaining element. Components keep their children because
+    /// they are passed to the component each time it renders.
+    pub(crate) fn split(self) -> (Element<P>, Option<Vec<Element<P>>>) {
+        match self {
+            Element::Builtin(mut element) => {
+                let children = std::mem::take(&mut *element.children);
+                (Element::Builtin(element), Some(vec![children]))
+            }
+            Element::Context(mut element) => {
+                let children = std::mem::take(&mut *element.children);
+                (Element::Context(element), Some(vec![children]))
+            }
+            Element::Fragment(mut element) => {
+                let elements = std::mem::take(&mut element.elements);
+                (Element::Fragment(element), Some(elements))
+            }
+            element => (element, None),
+        }
+    }
+
     pub fn to_vec(&self) -> Vec<&Element<P>> {
         let mut results = vec![];
 
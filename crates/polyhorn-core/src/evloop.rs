@@ -8,20 +8,32 @@ use std::future::Future;
 use std::mem::take;
 use std::pin::Pin;
 use std::rc::Rc;
+use std::sync::Arc;
 
-use crate::Disposable;
+use crate::{Disposable, Executor, WorkerPool};
 
 /// Event loop that is used internally.
 pub struct EventLoop {
     state: Rc<RefCell<BusState>>,
     tx: mpsc::Sender<Message>,
+    executor: Arc<dyn Executor>,
+    workers: WorkerPool,
 }
 
 impl EventLoop {
     /// Returns a new loop and its handler. The handler should be send to the
     /// dedicated Polyhorn thread while the reference-counted loop itself can be
     /// shared among all threads.
-    pub fn new() -> (EventLoop, EventLoopHandler) {
+    pub fn new(executor: Arc<dyn Executor>) -> (EventLoop, EventLoopHandler) {
+        EventLoop::with_workers(executor, WorkerPool::default())
+    }
+
+    /// Returns a new loop and its handler that run CPU-heavy work on the given
+    /// pool of worker threads (see `EventLoop::new`).
+    pub fn with_workers(
+        executor: Arc<dyn Executor>,
+        workers: WorkerPool,
+    ) -> (EventLoop, EventLoopHandler) {
         let state = Rc::new(RefCell::new(BusState::default()));
 
         let (tx, rx) = mpsc::channel::<Message>(1024);
@@ -30,11 +42,40 @@ impl EventLoop {
             EventLoop {
                 state: state.clone(),
                 tx,
+                executor,
+                workers,
             },
             EventLoopHandler { state, rx },
         )
     }
 
+    /// Blocks the current thread on a new event loop that is driven by the
+    /// given executor. The given task is invoked with the event loop once it
+    /// has started and the disposable it returns is retained until the loop
+    /// terminates.
+    pub fn run<F>(executor: Arc<dyn Executor>, task: F)
+    where
+        F: FnOnce(EventLoop) -> Disposable + 'static,
+    {
+        executor.clone().block_on(Box::pin(async move {
+            let (evloop, handler) = EventLoop::new(executor);
+            let _result = task(evloop);
+
+            handler.main().await;
+        }))
+    }
+
+    /// Returns the executor that drives this event loop.
+    pub fn executor(&self) -> &Arc<dyn Executor> {
+        &self.executor
+    }
+
+    /// Returns the pool of worker threads that runs CPU-heavy work off the
+    /// render thread.
+    pub fn workers(&self) -> &WorkerPool {
+        &self.workers
+    }
+
     pub fn queue<F>(&self, task: F) -> Disposable
     where
         F: Future<Output = ()> + 'static,
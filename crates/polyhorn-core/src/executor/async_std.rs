@@ -0,0 +1,33 @@
+use async_std::task;
+use std::time::Duration;
+
+use super::{BoxFuture, Executor, LocalBoxFuture};
+
+/// Executor that is backed by the global async-std runtime.
+#[derive(Default)]
+pub struct AsyncStdExecutor;
+
+impl AsyncStdExecutor {
+    /// Returns a new executor that is backed by the global async-std runtime.
+    pub fn new() -> AsyncStdExecutor {
+        AsyncStdExecutor
+    }
+}
+
+impl Executor for AsyncStdExecutor {
+    fn block_on(&self, future: LocalBoxFuture) {
+        task::block_on(future)
+    }
+
+    fn spawn(&self, future: BoxFuture) {
+        task::spawn(future);
+    }
+
+    fn delay(&self, duration: Duration) -> BoxFuture {
+        Box::pin(task::sleep(duration))
+    }
+
+    fn spawn_blocking(&self, task: Box<dyn FnOnce() + Send>) {
+        task::spawn_blocking(task);
+    }
+}
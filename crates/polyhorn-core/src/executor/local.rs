@@ -0,0 +1,245 @@
+use futures::channel::{mpsc, oneshot};
+use futures::select;
+use futures::stream::FuturesUnordered;
+use futures::{FutureExt, StreamExt};
+use std::cmp::{Ordering, Reverse};
+use std::collections::BinaryHeap;
+use std::sync::{Arc, Condvar, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use super::{BoxFuture, Executor, LocalBoxFuture};
+
+/// Single-threaded executor that polls all spawned futures on the thread that
+/// drives the event loop. Timers are driven by a single timer thread and
+/// blocking work is offloaded to dedicated threads. This executor does not
+/// depend on any external runtime.
+pub struct LocalExecutor {
+    tx: mpsc::UnboundedSender<BoxFuture>,
+    rx: Mutex<Option<mpsc::UnboundedReceiver<BoxFuture>>>,
+    timers: Arc<Timers>,
+}
+
+impl LocalExecutor {
+    /// Returns a new local executor.
+    pub fn new() -> LocalExecutor {
+        let (tx, rx) = mpsc::unbounded();
+
+        LocalExecutor {
+            tx,
+            rx: Mutex::new(Some(rx)),
+            timers: Arc::new(Timers::default()),
+        }
+    }
+}
+
+impl Default for LocalExecutor {
+    fn default() -> Self {
+        LocalExecutor::new()
+    }
+}
+
+impl Executor for LocalExecutor {
+    fn block_on(&self, future: LocalBoxFuture) {
+        let mut rx = self
+            .rx
+            .lock()
+            .unwrap()
+            .take()
+            .expect("Local executor is already blocking on another future.");
+
+        futures::executor::block_on(async {
+            let mut tasks = FuturesUnordered::new();
+            let mut future = future.fuse();
+
+            loop {
+                select! {
+                    _ = future => break,
+                    task = rx.next() => {
+                        if let Some(task) = task {
+                            tasks.push(task);
+                        }
+                    },
+                    _ = tasks.next() => {},
+                };
+            }
+        });
+
+        self.rx.lock().unwrap().replace(rx);
+    }
+
+    fn spawn(&self, future: BoxFuture) {
+        // This will always succeed because the executor itself owns the
+        // receiver.
+        let _ = self.tx.unbounded_send(future);
+    }
+
+    fn delay(&self, duration: Duration) -> BoxFuture {
+        let timers = self.timers.clone();
+
+        Box::pin(async move {
+            let (tx, rx) = oneshot::channel();
+            timers.insert(Instant::now() + duration, tx);
+
+            let _ = rx.await;
+        })
+    }
+
+    fn spawn_blocking(&self, task: Box<dyn FnOnce() + Send>) {
+        thread::spawn(task);
+    }
+}
+
+impl Drop for LocalExecutor {
+    fn drop(&mut self) {
+        let mut state = self.timers.state.lock().unwrap();
+        state.terminated = true;
+        state.timers.clear();
+
+        self.timers.condvar.notify_all();
+    }
+}
+
+/// Timers of a local executor, which are all fired by a single thread that is
+/// spawned once the first timer is inserted.
+#[derive(Default)]
+struct Timers {
+    state: Mutex<TimerState>,
+    condvar: Condvar,
+}
+
+#[derive(Default)]
+struct TimerState {
+    timers: BinaryHeap<Reverse<Timer>>,
+    sequence: usize,
+    running: bool,
+    terminated: bool,
+}
+
+struct Timer {
+    deadline: Instant,
+    sequence: usize,
+    tx: oneshot::Sender<()>,
+}
+
+impl Timer {
+    fn key(&self) -> (Instant, usize) {
+        (self.deadline, self.sequence)
+    }
+}
+
+impl PartialEq for Timer {
+    fn eq(&self, other: &Self) -> bool {
+        self.key() == other.key()
+    }
+}
+
+impl Eq for Timer {}
+
+impl PartialOrd for Timer {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Timer {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.key().cmp(&other.key())
+    }
+}
+
+impl Timers {
+    fn insert(self: &Arc<Self>, deadline: Instant, tx: oneshot::Sender<()>) {
+        let mut state = self.state.lock().unwrap();
+
+        let sequence = state.sequence;
+        state.sequence += 1;
+        state.timers.push(Reverse(Timer {
+            deadline,
+            sequence,
+            tx,
+        }));
+
+        if state.running {
+            self.condvar.notify_one();
+        } else {
+            state.running = true;
+
+            let timers = self.clone();
+
+            thread::Builder::new()
+                .name("com.glacyr.Polyhorn.Timer".to_owned())
+                .spawn(move || timers.run())
+                .unwrap();
+        }
+    }
+
+    fn run(&self) {
+        let mut state = self.state.lock().unwrap();
+
+        loop {
+            if state.terminated {
+                return;
+            }
+
+            let now = Instant::now();
+
+            while state
+                .timers
+                .peek()
+                .map(|Reverse(timer)| timer.deadline <= now)
+                .unwrap_or_default()
+            {
+                let Reverse(timer) = state.timers.pop().unwrap();
+
+                // Note: we ignore the result. The result will be an error if
+                // the delay has been dropped in the meantime.
+                let _ = timer.tx.send(());
+            }
+
+            state = match state.timers.peek() {
+                Some(Reverse(timer)) => {
+                    let timeout = timer.deadline - now;
+                    self.condvar.wait_timeout(state, timeout).unwrap().0
+                }
+                None => self.condvar.wait(state).unwrap(),
+            };
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::channel::mpsc;
+    use futures::StreamExt;
+    use std::time::Duration;
+
+    use super::LocalExecutor;
+    use crate::Executor;
+
+    #[test]
+    fn test_delays_resolve_in_order() {
+        let executor = LocalExecutor::new();
+        let (tx, rx) = mpsc::unbounded();
+
+        for &millis in &[60u64, 20, 40] {
+            let delay = executor.delay(Duration::from_millis(millis));
+            let tx = tx.clone();
+
+            executor.spawn(Box::pin(async move {
+                delay.await;
+                tx.unbounded_send(millis).unwrap();
+            }));
+        }
+
+        drop(tx);
+
+        executor.block_on(Box::pin(async move {
+            assert_eq!(rx.collect::<Vec<_>>().await, vec![20, 40, 60]);
+        }));
+
+        // All delays share a single timer thread.
+        assert!(executor.timers.state.lock().unwrap().running);
+        assert!(executor.timers.state.lock().unwrap().timers.is_empty());
+    }
+}
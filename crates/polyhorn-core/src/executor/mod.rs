@@ -0,0 +1,67 @@
+use futures::channel::oneshot;
+use std::future::Future;
+use std::pin::Pin;
+use std::time::Duration;
+
+#[cfg(feature = "async-std")]
+mod async_std;
+mod local;
+mod test;
+#[cfg(feature = "tokio")]
+mod tokio;
+
+#[cfg(feature = "async-std")]
+pub use self::async_std::AsyncStdExecutor;
+pub use self::local::LocalExecutor;
+pub use self::test::TestExecutor;
+#[cfg(feature = "tokio")]
+pub use self::tokio::TokioExecutor;
+
+/// Future that is not `Send` and can only be polled on the render thread.
+pub type LocalBoxFuture = Pin<Box<dyn Future<Output = ()>>>;
+
+/// Future that is `Send` and can be polled on any thread.
+pub type BoxFuture = Pin<Box<dyn Future<Output = ()> + Send>>;
+
+/// Runtime that drives the event loop. Spawning tasks, creating timers and
+/// offloading blocking work all go through the executor, which means that
+/// Polyhorn itself does not depend on a specific async runtime.
+pub trait Executor: Send + Sync + 'static {
+    /// This function should run the given future to completion on the current
+    /// thread. Polyhorn invokes this function from its dedicated render thread
+    /// with a future that drives the event loop.
+    fn block_on(&self, future: LocalBoxFuture);
+
+    /// This function should spawn the given future. The future may be polled
+    /// on any thread.
+    fn spawn(&self, future: BoxFuture);
+
+    /// This function should return a future that resolves once the given
+    /// duration has elapsed since it was first polled.
+    fn delay(&self, duration: Duration) -> BoxFuture;
+
+    /// This function should run the given closure on a thread where blocking
+    /// is acceptable.
+    fn spawn_blocking(&self, task: Box<dyn FnOnce() + Send>);
+}
+
+impl dyn Executor {
+    /// Runs the given closure on a thread where blocking is acceptable and
+    /// returns a future that resolves to its result. The future resolves to an
+    /// error if the closure panics.
+    pub fn unblock<F, T>(&self, task: F) -> impl Future<Output = Result<T, oneshot::Canceled>>
+    where
+        F: FnOnce() -> T + Send + 'static,
+        T: Send + 'static,
+    {
+        let (tx, rx) = oneshot::channel();
+
+        self.spawn_blocking(Box::new(move || {
+            // Note: we ignore the result. The result will be an error if the
+            // receiver has been dropped in the meantime.
+            let _ = tx.send(task());
+        }));
+
+        rx
+    }
+}
@@ -0,0 +1,195 @@
+use futures::channel::oneshot;
+use futures::executor::LocalPool;
+use futures::task::{LocalSpawnExt, SpawnExt};
+use std::cell::Cell;
+use std::mem::take;
+use std::rc::Rc;
+use std::sync::{Arc, Mutex};
+use std::thread::{self, Thread};
+use std::time::Duration;
+
+use super::{BoxFuture, Executor, LocalBoxFuture};
+
+/// Deterministic executor with a virtual clock that is intended for tests.
+/// Whenever all futures are stalled, the clock is advanced to the earliest
+/// pending timer, so delays resolve immediately and in order of their
+/// deadlines. Blocking work runs synchronously on the calling thread.
+#[derive(Clone, Default)]
+pub struct TestExecutor {
+    state: Arc<Mutex<TestState>>,
+}
+
+#[derive(Default)]
+struct TestState {
+    elapsed: Duration,
+    sequence: usize,
+    timers: Vec<Timer>,
+    spawned: Vec<BoxFuture>,
+    thread: Option<Thread>,
+}
+
+struct Timer {
+    deadline: Duration,
+    sequence: usize,
+    tx: oneshot::Sender<()>,
+}
+
+impl TestState {
+    fn next_timer(&mut self) -> Option<Timer> {
+        let index = self
+            .timers
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, timer)| (timer.deadline, timer.sequence))
+            .map(|(index, _)| index)?;
+
+        Some(self.timers.swap_remove(index))
+    }
+}
+
+impl TestExecutor {
+    /// Returns a new test executor with its virtual clock set to zero.
+    pub fn new() -> TestExecutor {
+        TestExecutor::default()
+    }
+
+    /// Returns the virtual time that has elapsed since this executor was
+    /// created.
+    pub fn elapsed(&self) -> Duration {
+        self.state.lock().unwrap().elapsed
+    }
+}
+
+impl Executor for TestExecutor {
+    fn block_on(&self, future: LocalBoxFuture) {
+        let mut pool = LocalPool::new();
+        let spawner = pool.spawner();
+        let done = Rc::new(Cell::new(false));
+
+        spawner
+            .spawn_local({
+                let done = done.clone();
+
+                async move {
+                    future.await;
+                    done.set(true);
+                }
+            })
+            .unwrap();
+
+        self.state.lock().unwrap().thread = Some(thread::current());
+
+        while !done.get() {
+            for task in take(&mut self.state.lock().unwrap().spawned) {
+                spawner.spawn(task).unwrap();
+            }
+
+            pool.run_until_stalled();
+
+            if done.get() {
+                break;
+            }
+
+            let mut state = self.state.lock().unwrap();
+
+            if !state.spawned.is_empty() {
+                continue;
+            }
+
+            if let Some(timer) = state.next_timer() {
+                state.elapsed = state.elapsed.max(timer.deadline);
+                drop(state);
+
+                let _ = timer.tx.send(());
+                continue;
+            }
+
+            drop(state);
+
+            // Nothing can make progress until another thread wakes one of our
+            // futures.
+            thread::park();
+        }
+
+        self.state.lock().unwrap().thread = None;
+    }
+
+    fn spawn(&self, future: BoxFuture) {
+        let mut state = self.state.lock().unwrap();
+        state.spawned.push(future);
+
+        if let Some(thread) = state.thread.as_ref() {
+            thread.unpark();
+        }
+    }
+
+    fn delay(&self, duration: Duration) -> BoxFuture {
+        let state = self.state.clone();
+
+        Box::pin(async move {
+            let (tx, rx) = oneshot::channel();
+
+            {
+                let mut state = state.lock().unwrap();
+                let deadline = state.elapsed + duration;
+                let sequence = state.sequence;
+                state.sequence += 1;
+                state.timers.push(Timer {
+                    deadline,
+                    sequence,
+                    tx,
+                });
+            }
+
+            let _ = rx.await;
+        })
+    }
+
+    fn spawn_blocking(&self, task: Box<dyn FnOnce() + Send>) {
+        task()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::channel::mpsc;
+    use futures::StreamExt;
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use super::TestExecutor;
+    use crate::Executor;
+
+    #[test]
+    fn test_delays_resolve_in_order() {
+        let executor = TestExecutor::new();
+        let (tx, rx) = mpsc::unbounded();
+
+        for &millis in &[30u64, 10, 20] {
+            let delay = executor.delay(Duration::from_millis(millis));
+            let tx = tx.clone();
+
+            executor.spawn(Box::pin(async move {
+                delay.await;
+                tx.unbounded_send(millis).unwrap();
+            }));
+        }
+
+        drop(tx);
+
+        executor.block_on(Box::pin(async move {
+            assert_eq!(rx.collect::<Vec<_>>().await, vec![10, 20, 30]);
+        }));
+
+        assert_eq!(executor.elapsed(), Duration::from_millis(30));
+    }
+
+    #[test]
+    fn test_unblock() {
+        let executor: Arc<dyn Executor> = Arc::new(TestExecutor::new());
+
+        executor.clone().block_on(Box::pin(async move {
+            assert_eq!(executor.unblock(|| 40 + 2).await, Ok(42));
+        }));
+    }
+}
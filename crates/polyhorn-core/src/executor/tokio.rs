@@ -0,0 +1,49 @@
+use std::time::Duration;
+use tokio::runtime::Runtime;
+
+use super::{BoxFuture, Executor, LocalBoxFuture};
+
+/// Executor that is backed by a Tokio 0.2 runtime.
+pub struct TokioExecutor {
+    runtime: Runtime,
+}
+
+impl TokioExecutor {
+    /// Returns a new executor with a default multi-threaded Tokio runtime.
+    pub fn new() -> TokioExecutor {
+        TokioExecutor::with_runtime(Runtime::new().unwrap())
+    }
+
+    /// Returns a new executor that is backed by the given Tokio runtime.
+    pub fn with_runtime(runtime: Runtime) -> TokioExecutor {
+        TokioExecutor { runtime }
+    }
+}
+
+impl Default for TokioExecutor {
+    fn default() -> Self {
+        TokioExecutor::new()
+    }
+}
+
+impl Executor for TokioExecutor {
+    fn block_on(&self, future: LocalBoxFuture) {
+        self.runtime.handle().block_on(future)
+    }
+
+    fn spawn(&self, future: BoxFuture) {
+        self.runtime.handle().spawn(future);
+    }
+
+    fn delay(&self, duration: Duration) -> BoxFuture {
+        let handle = self.runtime.handle().clone();
+
+        Box::pin(async move {
+            handle.enter(|| tokio::time::delay_for(duration)).await;
+        })
+    }
+
+    fn spawn_blocking(&self, task: Box<dyn FnOnce() + Send>) {
+        self.runtime.handle().spawn_blocking(task);
+    }
+}
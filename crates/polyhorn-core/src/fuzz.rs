@@ -0,0 +1,677 @@
+//! Property-based tests for reconciliation. Each case renders a random tree
+//! of builtins, components, fragments, empty elements and strings, applies a
+//! random sequence of edits and re-renders. After each render, we check that
+//! the container tree matches the element tree and that elements that kept
+//! their place (the same keys along the path from the root) kept their
+//! memory and their container.
+
+use proptest::collection::vec;
+use proptest::option;
+use proptest::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::any::Any;
+use std::cell::{Cell, RefCell};
+use std::collections::{HashMap, HashSet};
+use std::marker::PhantomData;
+use std::rc::Rc;
+use std::sync::Arc;
+
+use crate::testing::{TestComponent, TestCompositor, TestContainer, TestPlatform};
+use crate::{use_reference, Builtin, Element, EventLoop, Key, Manager, Operation, Payload};
+use crate::{Renderer, TestExecutor};
+
+const ROOT: usize = usize::MAX;
+
+#[derive(Clone, Debug)]
+enum Node {
+    Builtin {
+        key: usize,
+        children: Vec<Node>,
+    },
+    Component {
+        alt: bool,
+        key: usize,
+        children: Vec<Node>,
+    },
+    Fragment {
+        key: Option<usize>,
+        children: Vec<Node>,
+    },
+    Empty,
+    Text {
+        key: usize,
+        strings: Vec<String>,
+    },
+}
+
+impl Node {
+    fn children_mut(&mut self) -> Option<&mut Vec<Node>> {
+        match self {
+            Node::Builtin { children, .. }
+            | Node::Component { children, .. }
+            | Node::Fragment { children, .. } => Some(children),
+            Node::Empty | Node::Text { .. } => None,
+        }
+    }
+
+    fn rekey(&mut self, new_key: usize) {
+        match self {
+            Node::Builtin { key, .. } | Node::Component { key, .. } | Node::Text { key, .. } => {
+                *key = new_key
+            }
+            Node::Fragment { key, .. } => *key = Some(new_key),
+            Node::Empty => {}
+        }
+    }
+}
+
+/// Small key spaces make it likely that siblings share keys and that edits
+/// preserve some of them.
+fn node() -> impl Strategy<Value = Node> {
+    let leaf = prop_oneof![
+        Just(Node::Empty),
+        (0..3usize, vec("[ab]", 0..3)).prop_map(|(key, strings)| Node::Text { key, strings }),
+    ];
+
+    leaf.prop_recursive(3, 24, 4, |inner| {
+        prop_oneof![
+            (0..3usize, vec(inner.clone(), 0..4))
+                .prop_map(|(key, children)| Node::Builtin { key, children }),
+            (any::<bool>(), 0..3usize, vec(inner.clone(), 0..4))
+                .prop_map(|(alt, key, children)| Node::Component { alt, key, children }),
+            (option::of(0..3usize), vec(inner, 0..4))
+                .prop_map(|(key, children)| Node::Fragment { key, children }),
+        ]
+    })
+}
+
+#[derive(Clone, Debug)]
+enum Edit {
+    Insert {
+        list: usize,
+        index: usize,
+        node: Node,
+    },
+    Remove {
+        list: usize,
+        index: usize,
+    },
+    Swap {
+        list: usize,
+        a: usize,
+        b: usize,
+    },
+    Rekey {
+        list: usize,
+        index: usize,
+        key: usize,
+    },
+}
+
+fn edit() -> impl Strategy<Value = Edit> {
+    prop_oneof![
+        (any::<usize>(), any::<usize>(), node()).prop_map(|(list, index, node)| Edit::Insert {
+            list,
+            index,
+            node
+        }),
+        (any::<usize>(), any::<usize>()).prop_map(|(list, index)| Edit::Remove { list, index }),
+        (any::<usize>(), any::<usize>(), any::<usize>()).prop_map(|(list, a, b)| Edit::Swap {
+            list,
+            a,
+            b
+        }),
+        (any::<usize>(), any::<usize>(), 0..3usize).prop_map(|(list, index, key)| Edit::Rekey {
+            list,
+            index,
+            key
+        }),
+    ]
+}
+
+fn count_lists(list: &[Node]) -> usize {
+    1 + list
+        .iter()
+        .map(|node| match node {
+            Node::Builtin { children, .. }
+            | Node::Component { children, .. }
+            | Node::Fragment { children, .. } => count_lists(children),
+            Node::Empty | Node::Text { .. } => 0,
+        })
+        .sum::<usize>()
+}
+
+/// Returns the n-th list of siblings in pre-order.
+fn nth_list<'a>(list: &'a mut Vec<Node>, n: &mut usize) -> Option<&'a mut Vec<Node>> {
+    if *n == 0 {
+        return Some(list);
+    }
+
+    *n -= 1;
+
+    for node in list.iter_mut() {
+        if let Some(found) = node
+            .children_mut()
+            .and_then(|children| nth_list(children, n))
+        {
+            return Some(found);
+        }
+    }
+
+    None
+}
+
+impl Edit {
+    /// Applies this edit to one of the lists of siblings in the given tree.
+    /// Indices wrap around, so every edit applies to any tree.
+    fn apply(self, tree: &mut Vec<Node>) {
+        let mut n = match self {
+            Edit::Insert { list, .. }
+            | Edit::Remove { list, .. }
+            | Edit::Swap { list, .. }
+            | Edit::Rekey { list, .. } => list % count_lists(tree),
+        };
+
+        let list = nth_list(tree, &mut n).unwrap();
+        let len = list.len();
+
+        match self {
+            Edit::Insert { index, node, .. } => list.insert(index % (len + 1), node),
+            Edit::Remove { index, .. } if len > 0 => {
+                list.remove(index % len);
+            }
+            Edit::Swap { a, b, .. } if len > 0 => list.swap(a % len, b % len),
+            Edit::Rekey { index, key, .. } if len > 0 => list[index % len].rekey(key),
+            _ => {}
+        }
+    }
+}
+
+/// Returns the kind and key of the element that the given node is rendered
+/// as. The kind distinguishes elements with the same key that can't be
+/// reconciled with each other.
+fn identify(node: &Node) -> (&'static str, Key) {
+    match node {
+        Node::Builtin { key, .. } => ("B", Key::new(*key)),
+        Node::Component {
+            alt: false, key, ..
+        } => ("C", Key::new(*key)),
+        Node::Component { alt: true, key, .. } => ("D", Key::new(*key)),
+        Node::Fragment { key: Some(key), .. } => ("F", Key::new(*key)),
+        Node::Fragment { key: None, .. } => ("F", Key::new(())),
+        Node::Empty => ("F", Key::new("empty")),
+        Node::Text { key, .. } => ("T", Key::new(*key)),
+    }
+}
+
+/// Assigns paths to a list of siblings. Each node is identified by the kinds
+/// and keys of the elements along its path from the root. Siblings with equal
+/// keys are told apart by their occurrence.
+struct Paths<'a> {
+    parent: &'a str,
+    occurrences: HashMap<Key, usize>,
+}
+
+impl<'a> Paths<'a> {
+    fn new(parent: &'a str) -> Paths<'a> {
+        Paths {
+            parent,
+            occurrences: HashMap::new(),
+        }
+    }
+
+    fn next(&mut self, node: &Node) -> (String, Key) {
+        let (kind, key) = identify(node);
+        let occurrence = self.occurrences.entry(key.clone()).or_insert(0);
+        let path = format!("{}/{}{:?}#{}", self.parent, kind, key, occurrence);
+        *occurrence += 1;
+
+        (path, key)
+    }
+}
+
+/// Returns the path of the fragment that a builtin or component at the given
+/// path passes its children in.
+fn nested(path: &str) -> String {
+    format!("{}/F{:?}#0", path, Key::new(()))
+}
+
+fn root() -> String {
+    format!("/B{:?}#0", Key::new("root"))
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+struct Widget(String);
+
+impl Builtin<TestPlatform> for Widget {
+    fn instantiate(&self, _parent: &mut TestContainer, _environment: &mut ()) -> TestContainer {
+        TestContainer
+    }
+
+    fn update(&self, _container: &mut TestContainer, _environment: &mut ()) {}
+
+    fn as_any(&self) -> Option<&dyn Any> {
+        Some(self)
+    }
+}
+
+impl Payload<TestPlatform> for Widget {
+    type Builtin = Widget;
+
+    fn encode(builtin: &Widget) -> Widget {
+        builtin.clone()
+    }
+
+    fn decode(self) -> Widget {
+        self
+    }
+}
+
+/// Shared between all probes of a single case.
+#[derive(Default)]
+struct Probes {
+    counter: Cell<usize>,
+    tokens: RefCell<HashMap<String, usize>>,
+}
+
+struct Primary;
+struct Alternate;
+
+/// Component that passes its children through and reports a token that is
+/// stored in its memory when it is first rendered. The marker distinguishes
+/// two component types.
+struct Probe<M> {
+    path: String,
+    probes: Rc<Probes>,
+    marker: PhantomData<M>,
+}
+
+impl<M> TestComponent for Probe<M>
+where
+    M: 'static,
+{
+    fn render(&self, manager: &mut Manager<TestPlatform>) -> Element<TestPlatform> {
+        let probes = self.probes.clone();
+        let token = use_reference!(manager, {
+            let token = probes.counter.get();
+            probes.counter.set(token + 1);
+            token
+        })
+        .cloned(manager);
+
+        let previous = self
+            .probes
+            .tokens
+            .borrow_mut()
+            .insert(self.path.clone(), token);
+        assert_eq!(previous, None, "Probe {} rendered twice.", self.path);
+
+        manager.children()
+    }
+}
+
+/// Component that concatenates its string children into a single widget.
+struct Text;
+
+impl TestComponent for Text {
+    fn render(&self, manager: &mut Manager<TestPlatform>) -> Element<TestPlatform> {
+        let text = manager
+            .children()
+            .to_vec()
+            .into_iter()
+            .filter_map(|element| match element {
+                Element::String(string) => Some(string.as_str()),
+                _ => None,
+            })
+            .collect::<String>();
+
+        Element::builtin(
+            Key::new(()),
+            Widget(format!("t:{}", text)),
+            Element::empty(),
+            None,
+        )
+    }
+}
+
+struct Harness {
+    tree: Rc<RefCell<Vec<Node>>>,
+    probes: Rc<Probes>,
+}
+
+impl Harness {
+    /// Converts the given nodes into elements (see `Paths`).
+    fn build(&self, nodes: &[Node], path: &str) -> Vec<Element<TestPlatform>> {
+        let mut paths = Paths::new(path);
+
+        nodes
+            .iter()
+            .map(|node| {
+                let (path, key) = paths.next(node);
+                self.element(node, key, &path)
+            })
+            .collect()
+    }
+
+    /// Returns the fragment that a builtin or component passes its children
+    /// in.
+    fn children(&self, nodes: &[Node], path: &str) -> Element<TestPlatform> {
+        Element::fragment(Key::new(()), self.build(nodes, &nested(path)))
+    }
+
+    fn element(&self, node: &Node, key: Key, path: &str) -> Element<TestPlatform> {
+        match node {
+            Node::Builtin {
+                key: name,
+                children,
+            } => Element::builtin(
+                key,
+                Widget(format!("b{}", name)),
+                self.children(children, path),
+                None,
+            ),
+            &Node::Component {
+                alt, ref children, ..
+            } => {
+                let probes = self.probes.clone();
+                let path = path.to_owned();
+                let children = self.children(children, &path);
+
+                if alt {
+                    let probe = Probe::<Alternate> {
+                        path,
+                        probes,
+                        marker: PhantomData,
+                    };
+                    Element::new(key, probe.into(), children)
+                } else {
+                    let probe = Probe::<Primary> {
+                        path,
+                        probes,
+                        marker: PhantomData,
+                    };
+                    Element::new(key, probe.into(), children)
+                }
+            }
+            Node::Fragment { children, .. } => Element::fragment(key, self.build(children, path)),
+            Node::Empty => Element::empty(),
+            Node::Text { strings, .. } => {
+                let strings = strings
+                    .iter()
+                    .map(|string| Element::string(string))
+                    .collect();
+                Element::new(key, Text.into(), Element::fragment(Key::new(()), strings))
+            }
+        }
+    }
+}
+
+impl TestComponent for Harness {
+    fn render(&self, _manager: &mut Manager<TestPlatform>) -> Element<TestPlatform> {
+        let tree = self.tree.borrow();
+
+        Element::builtin(
+            Key::new("root"),
+            Widget("root".to_owned()),
+            self.children(&tree, &root()),
+            None,
+        )
+    }
+}
+
+/// Tree of labels, where each node is tagged with either its path (in the
+/// expected tree) or its container (in the rendered tree).
+#[derive(Debug)]
+struct Tree<T> {
+    label: String,
+    tag: T,
+    children: Vec<Tree<T>>,
+}
+
+impl<T> Tree<T> {
+    fn shape(&self) -> String {
+        let children = self
+            .children
+            .iter()
+            .map(Tree::shape)
+            .collect::<Vec<_>>()
+            .join(",");
+
+        format!("{}[{}]", self.label, children)
+    }
+
+    fn tags<'a>(&'a self, tags: &mut Vec<&'a T>) {
+        tags.push(&self.tag);
+        self.children.iter().for_each(|child| child.tags(tags));
+    }
+}
+
+/// Returns the containers that the given nodes should render into, tagged
+/// with their paths, and collects the paths of components.
+fn expected(
+    nodes: &[Node],
+    path: &str,
+    trees: &mut Vec<Tree<String>>,
+    components: &mut HashSet<String>,
+) {
+    let mut paths = Paths::new(path);
+
+    for node in nodes {
+        let (path, _) = paths.next(node);
+
+        match node {
+            Node::Builtin { key, children } => {
+                let mut nested_trees = vec![];
+                expected(children, &nested(&path), &mut nested_trees, components);
+
+                trees.push(Tree {
+                    label: format!("b{}", key),
+                    tag: path,
+                    children: nested_trees,
+                });
+            }
+            Node::Component { children, .. } => {
+                expected(children, &nested(&path), trees, components);
+                components.insert(path);
+            }
+            Node::Fragment { children, .. } => expected(children, &path, trees, components),
+            Node::Empty => {}
+            Node::Text { strings, .. } => trees.push(Tree {
+                label: format!("t:{}", strings.concat()),
+                tag: format!("{}/B{:?}#0", path, Key::new(())),
+                children: vec![],
+            }),
+        }
+    }
+}
+
+/// Mirror of the container tree that is maintained by applying recorded
+/// operations.
+#[derive(Default)]
+struct Mirror {
+    labels: HashMap<usize, String>,
+    parents: HashMap<usize, usize>,
+    children: HashMap<usize, Vec<usize>>,
+}
+
+impl Mirror {
+    fn apply(&mut self, operation: Operation<usize, Widget>) {
+        match operation {
+            Operation::Mount {
+                id,
+                parent,
+                builtin,
+            } => {
+                assert!(!self.labels.contains_key(&id), "Container {} reused.", id);
+                assert!(parent == ROOT || self.labels.contains_key(&parent));
+
+                self.labels.insert(id, builtin.0);
+                self.parents.insert(id, parent);
+                self.children.entry(parent).or_default().push(id);
+            }
+            Operation::Update { id, builtin } => {
+                *self
+                    .labels
+                    .get_mut(&id)
+                    .expect("Update of unknown container.") = builtin.0;
+            }
+            Operation::Unmount { id } => {
+                self.labels
+                    .remove(&id)
+                    .expect("Unmount of unknown container.");
+                let parent = self.parents.remove(&id).unwrap();
+                self.children
+                    .get_mut(&parent)
+                    .unwrap()
+                    .retain(|&child| child != id);
+            }
+            Operation::Reorder { parent, children } => {
+                let previous = self.children.entry(parent).or_default();
+                let mut sorted = previous.clone();
+                sorted.sort_unstable();
+                let mut reordered = children.clone();
+                reordered.sort_unstable();
+                assert_eq!(sorted, reordered, "Reorder is not a permutation.");
+
+                *previous = children;
+            }
+        }
+    }
+
+    fn tree(&self, id: usize) -> Tree<usize> {
+        Tree {
+            label: self.labels[&id].clone(),
+            tag: id,
+            children: self
+                .children
+                .get(&id)
+                .map(|children| children.iter().map(|&id| self.tree(id)).collect())
+                .unwrap_or_default(),
+        }
+    }
+
+    fn roots(&self) -> Vec<Tree<usize>> {
+        self.children
+            .get(&ROOT)
+            .map(|children| children.iter().map(|&id| self.tree(id)).collect())
+            .unwrap_or_default()
+    }
+}
+
+/// Checks that identities (containers or tokens) that are assigned to the
+/// same path in two consecutive renders are equal and that new paths are
+/// assigned new identities.
+fn check_identities(previous: &HashMap<String, usize>, current: &HashMap<String, usize>) {
+    let retired = previous.values().collect::<HashSet<_>>();
+
+    for (path, identity) in current {
+        match previous.get(path) {
+            Some(previous) => assert_eq!(previous, identity, "{} lost its identity.", path),
+            None => assert!(
+                !retired.contains(identity),
+                "{} reused the identity of another element.",
+                path
+            ),
+        }
+    }
+}
+
+fn run(initial: Vec<Node>, steps: Vec<Vec<Edit>>) {
+    let tree = Rc::new(RefCell::new(initial));
+    let probes = Rc::new(Probes::default());
+    let mirror = Rc::new(RefCell::new(Mirror::default()));
+
+    let compositor = TestCompositor::default();
+    let (evloop, _handler) = EventLoop::new(Arc::new(TestExecutor::new()));
+    let renderer = Renderer::new(compositor.clone(), evloop);
+
+    renderer.record::<Widget, _>({
+        let mirror = mirror.clone();
+        move |operation| mirror.borrow_mut().apply(operation)
+    });
+
+    let harness = Harness {
+        tree: tree.clone(),
+        probes: probes.clone(),
+    };
+
+    let instance = renderer.render(
+        Element::new(Key::new(()), harness.into(), Element::empty()),
+        ROOT,
+    );
+
+    let mut containers = HashMap::new();
+    let mut tokens = HashMap::new();
+
+    for (step, edits) in std::iter::once(vec![]).chain(steps).enumerate() {
+        if step > 0 {
+            for edit in edits {
+                edit.apply(&mut tree.borrow_mut());
+            }
+
+            probes.tokens.borrow_mut().clear();
+            renderer.rerender(&instance);
+        }
+
+        let mirror = mirror.borrow();
+
+        let mut trees = vec![];
+        let mut components = HashSet::new();
+        expected(
+            &tree.borrow(),
+            &nested(&root()),
+            &mut trees,
+            &mut components,
+        );
+
+        let expected = Tree {
+            label: "root".to_owned(),
+            tag: root(),
+            children: trees,
+        };
+
+        // The container tree matches the element tree.
+        let rendered = mirror.roots();
+        assert_eq!(
+            rendered.iter().map(Tree::shape).collect::<Vec<_>>(),
+            vec![expected.shape()],
+        );
+
+        // There are no containers besides the ones in the tree and each
+        // container was committed to the parent that it was recorded with.
+        let mut paths = vec![];
+        expected.tags(&mut paths);
+        let mut ids = vec![];
+        rendered[0].tags(&mut ids);
+        assert_eq!(mirror.labels.len(), ids.len());
+        assert_eq!(compositor.parents(), mirror.parents);
+
+        // Builtins that kept their path kept their container.
+        let current = paths
+            .into_iter()
+            .cloned()
+            .zip(ids.into_iter().copied())
+            .collect::<HashMap<_, _>>();
+        check_identities(&containers, &current);
+        containers = current;
+
+        // Each component rendered exactly once and components that kept their
+        // path kept their memory.
+        let current = probes.tokens.borrow().clone();
+        assert_eq!(current.keys().cloned().collect::<HashSet<_>>(), components);
+        check_identities(&tokens, &current);
+        tokens = current;
+    }
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(256))]
+
+    #[test]
+    fn test_reconciliation(
+        initial in vec(node(), 0..4),
+        steps in vec(vec(edit(), 1..4), 1..6),
+    ) {
+        run(initial, steps);
+    }
+}
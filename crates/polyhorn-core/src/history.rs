@@ -0,0 +1,320 @@
+//! Undo and redo for a single value. `use_history!` wraps a state in a
+//! timeline of past and future values. Rapid successive changes (e.g. the
+//! keystrokes of a single word) can be coalesced into a single step.
+
+use serde::{Deserialize, Serialize};
+use std::cell::Ref;
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+use super::{Key, Link, State, UseState};
+
+#[macro_export]
+macro_rules! use_history {
+    ($manager:expr, $initial:expr) => {
+        $crate::use_history!($manager, $initial, $crate::HistoryOptions::default())
+    };
+    ($manager:expr, $initial:expr, $options:expr) => {
+        $crate::UseHistory::use_history($manager, $crate::use_id!().into(), $initial, $options)
+    };
+}
+
+pub trait UseHistory {
+    /// Returns a history of a value that starts out with the given initial
+    /// value. The options are only used when the component is first rendered.
+    fn use_history<T>(&mut self, key: Key, initial: T, options: HistoryOptions) -> History<T>
+    where
+        T: Serialize + for<'b> Deserialize<'b> + 'static;
+}
+
+/// Options that control the behavior of a history.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct HistoryOptions {
+    limit: usize,
+    coalesce: Duration,
+}
+
+impl HistoryOptions {
+    /// Sets the maximum number of past values that are retained. Once this
+    /// limit is reached, the oldest value is discarded. The default limit is
+    /// 100.
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = limit;
+        self
+    }
+
+    /// Sets the window within which successive pushes are coalesced into a
+    /// single step: a push that follows the previous push within this window
+    /// replaces the present value rather than adding a new step. By default,
+    /// pushes are never coalesced.
+    pub fn coalesce(mut self, window: Duration) -> Self {
+        self.coalesce = window;
+        self
+    }
+}
+
+impl Default for HistoryOptions {
+    fn default() -> Self {
+        HistoryOptions {
+            limit: 100,
+            coalesce: Duration::default(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct Timeline<T> {
+    past: VecDeque<T>,
+    present: T,
+    future: Vec<T>,
+    limit: usize,
+    window: Duration,
+
+    #[serde(skip)]
+    last_push: Option<Instant>,
+}
+
+impl<T> Timeline<T> {
+    fn new(present: T, options: HistoryOptions) -> Timeline<T> {
+        Timeline {
+            past: VecDeque::new(),
+            present,
+            future: vec![],
+            limit: options.limit,
+            window: options.coalesce,
+            last_push: None,
+        }
+    }
+
+    fn push_at(&mut self, value: T, now: Instant) {
+        let coalesce = match self.last_push {
+            Some(last_push) => now.saturating_duration_since(last_push) < self.window,
+            None => false,
+        };
+
+        let previous = std::mem::replace(&mut self.present, value);
+
+        if !coalesce {
+            self.past.push_back(previous);
+
+            while self.past.len() > self.limit {
+                self.past.pop_front();
+            }
+        }
+
+        self.future.clear();
+        self.last_push = Some(now);
+    }
+
+    fn undo(&mut self) -> bool {
+        let value = match self.past.pop_back() {
+            Some(value) => value,
+            None => return false,
+        };
+
+        let present = std::mem::replace(&mut self.present, value);
+        self.future.push(present);
+        self.last_push = None;
+        true
+    }
+
+    fn redo(&mut self) -> bool {
+        let value = match self.future.pop() {
+            Some(value) => value,
+            None => return false,
+        };
+
+        let present = std::mem::replace(&mut self.present, value);
+        self.past.push_back(present);
+        self.last_push = None;
+        true
+    }
+}
+
+/// Handle to a value with undo and redo. Each change to the history
+/// re-renders the component that owns it, just like `State::replace`.
+pub struct History<T>
+where
+    T: 'static,
+{
+    state: State<Timeline<T>>,
+}
+
+impl<T> History<T> {
+    /// Returns the present value.
+    pub fn get<'a, L>(&self, link: &'a L) -> Ref<'a, T>
+    where
+        L: Link,
+    {
+        Ref::map(self.state.get(link), |timeline| &timeline.present)
+    }
+
+    /// Replaces the present value with the given value and discards any
+    /// values that can be redone.
+    pub fn push<L>(&self, link: &L, value: T)
+    where
+        L: Link,
+    {
+        let now = Instant::now();
+        self.state
+            .apply(link, |timeline| timeline.push_at(value, now));
+    }
+
+    /// Restores the previous value. Returns a boolean that indicates if there
+    /// was a value to restore.
+    pub fn undo<L>(&self, link: &L) -> bool
+    where
+        L: Link,
+    {
+        self.can_undo(link) && self.state.apply(link, Timeline::undo)
+    }
+
+    /// Restores the value that was most recently undone. Returns a boolean
+    /// that indicates if there was a value to restore.
+    pub fn redo<L>(&self, link: &L) -> bool
+    where
+        L: Link,
+    {
+        self.can_redo(link) && self.state.apply(link, Timeline::redo)
+    }
+
+    /// Returns a boolean that indicates if there is a value to undo.
+    pub fn can_undo<L>(&self, link: &L) -> bool
+    where
+        L: Link,
+    {
+        !self.state.get(link).past.is_empty()
+    }
+
+    /// Returns a boolean that indicates if there is a value to redo.
+    pub fn can_redo<L>(&self, link: &L) -> bool
+    where
+        L: Link,
+    {
+        !self.state.get(link).future.is_empty()
+    }
+}
+
+impl<T> Clone for History<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for History<T> {}
+
+impl<M> UseHistory for M
+where
+    M: UseState,
+{
+    fn use_history<T>(&mut self, key: Key, initial: T, options: HistoryOptions) -> History<T>
+    where
+        T: Serialize + for<'b> Deserialize<'b> + 'static,
+    {
+        History {
+            state: self.use_state(key, Timeline::new(initial, options)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::{Cell, RefCell};
+    use std::rc::Rc;
+    use std::time::{Duration, Instant};
+
+    use super::{History, HistoryOptions, Timeline};
+    use crate::testing::{render, rerender, TestComponent, TestPlatform};
+    use crate::{Element, Key, Manager, WeakLink};
+
+    #[test]
+    fn test_timeline() {
+        let start = Instant::now();
+        let options = HistoryOptions::default()
+            .limit(2)
+            .coalesce(Duration::from_millis(100));
+        let mut timeline = Timeline::new("", options);
+
+        // The first two pushes are coalesced, the third is not.
+        timeline.push_at("a", start);
+        timeline.push_at("ab", start + Duration::from_millis(50));
+        timeline.push_at("abc", start + Duration::from_millis(200));
+        assert_eq!(timeline.past, vec!["", "ab"]);
+        assert_eq!(timeline.present, "abc");
+
+        // Only the most recent values are retained.
+        timeline.push_at("abcd", start + Duration::from_millis(400));
+        assert_eq!(timeline.past, vec!["ab", "abc"]);
+
+        assert!(timeline.undo());
+        assert!(timeline.undo());
+        assert!(!timeline.undo());
+        assert_eq!(timeline.present, "ab");
+
+        assert!(timeline.redo());
+        assert_eq!(timeline.present, "abc");
+
+        // A push after an undo is never coalesced and discards the future.
+        timeline.push_at("x", start + Duration::from_millis(401));
+        assert_eq!(timeline.past, vec!["ab", "abc"]);
+        assert!(timeline.future.is_empty());
+        assert!(!timeline.redo());
+    }
+
+    struct Editor {
+        history: Rc<RefCell<Option<History<String>>>>,
+        renders: Rc<Cell<usize>>,
+    }
+
+    impl TestComponent for Editor {
+        fn render(&self, manager: &mut Manager<TestPlatform>) -> Element<TestPlatform> {
+            let history = use_history!(manager, String::new());
+            self.history.replace(Some(history));
+            self.renders.set(self.renders.get() + 1);
+
+            Element::empty()
+        }
+    }
+
+    #[test]
+    fn test_use_history() {
+        let history = Rc::new(RefCell::new(None));
+        let renders = Rc::new(Cell::new(0));
+
+        let (renderer, instance, _handler) = render(Element::new(
+            Key::new(()),
+            Editor {
+                history: history.clone(),
+                renders: renders.clone(),
+            }
+            .into(),
+            Element::empty(),
+        ));
+
+        let history = history.borrow().unwrap();
+
+        {
+            let memory = instance.memory();
+            let link = WeakLink::new(&instance, &memory);
+
+            assert!(!history.can_undo(&link));
+            assert!(!history.undo(&link));
+
+            history.push(&link, "hello".to_owned());
+            history.push(&link, "hello world".to_owned());
+            assert!(history.undo(&link));
+            assert_eq!(*history.get(&link), "hello");
+            assert!(history.can_redo(&link));
+        }
+
+        rerender(&renderer, &instance);
+        assert_eq!(renders.get(), 2);
+
+        let memory = instance.memory();
+        let link = WeakLink::new(&instance, &memory);
+        assert_eq!(*history.get(&link), "hello");
+        assert!(history.redo(&link));
+        assert_eq!(*history.get(&link), "hello world");
+        assert!(!history.can_redo(&link));
+    }
+}
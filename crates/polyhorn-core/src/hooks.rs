@@ -7,7 +7,7 @@ use super::{Context, EffectLink, Key, Platform, Reference, State};
 macro_rules! use_id {
     () => {{
         struct ID;
-        std::rc::Rc::new(std::any::TypeId::of::<ID>())
+        std::any::TypeId::of::<ID>()
     }};
 }
 
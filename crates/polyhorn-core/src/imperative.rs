@@ -0,0 +1,153 @@
+use super::{Key, Link, Platform, UseReference, WeakReference};
+
+#[macro_export]
+macro_rules! use_imperative_handle {
+    ($manager:expr, $reference:expr, $handle:expr) => {{
+        let handle = $handle;
+
+        $crate::UseImperativeHandle::use_imperative_handle(
+            $manager,
+            $crate::use_id!().into(),
+            $reference,
+            handle,
+        )
+    }};
+}
+
+pub trait UseImperativeHandle<P>
+where
+    P: Platform + ?Sized,
+{
+    /// Exposes the given handle to the owner of the given reference. The
+    /// reference is usually passed to a component through its `reference` prop
+    /// (e.g. `<MyInput ref=reference />` in `poly!`). The handle is replaced on
+    /// every render and is reset to `None` once this component unmounts (or
+    /// starts rendering with a different reference).
+    fn use_imperative_handle<'a, H, R>(&mut self, key: Key, reference: R, handle: H)
+    where
+        H: 'static,
+        R: Into<Option<&'a WeakReference<P, Option<H>>>>;
+}
+
+/// Resets the reference that a handle is exposed through when it is dropped,
+/// which happens when the memory of the component that exposes the handle is
+/// dropped.
+struct HandleGuard<P, H>(Option<WeakReference<P, Option<H>>>)
+where
+    P: Platform + ?Sized,
+    H: 'static;
+
+impl<P, H> Drop for HandleGuard<P, H>
+where
+    P: Platform + ?Sized,
+    H: 'static,
+{
+    fn drop(&mut self) {
+        if let Some(reference) = self.0.take() {
+            reference.replace(None);
+        }
+    }
+}
+
+impl<M> UseImperativeHandle<M::Platform> for M
+where
+    M: UseReference + Link,
+{
+    fn use_imperative_handle<'a, H, R>(&mut self, key: Key, reference: R, handle: H)
+    where
+        H: 'static,
+        R: Into<Option<&'a WeakReference<M::Platform, Option<H>>>>,
+    {
+        let reference = reference.into().cloned();
+
+        let guard = self.use_reference(key, || HandleGuard(None));
+
+        // We swap the reference in place rather than replacing the guard,
+        // because dropping the guard would reset the new reference.
+        let previous = guard.apply(self, |guard| {
+            std::mem::replace(&mut guard.0, reference.clone())
+        });
+
+        if let Some(previous) = previous {
+            previous.replace(None);
+        }
+
+        if let Some(reference) = reference {
+            reference.replace(Some(handle));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use crate::testing::{render, rerender, TestComponent, TestPlatform};
+    use crate::{use_reference, Element, Key, Manager, Reference, WeakLink, WeakReference};
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct Handle(usize);
+
+    struct Parent {
+        show: Rc<RefCell<bool>>,
+        probe: Rc<RefCell<Option<Reference<Option<Handle>>>>>,
+    }
+
+    impl TestComponent for Parent {
+        fn render(&self, manager: &mut Manager<TestPlatform>) -> Element<TestPlatform> {
+            let handle = use_reference!(manager, None);
+            self.probe.replace(Some(handle));
+
+            match *self.show.borrow() {
+                true => Element::new(
+                    Key::new(()),
+                    Child {
+                        reference: Some(handle.weak(manager)),
+                    }
+                    .into(),
+                    Element::empty(),
+                ),
+                false => Element::empty(),
+            }
+        }
+    }
+
+    struct Child {
+        reference: Option<WeakReference<TestPlatform, Option<Handle>>>,
+    }
+
+    impl TestComponent for Child {
+        fn render(&self, manager: &mut Manager<TestPlatform>) -> Element<TestPlatform> {
+            use_imperative_handle!(manager, &self.reference, Handle(42));
+
+            Element::empty()
+        }
+    }
+
+    #[test]
+    fn test_imperative_handle() {
+        let show = Rc::new(RefCell::new(true));
+        let probe = Rc::new(RefCell::new(None));
+
+        let (renderer, instance, _handler) = render(Element::new(
+            Key::new(()),
+            Parent {
+                show: show.clone(),
+                probe: probe.clone(),
+            }
+            .into(),
+            Element::empty(),
+        ));
+
+        let handle = probe.borrow().unwrap();
+        let current = || handle.cloned(&WeakLink::new(&instance, &instance.memory()));
+
+        assert_eq!(current(), Some(Handle(42)));
+
+        show.replace(false);
+        rerender(&renderer, &instance);
+
+        assert_eq!(current(), None);
+    }
+}
@@ -1,81 +1,231 @@
-use super::{ContextTree, Element, Memory, Platform, Renderer, Topology};
+use super::arena::Index;
+use super::diagnostics::{self, RenderReason};
+use super::leaks;
+use super::signal::InstanceSubscriber;
+use super::{Component, ContextTree, Element, Key, Memory, Platform, Renderer, Topology, Weak};
 use std::cell::{Ref, RefCell, RefMut};
 use std::rc::Rc;
 use std::sync::atomic::{AtomicUsize, Ordering::Relaxed};
 
 static INSTANCE_ID: AtomicUsize = AtomicUsize::new(0);
 
-pub struct Instance<P>
+/// Returns a new, unique ID for an instance.
+pub(crate) fn next_id() -> usize {
+    INSTANCE_ID.fetch_add(1, Relaxed)
+}
+
+/// This is the state of an instance, which is owned by the arena of its
+/// renderer. The renderer removes it from the arena when it is unmounted.
+pub(crate) struct Node<P>
 where
     P: Platform + ?Sized,
 {
-    pub(crate) id: usize,
+    pub id: usize,
 
-    renderer: Rc<Renderer<P>>,
+    /// This field holds the index of the parent of this instance (if any).
+    /// This field never changes.
+    pub parent: Option<Index>,
+
+    /// This field holds the key of the element that this instance was created
+    /// for and the type name of its component (if it renders a component).
+    /// Both fields never change.
+    pub key: Key,
+    pub component: Option<&'static str>,
 
     /// This field holds the ID of the container that the instance was mounted
     /// onto. This field never changes.
-    container: P::ContainerID,
+    pub container: P::ContainerID,
 
     /// This fields holds a reference to the corresponding branch within the
     /// context tree. This field never changes once created.
-    context: Rc<ContextTree>,
+    pub context: Rc<ContextTree>,
+
+    pub topology: Topology<P>,
+
+    /// This field holds the memory of this instance, which is moved out of
+    /// the arena while the instance renders.
+    pub memory: Option<Memory>,
 
-    topology: RefCell<Topology<P>>,
-    memory: RefCell<Memory>,
+    /// This field holds the reasons for the next render of this instance. It
+    /// is only used when render diagnostics are enabled.
+    pub reasons: RefCell<Vec<RenderReason>>,
+
+    /// This field holds the signals that this instance read during its most
+    /// recent render. Only components read signals, so other instances don't
+    /// have a subscriber.
+    pub subscriber: Option<Rc<InstanceSubscriber<P>>>,
 }
 
-impl<P> Instance<P>
+impl<P> Node<P>
 where
     P: Platform + ?Sized,
 {
     pub fn new(
-        renderer: Rc<Renderer<P>>,
-        parent: Option<Rc<Instance<P>>>,
+        renderer: &Rc<Renderer<P>>,
+        id: usize,
+        index: Index,
+        parent: Option<Index>,
+        context: Rc<ContextTree>,
         element: Element<P>,
         container: P::ContainerID,
-    ) -> Instance<P> {
+    ) -> Node<P> {
+        let component = match &element {
+            Element::Component(element) => Some(element.component.type_name()),
+            _ => None,
+        };
+
+        Node {
+            id,
+            parent,
+            key: element.key().clone(),
+            component,
+            container,
+            context,
+            topology: Topology::new(element),
+            memory: Some(Memory::new()),
+            reasons: RefCell::new(vec![]),
+            subscriber: component
+                .map(|_| Rc::new(InstanceSubscriber::new(Weak::from_raw(renderer, index, id)))),
+        }
+    }
+}
+
+impl<P> Drop for Node<P>
+where
+    P: Platform + ?Sized,
+{
+    fn drop(&mut self) {
+        leaks::untrack(self.id);
+    }
+}
+
+/// Handle to an instance that is owned by a renderer. Handles are cheap to
+/// clone and do not keep the instance alive: once the instance is unmounted,
+/// accessing its state through a handle panics.
+pub struct Instance<P>
+where
+    P: Platform + ?Sized,
+{
+    pub(crate) id: usize,
+    pub(crate) index: Index,
+    renderer: Rc<Renderer<P>>,
+}
+
+impl<P> Instance<P>
+where
+    P: Platform + ?Sized,
+{
+    pub(crate) fn new(renderer: Rc<Renderer<P>>, index: Index, id: usize) -> Instance<P> {
         Instance {
-            id: INSTANCE_ID.fetch_add(1, Relaxed),
+            id,
+            index,
             renderer,
-            container,
-            context: parent
-                .map(|parent| Rc::new(parent.context.enter()))
-                .unwrap_or_default(),
-            topology: RefCell::new(Topology::new(element)),
-            memory: RefCell::new(Memory::new()),
         }
     }
 
+    pub(crate) fn node(&self) -> Ref<'_, Node<P>> {
+        self.renderer.node(self.index)
+    }
+
+    pub(crate) fn node_mut(&self) -> RefMut<'_, Node<P>> {
+        self.renderer.node_mut(self.index)
+    }
+
     pub fn renderer(&self) -> &Rc<Renderer<P>> {
         &self.renderer
     }
 
+    /// Returns a boolean that indicates if this instance is still mounted.
+    pub fn is_mounted(&self) -> bool {
+        self.renderer.contains(self.index)
+    }
+
     pub fn container(&self) -> P::ContainerID {
-        self.container
+        self.node().container
+    }
+
+    pub fn context(&self) -> Rc<ContextTree> {
+        self.node().context.clone()
+    }
+
+    pub fn topology(&self) -> Ref<'_, Topology<P>> {
+        Ref::map(self.node(), |node| &node.topology)
     }
 
-    pub fn context(&self) -> &ContextTree {
-        &self.context
+    pub fn topology_mut(&self) -> RefMut<'_, Topology<P>> {
+        RefMut::map(self.node_mut(), |node| &mut node.topology)
     }
 
-    pub fn topology(&self) -> Ref<Topology<P>> {
-        self.topology
-            .try_borrow()
-            .expect("Can't borrow instance topology that is already borrowed mutably.")
+    /// Returns the instances that this instance rendered, in the order in
+    /// which they were most recently rendered.
+    pub fn edges(&self) -> Vec<Instance<P>> {
+        let edges = self.topology().edges().collect::<Vec<_>>();
+
+        edges
+            .into_iter()
+            .map(|index| self.renderer.instance(index))
+            .collect()
     }
 
-    pub fn topology_mut(&self) -> RefMut<Topology<P>> {
-        self.topology.borrow_mut()
+    pub fn memory(&self) -> Ref<'_, Memory> {
+        Ref::map(self.node(), |node| {
+            node.memory
+                .as_ref()
+                .expect("Can't borrow instance memory that is already borrowed mutably.")
+        })
     }
 
-    pub fn memory(&self) -> Ref<Memory> {
-        self.memory
-            .try_borrow()
-            .expect("Can't borrow instance memory that is already borrowed mutably.")
+    /// Returns the memory of this instance unless it has been unmounted or is
+    /// currently rendering.
+    pub(crate) fn try_memory(&self) -> Option<Ref<'_, Memory>> {
+        let node = self.renderer.try_node(self.index)?;
+        node.memory.as_ref()?;
+        Some(Ref::map(node, |node| node.memory.as_ref().unwrap()))
     }
 
-    pub fn memory_mut(&self) -> RefMut<Memory> {
-        self.memory.borrow_mut()
+    /// Returns the type names of the components and the keys of the elements
+    /// from the root to this instance (including the instance itself).
+    pub(crate) fn path(&self) -> (Vec<&'static str>, Vec<Key>) {
+        let mut components = vec![];
+        let mut keys = vec![];
+        let mut index = Some(self.index);
+
+        while let Some(node) = index.and_then(|index| self.renderer.try_node(index)) {
+            components.extend(node.component);
+            keys.push(node.key.clone());
+            index = node.parent;
+        }
+
+        components.reverse();
+        keys.reverse();
+
+        (components, keys)
+    }
+
+    /// Records a reason for the next render of this instance if render
+    /// diagnostics are enabled.
+    pub(crate) fn push_reason(&self, reason: RenderReason) {
+        if diagnostics::is_enabled() {
+            if let Some(node) = self.renderer.try_node(self.index) {
+                node.reasons.borrow_mut().push(reason);
+            }
+        }
+    }
+
+    pub(crate) fn take_reasons(&self) -> Vec<RenderReason> {
+        std::mem::take(&mut self.node().reasons.borrow_mut())
+    }
+}
+
+impl<P> Clone for Instance<P>
+where
+    P: Platform + ?Sized,
+{
+    fn clone(&self) -> Self {
+        Instance {
+            id: self.id,
+            index: self.index,
+            renderer: self.renderer.clone(),
+        }
     }
 }
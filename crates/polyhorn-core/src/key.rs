@@ -1,5 +1,6 @@
+use std::any::{Any, TypeId};
 use std::cmp::PartialEq;
-use std::fmt::Debug;
+use std::fmt::{self, Debug};
 use std::hash::{Hash, Hasher};
 use std::rc::Rc;
 
@@ -7,8 +8,53 @@ use std::rc::Rc;
 /// + Hash` (note that `Any` implies `'static`). Key acts as a transparent
 /// wrapper for these traits, where `Eq` and `PartialEq` in particular perform
 /// dynamic type checks before determining value equality.
-#[derive(Clone, Debug)]
-pub struct Key(Rc<dyn machinery::Keyable>);
+///
+/// Keys of the most common types (the type IDs that are returned by `use_id!`,
+/// `()`, integers and static strings) are stored inline, which makes them cheap
+/// to create, clone, hash and compare.
+#[derive(Clone)]
+pub struct Key(Repr);
+
+#[derive(Clone)]
+enum Repr {
+    Id(TypeId),
+    Unit,
+    I32(i32),
+    Usize(usize),
+    Str(&'static str),
+    Dynamic(Rc<dyn machinery::Keyable>),
+}
+
+impl Repr {
+    /// Returns an inline representation of the given value if its type can be
+    /// stored inline.
+    fn inline(value: &dyn Any) -> Option<Repr> {
+        if let Some(&id) = value.downcast_ref::<TypeId>() {
+            Some(Repr::Id(id))
+        } else if value.is::<()>() {
+            Some(Repr::Unit)
+        } else if let Some(&value) = value.downcast_ref::<i32>() {
+            Some(Repr::I32(value))
+        } else if let Some(&value) = value.downcast_ref::<usize>() {
+            Some(Repr::Usize(value))
+        } else if let Some(&value) = value.downcast_ref::<&'static str>() {
+            Some(Repr::Str(value))
+        } else {
+            None
+        }
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        match self {
+            Repr::Id(id) => id,
+            Repr::Unit => &(),
+            Repr::I32(value) => value,
+            Repr::Usize(value) => value,
+            Repr::Str(value) => value,
+            Repr::Dynamic(value) => value.as_any(),
+        }
+    }
+}
 
 impl Key {
     /// This function returns a new key with the given value. The prescribed
@@ -19,7 +65,25 @@ impl Key {
     where
         T: machinery::Keyable + 'static,
     {
-        Key(Rc::new(value))
+        match Repr::inline(&value) {
+            Some(repr) => Key(repr),
+            None => Key(Repr::Dynamic(Rc::new(value))),
+        }
+    }
+
+    /// This function returns a reference to the value of this key if it is of
+    /// the given type.
+    pub fn downcast_ref<T>(&self) -> Option<&T>
+    where
+        T: 'static,
+    {
+        self.0.as_any().downcast_ref::<T>()
+    }
+}
+
+impl From<TypeId> for Key {
+    fn from(value: TypeId) -> Self {
+        Key(Repr::Id(value))
     }
 }
 
@@ -28,13 +92,39 @@ where
     T: machinery::Keyable + 'static,
 {
     fn from(value: Rc<T>) -> Self {
-        Key(value)
+        match Repr::inline(value.as_ref()) {
+            Some(repr) => Key(repr),
+            None => Key(Repr::Dynamic(value)),
+        }
+    }
+}
+
+impl Debug for Key {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let value: &dyn Debug = match &self.0 {
+            Repr::Id(id) => id,
+            Repr::Unit => &(),
+            Repr::I32(value) => value,
+            Repr::Usize(value) => value,
+            Repr::Str(value) => value,
+            Repr::Dynamic(value) => value,
+        };
+
+        f.debug_tuple("Key").field(value).finish()
     }
 }
 
 impl PartialEq for Key {
     fn eq(&self, other: &Key) -> bool {
-        self.0.eq(other.0.as_any())
+        match (&self.0, &other.0) {
+            (Repr::Id(a), Repr::Id(b)) => a == b,
+            (Repr::Unit, Repr::Unit) => true,
+            (Repr::I32(a), Repr::I32(b)) => a == b,
+            (Repr::Usize(a), Repr::Usize(b)) => a == b,
+            (Repr::Str(a), Repr::Str(b)) => a == b,
+            (Repr::Dynamic(a), Repr::Dynamic(b)) => a.eq(b.as_any()),
+            _ => false,
+        }
     }
 }
 
@@ -45,7 +135,16 @@ impl Hash for Key {
     where
         H: Hasher,
     {
-        self.0.hash(state)
+        std::mem::discriminant(&self.0).hash(state);
+
+        match &self.0 {
+            Repr::Id(id) => id.hash(state),
+            Repr::Unit => {}
+            Repr::I32(value) => value.hash(state),
+            Repr::Usize(value) => value.hash(state),
+            Repr::Str(value) => value.hash(state),
+            Repr::Dynamic(value) => value.hash(state),
+        }
     }
 }
 
@@ -84,6 +183,8 @@ mod machinery {
 
 #[cfg(test)]
 mod tests {
+    use std::rc::Rc;
+
     use super::Key;
 
     #[test]
@@ -91,4 +192,13 @@ mod tests {
         assert_eq!(Key::new((1, "Hello")), Key::new((1, "Hello")));
         assert_ne!(Key::new((1, "Hello")), Key::new((2, "Hello")));
     }
+
+    #[test]
+    fn test_inline_key() {
+        assert_eq!(Key::new(42usize), Key::from(Rc::new(42usize)));
+        assert_ne!(Key::new(42usize), Key::new(42i32));
+        assert_ne!(Key::new(()), Key::new("()"));
+        assert_eq!(Key::new("a").downcast_ref::<&str>(), Some(&"a"));
+        assert_eq!(format!("{:?}", Key::new(42usize)), "Key(42)");
+    }
 }
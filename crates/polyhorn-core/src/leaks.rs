@@ -0,0 +1,356 @@
+//! Debug tool that detects instances that outlive their unmount. Instances are
+//! owned by their renderer, which drops them when they are unmounted, so
+//! handles that are stored in memory can't keep them alive. Instances that are
+//! still alive although their UI was disposed usually indicate that their
+//! renderer leaked, e.g. because a closure that is stored in the memory of
+//! an instance holds a handle to an instance of the same renderer.
+
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::fmt::{self, Display};
+
+use super::{Instance, Key, Platform, Weak};
+
+thread_local! {
+    static REGISTRY: RefCell<Option<Registry>> = const { RefCell::new(None) };
+}
+
+#[derive(Default)]
+struct Registry {
+    instances: BTreeMap<usize, Entry>,
+}
+
+struct Entry {
+    instance: Box<dyn Tracked>,
+    components: Vec<&'static str>,
+    keys: Vec<Key>,
+    unmounted: bool,
+}
+
+/// Type-erased view on an instance that is used to collect statistics.
+trait Tracked {
+    /// Returns the number of memory slots and context values of the instance
+    /// if it is still alive.
+    fn statistics(&self) -> Option<(usize, usize)>;
+}
+
+impl<P> Tracked for Weak<P>
+where
+    P: Platform + ?Sized,
+{
+    fn statistics(&self) -> Option<(usize, usize)> {
+        let instance = self.upgrade()?;
+
+        // The memory of an instance is moved out of its renderer while it
+        // renders.
+        let memory_slots = instance
+            .try_memory()
+            .map(|memory| memory.len())
+            .unwrap_or_default();
+
+        Some((memory_slots, instance.context().len()))
+    }
+}
+
+fn with_registry<F>(op: F)
+where
+    F: FnOnce(&mut Registry),
+{
+    // Instances may be dropped while thread-local storage is being destroyed.
+    let _ = REGISTRY.try_with(|registry| {
+        if let Some(registry) = registry.borrow_mut().as_mut() {
+            op(registry)
+        }
+    });
+}
+
+/// Starts tracking the given instance if a leak tracker is active on the
+/// current thread.
+pub(crate) fn track<P>(instance: &Instance<P>, parent: Option<usize>)
+where
+    P: Platform + ?Sized,
+{
+    with_registry(|registry| {
+        let (mut components, mut keys) = parent
+            .and_then(|parent| registry.instances.get(&parent))
+            .map(|parent| (parent.components.clone(), parent.keys.clone()))
+            .unwrap_or_default();
+
+        let node = instance.node();
+        components.extend(node.component);
+        keys.push(node.key.clone());
+
+        registry.instances.insert(
+            instance.id,
+            Entry {
+                instance: Box::new(Weak::new(instance)),
+                components,
+                keys,
+                unmounted: false,
+            },
+        );
+    })
+}
+
+/// Marks the instance with the given ID as unmounted. From this point on, the
+/// instance is reported as a leak for as long as it remains alive.
+pub(crate) fn unmount(id: usize) {
+    with_registry(|registry| {
+        if let Some(entry) = registry.instances.get_mut(&id) {
+            entry.unmounted = true;
+        }
+    })
+}
+
+/// Stops tracking the instance with the given ID, which happens once the
+/// instance is dropped.
+pub(crate) fn untrack(id: usize) {
+    with_registry(|registry| {
+        registry.instances.remove(&id);
+    })
+}
+
+/// Tracks the instances that are created on the current thread for as long as
+/// the tracker is alive. Only one tracker can be active on a thread at a time.
+pub struct LeakTracker {
+    _private: (),
+}
+
+impl LeakTracker {
+    /// Starts tracking instances on the current thread. Instances that were
+    /// created before the tracker was started are not tracked.
+    pub fn start() -> LeakTracker {
+        REGISTRY.with(|registry| {
+            let mut registry = registry.borrow_mut();
+            assert!(
+                registry.is_none(),
+                "Another leak tracker is already active on this thread."
+            );
+            registry.replace(Registry::default());
+        });
+
+        LeakTracker { _private: () }
+    }
+
+    fn reports<F>(&self, filter: F) -> Vec<InstanceReport>
+    where
+        F: Fn(&Entry) -> bool,
+    {
+        REGISTRY.with(|registry| {
+            let registry = registry.borrow();
+            let registry = registry.as_ref().unwrap();
+
+            registry
+                .instances
+                .iter()
+                .filter(|(_, entry)| filter(entry))
+                .map(|(&instance_id, entry)| InstanceReport {
+                    instance_id,
+                    components: entry.components.clone(),
+                    keys: entry.keys.clone(),
+                    unmounted: entry.unmounted,
+                })
+                .collect()
+        })
+    }
+
+    /// Returns a report for each tracked instance that is still alive.
+    pub fn live(&self) -> Vec<InstanceReport> {
+        self.reports(|_| true)
+    }
+
+    /// Returns a report for each tracked instance that is still alive even
+    /// though it has been unmounted.
+    pub fn leaks(&self) -> Vec<InstanceReport> {
+        self.reports(|entry| entry.unmounted)
+    }
+
+    /// Returns the number of live instances, memory slots (states and
+    /// references) and context values.
+    pub fn statistics(&self) -> LeakStatistics {
+        REGISTRY.with(|registry| {
+            let registry = registry.borrow();
+            let registry = registry.as_ref().unwrap();
+
+            let mut statistics = LeakStatistics::default();
+
+            for (memory_slots, context_values) in registry
+                .instances
+                .values()
+                .filter_map(|entry| entry.instance.statistics())
+            {
+                statistics.instances += 1;
+                statistics.memory_slots += memory_slots;
+                statistics.context_values += context_values;
+            }
+
+            statistics
+        })
+    }
+
+    /// Panics with a description of each leaked instance if any instance
+    /// survived its unmount.
+    pub fn assert_no_leaks(&self) {
+        let leaks = self.leaks();
+
+        if !leaks.is_empty() {
+            let leaks = leaks
+                .iter()
+                .map(|leak| format!("  - {}", leak))
+                .collect::<Vec<_>>()
+                .join("\n");
+
+            panic!("Instances survived their unmount:\n{}", leaks);
+        }
+    }
+}
+
+impl Drop for LeakTracker {
+    fn drop(&mut self) {
+        let _ = REGISTRY.try_with(|registry| registry.borrow_mut().take());
+    }
+}
+
+/// Describes a tracked instance that is still alive.
+#[derive(Clone, Debug)]
+pub struct InstanceReport {
+    /// ID of the instance.
+    pub instance_id: usize,
+
+    /// Type names of the components from the root to this instance (including
+    /// the instance itself if it renders a component).
+    pub components: Vec<&'static str>,
+
+    /// Keys of the elements from the root to this instance.
+    pub keys: Vec<Key>,
+
+    /// Boolean that indicates if this instance has been unmounted.
+    pub unmounted: bool,
+}
+
+impl Display for InstanceReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "#{} {} at {:?}",
+            self.instance_id,
+            self.components.join(" > "),
+            self.keys
+        )
+    }
+}
+
+/// Number of live instances, memory slots and context values.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct LeakStatistics {
+    pub instances: usize,
+    pub memory_slots: usize,
+    pub context_values: usize,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use super::LeakTracker;
+    use crate::testing::{render, rerender, TestComponent, TestPlatform};
+    use crate::{use_reference, Element, Key, Link, Manager};
+
+    struct Screen {
+        show: Rc<RefCell<bool>>,
+        leak: bool,
+    }
+
+    impl TestComponent for Screen {
+        fn render(&self, _manager: &mut Manager<TestPlatform>) -> Element<TestPlatform> {
+            match *self.show.borrow() {
+                true => Element::new(
+                    Key::new("row"),
+                    Row { leak: self.leak }.into(),
+                    Element::empty(),
+                ),
+                false => Element::empty(),
+            }
+        }
+    }
+
+    struct Row {
+        leak: bool,
+    }
+
+    impl TestComponent for Row {
+        fn render(&self, manager: &mut Manager<TestPlatform>) -> Element<TestPlatform> {
+            // Storing a handle to the instance in its own memory creates a
+            // cycle through the renderer for as long as it is mounted.
+            let instance = match self.leak {
+                true => Some(manager.instance().clone()),
+                false => None,
+            };
+
+            use_reference!(manager, instance);
+
+            Element::empty()
+        }
+    }
+
+    fn unmount_row(leak: bool) -> LeakTracker {
+        let tracker = LeakTracker::start();
+        let show = Rc::new(RefCell::new(true));
+
+        let (renderer, instance, _handler) = render(Element::new(
+            Key::new("screen"),
+            Screen {
+                show: show.clone(),
+                leak,
+            }
+            .into(),
+            Element::empty(),
+        ));
+
+        assert_eq!(tracker.statistics().instances, 3);
+        assert!(tracker.leaks().is_empty());
+
+        show.replace(false);
+        rerender(&renderer, &instance);
+
+        tracker
+    }
+
+    #[test]
+    fn test_no_leaks() {
+        let tracker = unmount_row(false);
+        tracker.assert_no_leaks();
+        assert_eq!(tracker.statistics().instances, 0);
+    }
+
+    #[test]
+    fn test_unmounted_cycle() {
+        // The cycle is broken when the row is unmounted.
+        let tracker = unmount_row(true);
+        tracker.assert_no_leaks();
+        assert_eq!(tracker.statistics().instances, 0);
+    }
+
+    #[test]
+    fn test_renderer_leak() {
+        let tracker = LeakTracker::start();
+
+        let (renderer, instance, handler) = render(Element::new(
+            Key::new("row"),
+            Row { leak: true }.into(),
+            Element::empty(),
+        ));
+
+        drop((renderer, instance, handler));
+
+        // The row and its own children are still mounted, so they are alive
+        // but not reported as leaks.
+        let live = tracker.live();
+        assert_eq!(live.len(), 2);
+        assert!(live[0].components[0].ends_with("Row"));
+        assert_eq!(live[0].keys, vec![Key::new("row")]);
+        assert!(tracker.leaks().is_empty());
+        assert_eq!(tracker.statistics().memory_slots, 1);
+    }
+}
@@ -1,45 +1,79 @@
+mod arena;
 mod builtin;
 mod channel;
+mod children;
 mod component;
 mod compositor;
 mod container;
 mod context;
+mod diagnostics;
 mod disposable;
 mod effect;
 mod element;
 mod evloop;
+mod executor;
+#[cfg(test)]
+mod fuzz;
+mod history;
 mod hooks;
+mod imperative;
 mod instance;
 mod key;
+mod leaks;
 mod link;
+mod machine;
 mod manager;
 mod memory;
 mod platform;
+mod protocol;
 mod reference;
 mod render;
+mod signal;
 mod state;
+#[cfg(test)]
+mod testing;
+mod timetravel;
 mod topology;
 mod weak;
+mod worker;
 
 pub use builtin::Builtin;
 pub use channel::{Receiver, Sender, UseChannel};
+pub use children::{Children, Slot};
 pub use component::Component;
 pub use compositor::{Command, CommandBuffer, Composition, Compositor};
 pub use container::Container;
 pub use context::{Context, ContextProvider, ContextTree};
+pub use diagnostics::{
+    log_renders, observe_renders, stop_observing_renders, PropChange, PropsDiff, RenderReason,
+    RenderRecord,
+};
 pub use disposable::Disposable;
 pub use effect::{Effect, EffectLink, LayoutEffect};
 pub use element::Element;
-pub use evloop::EventLoop;
+pub use evloop::{EventLoop, EventLoopHandler};
+#[cfg(feature = "async-std")]
+pub use executor::AsyncStdExecutor;
+#[cfg(feature = "tokio")]
+pub use executor::TokioExecutor;
+pub use executor::{BoxFuture, Executor, LocalBoxFuture, LocalExecutor, TestExecutor};
+pub use history::{History, HistoryOptions, UseHistory};
 pub use hooks::{UseAsync, UseContext, UseEffect, UseLayoutEffect, UseReference, UseState};
+pub use imperative::UseImperativeHandle;
 pub use instance::Instance;
 pub use key::Key;
+pub use leaks::{InstanceReport, LeakStatistics, LeakTracker};
 pub use link::Link;
+pub use machine::{Chart, Machine, StateBuilder, UseMachine};
 pub use manager::Manager;
 pub use memory::Memory;
 pub use platform::Platform;
+pub use protocol::{Operation, Payload, Replayer};
 pub use reference::Reference;
-pub use render::{render, Renderer};
+pub use render::{render, render_with_executor, Renderer};
+pub use signal::{untracked, Computed, Signal};
 pub use state::State;
+pub use timetravel::{MemorySlot, StateChange, StateLog};
 pub use topology::Topology;
 pub use weak::{Weak, WeakLink, WeakReference, WeakState};
+pub use worker::{UseWorker, WorkerPool};
@@ -1,11 +1,9 @@
-use std::rc::Rc;
-
 use super::{Instance, Memory, Platform};
 
 pub trait Link {
     type Platform: Platform + ?Sized;
 
-    fn instance(&self) -> &Rc<Instance<Self::Platform>>;
+    fn instance(&self) -> &Instance<Self::Platform>;
     fn memory(&self) -> &Memory;
 
     fn queue_rerender(&self) {
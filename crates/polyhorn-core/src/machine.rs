@@ -0,0 +1,980 @@
+//! Statecharts: hierarchical finite-state machines with nested and parallel
+//! states, guarded transitions, entry and exit actions and delayed
+//! transitions. A chart is described once with `Chart` and then driven by a
+//! `Machine`, which is usually obtained with `use_machine!`.
+
+use std::cell::RefCell;
+use std::collections::{BTreeSet, HashMap};
+use std::fmt::{Debug, Write};
+use std::hash::Hash;
+use std::mem::Discriminant;
+use std::rc::{Rc, Weak};
+use std::time::Duration;
+
+use super::{Disposable, Key, Link, UseEffect, UseReference};
+
+#[macro_export]
+macro_rules! use_machine {
+    ($manager:expr, $chart:expr) => {
+        $crate::UseMachine::use_machine($manager, $crate::use_id!().into(), || $chart)
+    };
+}
+
+pub trait UseMachine {
+    /// Returns a machine that is driven by the given chart. The chart is only
+    /// built when the component is first rendered. Sending an event to the
+    /// machine that causes a transition re-renders the component. Entry and
+    /// exit actions run as effects, after the resulting render.
+    fn use_machine<S, E, F>(&mut self, key: Key, chart: F) -> Machine<S, E>
+    where
+        S: Copy + Eq + Hash + Debug + 'static,
+        E: Debug + 'static,
+        F: FnOnce() -> Chart<S, E>;
+}
+
+type Action<S, E> = Rc<dyn Fn(&Machine<S, E>)>;
+type Guard<E> = Rc<dyn Fn(&E) -> bool>;
+type Scheduler = Box<dyn Fn(Duration, Box<dyn FnOnce()>) -> Option<Disposable>>;
+
+struct Transition<S, E> {
+    event: Discriminant<E>,
+    label: String,
+    guard: Option<Guard<E>>,
+    target: S,
+}
+
+struct Node<S, E> {
+    id: S,
+    parent: Option<S>,
+    initial: Option<S>,
+    parallel: bool,
+    transitions: Vec<Transition<S, E>>,
+    delayed: Vec<(Duration, S)>,
+    entry: Vec<Action<S, E>>,
+    exit: Vec<Action<S, E>>,
+}
+
+/// Builder for a single state of a chart (see `Chart::state`).
+pub struct StateBuilder<S, E> {
+    node: Node<S, E>,
+}
+
+impl<S, E> StateBuilder<S, E>
+where
+    S: Copy + Eq + Hash + Debug + 'static,
+    E: Debug + 'static,
+{
+    /// Nests this state within the given parent state.
+    pub fn parent(mut self, parent: S) -> Self {
+        self.node.parent = Some(parent);
+        self
+    }
+
+    /// Sets the child state that is entered when this state is entered. By
+    /// default, this is the first child state.
+    pub fn initial(mut self, initial: S) -> Self {
+        self.node.initial = Some(initial);
+        self
+    }
+
+    /// Turns this state into a parallel state: all of its child states are
+    /// active at the same time, each as an independent region.
+    pub fn parallel(mut self) -> Self {
+        self.node.parallel = true;
+        self
+    }
+
+    /// Adds a transition to the given target state that is taken when an
+    /// event with the same variant as the given event is sent. Payloads are
+    /// ignored when matching events.
+    pub fn on(self, event: E, target: S) -> Self {
+        self.transition(event, None, target)
+    }
+
+    /// Adds a transition that is only taken if the given guard accepts the
+    /// event. Guards are evaluated in the order in which they are added.
+    pub fn on_if<G>(self, event: E, guard: G, target: S) -> Self
+    where
+        G: Fn(&E) -> bool + 'static,
+    {
+        self.transition(event, Some(Rc::new(guard)), target)
+    }
+
+    fn transition(mut self, event: E, guard: Option<Guard<E>>, target: S) -> Self {
+        // We only keep the name of the variant for exports.
+        let label = format!("{:?}", event);
+        let label = label
+            .split(|c: char| c == '(' || c == '{' || c.is_whitespace())
+            .next()
+            .unwrap_or_default()
+            .to_owned();
+
+        self.node.transitions.push(Transition {
+            event: std::mem::discriminant(&event),
+            label,
+            guard,
+            target,
+        });
+
+        self
+    }
+
+    /// Adds a transition to the given target state that is taken once this
+    /// state has been active for the given duration.
+    pub fn after(mut self, duration: Duration, target: S) -> Self {
+        self.node.delayed.push((duration, target));
+        self
+    }
+
+    /// Adds an action that runs when this state is entered.
+    pub fn entry<F>(mut self, action: F) -> Self
+    where
+        F: Fn(&Machine<S, E>) + 'static,
+    {
+        self.node.entry.push(Rc::new(action));
+        self
+    }
+
+    /// Adds an action that runs when this state is exited.
+    pub fn exit<F>(mut self, action: F) -> Self
+    where
+        F: Fn(&Machine<S, E>) + 'static,
+    {
+        self.node.exit.push(Rc::new(action));
+        self
+    }
+}
+
+/// Definition of a statechart.
+pub struct Chart<S, E> {
+    initial: S,
+    nodes: Vec<Node<S, E>>,
+}
+
+impl<S, E> Chart<S, E>
+where
+    S: Copy + Eq + Hash + Debug + 'static,
+    E: Debug + 'static,
+{
+    /// Returns a new chart that starts in the given state.
+    pub fn new(initial: S) -> Chart<S, E> {
+        Chart {
+            initial,
+            nodes: vec![],
+        }
+    }
+
+    /// Adds a state to this chart, which is configured with the given closure.
+    pub fn state<F>(mut self, id: S, build: F) -> Self
+    where
+        F: FnOnce(StateBuilder<S, E>) -> StateBuilder<S, E>,
+    {
+        assert!(
+            self.nodes.iter().all(|node| node.id != id),
+            "State {:?} is declared more than once.",
+            id
+        );
+
+        let builder = build(StateBuilder {
+            node: Node {
+                id,
+                parent: None,
+                initial: None,
+                parallel: false,
+                transitions: vec![],
+                delayed: vec![],
+                entry: vec![],
+                exit: vec![],
+            },
+        });
+
+        self.nodes.push(builder.node);
+        self
+    }
+
+    /// Exports this chart to Graphviz DOT. Nested and parallel states are
+    /// rendered as clusters (parallel states with a dashed border).
+    pub fn to_dot(&self) -> String {
+        Graph::new(self).to_dot()
+    }
+}
+
+/// Chart with resolved indices.
+struct Graph<'a, S, E> {
+    chart: &'a Chart<S, E>,
+    index: HashMap<S, usize>,
+    parents: Vec<Option<usize>>,
+    children: Vec<Vec<usize>>,
+    initial: usize,
+}
+
+impl<'a, S, E> Graph<'a, S, E>
+where
+    S: Copy + Eq + Hash + Debug + 'static,
+    E: Debug + 'static,
+{
+    fn new(chart: &'a Chart<S, E>) -> Graph<'a, S, E> {
+        let index = chart
+            .nodes
+            .iter()
+            .enumerate()
+            .map(|(i, node)| (node.id, i))
+            .collect::<HashMap<_, _>>();
+
+        let resolve = |id: &S| match index.get(id) {
+            Some(&i) => i,
+            None => panic!("State {:?} is not declared.", id),
+        };
+
+        let parents = chart
+            .nodes
+            .iter()
+            .map(|node| node.parent.as_ref().map(resolve))
+            .collect::<Vec<_>>();
+
+        let mut children = vec![vec![]; chart.nodes.len()];
+
+        for (i, parent) in parents.iter().enumerate() {
+            if let &Some(parent) = parent {
+                children[parent].push(i);
+            }
+        }
+
+        // We make sure that all targets are declared before the chart is used.
+        for node in chart.nodes.iter() {
+            let targets = node
+                .initial
+                .iter()
+                .chain(node.transitions.iter().map(|transition| &transition.target))
+                .chain(node.delayed.iter().map(|(_, target)| target));
+
+            for target in targets {
+                resolve(target);
+            }
+        }
+
+        let initial = resolve(&chart.initial);
+
+        Graph {
+            chart,
+            index,
+            parents,
+            children,
+            initial,
+        }
+    }
+
+    fn node(&self, i: usize) -> &Node<S, E> {
+        &self.chart.nodes[i]
+    }
+
+    /// Returns the proper ancestors of the given state, nearest first.
+    fn ancestors(&self, mut i: usize) -> Vec<usize> {
+        let mut ancestors = vec![];
+
+        while let Some(parent) = self.parents[i] {
+            ancestors.push(parent);
+            i = parent;
+        }
+
+        ancestors
+    }
+
+    fn depth(&self, i: usize) -> usize {
+        self.ancestors(i).len()
+    }
+
+    /// Returns the child that is entered by default when the given compound
+    /// state is entered.
+    fn initial_child(&self, i: usize) -> Option<usize> {
+        match self.node(i).initial {
+            Some(initial) => Some(self.index[&initial]),
+            None => self.children[i].first().copied(),
+        }
+    }
+
+    /// Returns the innermost compound state that properly contains both the
+    /// source and the target of a transition. `None` represents the root.
+    fn domain(&self, source: usize, target: usize) -> Option<usize> {
+        let targets = self.ancestors(target);
+
+        self.ancestors(source)
+            .into_iter()
+            .find(|ancestor| !self.node(*ancestor).parallel && targets.contains(ancestor))
+    }
+
+    /// Returns the atomic state that represents the given state in exports.
+    fn anchor(&self, mut i: usize) -> usize {
+        while let Some(child) = self.initial_child(i) {
+            i = child;
+        }
+
+        i
+    }
+
+    fn to_dot(&self) -> String {
+        let name = |i: usize| format!("{:?}", self.node(i).id).replace('"', "\\\"");
+
+        let mut dot = String::new();
+        writeln!(dot, "digraph {{").unwrap();
+        writeln!(dot, "    compound=true;").unwrap();
+        writeln!(dot, "    node [shape=box, style=rounded];").unwrap();
+        writeln!(dot, "    __initial [shape=point];").unwrap();
+
+        fn write_state<S, E>(
+            graph: &Graph<S, E>,
+            dot: &mut String,
+            i: usize,
+            indent: usize,
+            name: &dyn Fn(usize) -> String,
+        ) where
+            S: Copy + Eq + Hash + Debug + 'static,
+            E: Debug + 'static,
+        {
+            let padding = "    ".repeat(indent);
+
+            if graph.children[i].is_empty() {
+                writeln!(dot, "{}\"{}\";", padding, name(i)).unwrap();
+                return;
+            }
+
+            writeln!(dot, "{}subgraph \"cluster_{}\" {{", padding, name(i)).unwrap();
+            writeln!(dot, "{}    label=\"{}\";", padding, name(i)).unwrap();
+
+            if graph.node(i).parallel {
+                writeln!(dot, "{}    style=dashed;", padding).unwrap();
+            }
+
+            for &child in graph.children[i].iter() {
+                write_state(graph, dot, child, indent + 1, name);
+            }
+
+            writeln!(dot, "{}}}", padding).unwrap();
+        }
+
+        for i in (0..self.chart.nodes.len()).filter(|&i| self.parents[i].is_none()) {
+            write_state(self, &mut dot, i, 1, &name);
+        }
+
+        let mut edge = |source: usize, target: usize, label: String| {
+            let mut attributes = vec![format!("label=\"{}\"", label)];
+
+            if !self.children[source].is_empty() {
+                attributes.push(format!("ltail=\"cluster_{}\"", name(source)));
+            }
+
+            if !self.children[target].is_empty() {
+                attributes.push(format!("lhead=\"cluster_{}\"", name(target)));
+            }
+
+            writeln!(
+                dot,
+                "    \"{}\" -> \"{}\" [{}];",
+                name(self.anchor(source)),
+                name(self.anchor(target)),
+                attributes.join(", ")
+            )
+            .unwrap();
+        };
+
+        for (i, node) in self.chart.nodes.iter().enumerate() {
+            for transition in node.transitions.iter() {
+                let label = match transition.guard.is_some() {
+                    true => format!("{} [guarded]", transition.label),
+                    false => transition.label.clone(),
+                };
+
+                edge(i, self.index[&transition.target], label);
+            }
+
+            for (duration, target) in node.delayed.iter() {
+                edge(i, self.index[target], format!("after {:?}", duration));
+            }
+        }
+
+        writeln!(
+            dot,
+            "    __initial -> \"{}\";",
+            name(self.anchor(self.initial))
+        )
+        .unwrap();
+        writeln!(dot, "}}").unwrap();
+
+        dot
+    }
+}
+
+struct Inner<S, E> {
+    chart: Chart<S, E>,
+    active: BTreeSet<usize>,
+    pending: Vec<Action<S, E>>,
+    timers: HashMap<usize, Vec<Disposable>>,
+    rerender: Box<dyn Fn()>,
+    schedule: Scheduler,
+}
+
+/// Running instance of a chart. Machines are cheap to clone: clones refer to
+/// the same configuration.
+pub struct Machine<S, E> {
+    inner: Rc<RefCell<Inner<S, E>>>,
+}
+
+impl<S, E> Machine<S, E>
+where
+    S: Copy + Eq + Hash + Debug + 'static,
+    E: Debug + 'static,
+{
+    /// Returns a new machine that invokes the given closure whenever its
+    /// configuration changes and uses the given scheduler for delayed
+    /// transitions. The machine immediately enters its initial states, but
+    /// their entry actions only run once `flush` is called.
+    pub(crate) fn new(chart: Chart<S, E>, rerender: Box<dyn Fn()>, schedule: Scheduler) -> Self {
+        let machine = Machine {
+            inner: Rc::new(RefCell::new(Inner {
+                chart,
+                active: BTreeSet::new(),
+                pending: vec![],
+                timers: HashMap::new(),
+                rerender,
+                schedule,
+            })),
+        };
+
+        {
+            let mut inner = machine.inner.borrow_mut();
+            let graph = Graph::new(&inner.chart);
+            let initial = graph.initial;
+            let entered = machine.enter(&graph, initial, None);
+            drop(graph);
+            machine.apply(&mut inner, entered, vec![]);
+        }
+
+        machine
+    }
+
+    /// Returns true if the given state is currently active.
+    pub fn matches(&self, state: S) -> bool {
+        let inner = self.inner.borrow();
+
+        inner
+            .chart
+            .nodes
+            .iter()
+            .position(|node| node.id == state)
+            .map(|i| inner.active.contains(&i))
+            .unwrap_or_default()
+    }
+
+    /// Returns all states that are currently active, in the order in which
+    /// they were declared.
+    pub fn configuration(&self) -> Vec<S> {
+        let inner = self.inner.borrow();
+
+        inner
+            .active
+            .iter()
+            .map(|&i| inner.chart.nodes[i].id)
+            .collect()
+    }
+
+    /// Sends the given event to this machine and returns true if it caused
+    /// any transitions. Guards must not access the machine itself.
+    pub fn send(&self, event: E) -> bool {
+        let discriminant = std::mem::discriminant(&event);
+        let mut inner = self.inner.borrow_mut();
+
+        let selected = {
+            let graph = Graph::new(&inner.chart);
+            let mut selected: Vec<(usize, usize)> = vec![];
+
+            // Each active atomic state selects the first enabled transition
+            // of itself or its nearest ancestor.
+            for &atomic in inner
+                .active
+                .iter()
+                .filter(|&&i| graph.children[i].is_empty())
+            {
+                let candidates = std::iter::once(atomic).chain(graph.ancestors(atomic));
+
+                let transition = candidates.into_iter().find_map(|source| {
+                    graph
+                        .node(source)
+                        .transitions
+                        .iter()
+                        .find(|transition| {
+                            transition.event == discriminant
+                                && transition
+                                    .guard
+                                    .as_ref()
+                                    .map(|guard| guard(&event))
+                                    .unwrap_or(true)
+                        })
+                        .map(|transition| (source, graph.index[&transition.target]))
+                });
+
+                if let Some(transition) = transition {
+                    if selected.iter().all(|(source, _)| *source != transition.0) {
+                        selected.push(transition);
+                    }
+                }
+            }
+
+            selected
+        };
+
+        for &(source, target) in selected.iter() {
+            // An earlier transition may have exited the source of this one.
+            if inner.active.contains(&source) {
+                self.transition(&mut inner, source, target);
+            }
+        }
+
+        if !selected.is_empty() {
+            (inner.rerender)();
+        }
+
+        !selected.is_empty()
+    }
+
+    /// Runs the entry and exit actions of all transitions that have been
+    /// taken since the previous flush.
+    pub fn flush(&self) {
+        let actions = std::mem::take(&mut self.inner.borrow_mut().pending);
+
+        for action in actions {
+            action(self);
+        }
+    }
+
+    /// Exports the chart of this machine to Graphviz DOT.
+    pub fn to_dot(&self) -> String {
+        self.inner.borrow().chart.to_dot()
+    }
+
+    fn transition(&self, inner: &mut Inner<S, E>, source: usize, target: usize) {
+        let graph = Graph::new(&inner.chart);
+        let domain = graph.domain(source, target);
+
+        // We exit the active descendants of the domain, deepest first.
+        let mut exited = inner
+            .active
+            .iter()
+            .copied()
+            .filter(|&i| match domain {
+                Some(domain) => graph.ancestors(i).contains(&domain),
+                None => true,
+            })
+            .collect::<Vec<_>>();
+        exited.sort_by_key(|&i| std::cmp::Reverse(graph.depth(i)));
+
+        let entered = self.enter(&graph, target, domain);
+        drop(graph);
+
+        self.apply(inner, entered, exited);
+    }
+
+    /// Returns the states that are entered (in order) when the given target
+    /// is entered from the given domain.
+    fn enter(&self, graph: &Graph<S, E>, target: usize, domain: Option<usize>) -> Vec<usize> {
+        fn enter_default<S, E>(graph: &Graph<S, E>, i: usize, entered: &mut Vec<usize>)
+        where
+            S: Copy + Eq + Hash + Debug + 'static,
+            E: Debug + 'static,
+        {
+            entered.push(i);
+            enter_descendants(graph, i, entered);
+        }
+
+        fn enter_descendants<S, E>(graph: &Graph<S, E>, i: usize, entered: &mut Vec<usize>)
+        where
+            S: Copy + Eq + Hash + Debug + 'static,
+            E: Debug + 'static,
+        {
+            if graph.node(i).parallel {
+                for &child in graph.children[i].iter() {
+                    enter_default(graph, child, entered);
+                }
+            } else if let Some(child) = graph.initial_child(i) {
+                enter_default(graph, child, entered);
+            }
+        }
+
+        let mut path = graph
+            .ancestors(target)
+            .into_iter()
+            .take_while(|&ancestor| Some(ancestor) != domain)
+            .collect::<Vec<_>>();
+        path.reverse();
+        path.push(target);
+
+        let mut entered = vec![];
+
+        for &i in path.iter() {
+            entered.push(i);
+
+            // Entering a parallel state enters all of its regions, not just
+            // the region that contains the target.
+            if graph.node(i).parallel && i != target {
+                for &child in graph.children[i].iter() {
+                    if !path.contains(&child) {
+                        enter_default(graph, child, &mut entered);
+                    }
+                }
+            }
+        }
+
+        enter_descendants(graph, target, &mut entered);
+
+        entered
+    }
+
+    /// Exits and enters the given states, queues their actions and schedules
+    /// their delayed transitions.
+    fn apply(&self, inner: &mut Inner<S, E>, entered: Vec<usize>, exited: Vec<usize>) {
+        for i in exited {
+            inner.active.remove(&i);
+
+            // Dropping the timers of a state cancels them.
+            inner.timers.remove(&i);
+
+            let actions = inner.chart.nodes[i].exit.clone();
+            inner.pending.extend(actions);
+        }
+
+        for i in entered {
+            if !inner.active.insert(i) {
+                continue;
+            }
+
+            let node = &inner.chart.nodes[i];
+            let actions = node.entry.clone();
+            let delayed = node.delayed.clone();
+            inner.pending.extend(actions);
+
+            for (duration, target) in delayed {
+                let weak = Rc::downgrade(&self.inner);
+                let timer = (inner.schedule)(
+                    duration,
+                    Box::new(move || {
+                        if let Some(inner) = Weak::upgrade(&weak) {
+                            Machine { inner }.fire(i, target);
+                        }
+                    }),
+                );
+
+                inner.timers.entry(i).or_default().extend(timer);
+            }
+        }
+    }
+
+    /// Takes a delayed transition from the given state if it is still active.
+    fn fire(&self, source: usize, target: S) {
+        let mut inner = self.inner.borrow_mut();
+
+        if !inner.active.contains(&source) {
+            return;
+        }
+
+        let target = match inner.chart.nodes.iter().position(|node| node.id == target) {
+            Some(target) => target,
+            None => return,
+        };
+
+        self.transition(&mut inner, source, target);
+        (inner.rerender)();
+    }
+}
+
+impl<S, E> Clone for Machine<S, E> {
+    fn clone(&self) -> Self {
+        Machine {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<M> UseMachine for M
+where
+    M: UseReference + UseEffect<<M as Link>::Platform> + Link,
+{
+    fn use_machine<S, E, F>(&mut self, key: Key, chart: F) -> Machine<S, E>
+    where
+        S: Copy + Eq + Hash + Debug + 'static,
+        E: Debug + 'static,
+        F: FnOnce() -> Chart<S, E>,
+    {
+        let instance = super::Weak::new(self.instance());
+
+        let machine = self
+            .use_reference(key.clone(), move || {
+                let rerender = {
+                    let instance = instance.clone();
+
+                    move || {
+                        if let Some(instance) = instance.upgrade() {
+                            instance.renderer().queue_rerender(&instance);
+                        }
+                    }
+                };
+
+                let schedule = move |duration, callback: Box<dyn FnOnce()>| {
+                    let instance = instance.upgrade()?;
+                    let bus = instance.renderer().bus();
+                    let delay = bus.executor().delay(duration);
+
+                    Some(bus.queue(async move {
+                        delay.await;
+                        callback();
+                    }))
+                };
+
+                Machine::new(chart(), Box::new(rerender), Box::new(schedule))
+            })
+            .cloned(self);
+
+        let effect = machine.clone();
+        self.use_effect(key, None, move |_| effect.flush());
+
+        machine
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::{Cell, RefCell};
+    use std::rc::Rc;
+    use std::time::Duration;
+
+    use super::{Chart, Machine};
+    use crate::testing::{render, rerender, TestComponent, TestPlatform};
+    use crate::{Disposable, Element, Key, Manager};
+
+    #[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+    enum Step {
+        Idle,
+        Checkout,
+        Shipping,
+        Payment,
+        Method,
+        Card,
+        Wallet,
+        Verification,
+        Pending,
+        Verified,
+        Done,
+    }
+
+    #[derive(Debug)]
+    enum Event {
+        Start,
+        Next,
+        Pay { amount: u32 },
+        Cancel,
+    }
+
+    type Log = Rc<RefCell<Vec<String>>>;
+    type Timers = Rc<RefCell<Vec<(Duration, Box<dyn FnOnce()>)>>>;
+
+    fn chart(log: &Log) -> Chart<Step, Event> {
+        let entry = |log: &Log, step: Step| {
+            let log = log.clone();
+            move |_: &Machine<Step, Event>| log.borrow_mut().push(format!("enter {:?}", step))
+        };
+
+        let exit = |log: &Log, step: Step| {
+            let log = log.clone();
+            move |_: &Machine<Step, Event>| log.borrow_mut().push(format!("exit {:?}", step))
+        };
+
+        Chart::new(Step::Idle)
+            .state(Step::Idle, |s| s.on(Event::Start, Step::Checkout))
+            .state(Step::Checkout, |s| {
+                s.initial(Step::Shipping)
+                    .on(Event::Cancel, Step::Idle)
+                    .exit(exit(log, Step::Checkout))
+            })
+            .state(Step::Shipping, |s| {
+                s.parent(Step::Checkout).on(Event::Next, Step::Payment)
+            })
+            .state(Step::Payment, |s| {
+                s.parent(Step::Checkout)
+                    .parallel()
+                    .on_if(
+                        Event::Pay { amount: 0 },
+                        |event| matches!(event, Event::Pay { amount } if *amount > 0),
+                        Step::Done,
+                    )
+                    .entry(entry(log, Step::Payment))
+            })
+            .state(Step::Method, |s| s.parent(Step::Payment))
+            .state(Step::Card, |s| {
+                s.parent(Step::Method).on(Event::Next, Step::Wallet)
+            })
+            .state(Step::Wallet, |s| s.parent(Step::Method))
+            .state(Step::Verification, |s| s.parent(Step::Payment))
+            .state(Step::Pending, |s| {
+                s.parent(Step::Verification)
+                    .after(Duration::from_secs(1), Step::Verified)
+                    .entry(entry(log, Step::Pending))
+                    .exit(exit(log, Step::Pending))
+            })
+            .state(Step::Verified, |s| s.parent(Step::Verification))
+            .state(Step::Done, |s| s.entry(entry(log, Step::Done)))
+    }
+
+    struct Timer(Rc<Cell<usize>>);
+
+    impl Drop for Timer {
+        fn drop(&mut self) {
+            self.0.set(self.0.get() + 1);
+        }
+    }
+
+    fn machine(log: &Log, timers: &Timers, canceled: &Rc<Cell<usize>>) -> Machine<Step, Event> {
+        let timers = timers.clone();
+        let canceled = canceled.clone();
+
+        Machine::new(
+            chart(log),
+            Box::new(|| {}),
+            Box::new(move |duration, callback| {
+                timers.borrow_mut().push((duration, callback));
+                Some(Disposable::new(Timer(canceled.clone())))
+            }),
+        )
+    }
+
+    #[test]
+    fn test_transitions() {
+        let log = Log::default();
+        let timers = Timers::default();
+        let canceled = Rc::new(Cell::new(0));
+        let machine = machine(&log, &timers, &canceled);
+
+        assert_eq!(machine.configuration(), vec![Step::Idle]);
+        assert!(!machine.send(Event::Next));
+
+        assert!(machine.send(Event::Start));
+        assert_eq!(
+            machine.configuration(),
+            vec![Step::Checkout, Step::Shipping]
+        );
+
+        // Entering a parallel state enters all of its regions.
+        assert!(machine.send(Event::Next));
+        assert_eq!(
+            machine.configuration(),
+            vec![
+                Step::Checkout,
+                Step::Payment,
+                Step::Method,
+                Step::Card,
+                Step::Verification,
+                Step::Pending,
+            ]
+        );
+
+        // Actions only run once flushed.
+        assert!(log.borrow().is_empty());
+        machine.flush();
+        assert_eq!(*log.borrow(), vec!["enter Payment", "enter Pending"]);
+        log.borrow_mut().clear();
+
+        // Transitions within one region don't affect other regions.
+        assert!(machine.send(Event::Next));
+        assert!(machine.matches(Step::Wallet));
+        assert!(machine.matches(Step::Pending));
+
+        // Guards can inspect the payload of an event.
+        assert!(!machine.send(Event::Pay { amount: 0 }));
+        assert!(machine.send(Event::Pay { amount: 42 }));
+        assert_eq!(machine.configuration(), vec![Step::Done]);
+
+        machine.flush();
+        assert_eq!(
+            *log.borrow(),
+            vec!["exit Pending", "exit Checkout", "enter Done"]
+        );
+    }
+
+    #[test]
+    fn test_delayed_transitions() {
+        let log = Log::default();
+        let timers = Timers::default();
+        let canceled = Rc::new(Cell::new(0));
+        let machine = machine(&log, &timers, &canceled);
+
+        machine.send(Event::Start);
+        machine.send(Event::Next);
+        assert_eq!(timers.borrow().len(), 1);
+        assert_eq!(timers.borrow()[0].0, Duration::from_secs(1));
+
+        let (_, callback) = timers.borrow_mut().remove(0);
+        callback();
+        assert!(machine.matches(Step::Verified));
+        assert_eq!(canceled.get(), 1);
+
+        // Exiting a state cancels its pending timers.
+        machine.send(Event::Cancel);
+        machine.send(Event::Start);
+        machine.send(Event::Next);
+        assert_eq!(canceled.get(), 1);
+        machine.send(Event::Cancel);
+        assert_eq!(canceled.get(), 2);
+        assert_eq!(machine.configuration(), vec![Step::Idle]);
+    }
+
+    #[test]
+    fn test_to_dot() {
+        let dot = chart(&Log::default()).to_dot();
+
+        assert!(dot.starts_with("digraph {"));
+        assert!(dot.contains("subgraph \"cluster_Payment\" {"));
+        assert!(dot.contains("style=dashed;"));
+        assert!(
+            dot.contains("\"Shipping\" -> \"Card\" [label=\"Next\", lhead=\"cluster_Payment\"];")
+        );
+        assert!(dot.contains("[label=\"Pay [guarded]\", ltail=\"cluster_Payment\"]"));
+        assert!(dot.contains("\"Pending\" -> \"Verified\" [label=\"after 1s\"];"));
+        assert!(dot.contains("__initial -> \"Idle\";"));
+    }
+
+    struct Flow {
+        log: Log,
+        machine: Rc<RefCell<Option<Machine<Step, Event>>>>,
+    }
+
+    impl TestComponent for Flow {
+        fn render(&self, manager: &mut Manager<TestPlatform>) -> Element<TestPlatform> {
+            let machine = use_machine!(manager, chart(&self.log));
+            self.machine.replace(Some(machine));
+
+            Element::empty()
+        }
+    }
+
+    #[test]
+    fn test_use_machine() {
+        let log = Log::default();
+        let machine = Rc::new(RefCell::new(None));
+
+        let (renderer, instance, _handler) = render(Element::new(
+            Key::new(()),
+            Flow {
+                log: log.clone(),
+                machine: machine.clone(),
+            }
+            .into(),
+            Element::empty(),
+        ));
+
+        let first = machine.borrow_mut().take().unwrap();
+        first.send(Event::Start);
+        first.send(Event::Next);
+
+        // The machine survives re-renders.
+        rerender(&renderer, &instance);
+        let second = machine.borrow_mut().take().unwrap();
+        assert!(second.matches(Step::Payment));
+    }
+}
@@ -1,11 +1,12 @@
 use super::hooks::{UseAsync, UseContext, UseEffect, UseLayoutEffect, UseReference, UseState};
 use super::{
-    Context, ContextTree, Effect, EffectLink, Element, EventLoop, Instance, Key, LayoutEffect,
-    Link, Memory, Platform, Reference, State, Weak, WeakLink,
+    Context, ContextTree, Effect, EffectLink, Element, EventLoop, Executor, Instance, Key,
+    LayoutEffect, Link, Memory, Platform, Reference, State, UseWorker, Weak, WeakLink,
 };
 use serde::{Deserialize, Serialize};
+use std::any::TypeId;
 use std::future::Future;
-use std::rc::Rc;
+use std::sync::Arc;
 
 pub struct Manager<'a, P>
 where
@@ -15,10 +16,11 @@ where
     bus: &'a EventLoop,
     memory: &'a mut Memory,
     context: &'a ContextTree,
-    children: Element<P>,
+    children: &'a Element<P>,
     effects: Vec<Effect<P>>,
     layout_effects: Vec<LayoutEffect<P>>,
-    instance: &'a Rc<Instance<P>>,
+    instance: &'a Instance<P>,
+    contexts: Vec<TypeId>,
 }
 
 impl<'a, P> Manager<'a, P>
@@ -30,8 +32,8 @@ where
         bus: &'a EventLoop,
         memory: &'a mut Memory,
         context: &'a ContextTree,
-        children: Element<P>,
-        instance: &'a Rc<Instance<P>>,
+        children: &'a Element<P>,
+        instance: &'a Instance<P>,
     ) -> Manager<'a, P> {
         Manager {
             compositor,
@@ -42,6 +44,7 @@ where
             effects: vec![],
             layout_effects: vec![],
             instance,
+            contexts: vec![],
         }
     }
 
@@ -49,6 +52,12 @@ where
         self.compositor
     }
 
+    /// Returns the executor that drives the event loop. This can be used to
+    /// spawn tasks, create timers and offload blocking work.
+    pub fn executor(&self) -> &Arc<dyn Executor> {
+        self.bus.executor()
+    }
+
     pub fn children(&mut self) -> Element<P> {
         self.children.clone()
     }
@@ -64,6 +73,11 @@ where
         }
     }
 
+    /// Returns the types of the contexts that were used during this render.
+    pub(crate) fn contexts(&self) -> &[TypeId] {
+        &self.contexts
+    }
+
     pub(crate) fn into_effects(self) -> (Vec<Effect<P>>, Vec<LayoutEffect<P>>) {
         (self.effects, self.layout_effects)
     }
@@ -90,6 +104,7 @@ where
     where
         T: 'static,
     {
+        self.contexts.push(TypeId::of::<T>());
         self.context.get::<T>().map(|value| Context::new(&value))
     }
 }
@@ -166,7 +181,7 @@ where
 {
     type Platform = P;
 
-    fn instance(&self) -> &Rc<Instance<Self::Platform>> {
+    fn instance(&self) -> &Instance<Self::Platform> {
         self.instance
     }
 
@@ -174,3 +189,37 @@ where
         self.memory
     }
 }
+
+impl<'a, P> UseWorker for Manager<'a, P>
+where
+    P: Platform + ?Sized,
+{
+    fn use_worker<T, F>(&mut self, key: Key, dependencies: Key, task: F) -> Option<T>
+    where
+        T: Clone + Send + 'static,
+        F: FnOnce() -> T + Send + 'static,
+    {
+        let result: Reference<Option<T>> = self.use_reference(key.clone(), || None);
+
+        if self.memory.effect(key.clone(), dependencies) {
+            // The result of the previous dependencies (if any) is stale now.
+            result.replace(self, None);
+
+            let rx = self.bus.workers().spawn(task);
+            let result = result.weak(self);
+
+            // Replacing the previous future cancels it, which also drops its
+            // receiver, so a stale result will never be delivered.
+            let future = self.bus.queue(async move {
+                if let Ok(value) = rx.await {
+                    result.replace(Some(value));
+                    result.queue_rerender();
+                }
+            });
+
+            self.memory.replace_future(key, future);
+        }
+
+        result.apply(self, |result| result.clone())
+    }
+}
@@ -1,7 +1,9 @@
+use serde::{Deserialize, Serialize};
 use std::any::Any;
 use std::cell::{Ref, RefCell, RefMut};
 use std::collections::HashMap;
 
+use super::timetravel::Codec;
 use super::{Disposable, Key};
 
 pub struct Memory {
@@ -9,6 +11,10 @@ pub struct Memory {
     state: Vec<Box<RefCell<dyn Any>>>,
     state_ids: HashMap<Key, usize>,
 
+    /// This is the codec of each state, which is used to record and restore
+    /// state changes (see `StateLog`).
+    state_codecs: Vec<Codec>,
+
     /// This is a map of references of this component.
     references: Vec<Box<RefCell<dyn Any>>>,
     reference_ids: HashMap<Key, usize>,
@@ -24,6 +30,7 @@ impl Memory {
         Memory {
             state: vec![],
             state_ids: HashMap::new(),
+            state_codecs: vec![],
             references: vec![],
             reference_ids: HashMap::new(),
             effects: HashMap::new(),
@@ -31,6 +38,16 @@ impl Memory {
         }
     }
 
+    /// Returns the number of slots (states and references) in this memory.
+    pub fn len(&self) -> usize {
+        self.state.len() + self.references.len()
+    }
+
+    /// Returns a boolean that indicates if this memory does not have any slots.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
     pub fn state(&self, id: usize) -> Ref<dyn Any> {
         self.state[id].borrow()
     }
@@ -42,18 +59,31 @@ impl Memory {
     pub fn state_id<F, T>(&mut self, key: Key, initializer: F) -> usize
     where
         F: FnOnce() -> T,
-        T: 'static,
+        T: Serialize + for<'b> Deserialize<'b> + 'static,
     {
         let state = &mut self.state;
+        let state_codecs = &mut self.state_codecs;
         let &mut id = self.state_ids.entry(key).or_insert_with(|| {
             let value = initializer();
             state.push(Box::new(RefCell::new(value)));
+            state_codecs.push(Codec::of::<T>());
             state.len() - 1
         });
 
         id
     }
 
+    pub(crate) fn state_codec(&self, id: usize) -> &Codec {
+        &self.state_codecs[id]
+    }
+
+    pub(crate) fn state_key(&self, id: usize) -> Option<&Key> {
+        self.state_ids
+            .iter()
+            .find(|(_, &state_id)| state_id == id)
+            .map(|(key, _)| key)
+    }
+
     pub fn reference(&self, id: usize) -> Ref<dyn Any> {
         self.references[id].borrow()
     }
@@ -77,6 +107,13 @@ impl Memory {
         id
     }
 
+    pub(crate) fn reference_key(&self, id: usize) -> Option<&Key> {
+        self.reference_ids
+            .iter()
+            .find(|(_, &reference_id)| reference_id == id)
+            .map(|(key, _)| key)
+    }
+
     pub fn effect(&mut self, key: Key, conditions: Key) -> bool {
         let result = !self
             .effects
@@ -95,4 +132,9 @@ impl Memory {
     {
         self.futures.entry(key).or_insert_with(initializer);
     }
+
+    pub fn replace_future(&mut self, key: Key, future: Disposable) {
+        // Dropping the previous disposable cancels its future.
+        self.futures.insert(key, future);
+    }
 }
@@ -1,6 +1,9 @@
 use std::hash::Hash;
+use std::sync::Arc;
 
-use super::{CommandBuffer, Component, Compositor, Container, Disposable, EventLoop};
+use super::{
+    CommandBuffer, Component, Compositor, Container, Disposable, EventLoop, Executor, LocalExecutor,
+};
 
 /// This is a platform that needs to be implemented by every render host.
 pub trait Platform: 'static {
@@ -21,7 +24,17 @@ pub trait Platform: 'static {
 
     type Environment;
 
-    fn with_compositor<F>(container: Self::Container, task: F) -> Disposable
+    /// Returns the executor that is used by `render` to drive the event loop.
+    /// Platforms that depend on a specific async runtime should override this.
+    fn default_executor() -> Arc<dyn Executor> {
+        Arc::new(LocalExecutor::new())
+    }
+
+    fn with_compositor<F>(
+        container: Self::Container,
+        executor: Arc<dyn Executor>,
+        task: F,
+    ) -> Disposable
     where
         F: FnOnce(Self::ContainerID, Self::Compositor, EventLoop) -> Disposable + Send + 'static;
 }
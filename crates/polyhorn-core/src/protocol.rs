@@ -0,0 +1,447 @@
+//! Data-driven representation of the commands that the renderer issues. In
+//! contrast to `Command`, which carries opaque closures, operations carry
+//! serializable builtin payloads. This makes it possible to record operations
+//! to a file, replay them against a platform or stream them over a socket to
+//! a renderer in another process.
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use super::{Builtin, CommandBuffer, Container, Platform};
+
+/// Operation on the containers of a platform.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub enum Operation<I, B> {
+    /// Instantiates the given builtin into a new container with the given ID
+    /// and mounts it onto the parent container.
+    Mount { id: I, parent: I, builtin: B },
+
+    /// Updates the container with the given ID with the given builtin.
+    Update { id: I, builtin: B },
+
+    /// Unmounts the container with the given ID.
+    Unmount { id: I },
+
+    /// Moves the children of the given parent container into the given order.
+    Reorder { parent: I, children: Vec<I> },
+}
+
+impl<I, B> Operation<I, B> {
+    /// Converts the builtin of this operation with the given function. Returns
+    /// `None` if the function can't convert the builtin.
+    pub fn try_map<F, C>(self, op: F) -> Option<Operation<I, C>>
+    where
+        F: FnOnce(B) -> Option<C>,
+    {
+        Some(match self {
+            Operation::Mount {
+                id,
+                parent,
+                builtin,
+            } => Operation::Mount {
+                id,
+                parent,
+                builtin: op(builtin)?,
+            },
+            Operation::Update { id, builtin } => Operation::Update {
+                id,
+                builtin: op(builtin)?,
+            },
+            Operation::Unmount { id } => Operation::Unmount { id },
+            Operation::Reorder { parent, children } => Operation::Reorder { parent, children },
+        })
+    }
+}
+
+/// Serializable representation of builtins that can be recorded and
+/// replayed. Usually, this is an enum that mirrors the builtins of a platform
+/// without the closures and other runtime state that they carry.
+pub trait Payload<P>: Clone + Serialize + DeserializeOwned + 'static
+where
+    P: Platform + ?Sized,
+{
+    /// This is the builtin that this payload represents.
+    type Builtin: Builtin<P> + 'static;
+
+    /// Encodes the given builtin into a payload.
+    fn encode(builtin: &Self::Builtin) -> Self;
+
+    /// Decodes this payload into a builtin that can be instantiated.
+    fn decode(self) -> Self::Builtin;
+}
+
+/// Receives the operations of a renderer before their builtins are encoded.
+type Sink<P> = Box<dyn FnMut(Operation<<P as Platform>::ContainerID, &dyn Builtin<P>>)>;
+
+/// Records the operations of a renderer (see `Renderer::record`). In addition
+/// to forwarding operations to its sink, the recorder keeps track of the
+/// order of the children of each container so that it can emit reorder
+/// operations.
+pub(crate) struct Recorder<P>
+where
+    P: Platform + ?Sized,
+{
+    sink: Sink<P>,
+    parents: HashMap<P::ContainerID, P::ContainerID>,
+    children: HashMap<P::ContainerID, Vec<P::ContainerID>>,
+}
+
+impl<P> Recorder<P>
+where
+    P: Platform + ?Sized,
+{
+    pub fn new<F>(sink: F) -> Recorder<P>
+    where
+        F: FnMut(Operation<P::ContainerID, &dyn Builtin<P>>) + 'static,
+    {
+        Recorder {
+            sink: Box::new(sink),
+            parents: HashMap::new(),
+            children: HashMap::new(),
+        }
+    }
+
+    pub fn mount(&mut self, id: P::ContainerID, parent: P::ContainerID, builtin: &dyn Builtin<P>) {
+        self.parents.insert(id, parent);
+        self.children.entry(parent).or_default().push(id);
+
+        (self.sink)(Operation::Mount {
+            id,
+            parent,
+            builtin,
+        });
+    }
+
+    pub fn update(&mut self, id: P::ContainerID, builtin: &dyn Builtin<P>) {
+        (self.sink)(Operation::Update { id, builtin });
+    }
+
+    pub fn unmount(&mut self, id: P::ContainerID) {
+        self.children.remove(&id);
+
+        if let Some(parent) = self.parents.remove(&id) {
+            if let Some(children) = self.children.get_mut(&parent) {
+                children.retain(|&child| child != id);
+            }
+        }
+
+        (self.sink)(Operation::Unmount { id });
+    }
+
+    /// Emits a reorder operation if the given order of children differs from
+    /// the order in which they were mounted.
+    pub fn reorder(&mut self, parent: P::ContainerID, children: Vec<P::ContainerID>) {
+        let previous = self.children.entry(parent).or_default();
+
+        if *previous == children {
+            return;
+        }
+
+        *previous = children.clone();
+
+        (self.sink)(Operation::Reorder { parent, children });
+    }
+}
+
+/// Replays recorded operations onto a command buffer. Because the buffer
+/// assigns new IDs to the containers that it mounts, the replayer maintains a
+/// map from recorded IDs to live IDs.
+pub struct Replayer<P>
+where
+    P: Platform + ?Sized,
+{
+    ids: HashMap<P::ContainerID, P::ContainerID>,
+}
+
+impl<P> Replayer<P>
+where
+    P: Platform + ?Sized,
+{
+    /// Returns a new replayer that replays operations on the recorded root
+    /// container onto the given live root container.
+    pub fn new(recorded_root: P::ContainerID, root: P::ContainerID) -> Replayer<P> {
+        let mut ids = HashMap::new();
+        ids.insert(recorded_root, root);
+
+        Replayer { ids }
+    }
+
+    /// Returns the live ID of the container with the given recorded ID (if it
+    /// is currently mounted).
+    pub fn resolve(&self, id: &P::ContainerID) -> Option<P::ContainerID> {
+        self.ids.get(id).copied()
+    }
+
+    /// Applies the given operation to the given command buffer after decoding
+    /// its payload. Operations on containers that are not mounted are
+    /// ignored, just like `Composition` ignores commands on unknown
+    /// containers.
+    pub fn apply<B>(
+        &mut self,
+        buffer: &mut P::CommandBuffer,
+        operation: Operation<P::ContainerID, B>,
+    ) where
+        B: Payload<P>,
+    {
+        match operation {
+            Operation::Mount {
+                id,
+                parent,
+                builtin,
+            } => {
+                if let Some(parent) = self.resolve(&parent) {
+                    let builtin = builtin.decode();
+                    let container = buffer.mount(parent, move |parent, environment| {
+                        builtin.instantiate(parent, environment)
+                    });
+
+                    self.ids.insert(id, container);
+                }
+            }
+            Operation::Update { id, builtin } => {
+                if let Some(container) = self.resolve(&id) {
+                    let builtin = builtin.decode();
+                    buffer.mutate(&[container], move |containers, environment| {
+                        builtin.update(containers[0], environment)
+                    });
+                }
+            }
+            Operation::Unmount { id } => {
+                if let Some(container) = self.ids.remove(&id) {
+                    buffer.unmount(container);
+                }
+            }
+            Operation::Reorder { parent, children } => {
+                let ids = std::iter::once(parent)
+                    .chain(children)
+                    .map(|id| self.resolve(&id))
+                    .collect::<Option<Vec<_>>>();
+
+                if let Some(ids) = ids {
+                    buffer.mutate(&ids, |containers, environment| {
+                        if let Some((parent, children)) = containers.split_first_mut() {
+                            parent.reorder(children, environment);
+                        }
+                    });
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+    use std::any::Any;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+    use std::sync::Arc;
+
+    use super::{Operation, Payload, Replayer};
+    use crate::testing::{TestComponent, TestCompositor, TestContainer, TestPlatform};
+    use crate::{
+        Builtin, CommandBuffer, Compositor, Element, EventLoop, Key, Manager, Renderer,
+        TestExecutor,
+    };
+
+    const ROOT: usize = usize::MAX;
+
+    #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+    enum Node {
+        Stack,
+        Label(String),
+    }
+
+    impl Builtin<TestPlatform> for Node {
+        fn instantiate(&self, _parent: &mut TestContainer, _environment: &mut ()) -> TestContainer {
+            TestContainer
+        }
+
+        fn update(&self, _container: &mut TestContainer, _environment: &mut ()) {}
+
+        fn as_any(&self) -> Option<&dyn Any> {
+            Some(self)
+        }
+    }
+
+    impl Payload<TestPlatform> for Node {
+        type Builtin = Node;
+
+        fn encode(builtin: &Node) -> Node {
+            builtin.clone()
+        }
+
+        fn decode(self) -> Node {
+            self
+        }
+    }
+
+    struct List {
+        items: Rc<RefCell<Vec<&'static str>>>,
+    }
+
+    impl TestComponent for List {
+        fn render(&self, _manager: &mut Manager<TestPlatform>) -> Element<TestPlatform> {
+            let items = self
+                .items
+                .borrow()
+                .iter()
+                .map(|&item| {
+                    Element::builtin(
+                        Key::new(item),
+                        Node::Label(item.to_owned()),
+                        Element::empty(),
+                        None,
+                    )
+                })
+                .collect();
+
+            Element::builtin(
+                Key::new("stack"),
+                Node::Stack,
+                Element::fragment(Key::new(()), items),
+                None,
+            )
+        }
+    }
+
+    fn label(item: &str) -> Node {
+        Node::Label(item.to_owned())
+    }
+
+    #[test]
+    fn test_record_and_replay() {
+        let items = Rc::new(RefCell::new(vec!["a", "b", "c"]));
+        let operations = Rc::new(RefCell::new(vec![]));
+
+        let (evloop, _handler) = EventLoop::new(Arc::new(TestExecutor::new()));
+        let renderer = Renderer::new(TestCompositor::default(), evloop);
+
+        renderer.record::<Node, _>({
+            let operations = operations.clone();
+            move |operation| operations.borrow_mut().push(operation)
+        });
+
+        let instance = renderer.render(
+            Element::new(
+                Key::new(()),
+                List {
+                    items: items.clone(),
+                }
+                .into(),
+                Element::empty(),
+            ),
+            ROOT,
+        );
+
+        assert_eq!(
+            operations.replace(vec![]),
+            vec![
+                Operation::Mount {
+                    id: 0,
+                    parent: ROOT,
+                    builtin: Node::Stack,
+                },
+                Operation::Update {
+                    id: 0,
+                    builtin: Node::Stack,
+                },
+                Operation::Mount {
+                    id: 1,
+                    parent: 0,
+                    builtin: label("a"),
+                },
+                Operation::Update {
+                    id: 1,
+                    builtin: label("a"),
+                },
+                Operation::Mount {
+                    id: 2,
+                    parent: 0,
+                    builtin: label("b"),
+                },
+                Operation::Update {
+                    id: 2,
+                    builtin: label("b"),
+                },
+                Operation::Mount {
+                    id: 3,
+                    parent: 0,
+                    builtin: label("c"),
+                },
+                Operation::Update {
+                    id: 3,
+                    builtin: label("c"),
+                },
+            ]
+        );
+
+        items.replace(vec!["c", "a"]);
+        renderer.rerender(&instance);
+
+        let update = operations.replace(vec![]);
+
+        assert_eq!(
+            update,
+            vec![
+                Operation::Update {
+                    id: 0,
+                    builtin: Node::Stack,
+                },
+                Operation::Update {
+                    id: 3,
+                    builtin: label("c"),
+                },
+                Operation::Update {
+                    id: 1,
+                    builtin: label("a"),
+                },
+                Operation::Unmount { id: 2 },
+                Operation::Reorder {
+                    parent: 0,
+                    children: vec![3, 1],
+                },
+            ]
+        );
+
+        // Operations survive a round trip through JSON and can be replayed
+        // against a fresh compositor.
+        let json = serde_json::to_string(&update).unwrap();
+        let update: Vec<Operation<usize, Node>> = serde_json::from_str(&json).unwrap();
+
+        let mut replayer = Replayer::<TestPlatform>::new(ROOT, ROOT);
+        let mut buffer = TestCompositor::default().buffer();
+
+        replayer.apply(
+            &mut buffer,
+            Operation::Mount {
+                id: 0,
+                parent: ROOT,
+                builtin: Node::Stack,
+            },
+        );
+
+        for &(id, item) in &[(1, "a"), (2, "b"), (3, "c")] {
+            replayer.apply(
+                &mut buffer,
+                Operation::Mount {
+                    id,
+                    parent: 0,
+                    builtin: label(item),
+                },
+            );
+        }
+
+        for operation in update {
+            replayer.apply(&mut buffer, operation);
+        }
+
+        buffer.commit();
+
+        assert!(replayer.resolve(&0).is_some());
+        assert!(replayer.resolve(&1).is_some());
+        assert!(replayer.resolve(&2).is_none());
+        assert!(replayer.resolve(&3).is_some());
+    }
+}
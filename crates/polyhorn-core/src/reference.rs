@@ -1,6 +1,7 @@
 use std::cell::{Ref, RefMut};
 use std::marker::PhantomData;
 
+use super::timetravel;
 use super::{Link, Weak, WeakReference};
 
 pub struct Reference<T> {
@@ -38,6 +39,15 @@ where
     {
         assert_eq!(self.instance_id, link.instance().id);
 
+        if timetravel::is_enabled() {
+            timetravel::record_reference(
+                link.instance(),
+                link.memory(),
+                self.reference_id,
+                std::any::type_name::<T>(),
+            );
+        }
+
         let reference = link.memory().reference_mut(self.reference_id);
         let mut reference = RefMut::map(reference, |reference| reference.downcast_mut().unwrap());
         std::mem::replace(&mut reference, value)
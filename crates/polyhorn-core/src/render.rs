@@ -1,11 +1,18 @@
-use super::element::{ElementBuiltin, ElementComponent, ElementContext, ElementFragment};
+use super::arena::{Arena, Index};
+use super::diagnostics::{self, RenderReason, RenderRecord};
+use super::instance::{self, Node};
+use super::leaks;
+use super::protocol::Recorder;
+use super::signal;
 use super::{
-    CommandBuffer, Component, Compositor, Disposable, Effect, EffectLink, Element, EventLoop,
-    Instance, LayoutEffect, Manager, Platform,
+    Builtin, CommandBuffer, Component, Compositor, Disposable, Effect, EffectLink, Element,
+    EventLoop, Executor, Instance, Key, LayoutEffect, Manager, Operation, Payload, Platform,
 };
-use std::cell::RefCell;
-use std::ops::DerefMut;
+use std::any::{Any, TypeId};
+use std::cell::{Ref, RefCell, RefMut};
+use std::iter::once;
 use std::rc::Rc;
+use std::sync::Arc;
 
 pub struct Render<P>
 where
@@ -15,6 +22,10 @@ where
     buffer: P::CommandBuffer,
     layout_effects: Vec<LayoutEffect<P>>,
     effects: Vec<Effect<P>>,
+
+    /// This is a stack of contexts that changed in the branch that is
+    /// currently being rendered. It is only used for render diagnostics.
+    contexts: Vec<(TypeId, &'static str)>,
 }
 
 impl<P> Render<P>
@@ -33,24 +44,63 @@ where
             buffer,
             layout_effects: vec![],
             effects: vec![],
+            contexts: vec![],
         }
     }
 
-    fn rerender_builtin(&mut self, instance: &Rc<Instance<P>>, element: ElementBuiltin<P>) {
-        let container = instance.container();
-        let builtin = element.builtin;
+    fn rerender_builtin(
+        &mut self,
+        instance: &Instance<P>,
+        container: P::ContainerID,
+        builtin: Arc<dyn Builtin<P>>,
+        children: Option<Vec<Element<P>>>,
+    ) {
+        if let Some(recorder) = self.renderer.recorder.borrow_mut().as_mut() {
+            recorder.update(container, builtin.as_ref());
+        }
 
         self.buffer
             .mutate(&[container], move |containers, environment| {
                 builtin.update(containers[0], environment);
             });
 
-        self.rerender_edges(instance, vec![*element.children]);
+        self.rerender_children(instance, children);
+
+        if let Some(recorder) = self.renderer.recorder.borrow_mut().as_mut() {
+            let mut children = vec![];
+            Self::containers(&self.renderer.instances(), instance.index, &mut children);
+            recorder.reorder(container, children);
+        }
+    }
+
+    /// Collects the containers of the nearest builtin descendants of the
+    /// instance at the given index in the order in which they were most
+    /// recently rendered.
+    fn containers(instances: &Arena<Node<P>>, index: Index, containers: &mut Vec<P::ContainerID>) {
+        for edge in instances[index].topology.edges() {
+            match instances[edge].topology.element() {
+                Element::Builtin(_) => containers.push(instances[edge].container),
+                _ => Self::containers(instances, edge, containers),
+            }
+        }
     }
 
-    fn rerender_component(&mut self, instance: &Rc<Instance<P>>, element: ElementComponent<P>) {
+    fn rerender_component(&mut self, instance: &Instance<P>) {
+        // The memory of the instance is moved out of the arena while it
+        // renders, because the component may access other instances in the
+        // meantime.
+        let mut memory = instance
+            .node_mut()
+            .memory
+            .take()
+            .expect("Can't render instance that is already rendering.");
+
         let (edges, (effects, layout_effects)) = {
-            let mut memory = instance.memory_mut();
+            let node = instance.node();
+            let element = match node.topology.element() {
+                Element::Component(element) => element,
+                _ => unreachable!(),
+            };
             let compositor = self
                 .renderer
                 .compositor
@@ -65,94 +115,235 @@ where
                 &*compositor,
                 &*bus,
                 &mut memory,
-                instance.context(),
-                *element.children,
-                &instance,
+                &node.context,
+                &element.children,
+                instance,
             );
-            (
-                vec![element.component.render(&mut manager)],
-                manager.into_effects(),
-            )
+            let edges = {
+                let _tracking = node
+                    .subscriber
+                    .clone()
+                    .map(|subscriber| signal::track(subscriber));
+                once(element.component.render(&mut manager))
+            };
+
+            if diagnostics::is_enabled() {
+                self.record(instance, &element.component, manager.contexts());
+            }
+
+            (edges, manager.into_effects())
         };
 
+        instance.node_mut().memory = Some(memory);
+
         self.rerender_edges(instance, edges);
 
         self.effects.extend(effects);
         self.layout_effects.extend(layout_effects);
     }
 
-    fn rerender_context(&mut self, instance: &Rc<Instance<P>>, element: ElementContext<P>) {
-        instance.context().insert_raw(element.value);
+    fn record(&self, instance: &Instance<P>, component: &P::Component, contexts: &[TypeId]) {
+        let mut reasons = instance.take_reasons();
+
+        reasons.extend(
+            self.contexts
+                .iter()
+                .filter(|(id, _)| contexts.contains(id))
+                .map(|&(_, type_name)| RenderReason::ContextChanged { type_name }),
+        );
 
-        self.rerender_edges(instance, vec![*element.children])
+        diagnostics::emit(&RenderRecord {
+            instance_id: instance.id,
+            component: component.type_name(),
+            reasons,
+        });
     }
 
-    fn rerender_fragment(&mut self, instance: &Rc<Instance<P>>, element: ElementFragment<P>) {
-        self.rerender_edges(instance, element.elements)
+    fn record_parent_render(&self, instance: &Instance<P>, previous: Element<P>) {
+        if let (Element::Component(previous), Element::Component(current)) =
+            (previous, instance.topology().element())
+        {
+            instance.push_reason(RenderReason::ParentRendered {
+                props: current.component.diff_props(&previous.component),
+            });
+        }
     }
 
-    fn rerender_edges(&mut self, instance: &Rc<Instance<P>>, edges: Vec<Element<P>>) {
-        let mut topology = instance.topology_mut();
-        let topology = topology.deref_mut();
+    fn rerender_context(
+        &mut self,
+        instance: &Instance<P>,
+        value: Rc<dyn Any>,
+        type_name: &'static str,
+        children: Option<Vec<Element<P>>>,
+    ) {
+        let id = value.as_ref().type_id();
+        let previous = instance.context().insert_raw(value.clone());
+
+        let changed = match previous {
+            Some(previous) => !Rc::ptr_eq(&previous, &value),
+            None => false,
+        };
 
-        // Re-rendering looks a bit like mark and sweep. We start by collecting
-        // the set of keys of edges.
-        let mut keys = topology.keys();
+        if changed && diagnostics::is_enabled() {
+            self.contexts.push((id, type_name));
+            self.rerender_children(instance, children);
+            self.contexts.pop();
+        } else {
+            self.rerender_children(instance, children);
+        }
+    }
 
-        for element in edges {
-            let key = element.key();
+    /// Re-renders the edges of the given instance with the given children. If
+    /// there are no new children (i.e. the instance itself is re-rendered),
+    /// each edge is re-rendered with the element that it already has.
+    fn rerender_children(&mut self, instance: &Instance<P>, children: Option<Vec<Element<P>>>) {
+        match children {
+            Some(children) => self.rerender_edges(instance, children),
+            None => {
+                for edge in instance.edges() {
+                    self.rerender(&edge);
+                }
+            }
+        }
+    }
 
-            keys.remove(key);
+    fn rerender_edges<I>(&mut self, instance: &Instance<P>, edges: I)
+    where
+        I: IntoIterator<Item = Element<P>>,
+    {
+        // Re-rendering looks a bit like mark and sweep. We start a new pass, in
+        // which each edge that is still present is marked as visited.
+        instance.topology_mut().begin();
 
-            if let Some(existing) = topology.edge(key) {
+        for element in edges {
+            let (key, existing) = self.visit(instance, &element);
+
+            if let Some(existing) = existing {
                 // The edge already exists. We replace its element and issue a
                 // re-render.
-                existing.topology_mut().deref_mut().update(element);
-                self.rerender(existing)
+                self.rerender_with(&existing, Some(element))
             } else {
                 // The edge does not yet exist. We issue a fresh render and store
                 // the resulting instance in the topology of this instance.
-                let key = key.clone();
-                let instance = self.render(
-                    Some(instance.clone()),
-                    element,
-                    instance.container().clone(),
-                );
-                topology.add_edge(key, instance);
+                let edge = self.render(Some(instance), element, instance.container());
+                instance.topology_mut().add_edge(key, edge.index);
             }
         }
 
         // Finally, we unmount all instances that correspond to edges that are
         // no longer present.
-        for key in keys {
-            if let Some(instance) = topology.remove_edge(&key) {
-                self.unmount(&instance);
-            }
+        let stale = instance.topology_mut().sweep();
+
+        for index in stale {
+            self.unmount(index);
         }
     }
 
-    fn unmount(&mut self, instance: &Rc<Instance<P>>) {
-        for edge in instance.topology_mut().edges() {
-            self.unmount(&edge);
+    /// Marks the edge that corresponds to the given element as visited and
+    /// returns its key, along with its instance if that instance can be
+    /// re-rendered with the given element.
+    fn visit(
+        &mut self,
+        instance: &Instance<P>,
+        element: &Element<P>,
+    ) -> (Key, Option<Instance<P>>) {
+        let (key, edge) = {
+            let mut instances = self.renderer.instances_mut();
+            let topology = &mut instances[instance.index].topology;
+            let key = topology.occurrence(element.key());
+
+            let edge = match topology.visit(&key) {
+                Some(edge) => edge,
+                None => return (key, None),
+            };
+
+            // An existing edge can only be re-rendered with an element of the
+            // same kind and type. Otherwise, we unmount it and render the
+            // element from scratch.
+            let node = &instances[edge];
+
+            if node.topology.element().reconciles_with(element) {
+                let existing = Instance::new(self.renderer.clone(), edge, node.id);
+                return (key, Some(existing));
+            }
+
+            (key, edge)
+        };
+
+        instance.topology_mut().remove_edge(&key);
+        self.unmount(edge);
+
+        (key, None)
+    }
+
+    fn unmount(&mut self, index: Index) {
+        // We drop the instance only after releasing the arena, because
+        // dropping its memory may run arbitrary code.
+        let node = self
+            .renderer
+            .instances_mut()
+            .remove(index)
+            .expect("Can't unmount instance that has already been unmounted.");
+
+        leaks::unmount(node.id);
+        if let Some(subscriber) = &node.subscriber {
+            signal::unsubscribe(subscriber);
+        }
+
+        for edge in node.topology.edges() {
+            self.unmount(edge);
         }
 
-        match instance.topology_mut().deref_mut().element() {
+        match node.topology.element() {
             Element::Builtin(_) => {
-                self.buffer.unmount(instance.container());
+                if let Some(recorder) = self.renderer.recorder.borrow_mut().as_mut() {
+                    recorder.unmount(node.container);
+                }
+
+                self.buffer.unmount(node.container);
             }
             _ => {}
         }
     }
 
     /// This function is called when re-rendering an existing instance.
-    pub fn rerender(&mut self, instance: &Rc<Instance<P>>) {
-        let element = instance.topology_mut().element().clone();
-
-        match element {
-            Element::Builtin(element) => self.rerender_builtin(instance, element),
-            Element::Component(element) => self.rerender_component(instance, element),
-            Element::Context(element) => self.rerender_context(instance, element),
-            Element::Fragment(element) => self.rerender_fragment(instance, element),
+    pub fn rerender(&mut self, instance: &Instance<P>) {
+        self.rerender_with(instance, None)
+    }
+
+    /// Re-renders an existing instance after replacing its element with the
+    /// given element (if any).
+    fn rerender_with(&mut self, instance: &Instance<P>, element: Option<Element<P>>) {
+        let mut node = instance.node_mut();
+        let previous = element.map(|element| node.topology.update(element));
+        let children = node.topology.take_children();
+        let container = node.container;
+
+        match node.topology.element() {
+            Element::Builtin(element) => {
+                let builtin = element.builtin.clone();
+                drop(node);
+                self.rerender_builtin(instance, container, builtin, children)
+            }
+            Element::Component(_) => {
+                drop(node);
+
+                if let Some(previous) = previous.filter(|_| diagnostics::is_enabled()) {
+                    self.record_parent_render(instance, previous);
+                }
+
+                self.rerender_component(instance)
+            }
+            Element::Context(element) => {
+                let value = element.value.clone();
+                let type_name = element.type_name;
+                drop(node);
+                self.rerender_context(instance, value, type_name, children)
+            }
+            Element::Fragment(_) => {
+                drop(node);
+                self.rerender_children(instance, children)
+            }
             Element::String(_text) => unimplemented!("Can't render string element directly."),
         }
     }
@@ -161,10 +352,10 @@ where
     /// the first time.
     pub fn render(
         &mut self,
-        parent: Option<Rc<Instance<P>>>,
+        parent: Option<&Instance<P>>,
         element: Element<P>,
         in_container: P::ContainerID,
-    ) -> Rc<Instance<P>> {
+    ) -> Instance<P> {
         // We start by figuring out if we need to create a new container for this
         // element or not.
         let container = match &element {
@@ -174,6 +365,10 @@ where
                     builtin.instantiate(parent, environment)
                 });
 
+                if let Some(recorder) = self.renderer.recorder.borrow_mut().as_mut() {
+                    recorder.mount(container, in_container, element.builtin.as_ref());
+                }
+
                 if let Some(reference) = &element.reference {
                     reference.replace(Some(container));
                 }
@@ -183,10 +378,33 @@ where
             _ => in_container,
         };
 
-        let renderer = self.renderer.clone();
-
         // Then, we create an instance for this element.
-        let instance = Rc::new(Instance::new(renderer, parent, element, container));
+        let is_component = matches!(element, Element::Component(_));
+        let context = match parent {
+            Some(parent) => Rc::new(parent.context().enter()),
+            None => Default::default(),
+        };
+
+        let renderer = &self.renderer;
+        let id = instance::next_id();
+        let index = renderer.instances_mut().insert_with(|index| {
+            Node::new(
+                renderer,
+                id,
+                index,
+                parent.map(|parent| parent.index),
+                context,
+                element,
+                container,
+            )
+        });
+
+        let instance = Instance::new(renderer.clone(), index, id);
+        leaks::track(&instance, parent.map(|parent| parent.id));
+
+        if is_component {
+            instance.push_reason(RenderReason::Mount);
+        }
 
         // Finally, we pretend that this is simply a re-render.
         self.rerender(&instance);
@@ -198,9 +416,14 @@ where
         self.buffer.layout();
 
         // Finally, we apply the effects and we're done!
+        // Effects of instances that have been unmounted in the meantime are
+        // skipped.
         for effect in self.layout_effects.into_iter() {
             let instance = effect.instance().clone();
-            let memory = instance.memory();
+            let memory = match instance.try_memory() {
+                Some(memory) => memory,
+                None => continue,
+            };
             let link = EffectLink::new(&instance, &memory);
 
             effect.invoke(&link, &mut self.buffer);
@@ -213,7 +436,10 @@ where
         self.renderer.bus.borrow().queue_retain(async move {
             for effect in effects.into_iter() {
                 let instance = effect.instance().clone();
-                let memory = instance.memory();
+                let memory = match instance.try_memory() {
+                    Some(memory) => memory,
+                    None => continue,
+                };
                 let link = EffectLink::new(&instance, &memory);
 
                 effect.invoke(&link);
@@ -228,6 +454,10 @@ where
 {
     compositor: RefCell<P::Compositor>,
     bus: RefCell<EventLoop>,
+    recorder: RefCell<Option<Recorder<P>>>,
+
+    /// This is the arena that owns the instances of this renderer.
+    instances: RefCell<Arena<Node<P>>>,
 }
 
 impl<P> Renderer<P>
@@ -240,25 +470,123 @@ where
         Rc::new(Renderer {
             compositor: RefCell::new(compositor),
             bus: RefCell::new(bus),
+            recorder: RefCell::new(None),
+            instances: RefCell::new(Arena::new()),
+        })
+    }
+
+    /// This function starts recording the operations that this renderer
+    /// performs and passes each operation to the given sink. Builtins are
+    /// encoded with the given payload type. Operations on builtins of another
+    /// type are skipped (along with their descendants when replayed).
+    /// Reorder operations are only emitted for the children of builtins.
+    pub fn record<B, F>(&self, mut sink: F)
+    where
+        B: Payload<P>,
+        F: FnMut(Operation<P::ContainerID, B>) + 'static,
+    {
+        let recorder = Recorder::new(move |operation: Operation<_, &dyn Builtin<P>>| {
+            let encode = |builtin: &dyn Builtin<P>| {
+                Some(B::encode(builtin.as_any()?.downcast_ref::<B::Builtin>()?))
+            };
+
+            match operation.try_map(encode) {
+                Some(operation) => sink(operation),
+                None => log::warn!(
+                    target: "polyhorn::protocol",
+                    "Skipping operation on builtin that can't be encoded as {}.",
+                    std::any::type_name::<B>()
+                ),
+            }
+        });
+
+        self.recorder.replace(Some(recorder));
+    }
+
+    /// This function stops recording operations.
+    pub fn stop_recording(&self) {
+        self.recorder.replace(None);
+    }
+
+    pub(crate) fn bus(&self) -> Ref<'_, EventLoop> {
+        self.bus.borrow()
+    }
+
+    pub(crate) fn instances(&self) -> Ref<'_, Arena<Node<P>>> {
+        self.instances
+            .try_borrow()
+            .expect("Can't access instances while instances are mounted or unmounted.")
+    }
+
+    pub(crate) fn instances_mut(&self) -> RefMut<'_, Arena<Node<P>>> {
+        self.instances
+            .try_borrow_mut()
+            .expect("Can't mount or unmount instances while instances are accessed.")
+    }
+
+    pub(crate) fn try_node(&self, index: Index) -> Option<Ref<'_, Node<P>>> {
+        Ref::filter_map(self.instances(), |instances| instances.get(index)).ok()
+    }
+
+    pub(crate) fn node(&self, index: Index) -> Ref<'_, Node<P>> {
+        self.try_node(index).expect("Instance has been unmounted.")
+    }
+
+    pub(crate) fn node_mut(&self, index: Index) -> RefMut<'_, Node<P>> {
+        RefMut::map(self.instances_mut(), |instances| {
+            instances
+                .get_mut(index)
+                .expect("Instance has been unmounted.")
         })
     }
 
-    pub fn queue_rerender(self: &Rc<Self>, instance: &Rc<Instance<P>>) {
+    pub(crate) fn contains(&self, index: Index) -> bool {
+        self.instances().contains(index)
+    }
+
+    /// Returns a handle to the instance at the given index.
+    pub(crate) fn instance(self: &Rc<Self>, index: Index) -> Instance<P> {
+        let id = self.node(index).id;
+        Instance::new(self.clone(), index, id)
+    }
+
+    pub fn queue_rerender(self: &Rc<Self>, instance: &Instance<P>) {
+        self.queue_rerender_with_reason(instance, RenderReason::Queued)
+    }
+
+    /// This function queues a re-render of the given instance and records the
+    /// given reason if render diagnostics are enabled.
+    pub fn queue_rerender_with_reason(
+        self: &Rc<Self>,
+        instance: &Instance<P>,
+        reason: RenderReason,
+    ) {
+        instance.push_reason(reason);
+
         let renderer = self.clone();
         let instance = instance.clone();
 
         self.bus.borrow().queue_retain(async move {
+            // The instance may have been unmounted in the meantime.
+            if !instance.is_mounted() {
+                return;
+            }
+
             let mut render = Render::new(renderer);
             render.rerender(&instance);
             render.finish();
         });
     }
 
-    pub fn render(
-        self: &Rc<Self>,
-        element: Element<P>,
-        container: P::ContainerID,
-    ) -> Rc<Instance<P>> {
+    /// This function synchronously re-renders the given instance. Usually,
+    /// re-renders are queued instead (see `queue_rerender`).
+    pub fn rerender(self: &Rc<Self>, instance: &Instance<P>) {
+        let mut render = Render::new(self.clone());
+        render.rerender(instance);
+        render.finish();
+    }
+
+    pub fn render(self: &Rc<Self>, element: Element<P>, container: P::ContainerID) -> Instance<P> {
         let mut render = Render::new(self.clone());
         let instance = render.render(None, element, container);
         render.finish();
@@ -275,9 +603,27 @@ where
     F: FnOnce() -> Element<P> + Send + 'static,
     P: Platform + ?Sized,
 {
-    P::with_compositor(container, move |container_id, compositor, bus| {
+    render_with_executor(element, container, P::default_executor())
+}
+
+/// This function renders an element into the given container, just like
+/// `render`, but drives the event loop with the given executor instead of the
+/// platform's default executor.
+pub fn render_with_executor<F, P>(
+    element: F,
+    container: P::Container,
+    executor: Arc<dyn Executor>,
+) -> Disposable
+where
+    F: FnOnce() -> Element<P> + Send + 'static,
+    P: Platform + ?Sized,
+{
+    P::with_compositor(container, executor, move |container_id, compositor, bus| {
         // We've now switched to the render thread.
+        // The renderer owns the instances that it renders, so retaining the
+        // renderer retains the UI.
         let renderer = Renderer::new(compositor, bus);
-        Disposable::new(renderer.render(element(), container_id))
+        renderer.render(element(), container_id);
+        Disposable::new(renderer)
     })
 }
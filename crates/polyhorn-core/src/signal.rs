@@ -0,0 +1,512 @@
+//! Fine-grained reactive values. In contrast to state, which belongs to a
+//! single component and re-renders that component whenever it changes, a
+//! signal can be shared by any number of components. A component that reads a
+//! signal while rendering automatically subscribes to it and only those
+//! components re-render when the signal changes.
+
+use std::cell::{Cell, RefCell};
+use std::rc::{Rc, Weak};
+
+use super::{Platform, RenderReason};
+
+thread_local! {
+    /// This is the stack of subscribers that are currently tracking their
+    /// dependencies: either an instance that is rendering or a computed value
+    /// that is being recomputed.
+    static TRACKING: RefCell<Vec<Rc<dyn Subscriber>>> = const { RefCell::new(vec![]) };
+}
+
+/// Returns the address of the given pointer without its metadata, which is
+/// used to compare subscribers and sources.
+fn address<T>(pointer: *const T) -> *const ()
+where
+    T: ?Sized,
+{
+    pointer as *const ()
+}
+
+/// Something that depends on one or more sources and is notified when any of
+/// them changes.
+pub(crate) trait Subscriber {
+    fn dependencies(&self) -> &Dependencies;
+
+    fn notify(self: Rc<Self>, type_name: &'static str);
+}
+
+/// Something that can be subscribed to.
+trait Source {
+    fn unsubscribe(&self, subscriber: *const ());
+}
+
+/// Sources that a subscriber currently depends on.
+#[derive(Default)]
+pub(crate) struct Dependencies {
+    sources: RefCell<Vec<Weak<dyn Source>>>,
+
+    /// This is true if the subscriber has been notified but did not yet
+    /// update. It is used to coalesce multiple notifications into a single
+    /// re-render.
+    pending: Cell<bool>,
+}
+
+impl Dependencies {
+    fn add(&self, source: Weak<dyn Source>) {
+        let mut sources = self.sources.borrow_mut();
+
+        if !sources
+            .iter()
+            .any(|existing| address(existing.as_ptr()) == address(source.as_ptr()))
+        {
+            sources.push(source);
+        }
+    }
+
+    /// Unsubscribes the given subscriber from all of its sources.
+    pub fn clear(&self, subscriber: *const ()) {
+        let sources = std::mem::take(&mut *self.sources.borrow_mut());
+
+        for source in sources.into_iter().filter_map(|source| source.upgrade()) {
+            source.unsubscribe(subscriber);
+        }
+    }
+
+    /// Marks the subscriber as pending and returns true if it wasn't already.
+    pub fn mark_pending(&self) -> bool {
+        !self.pending.replace(true)
+    }
+
+    pub fn clear_pending(&self) {
+        self.pending.set(false);
+    }
+}
+
+/// Subscribers of a single source.
+#[derive(Default)]
+struct Subscribers {
+    subscribers: RefCell<Vec<Weak<dyn Subscriber>>>,
+}
+
+impl Subscribers {
+    /// Subscribes the subscriber that is currently tracking its dependencies
+    /// (if any) to the given source.
+    fn track(&self, source: Weak<dyn Source>) {
+        let _ = TRACKING.try_with(|tracking| {
+            if let Some(subscriber) = tracking.borrow().last() {
+                let address = address(Rc::as_ptr(subscriber));
+                let mut subscribers = self.subscribers.borrow_mut();
+
+                if !subscribers
+                    .iter()
+                    .any(|existing| self::address(existing.as_ptr()) == address)
+                {
+                    subscribers.push(Rc::downgrade(subscriber));
+                }
+
+                subscriber.dependencies().add(source);
+            }
+        });
+    }
+
+    fn unsubscribe(&self, subscriber: *const ()) {
+        self.subscribers
+            .borrow_mut()
+            .retain(|existing| address(existing.as_ptr()) != subscriber);
+    }
+
+    fn notify(&self, type_name: &'static str) {
+        // Subscribers may subscribe to this source again while being
+        // notified, so we release the borrow first.
+        let subscribers = self.subscribers.borrow().clone();
+
+        for subscriber in subscribers.iter().filter_map(Weak::upgrade) {
+            subscriber.notify(type_name);
+        }
+    }
+}
+
+/// Starts tracking the dependencies of the given subscriber until the returned
+/// guard is dropped. The previous dependencies of the subscriber are cleared.
+pub(crate) fn track(subscriber: Rc<dyn Subscriber>) -> Tracking {
+    subscriber
+        .dependencies()
+        .clear(address(Rc::as_ptr(&subscriber)));
+    subscriber.dependencies().clear_pending();
+
+    TRACKING.with(|tracking| tracking.borrow_mut().push(subscriber));
+
+    Tracking { _private: () }
+}
+
+pub(crate) struct Tracking {
+    _private: (),
+}
+
+impl Drop for Tracking {
+    fn drop(&mut self) {
+        let _ = TRACKING.try_with(|tracking| tracking.borrow_mut().pop());
+    }
+}
+
+/// Unsubscribes the given subscriber from all of its sources. This is called
+/// when an instance is unmounted.
+pub(crate) fn unsubscribe<S>(subscriber: &Rc<S>)
+where
+    S: Subscriber,
+{
+    subscriber
+        .dependencies()
+        .clear(address(Rc::as_ptr(subscriber)));
+}
+
+/// Runs the given closure without tracking the signals that it reads.
+pub fn untracked<F, T>(op: F) -> T
+where
+    F: FnOnce() -> T,
+{
+    let stack = TRACKING.with(|tracking| std::mem::take(&mut *tracking.borrow_mut()));
+    let result = op();
+    TRACKING.with(|tracking| tracking.replace(stack));
+    result
+}
+
+/// Subscriber that re-renders an instance. It is owned by the instance, so
+/// that signals stop notifying it once the instance is unmounted.
+pub(crate) struct InstanceSubscriber<P>
+where
+    P: Platform + ?Sized,
+{
+    instance: super::Weak<P>,
+    dependencies: Dependencies,
+}
+
+impl<P> InstanceSubscriber<P>
+where
+    P: Platform + ?Sized,
+{
+    pub fn new(instance: super::Weak<P>) -> InstanceSubscriber<P> {
+        InstanceSubscriber {
+            instance,
+            dependencies: Dependencies::default(),
+        }
+    }
+}
+
+impl<P> Subscriber for InstanceSubscriber<P>
+where
+    P: Platform + ?Sized,
+{
+    fn dependencies(&self) -> &Dependencies {
+        &self.dependencies
+    }
+
+    fn notify(self: Rc<Self>, type_name: &'static str) {
+        // Multiple writes before the next render only queue a single
+        // re-render.
+        if self.dependencies.mark_pending() {
+            if let Some(instance) = self.instance.upgrade() {
+                instance.renderer().queue_rerender_with_reason(
+                    &instance,
+                    RenderReason::SignalChanged { type_name },
+                );
+            }
+        }
+    }
+}
+
+struct SignalInner<T> {
+    value: RefCell<T>,
+    subscribers: Subscribers,
+}
+
+impl<T> Source for SignalInner<T> {
+    fn unsubscribe(&self, subscriber: *const ()) {
+        self.subscribers.unsubscribe(subscriber)
+    }
+}
+
+/// Reactive value that re-renders the components that read it whenever it
+/// changes. Signals are cheap to clone: clones refer to the same value. A
+/// signal is usually stored in a reference (see `use_reference!`) or passed
+/// down through props or context.
+pub struct Signal<T> {
+    inner: Rc<SignalInner<T>>,
+}
+
+impl<T> Signal<T>
+where
+    T: 'static,
+{
+    /// Returns a new signal with the given initial value.
+    pub fn new(value: T) -> Signal<T> {
+        Signal {
+            inner: Rc::new(SignalInner {
+                value: RefCell::new(value),
+                subscribers: Subscribers::default(),
+            }),
+        }
+    }
+
+    fn track(&self) {
+        let source: Weak<SignalInner<T>> = Rc::downgrade(&self.inner);
+        self.inner.subscribers.track(source);
+    }
+
+    /// Invokes the given closure with a reference to the value of this signal
+    /// and subscribes the current component (if any) to this signal.
+    pub fn with<F, O>(&self, op: F) -> O
+    where
+        F: FnOnce(&T) -> O,
+    {
+        self.track();
+        op(&self.inner.value.borrow())
+    }
+
+    /// Replaces the value of this signal and notifies its subscribers.
+    pub fn set(&self, value: T) {
+        self.update(|current| *current = value)
+    }
+
+    /// Applies the given closure to the value of this signal and notifies its
+    /// subscribers.
+    pub fn update<F>(&self, op: F)
+    where
+        F: FnOnce(&mut T),
+    {
+        op(&mut self.inner.value.borrow_mut());
+        self.inner.subscribers.notify(std::any::type_name::<T>());
+    }
+}
+
+impl<T> Signal<T>
+where
+    T: Clone + 'static,
+{
+    /// Returns a clone of the value of this signal and subscribes the current
+    /// component (if any) to this signal.
+    pub fn get(&self) -> T {
+        self.with(Clone::clone)
+    }
+}
+
+impl<T> Clone for Signal<T> {
+    fn clone(&self) -> Self {
+        Signal {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+struct ComputedInner<T> {
+    compute: Box<dyn Fn() -> T>,
+
+    /// This is the cached value, which is `None` if one of its dependencies
+    /// changed since it was last computed.
+    value: RefCell<Option<T>>,
+
+    dependencies: Dependencies,
+    subscribers: Subscribers,
+}
+
+impl<T> Source for ComputedInner<T> {
+    fn unsubscribe(&self, subscriber: *const ()) {
+        self.subscribers.unsubscribe(subscriber)
+    }
+}
+
+impl<T> Subscriber for ComputedInner<T>
+where
+    T: 'static,
+{
+    fn dependencies(&self) -> &Dependencies {
+        &self.dependencies
+    }
+
+    fn notify(self: Rc<Self>, type_name: &'static str) {
+        // We only notify our own subscribers the first time, until someone
+        // reads the new value.
+        if self.value.borrow_mut().take().is_some() {
+            self.subscribers.notify(type_name);
+        }
+    }
+}
+
+impl<T> Drop for ComputedInner<T> {
+    fn drop(&mut self) {
+        self.dependencies.clear(address(self as *const Self));
+    }
+}
+
+/// Value that is derived from one or more signals (or other computed values).
+/// The value is computed lazily and cached until one of the signals that it
+/// read during its last computation changes.
+pub struct Computed<T> {
+    inner: Rc<ComputedInner<T>>,
+}
+
+impl<T> Computed<T>
+where
+    T: Clone + 'static,
+{
+    /// Returns a new computed value that is derived with the given closure.
+    pub fn new<F>(compute: F) -> Computed<T>
+    where
+        F: Fn() -> T + 'static,
+    {
+        Computed {
+            inner: Rc::new(ComputedInner {
+                compute: Box::new(compute),
+                value: RefCell::new(None),
+                dependencies: Dependencies::default(),
+                subscribers: Subscribers::default(),
+            }),
+        }
+    }
+
+    /// Returns the (possibly cached) value and subscribes the current
+    /// component (if any) to this computed value.
+    pub fn get(&self) -> T {
+        let source: Weak<ComputedInner<T>> = Rc::downgrade(&self.inner);
+        self.inner.subscribers.track(source);
+
+        if let Some(value) = self.inner.value.borrow().as_ref() {
+            return value.clone();
+        }
+
+        let value = {
+            let _tracking = track(self.inner.clone());
+            (self.inner.compute)()
+        };
+
+        self.inner.value.replace(Some(value.clone()));
+
+        value
+    }
+}
+
+impl<T> Clone for Computed<T> {
+    fn clone(&self) -> Self {
+        Computed {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    use super::{Computed, Signal};
+    use crate::testing::{render, rerender, TestComponent, TestPlatform};
+    use crate::{Element, Instance, Key, Manager};
+
+    struct Screen {
+        show: Rc<Cell<bool>>,
+        double: Computed<usize>,
+        renders: Rc<Cell<usize>>,
+    }
+
+    impl TestComponent for Screen {
+        fn render(&self, _manager: &mut Manager<TestPlatform>) -> Element<TestPlatform> {
+            self.renders.set(self.renders.get() + 1);
+
+            match self.show.get() {
+                true => Element::new(
+                    Key::new(()),
+                    Counter {
+                        double: self.double.clone(),
+                    }
+                    .into(),
+                    Element::empty(),
+                ),
+                false => Element::empty(),
+            }
+        }
+    }
+
+    struct Counter {
+        double: Computed<usize>,
+    }
+
+    impl TestComponent for Counter {
+        fn render(&self, _manager: &mut Manager<TestPlatform>) -> Element<TestPlatform> {
+            self.double.get();
+
+            Element::empty()
+        }
+    }
+
+    fn is_pending(instance: &Instance<TestPlatform>) -> bool {
+        match &instance.node().subscriber {
+            Some(subscriber) => subscriber.dependencies.pending.get(),
+            None => false,
+        }
+    }
+
+    #[test]
+    fn test_computed() {
+        let computations = Rc::new(Cell::new(0));
+        let count = Signal::new(1);
+
+        let double = Computed::new({
+            let count = count.clone();
+            let computations = computations.clone();
+
+            move || {
+                computations.set(computations.get() + 1);
+                count.get() * 2
+            }
+        });
+
+        assert_eq!(double.get(), 2);
+        assert_eq!(double.get(), 2);
+        assert_eq!(computations.get(), 1);
+
+        count.set(2);
+        count.set(3);
+        assert_eq!(double.get(), 6);
+        assert_eq!(computations.get(), 2);
+    }
+
+    #[test]
+    fn test_subscribers() {
+        let count = Signal::new(1);
+        let double = Computed::new({
+            let count = count.clone();
+            move || count.get() * 2
+        });
+        let show = Rc::new(Cell::new(true));
+        let renders = Rc::new(Cell::new(0));
+
+        let (renderer, instance, _handler) = render(Element::new(
+            Key::new(()),
+            Screen {
+                show: show.clone(),
+                double: double.clone(),
+                renders: renders.clone(),
+            }
+            .into(),
+            Element::empty(),
+        ));
+
+        let counter = instance.edges().remove(0);
+        assert_eq!(double.inner.subscribers.subscribers.borrow().len(), 1);
+
+        // Only the counter reads the signal (through the computed value), so
+        // only the counter is notified.
+        count.set(2);
+        assert!(is_pending(&counter));
+        assert!(!is_pending(&instance));
+
+        rerender(&renderer, &counter);
+        assert!(!is_pending(&counter));
+        assert_eq!(renders.get(), 1);
+
+        // Once unmounted, the counter no longer subscribes to the computed
+        // value.
+        show.set(false);
+        rerender(&renderer, &instance);
+        assert!(double.inner.subscribers.subscribers.borrow().is_empty());
+        assert_eq!(count.inner.subscribers.subscribers.borrow().len(), 1);
+    }
+}
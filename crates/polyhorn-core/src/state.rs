@@ -1,7 +1,8 @@
 use std::cell::{Ref, RefMut};
 use std::marker::PhantomData;
 
-use super::{Link, Weak, WeakState};
+use super::timetravel;
+use super::{Link, RenderReason, Weak, WeakState};
 
 pub struct State<T>
 where
@@ -38,12 +39,71 @@ impl<T> State<T> {
     {
         assert_eq!(self.instance_id, link.instance().id);
 
-        link.instance().renderer().queue_rerender(link.instance());
+        link.instance().renderer().queue_rerender_with_reason(
+            link.instance(),
+            RenderReason::StateChanged {
+                state_id: self.state_id,
+                type_name: std::any::type_name::<T>(),
+            },
+        );
 
         let mut state = RefMut::map(link.memory().state_mut(self.state_id), |state| {
             state.downcast_mut().unwrap()
         });
-        std::mem::replace(&mut state, value)
+        let previous = std::mem::replace(&mut *state, value);
+
+        if timetravel::is_enabled() {
+            timetravel::record_state(
+                link.instance(),
+                link.memory(),
+                self.state_id,
+                std::any::type_name::<T>(),
+                timetravel::serialize_state(link.memory(), self.state_id, &previous),
+                &*state,
+            );
+        }
+
+        previous
+    }
+
+    /// Applies the given closure to the value of this state in place and
+    /// queues a re-render, just like `replace`.
+    pub fn apply<L, F, O>(&self, link: &L, op: F) -> O
+    where
+        L: Link,
+        F: FnOnce(&mut T) -> O,
+    {
+        assert_eq!(self.instance_id, link.instance().id);
+
+        link.instance().renderer().queue_rerender_with_reason(
+            link.instance(),
+            RenderReason::StateChanged {
+                state_id: self.state_id,
+                type_name: std::any::type_name::<T>(),
+            },
+        );
+
+        let mut state = RefMut::map(link.memory().state_mut(self.state_id), |state| {
+            state.downcast_mut().unwrap()
+        });
+
+        if !timetravel::is_enabled() {
+            return op(&mut state);
+        }
+
+        let previous = timetravel::serialize_state(link.memory(), self.state_id, &*state);
+        let result = op(&mut state);
+
+        timetravel::record_state(
+            link.instance(),
+            link.memory(),
+            self.state_id,
+            std::any::type_name::<T>(),
+            previous,
+            &*state,
+        );
+
+        result
     }
 
     pub fn weak<L>(self, link: &L) -> WeakState<L::Platform, T>
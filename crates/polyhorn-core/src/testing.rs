@@ -0,0 +1,263 @@
+//! In-memory platform that is used to test Polyhorn Core itself.
+
+use std::any::Any;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::sync::Arc;
+
+use super::{
+    Command, CommandBuffer, Component, Composition, Compositor, Container, Disposable, Element,
+    EventLoop, EventLoopHandler, Executor, Instance, Manager, Platform, PropsDiff, Renderer,
+    TestExecutor,
+};
+
+pub enum TestPlatform {}
+
+impl Platform for TestPlatform {
+    type ContainerID = usize;
+    type Container = TestContainer;
+    type Component = OpaqueComponent;
+    type Compositor = TestCompositor;
+    type CommandBuffer = TestCommandBuffer;
+    type Environment = ();
+
+    fn default_executor() -> Arc<dyn Executor> {
+        Arc::new(TestExecutor::new())
+    }
+
+    /// Renders synchronously into a fresh test compositor. The event loop is
+    /// never driven, which means that effects are not run.
+    fn with_compositor<F>(
+        _container: TestContainer,
+        executor: Arc<dyn Executor>,
+        task: F,
+    ) -> Disposable
+    where
+        F: FnOnce(usize, TestCompositor, EventLoop) -> Disposable + Send + 'static,
+    {
+        let (evloop, handler) = EventLoop::new(executor);
+
+        struct Session {
+            // The task is dropped before the handler because the event loop
+            // (which is retained by the task) expects the handler to be alive.
+            _task: Disposable,
+            _handler: EventLoopHandler,
+        }
+
+        impl Drop for Session {
+            fn drop(&mut self) {}
+        }
+
+        Disposable::new(Session {
+            _task: task(0, TestCompositor::default(), evloop),
+            _handler: handler,
+        })
+    }
+}
+
+pub trait TestComponent: 'static {
+    fn render(&self, manager: &mut Manager<TestPlatform>) -> Element<TestPlatform>;
+
+    fn diff_props(&self, _previous: &dyn Any) -> Option<PropsDiff> {
+        None
+    }
+}
+
+#[derive(Clone)]
+pub struct OpaqueComponent(Rc<dyn Any>, Rc<dyn TestComponent>, &'static str);
+
+impl Component<TestPlatform> for OpaqueComponent {
+    fn render(&self, manager: &mut Manager<TestPlatform>) -> Element<TestPlatform> {
+        self.1.render(manager)
+    }
+
+    fn downcast_ref<T>(&self) -> Option<&T>
+    where
+        T: 'static,
+    {
+        self.0.downcast_ref::<T>()
+    }
+
+    fn type_name(&self) -> &'static str {
+        self.2
+    }
+
+    fn diff_props(&self, previous: &Self) -> Option<PropsDiff> {
+        self.1.diff_props(previous.0.as_ref())
+    }
+}
+
+impl<T> From<T> for OpaqueComponent
+where
+    T: TestComponent,
+{
+    fn from(value: T) -> Self {
+        let value = Rc::new(value);
+        OpaqueComponent(value.clone(), value, std::any::type_name::<T>())
+    }
+}
+
+pub struct TestContainer;
+
+impl Container<TestPlatform> for TestContainer {
+    fn mount(&mut self, _container: &mut TestContainer, _environment: &mut ()) {}
+
+    fn unmount(&mut self) {}
+
+    fn reorder(&mut self, _children: &mut [&mut TestContainer], _environment: &mut ()) {}
+}
+
+#[derive(Clone, Default)]
+pub struct TestCompositor {
+    counter: Rc<RefCell<usize>>,
+    composition: Rc<RefCell<Composition<TestPlatform>>>,
+    parents: Rc<RefCell<HashMap<usize, usize>>>,
+}
+
+impl TestCompositor {
+    /// Returns the parent of each container that is currently mounted, as of
+    /// the most recently committed command buffer.
+    pub fn parents(&self) -> HashMap<usize, usize> {
+        self.parents.borrow().clone()
+    }
+}
+
+impl Compositor<TestPlatform> for TestCompositor {
+    fn buffer(&self) -> TestCommandBuffer {
+        TestCommandBuffer {
+            compositor: self.clone(),
+            commands: vec![],
+        }
+    }
+}
+
+pub struct TestCommandBuffer {
+    compositor: TestCompositor,
+    commands: Vec<Command<TestPlatform>>,
+}
+
+impl CommandBuffer<TestPlatform> for TestCommandBuffer {
+    fn mount<F>(&mut self, parent_id: usize, initializer: F) -> usize
+    where
+        F: FnOnce(&mut TestContainer, &mut ()) -> TestContainer + Send + 'static,
+    {
+        let mut counter = self.compositor.counter.borrow_mut();
+        let id = *counter;
+        *counter += 1;
+
+        self.commands
+            .push(Command::Mount(id, parent_id, Box::new(initializer)));
+
+        id
+    }
+
+    fn mutate<F>(&mut self, ids: &[usize], mutator: F)
+    where
+        F: FnOnce(&mut [&mut TestContainer], &mut ()) + Send + 'static,
+    {
+        self.commands
+            .push(Command::Mutate(ids.to_vec(), Box::new(mutator)));
+    }
+
+    fn unmount(&mut self, id: usize) {
+        self.commands.push(Command::Unmount(id));
+    }
+
+    fn layout(&mut self) {}
+
+    fn commit(self) {
+        let mut composition = self.compositor.composition.borrow_mut();
+        let mut parents = self.compositor.parents.borrow_mut();
+
+        for command in self.commands {
+            match &command {
+                &Command::Mount(id, parent_id, _) => {
+                    parents.insert(id, parent_id);
+                }
+                &Command::Unmount(id) => {
+                    parents.remove(&id);
+                }
+                Command::Mutate(..) => {}
+            }
+
+            composition.process(&mut (), command);
+        }
+    }
+}
+
+/// Renders the given element into a fresh test compositor. The returned event
+/// loop handler must be retained for as long as the renderer is used.
+pub fn render(
+    element: Element<TestPlatform>,
+) -> (
+    Rc<Renderer<TestPlatform>>,
+    Instance<TestPlatform>,
+    EventLoopHandler,
+) {
+    let (evloop, handler) = EventLoop::new(Arc::new(TestExecutor::new()));
+    let renderer = Renderer::new(TestCompositor::default(), evloop);
+    let instance = renderer.render(element, 0);
+
+    (renderer, instance, handler)
+}
+
+/// Synchronously re-renders the given instance.
+pub fn rerender(renderer: &Rc<Renderer<TestPlatform>>, instance: &Instance<TestPlatform>) {
+    renderer.rerender(instance);
+}
+
+#[derive(Clone)]
+pub struct Foo;
+
+impl TestComponent for Foo {
+    fn render(&self, manager: &mut Manager<TestPlatform>) -> Element<TestPlatform> {
+        manager.children()
+    }
+}
+
+#[derive(Clone)]
+pub struct Bar;
+
+impl TestComponent for Bar {
+    fn render(&self, manager: &mut Manager<TestPlatform>) -> Element<TestPlatform> {
+        manager.children()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    use super::{TestComponent, TestContainer, TestPlatform};
+    use crate::{Element, Key, Manager};
+
+    struct Counter(Arc<AtomicUsize>);
+
+    impl TestComponent for Counter {
+        fn render(&self, _manager: &mut Manager<TestPlatform>) -> Element<TestPlatform> {
+            self.0.fetch_add(1, Ordering::SeqCst);
+
+            Element::empty()
+        }
+    }
+
+    #[test]
+    fn test_render() {
+        let renders = Arc::new(AtomicUsize::new(0));
+
+        let disposable = crate::render::<_, TestPlatform>(
+            {
+                let renders = renders.clone();
+
+                move || Element::new(Key::new(()), Counter(renders).into(), Element::empty())
+            },
+            TestContainer,
+        );
+
+        assert_eq!(renders.load(Ordering::SeqCst), 1);
+
+        drop(disposable);
+    }
+}
@@ -0,0 +1,390 @@
+//! Opt-in log of state changes that can be used to rewind an app to an earlier
+//! state. Once started, each call to `State::replace`, `State::apply` and
+//! `Reference::replace` on the current thread is recorded as a `StateChange`.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::any::Any;
+use std::cell::RefCell;
+use std::collections::HashSet;
+use std::time::SystemTime;
+
+use super::{Instance, Link, Memory, Platform, RenderReason, Weak};
+
+thread_local! {
+    static LOG: RefCell<Option<Log>> = const { RefCell::new(None) };
+}
+
+/// Serializes and restores the value of a state slot. Codecs are created when
+/// a state is first used, which is the only place where its type is known to
+/// be serializable.
+pub(crate) struct Codec {
+    serialize: fn(&dyn Any) -> Option<Value>,
+    restore: fn(&mut dyn Any, Value) -> bool,
+}
+
+impl Codec {
+    pub fn of<T>() -> Codec
+    where
+        T: Serialize + for<'b> Deserialize<'b> + 'static,
+    {
+        Codec {
+            serialize: |value| serde_json::to_value(value.downcast_ref::<T>()?).ok(),
+            restore: |slot, value| match (slot.downcast_mut::<T>(), serde_json::from_value(value)) {
+                (Some(slot), Ok(value)) => {
+                    *slot = value;
+                    true
+                }
+                _ => false,
+            },
+        }
+    }
+}
+
+/// Type-erased view on an instance that is used to restore its state.
+trait Restorable {
+    /// Restores the state with the given ID and returns true if the instance
+    /// is still mounted and the value could be restored.
+    fn restore(&self, state_id: usize, value: Value) -> bool;
+    fn rerender(&self, state_id: usize, type_name: &'static str);
+}
+
+impl<P> Restorable for Weak<P>
+where
+    P: Platform + ?Sized,
+{
+    fn restore(&self, state_id: usize, value: Value) -> bool {
+        self.with_link(|link| {
+            let memory = link.memory();
+            let mut state = memory.state_mut(state_id);
+            (memory.state_codec(state_id).restore)(&mut *state, value)
+        })
+        .unwrap_or_default()
+    }
+
+    fn rerender(&self, state_id: usize, type_name: &'static str) {
+        if let Some(instance) = self.upgrade() {
+            instance.renderer().queue_rerender_with_reason(
+                &instance,
+                RenderReason::StateChanged {
+                    state_id,
+                    type_name,
+                },
+            );
+        }
+    }
+}
+
+struct Entry {
+    change: StateChange,
+    instance: Box<dyn Restorable>,
+    type_name: &'static str,
+}
+
+#[derive(Default)]
+struct Log {
+    entries: Vec<Entry>,
+}
+
+/// Returns a boolean that indicates if a state log is active on the current
+/// thread.
+pub(crate) fn is_enabled() -> bool {
+    LOG.try_with(|log| log.borrow().is_some())
+        .unwrap_or_default()
+}
+
+fn record<P>(instance: &Instance<P>, change: StateChange, type_name: &'static str)
+where
+    P: Platform + ?Sized,
+{
+    let _ = LOG.try_with(|log| {
+        if let Some(log) = log.borrow_mut().as_mut() {
+            log.entries.push(Entry {
+                change: StateChange {
+                    index: log.entries.len(),
+                    ..change
+                },
+                instance: Box::new(Weak::new(instance)),
+                type_name,
+            });
+        }
+    });
+}
+
+fn change<P>(instance: &Instance<P>, slot: MemorySlot, key: String, type_name: &str) -> StateChange
+where
+    P: Platform + ?Sized,
+{
+    let (components, keys) = instance.path();
+
+    StateChange {
+        index: 0,
+        timestamp: SystemTime::now(),
+        instance_id: instance.id,
+        components: components.into_iter().map(ToOwned::to_owned).collect(),
+        keys: keys.iter().map(|key| format!("{:?}", key)).collect(),
+        slot,
+        key,
+        type_name: type_name.to_owned(),
+        old: None,
+        new: None,
+    }
+}
+
+/// Serializes the given value of the state with the given ID.
+pub(crate) fn serialize_state(memory: &Memory, state_id: usize, value: &dyn Any) -> Option<Value> {
+    (memory.state_codec(state_id).serialize)(value)
+}
+
+/// Records a change of the state with the given ID. The previous value is
+/// passed in serialized form because it may no longer exist.
+pub(crate) fn record_state<P>(
+    instance: &Instance<P>,
+    memory: &Memory,
+    state_id: usize,
+    type_name: &'static str,
+    old: Option<Value>,
+    new: &dyn Any,
+) where
+    P: Platform + ?Sized,
+{
+    let key = memory
+        .state_key(state_id)
+        .map(|key| format!("{:?}", key))
+        .unwrap_or_default();
+
+    let change = StateChange {
+        old,
+        new: serialize_state(memory, state_id, new),
+        ..change(instance, MemorySlot::State(state_id), key, type_name)
+    };
+
+    record(instance, change, type_name);
+}
+
+/// Records a change of the reference with the given ID. References are not
+/// required to be serializable, so their values are not recorded.
+pub(crate) fn record_reference<P>(
+    instance: &Instance<P>,
+    memory: &Memory,
+    reference_id: usize,
+    type_name: &'static str,
+) where
+    P: Platform + ?Sized,
+{
+    let key = memory
+        .reference_key(reference_id)
+        .map(|key| format!("{:?}", key))
+        .unwrap_or_default();
+    let change = change(
+        instance,
+        MemorySlot::Reference(reference_id),
+        key,
+        type_name,
+    );
+
+    record(instance, change, type_name);
+}
+
+/// Records state changes on the current thread for as long as it is alive.
+/// Only one state log can be active on a thread at a time.
+pub struct StateLog {
+    _private: (),
+}
+
+impl StateLog {
+    /// Starts recording state changes on the current thread.
+    pub fn start() -> StateLog {
+        LOG.with(|log| {
+            let mut log = log.borrow_mut();
+            assert!(
+                log.is_none(),
+                "Another state log is already active on this thread."
+            );
+            log.replace(Log::default());
+        });
+
+        StateLog { _private: () }
+    }
+
+    /// Returns all state changes that have been recorded so far.
+    pub fn entries(&self) -> Vec<StateChange> {
+        LOG.with(|log| {
+            let log = log.borrow();
+            let log = log.as_ref().unwrap();
+
+            log.entries
+                .iter()
+                .map(|entry| entry.change.clone())
+                .collect()
+        })
+    }
+
+    /// Restores each state to the value it had right before the change at
+    /// the given index, removes that change and all later changes from the
+    /// log and queues a re-render of each affected instance. States of
+    /// instances that have since been unmounted and references can't be
+    /// restored and are skipped. This function must not be called while
+    /// rendering.
+    pub fn rewind(&self, index: usize) {
+        let entries = LOG.with(|log| {
+            let mut log = log.borrow_mut();
+            let log = log.as_mut().unwrap();
+            let index = index.min(log.entries.len());
+
+            log.entries.split_off(index)
+        });
+
+        let mut affected = HashSet::new();
+
+        for entry in entries.into_iter().rev() {
+            let state_id = match entry.change.slot {
+                MemorySlot::State(state_id) => state_id,
+                MemorySlot::Reference(_) => continue,
+            };
+
+            let old = match entry.change.old {
+                Some(old) => old,
+                None => continue,
+            };
+
+            if entry.instance.restore(state_id, old) && affected.insert(entry.change.instance_id) {
+                entry.instance.rerender(state_id, entry.type_name);
+            }
+        }
+    }
+}
+
+impl Drop for StateLog {
+    fn drop(&mut self) {
+        let _ = LOG.try_with(|log| log.borrow_mut().take());
+    }
+}
+
+/// Slot of an instance's memory that changed.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub enum MemorySlot {
+    State(usize),
+    Reference(usize),
+}
+
+/// Describes a single change of a state or reference.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct StateChange {
+    /// Index of this change in the log.
+    pub index: usize,
+
+    /// Time at which this change was recorded.
+    pub timestamp: SystemTime,
+
+    /// ID of the instance that owns the state.
+    pub instance_id: usize,
+
+    /// Type names of the components from the root to the instance that owns
+    /// the state (including the instance itself).
+    pub components: Vec<String>,
+
+    /// Keys of the elements from the root to the instance that owns the state.
+    pub keys: Vec<String>,
+
+    /// Slot that changed.
+    pub slot: MemorySlot,
+
+    /// Key that the state was created with.
+    pub key: String,
+
+    /// Type name of the value.
+    pub type_name: String,
+
+    /// Serialized value before the change. This is always `None` for
+    /// references.
+    pub old: Option<Value>,
+
+    /// Serialized value after the change. This is always `None` for
+    /// references.
+    pub new: Option<Value>,
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use std::cell::{Cell, RefCell};
+    use std::rc::Rc;
+
+    use super::{MemorySlot, StateLog};
+    use crate::testing::{render, rerender, TestComponent, TestPlatform};
+    use crate::{use_reference, use_state, Element, Key, Manager, Reference, State, WeakLink};
+
+    type Slots = Option<(State<usize>, Reference<bool>)>;
+
+    struct Counter {
+        slots: Rc<RefCell<Slots>>,
+        renders: Rc<Cell<usize>>,
+    }
+
+    impl TestComponent for Counter {
+        fn render(&self, manager: &mut Manager<TestPlatform>) -> Element<TestPlatform> {
+            let count = use_state!(manager, 0);
+            let dirty = use_reference!(manager, false);
+            self.slots.replace(Some((count, dirty)));
+            self.renders.set(self.renders.get() + 1);
+
+            Element::empty()
+        }
+    }
+
+    #[test]
+    fn test_state_log() {
+        let log = StateLog::start();
+        let slots = Rc::new(RefCell::new(None));
+        let renders = Rc::new(Cell::new(0));
+
+        let (renderer, instance, _handler) = render(Element::new(
+            Key::new("counter"),
+            Counter {
+                slots: slots.clone(),
+                renders: renders.clone(),
+            }
+            .into(),
+            Element::empty(),
+        ));
+
+        let (count, dirty) = slots.borrow().unwrap();
+
+        {
+            let memory = instance.memory();
+            let link = WeakLink::new(&instance, &memory);
+
+            for value in 1..=3 {
+                count.replace(&link, value);
+            }
+
+            dirty.replace(&link, true);
+        }
+
+        let entries = log.entries();
+        assert_eq!(entries.len(), 4);
+        assert!(entries[0].components[0].ends_with("Counter"));
+        assert_eq!(entries[0].keys, vec!["Key(\"counter\")"]);
+        assert_eq!(entries[0].slot, MemorySlot::State(0));
+        assert_eq!(entries[2].old, Some(json!(2)));
+        assert_eq!(entries[2].new, Some(json!(3)));
+        assert_eq!(entries[3].slot, MemorySlot::Reference(0));
+        assert_eq!(entries[3].new, None);
+
+        // Entries can be serialized.
+        serde_json::to_string(&entries).unwrap();
+
+        // Rewinding to the second change restores the value that the state
+        // had right before that change.
+        log.rewind(1);
+        assert_eq!(log.entries().len(), 1);
+
+        rerender(&renderer, &instance);
+        assert_eq!(renders.get(), 2);
+
+        let memory = instance.memory();
+        let link = WeakLink::new(&instance, &memory);
+        assert_eq!(*count.get(&link), 1);
+    }
+}
@@ -1,15 +1,44 @@
-use super::{Element, Instance, Key, Platform};
+use super::arena::Index;
+use super::{Element, Key, Platform};
 use std::collections::{HashMap, HashSet};
-use std::rc::Rc;
+
+struct Edge {
+    /// This is the index of the instance in the arena of its renderer.
+    index: Index,
+
+    /// This is the reconciliation pass in which this edge was last visited.
+    epoch: u32,
+
+    /// This is the position of this edge in the order of the reconciliation
+    /// pass in which it was last visited.
+    position: usize,
+}
 
 pub struct Topology<P>
 where
     P: Platform + ?Sized,
 {
+    /// This is the element of the instance without its children, which are
+    /// moved into the instances that render them (see `Element::split`).
     element: Element<P>,
 
+    /// These are the children that the element was most recently updated with
+    /// and that have not yet been rendered.
+    children: Option<Vec<Element<P>>>,
+
     /// This is a map of edges.
-    edges: HashMap<Key, Rc<Instance<P>>>,
+    edges: HashMap<Key, Edge>,
+
+    /// These are the edges in the order of the most recent reconciliation
+    /// pass. Edges that are removed during a pass leave a tombstone.
+    order: Vec<Option<Index>>,
+
+    /// This is the current reconciliation pass.
+    epoch: u32,
+
+    /// This is the number of times that each key has been repeated during
+    /// the current reconciliation pass.
+    repetitions: HashMap<Key, usize>,
 }
 
 impl<P> Topology<P>
@@ -17,9 +46,15 @@ where
     P: Platform + ?Sized,
 {
     pub fn new(element: Element<P>) -> Topology<P> {
+        let (element, children) = element.split();
+
         Topology {
             element,
+            children,
             edges: HashMap::new(),
+            order: vec![],
+            epoch: 0,
+            repetitions: HashMap::new(),
         }
     }
 
@@ -27,27 +62,111 @@ where
         &self.element
     }
 
+    /// Replaces the element of this topology and returns the previous element
+    /// (without its children).
     pub fn update(&mut self, element: Element<P>) -> Element<P> {
+        let (element, children) = element.split();
+        self.children = children;
         std::mem::replace(&mut self.element, element)
     }
 
+    /// Returns the children that the element was most recently updated with,
+    /// unless they have already been taken.
+    pub(crate) fn take_children(&mut self) -> Option<Vec<Element<P>>> {
+        self.children.take()
+    }
+
     pub fn keys(&self) -> HashSet<Key> {
         self.edges.keys().cloned().collect()
     }
 
-    pub fn edge(&self, key: &Key) -> Option<&Rc<Instance<P>>> {
-        self.edges.get(key)
+    /// Returns the edges in the order in which they were most recently
+    /// rendered.
+    pub(crate) fn edges(&self) -> impl Iterator<Item = Index> + '_ {
+        self.order.iter().flatten().copied()
     }
 
-    pub fn edges(&self) -> impl Iterator<Item = &Rc<Instance<P>>> {
-        self.edges.values()
+    pub(crate) fn add_edge(&mut self, key: Key, index: Index) {
+        let position = self.order.len();
+        self.order.push(Some(index));
+        self.edges.insert(
+            key,
+            Edge {
+                index,
+                epoch: self.epoch,
+                position,
+            },
+        );
     }
 
-    pub fn add_edge(&mut self, key: Key, instance: Rc<Instance<P>>) {
-        self.edges.insert(key, instance);
+    pub(crate) fn remove_edge(&mut self, key: &Key) -> Option<Index> {
+        let edge = self.edges.remove(key)?;
+
+        if edge.epoch == self.epoch {
+            self.order[edge.position] = None;
+        }
+
+        Some(edge.index)
     }
 
-    pub fn remove_edge(&mut self, key: &Key) -> Option<Rc<Instance<P>>> {
-        self.edges.remove(key)
+    /// Starts a new reconciliation pass. Edges that are neither visited nor
+    /// added during this pass are removed by `sweep`.
+    pub(crate) fn begin(&mut self) {
+        self.epoch = self.epoch.wrapping_add(1);
+        self.order.clear();
+        self.repetitions.clear();
+    }
+
+    /// Returns the key of the edge that corresponds to the next element with
+    /// the given key during the current reconciliation pass. Siblings often
+    /// share a key (e.g. fragments and empty elements), so each repetition of
+    /// a key that has already been visited or added during this pass is
+    /// combined with the number of times that it has been repeated.
+    pub(crate) fn occurrence(&mut self, key: &Key) -> Key {
+        let visited = match self.edges.get(key) {
+            Some(edge) => edge.epoch == self.epoch,
+            None => false,
+        };
+
+        if !visited {
+            return key.clone();
+        }
+
+        let repetition = self.repetitions.entry(key.clone()).or_insert(0);
+        *repetition += 1;
+
+        Key::new((key.clone(), *repetition))
+    }
+
+    /// Marks the edge with the given key as visited during the current
+    /// reconciliation pass and returns the index of its instance (if it
+    /// exists).
+    pub(crate) fn visit(&mut self, key: &Key) -> Option<Index> {
+        let edge = self.edges.get_mut(key)?;
+
+        if edge.epoch != self.epoch {
+            edge.epoch = self.epoch;
+            edge.position = self.order.len();
+            self.order.push(Some(edge.index));
+        }
+
+        Some(edge.index)
+    }
+
+    /// Removes all edges that have not been visited during the current
+    /// reconciliation pass and returns the indices of their instances.
+    pub(crate) fn sweep(&mut self) -> Vec<Index> {
+        let epoch = self.epoch;
+        let mut stale = vec![];
+
+        self.edges.retain(|_, edge| match edge.epoch == epoch {
+            true => true,
+            false => {
+                stale.push(edge.index);
+                false
+            }
+        });
+
+        stale
     }
 }
@@ -1,6 +1,7 @@
 use std::rc::{Rc, Weak as WeakRc};
 
-use super::{Instance, Link, Memory, Platform, Reference, State};
+use super::arena::Index;
+use super::{Instance, Link, Memory, Platform, Reference, Renderer, State};
 
 /// This is a weak reference to an instance that can be converted into a link at
 /// request.
@@ -8,16 +9,34 @@ pub struct Weak<P>
 where
     P: Platform + ?Sized,
 {
-    instance: WeakRc<Instance<P>>,
+    renderer: WeakRc<Renderer<P>>,
+    index: Index,
+    id: usize,
 }
 
 impl<P> Weak<P>
 where
     P: Platform + ?Sized,
 {
-    pub fn new(instance: &Rc<Instance<P>>) -> Weak<P> {
+    pub fn new(instance: &Instance<P>) -> Weak<P> {
+        Weak::from_raw(instance.renderer(), instance.index, instance.id)
+    }
+
+    pub(crate) fn from_raw(renderer: &Rc<Renderer<P>>, index: Index, id: usize) -> Weak<P> {
         Weak {
-            instance: Rc::downgrade(instance),
+            renderer: Rc::downgrade(renderer),
+            index,
+            id,
+        }
+    }
+
+    /// Returns a handle to the instance if it is still mounted.
+    pub(crate) fn upgrade(&self) -> Option<Instance<P>> {
+        let renderer = self.renderer.upgrade()?;
+
+        match renderer.contains(self.index) {
+            true => Some(Instance::new(renderer, self.index, self.id)),
+            false => None,
         }
     }
 
@@ -25,14 +44,13 @@ where
     where
         F: FnOnce(&WeakLink<P>) -> T,
     {
-        if let Some(instance) = self.instance.upgrade() {
-            Some(op(&WeakLink {
-                instance: &instance,
-                memory: &instance.memory(),
-            }))
-        } else {
-            None
-        }
+        let instance = self.upgrade()?;
+        let memory = instance.memory();
+
+        Some(op(&WeakLink {
+            instance: &instance,
+            memory: &memory,
+        }))
     }
 }
 
@@ -42,7 +60,9 @@ where
 {
     fn clone(&self) -> Self {
         Weak {
-            instance: self.instance.clone(),
+            renderer: self.renderer.clone(),
+            index: self.index,
+            id: self.id,
         }
     }
 }
@@ -51,7 +71,7 @@ pub struct WeakLink<'a, P>
 where
     P: Platform + ?Sized,
 {
-    instance: &'a Rc<Instance<P>>,
+    instance: &'a Instance<P>,
     memory: &'a Memory,
 }
 
@@ -59,7 +79,7 @@ impl<'a, P> WeakLink<'a, P>
 where
     P: Platform + ?Sized,
 {
-    pub fn new(instance: &'a Rc<Instance<P>>, memory: &'a Memory) -> WeakLink<'a, P> {
+    pub fn new(instance: &'a Instance<P>, memory: &'a Memory) -> WeakLink<'a, P> {
         WeakLink { instance, memory }
     }
 }
@@ -70,7 +90,7 @@ where
 {
     type Platform = P;
 
-    fn instance(&self) -> &Rc<Instance<Self::Platform>> {
+    fn instance(&self) -> &Instance<Self::Platform> {
         self.instance
     }
 
@@ -0,0 +1,428 @@
+use futures::channel::oneshot;
+use std::collections::VecDeque;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::sync::{Arc, Condvar, Mutex};
+use std::thread;
+
+use super::Key;
+
+#[macro_export]
+macro_rules! use_worker {
+    ($manager:expr, $dependencies:expr, $task:expr) => {
+        $crate::UseWorker::use_worker(
+            $manager,
+            $crate::use_id!().into(),
+            $crate::Key::new($dependencies),
+            $task,
+        )
+    };
+}
+
+pub trait UseWorker {
+    /// Runs the given task on a worker thread whenever the given dependencies
+    /// change and returns its result once it has been delivered back to the
+    /// render thread. Until then, this function returns `None`. Results of
+    /// tasks that were started for previous dependencies, or that complete
+    /// after the component has been unmounted, are discarded.
+    fn use_worker<T, F>(&mut self, key: Key, dependencies: Key, task: F) -> Option<T>
+    where
+        T: Clone + Send + 'static,
+        F: FnOnce() -> T + Send + 'static;
+}
+
+type Job = Box<dyn FnOnce() + Send>;
+
+/// Bounded pool of threads that runs CPU-heavy work off the render thread.
+/// Threads are spawned lazily, up to the maximum number of threads that this
+/// pool is created with.
+pub struct WorkerPool {
+    shared: Arc<Shared>,
+}
+
+struct Shared {
+    state: Mutex<PoolState>,
+    condvar: Condvar,
+    max_threads: usize,
+}
+
+#[derive(Default)]
+struct PoolState {
+    jobs: VecDeque<Job>,
+    threads: usize,
+    idle: usize,
+    terminated: bool,
+}
+
+impl WorkerPool {
+    /// Returns a new pool that runs at most the given number of threads
+    /// concurrently.
+    pub fn new(max_threads: usize) -> WorkerPool {
+        assert!(max_threads > 0, "Worker pool needs at least one thread.");
+
+        WorkerPool {
+            shared: Arc::new(Shared {
+                state: Mutex::new(PoolState::default()),
+                condvar: Condvar::new(),
+                max_threads,
+            }),
+        }
+    }
+
+    /// Returns the maximum number of threads of this pool.
+    pub fn max_threads(&self) -> usize {
+        self.shared.max_threads
+    }
+
+    /// Queues the given task and returns a receiver for its result. If the
+    /// receiver is dropped before a thread picks up the task, the task is
+    /// skipped altogether.
+    pub fn spawn<F, T>(&self, task: F) -> oneshot::Receiver<T>
+    where
+        F: FnOnce() -> T + Send + 'static,
+        T: Send + 'static,
+    {
+        let (tx, rx) = oneshot::channel();
+
+        self.execute(Box::new(move || {
+            if tx.is_canceled() {
+                return;
+            }
+
+            // Note: we ignore the result. The result will be an error if the
+            // receiver has been dropped while the task was running.
+            let _ = tx.send(task());
+        }));
+
+        rx
+    }
+
+    fn execute(&self, job: Job) {
+        let mut state = self.shared.state.lock().unwrap();
+        state.jobs.push_back(job);
+
+        if state.idle == 0 && state.threads < self.shared.max_threads {
+            state.threads += 1;
+
+            let shared = self.shared.clone();
+
+            thread::Builder::new()
+                .name("com.glacyr.Polyhorn.Worker".to_owned())
+                .spawn(move || work(shared))
+                .unwrap();
+        } else {
+            self.shared.condvar.notify_one();
+        }
+    }
+}
+
+impl Default for WorkerPool {
+    fn default() -> Self {
+        WorkerPool::new(num_cpus::get())
+    }
+}
+
+impl Drop for WorkerPool {
+    fn drop(&mut self) {
+        let mut state = self.shared.state.lock().unwrap();
+        state.terminated = true;
+        state.jobs.clear();
+
+        self.shared.condvar.notify_all();
+    }
+}
+
+fn work(shared: Arc<Shared>) {
+    loop {
+        let job = {
+            let mut state = shared.state.lock().unwrap();
+
+            loop {
+                if state.terminated {
+                    return;
+                }
+
+                if let Some(job) = state.jobs.pop_front() {
+                    break job;
+                }
+
+                state.idle += 1;
+                state = shared.condvar.wait(state).unwrap();
+                state.idle -= 1;
+            }
+        };
+
+        // A panicking task drops its sender, which is how the receiver learns
+        // about the panic. The thread itself remains available.
+        let _ = catch_unwind(AssertUnwindSafe(job));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::channel::mpsc::{unbounded, UnboundedReceiver, UnboundedSender};
+    use futures::executor::block_on;
+    use futures::{select, FutureExt, StreamExt};
+    use std::cell::{Cell, RefCell};
+    use std::collections::HashMap;
+    use std::future::Future;
+    use std::rc::Rc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::{mpsc, Arc, Barrier};
+
+    use super::WorkerPool;
+    use crate::testing::{TestComponent, TestCompositor, TestPlatform};
+    use crate::{
+        Element, EventLoop, EventLoopHandler, Instance, Key, Manager, Renderer, TestExecutor,
+    };
+
+    type Results = UnboundedSender<(&'static str, Option<usize>)>;
+
+    /// Runs a task for its dependencies and reports every render's result. If
+    /// a gate is registered for the dependencies, the task blocks until the
+    /// gate is opened.
+    #[derive(Clone)]
+    struct Compute {
+        name: &'static str,
+        dependencies: Rc<Cell<usize>>,
+        gates: Rc<RefCell<HashMap<usize, mpsc::Receiver<()>>>>,
+        results: Results,
+    }
+
+    impl Compute {
+        fn new(name: &'static str, results: &Results) -> Compute {
+            Compute {
+                name,
+                dependencies: Rc::new(Cell::new(0)),
+                gates: Default::default(),
+                results: results.clone(),
+            }
+        }
+
+        fn gate(&self, dependencies: usize) -> mpsc::Sender<()> {
+            let (tx, rx) = mpsc::channel();
+            self.gates.borrow_mut().insert(dependencies, rx);
+            tx
+        }
+    }
+
+    impl TestComponent for Compute {
+        fn render(&self, manager: &mut Manager<TestPlatform>) -> Element<TestPlatform> {
+            let dependencies = self.dependencies.get();
+            let gate = self.gates.borrow_mut().remove(&dependencies);
+
+            let result = crate::use_worker!(manager, dependencies, move || {
+                if let Some(gate) = gate {
+                    let _ = gate.recv();
+                }
+
+                dependencies
+            });
+
+            self.results.unbounded_send((self.name, result)).unwrap();
+
+            Element::empty()
+        }
+    }
+
+    struct Screen {
+        show: Rc<Cell<bool>>,
+        sentinel: Compute,
+        compute: Compute,
+    }
+
+    impl TestComponent for Screen {
+        fn render(&self, _manager: &mut Manager<TestPlatform>) -> Element<TestPlatform> {
+            // The sentinel renders first, so that its task is spawned before
+            // the task of the compute component (if any).
+            let mut elements = vec![Element::new(
+                Key::new("sentinel"),
+                self.sentinel.clone().into(),
+                Element::empty(),
+            )];
+
+            if self.show.get() {
+                elements.push(Element::new(
+                    Key::new("compute"),
+                    self.compute.clone().into(),
+                    Element::empty(),
+                ));
+            }
+
+            Element::fragment(Key::new(()), elements)
+        }
+    }
+
+    /// Renders the given screen with a single worker thread, so that tasks
+    /// complete in the order in which they are spawned.
+    fn render(
+        screen: Screen,
+    ) -> (
+        Rc<Renderer<TestPlatform>>,
+        Instance<TestPlatform>,
+        EventLoopHandler,
+    ) {
+        let executor = Arc::new(TestExecutor::new());
+        let (evloop, handler) = EventLoop::with_workers(executor, WorkerPool::new(1));
+        let renderer = Renderer::new(TestCompositor::default(), evloop);
+        let element = Element::new(Key::new(()), screen.into(), Element::empty());
+        let instance = renderer.render(element, 0);
+
+        (renderer, instance, handler)
+    }
+
+    /// Runs the event loop until the given future completes.
+    fn run<F>(handler: EventLoopHandler, future: F)
+    where
+        F: Future<Output = ()>,
+    {
+        block_on(async move {
+            select! {
+                _ = Box::pin(handler.main()).fuse() => {},
+                _ = Box::pin(future).fuse() => {},
+            }
+        })
+    }
+
+    /// Waits until the component with the given name renders the given
+    /// result and returns the results that the compute component rendered
+    /// until then.
+    async fn until(
+        results: &mut UnboundedReceiver<(&'static str, Option<usize>)>,
+        name: &'static str,
+        value: Option<usize>,
+    ) -> Vec<Option<usize>> {
+        let mut computed = vec![];
+
+        while let Some((other, result)) = results.next().await {
+            if other == "compute" {
+                computed.push(result);
+            }
+
+            if (other, result) == (name, value) {
+                break;
+            }
+        }
+
+        computed
+    }
+
+    #[test]
+    fn test_use_worker_discards_stale_results() {
+        let (tx, mut rx) = unbounded();
+        let sentinel = Compute::new("sentinel", &tx);
+        let compute = Compute::new("compute", &tx);
+
+        compute.dependencies.set(1);
+        let first = compute.gate(1);
+
+        let (renderer, instance, handler) = render(Screen {
+            show: Rc::new(Cell::new(true)),
+            sentinel: sentinel.clone(),
+            compute: compute.clone(),
+        });
+
+        // The first task is still running when the dependencies change.
+        sentinel.dependencies.set(1);
+        compute.dependencies.set(2);
+        let second = compute.gate(2);
+        renderer.rerender(&instance);
+
+        let mut computed = vec![];
+
+        run(handler, async {
+            // Once the sentinel has its result, the first task has completed
+            // too.
+            let _ = first.send(());
+            computed.extend(until(&mut rx, "sentinel", Some(1)).await);
+
+            let _ = second.send(());
+            computed.extend(until(&mut rx, "compute", Some(2)).await);
+        });
+
+        assert_eq!(computed, vec![None, None, Some(2)]);
+    }
+
+    #[test]
+    fn test_use_worker_after_unmount() {
+        let (tx, mut rx) = unbounded();
+        let sentinel = Compute::new("sentinel", &tx);
+        let compute = Compute::new("compute", &tx);
+        let show = Rc::new(Cell::new(true));
+
+        compute.dependencies.set(1);
+        let gate = compute.gate(1);
+
+        let (renderer, instance, handler) = render(Screen {
+            show: show.clone(),
+            sentinel: sentinel.clone(),
+            compute: compute.clone(),
+        });
+
+        // The task is still running when the component is unmounted.
+        show.set(false);
+        sentinel.dependencies.set(1);
+        renderer.rerender(&instance);
+
+        let mut computed = vec![];
+
+        run(handler, async {
+            let _ = gate.send(());
+            computed.extend(until(&mut rx, "sentinel", Some(1)).await);
+        });
+
+        assert_eq!(computed, vec![None]);
+    }
+
+    #[test]
+    fn test_worker_pool() {
+        let pool = WorkerPool::new(2);
+
+        let receivers = (0..8)
+            .map(|i| pool.spawn(move || i * i))
+            .collect::<Vec<_>>();
+
+        let results = receivers
+            .into_iter()
+            .map(|rx| block_on(rx).unwrap())
+            .collect::<Vec<_>>();
+
+        assert_eq!(results, vec![0, 1, 4, 9, 16, 25, 36, 49]);
+        assert!(pool.shared.state.lock().unwrap().threads <= 2);
+    }
+
+    #[test]
+    fn test_worker_pool_skips_canceled_tasks() {
+        let pool = WorkerPool::new(1);
+        let barrier = Arc::new(Barrier::new(2));
+        let counter = Arc::new(AtomicUsize::new(0));
+
+        // Keep the only thread busy until the second task has been canceled.
+        let blocker = pool.spawn({
+            let barrier = barrier.clone();
+            move || {
+                barrier.wait();
+            }
+        });
+
+        drop(pool.spawn({
+            let counter = counter.clone();
+            move || counter.fetch_add(1, Ordering::SeqCst)
+        }));
+
+        barrier.wait();
+        block_on(blocker).unwrap();
+
+        let last = pool.spawn(|| ());
+        block_on(last).unwrap();
+
+        assert_eq!(counter.load(Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    fn test_worker_pool_survives_panics() {
+        let pool = WorkerPool::new(1);
+
+        assert!(block_on(pool.spawn(|| panic!("Expected panic."))).is_err());
+        assert_eq!(block_on(pool.spawn(|| 42)), Ok(42));
+    }
+}
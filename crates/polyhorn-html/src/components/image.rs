@@ -0,0 +1,76 @@
+use polyhorn_ui::assets::{Asset, ImageSource};
+use polyhorn_ui::geometry::{Dimension, Size};
+use polyhorn_ui::queries::ImageQuery;
+
+use crate::prelude::*;
+use crate::raw::Builtin;
+use crate::Key;
+
+/// Returns the media type of the given encoded image based on its signature.
+fn media_type(bytes: &[u8]) -> &'static str {
+    match bytes {
+        [0x89, b'P', b'N', b'G', ..] => "image/png",
+        [0xff, 0xd8, 0xff, ..] => "image/jpeg",
+        [b'G', b'I', b'F', b'8', ..] => "image/gif",
+        _ => "application/octet-stream",
+    }
+}
+
+/// Returns the size of the given encoded image if it is a PNG.
+fn png_size(bytes: &[u8]) -> Option<Size<f32>> {
+    // The header chunk is always the first chunk and starts with the width
+    // and height of the image.
+    match bytes.get(12..24)? {
+        &[b'I', b'H', b'D', b'R', w0, w1, w2, w3, h0, h1, h2, h3]
+            if media_type(bytes) == "image/png" =>
+        {
+            Some(Size::new(
+                u32::from_be_bytes([w0, w1, w2, w3]) as f32,
+                u32::from_be_bytes([h0, h1, h2, h3]) as f32,
+            ))
+        }
+        _ => None,
+    }
+}
+
+impl Component for Image {
+    fn render(&self, manager: &mut Manager) -> Element {
+        // Assets are referenced relative to the document, at the same path
+        // as their source files within the `assets/` folder of each package.
+        let (url, size) = match &self.source {
+            ImageSource::Asset(asset) => (
+                Some(format!("{}/{}.svg", asset.package(), asset.name())),
+                Some(Size::new(asset.width(), asset.height())),
+            ),
+            ImageSource::Bytes(bytes) => (
+                Some(format!(
+                    "data:{};base64,{}",
+                    media_type(bytes),
+                    base64::encode(bytes)
+                )),
+                png_size(bytes),
+            ),
+            ImageSource::Placeholder(size) => (None, Some(*size)),
+        };
+
+        // Images are sized to their intrinsic size unless a size is given.
+        let mut style = self.style;
+
+        if let Some(size) = size {
+            if let Dimension::Auto | Dimension::Undefined = style.view.size.width {
+                style.view.size.width = Dimension::Points(size.width);
+            }
+
+            if let Dimension::Auto | Dimension::Undefined = style.view.size.height {
+                style.view.size.height = Dimension::Points(size.height);
+            }
+        }
+
+        Element::builtin(
+            Key::new(()),
+            Builtin::Image(style, url),
+            manager.children(),
+            None,
+        )
+    }
+}
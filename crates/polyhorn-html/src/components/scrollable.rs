@@ -0,0 +1,38 @@
+use polyhorn_ui::geometry::{Dimension, Size};
+use polyhorn_ui::styles::{Position, Relative, ViewStyle};
+
+use crate::prelude::*;
+use crate::raw::Builtin;
+use crate::Key;
+
+impl Component for Scrollable {
+    fn render(&self, manager: &mut Manager) -> Element {
+        // The content fills at least the scrollable and is never shrunk to
+        // fit. Scroll padding becomes padding of the content. Scrollbar
+        // padding can't be expressed in CSS and is ignored.
+        let content_style = ViewStyle {
+            position: Position::Relative(Relative {
+                flex_shrink: 0.0,
+                ..Default::default()
+            }),
+            min_size: Size {
+                width: Dimension::Percentage(1.0),
+                height: Dimension::Percentage(1.0),
+            },
+            padding: self.style.scrollable.scroll_padding,
+            ..Default::default()
+        };
+
+        Element::builtin(
+            Key::new(()),
+            Builtin::Scrollable(self.style, self.direction),
+            Element::builtin(
+                Key::new(()),
+                Builtin::ScrollContent(content_style, self.direction),
+                manager.children(),
+                None,
+            ),
+            None,
+        )
+    }
+}
@@ -0,0 +1,32 @@
+use polyhorn_ui::geometry::{Dimension, Size};
+use polyhorn_ui::hooks::SafeAreaInsets;
+use polyhorn_ui::styles::ViewStyle;
+use std::rc::Rc;
+
+use crate::prelude::*;
+use crate::raw::Builtin;
+use crate::Key;
+
+impl Component for Window {
+    fn render(&self, manager: &mut Manager) -> Element {
+        // The window fills the body of the document.
+        let style = ViewStyle {
+            size: Size {
+                width: Dimension::Percentage(1.0),
+                height: Dimension::Percentage(1.0),
+            },
+            ..self.style
+        };
+
+        Element::builtin(
+            Key::new(()),
+            Builtin::View(style),
+            Element::context(
+                Key::new(()),
+                Rc::new(SafeAreaInsets::new(0.0, 0.0, 0.0, 0.0)),
+                manager.children(),
+            ),
+            None,
+        )
+    }
+}
@@ -0,0 +1,219 @@
+use polyhorn_core::{EventLoop, Renderer};
+
+use crate::raw::{Compositor, Node, Platform};
+use crate::Element;
+
+/// Renders the given element once and returns the HTML of the resulting
+/// nodes, which can be embedded into an existing document. Effects are not
+/// run.
+pub fn render_to_markup(element: Element) -> String {
+    let root = Node::new("body");
+
+    let mut compositor = Compositor::new();
+    let id = compositor.track(root.clone());
+
+    let executor = <Platform as polyhorn_core::Platform>::default_executor();
+    let (evloop, _handler) = EventLoop::new(executor);
+    let renderer = Renderer::new(compositor, evloop);
+    let _instance = renderer.render(element, id);
+
+    root.inner_html()
+}
+
+/// Renders the given element once and returns a standalone HTML document that
+/// contains the resulting nodes. Effects are not run.
+pub fn render_to_string(element: Element) -> String {
+    format!(
+        concat!(
+            "<!DOCTYPE html>",
+            "<html>",
+            "<head>",
+            "<meta charset=\"utf-8\">",
+            "<meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">",
+            "<style>html,body{{margin:0;height:100%}}</style>",
+            "</head>",
+            "<body>{}</body>",
+            "</html>"
+        ),
+        render_to_markup(element)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use polyhorn_core::{
+        CommandBuffer as _, Compositor as _, EventLoop, Operation, Renderer, Replayer,
+    };
+    use polyhorn_ui::assets::ImageSource;
+    use polyhorn_ui::font::FontWeight;
+    use polyhorn_ui::geometry::Dimension;
+    use polyhorn_ui::styles::{Inherited, TextStyle, ViewStyle};
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use super::{render_to_markup, render_to_string};
+    use crate::prelude::*;
+    use crate::raw::{Builtin, Compositor, ContainerID, Node, Payload, Platform};
+    use crate::Key;
+
+    /// Returns a view with a text, an image and a scrollable.
+    fn example() -> Element {
+        let bold = TextStyle {
+            font_weight: Inherited::Specified(FontWeight::Bold),
+            ..Default::default()
+        };
+
+        let mut view = View::default();
+        view.style.size.height = Dimension::Points(40.0);
+
+        // This is a 1x1 PNG.
+        let pixel: &'static [u8] = &[
+            0x89, 0x50, 0x4e, 0x47, 0x0d, 0x0a, 0x1a, 0x0a, 0x00, 0x00, 0x00, 0x0d, 0x49, 0x48,
+            0x44, 0x52, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01,
+        ];
+
+        Element::new(
+            Key::new(()),
+            view.into(),
+            Element::fragment(
+                Key::new(()),
+                vec![
+                    Element::new(
+                        Key::new(0),
+                        Text::default().into(),
+                        Element::fragment(
+                            Key::new(()),
+                            vec![
+                                Element::string("Hello, "),
+                                Element::new(
+                                    Key::new(()),
+                                    Text {
+                                        style: bold,
+                                        ..Default::default()
+                                    }
+                                    .into(),
+                                    Element::string("<world>"),
+                                ),
+                            ],
+                        ),
+                    ),
+                    Element::new(
+                        Key::new(1),
+                        Image {
+                            source: ImageSource::from(pixel),
+                            ..Default::default()
+                        }
+                        .into(),
+                        Element::empty(),
+                    ),
+                    Element::new(Key::new(2), Scrollable::default().into(), Element::empty()),
+                ],
+            ),
+        )
+    }
+
+    #[test]
+    fn test_render_to_markup() {
+        assert_eq!(
+            render_to_markup(example()),
+            concat!(
+                "<div style=\"display:flex;box-sizing:border-box;position:relative;height:40px;",
+                "min-width:0;min-height:0;flex-direction:column\">",
+                "<span style=\"white-space:pre-wrap\">Hello, ",
+                "<span style=\"font-weight:700\">&lt;world&gt;</span></span>",
+                "<div role=\"img\" style=\"display:flex;box-sizing:border-box;position:relative;",
+                "width:1px;height:1px;min-width:0;min-height:0;flex-direction:column;",
+                "background-size:100% 100%;background-position:center;background-repeat:no-repeat;",
+                "background-image:url(&quot;data:image/png;base64,iVBORw0KGgoAAAANSUhEUgAAAAEAAAAB&quot;)\"></div>",
+                "<div style=\"display:flex;box-sizing:border-box;position:relative;min-width:0;",
+                "min-height:0;flex-direction:column;overflow-x:hidden;overflow-y:auto\">",
+                "<div style=\"display:flex;box-sizing:border-box;position:relative;flex-shrink:0;",
+                "min-width:100%;min-height:100%;flex-direction:column\"></div></div>",
+                "</div>",
+            )
+        );
+    }
+
+    #[test]
+    fn test_render_text_with_other_children() {
+        // Children of a text that are neither texts nor strings are ignored.
+        let element = Element::new(
+            Key::new(()),
+            Text::default().into(),
+            Element::fragment(
+                Key::new(()),
+                vec![
+                    Element::string("Hello, "),
+                    Element::new(Key::new(0), View::default().into(), Element::string("view")),
+                    Element::builtin(
+                        Key::new(1),
+                        Builtin::View(ViewStyle::default()),
+                        Element::empty(),
+                        None,
+                    ),
+                    Element::context(Key::new(2), Rc::new(0), Element::string("context")),
+                    Element::string("world"),
+                ],
+            ),
+        );
+
+        assert_eq!(
+            render_to_markup(element),
+            "<span style=\"white-space:pre-wrap\">Hello, world</span>"
+        );
+    }
+
+    #[test]
+    fn test_record_and_replay() {
+        let recorded = Node::new("body");
+        let mut compositor = Compositor::new();
+        let root = compositor.track(recorded.clone());
+
+        let executor = <Platform as polyhorn_core::Platform>::default_executor();
+        let (evloop, _handler) = EventLoop::new(executor);
+        let renderer = Renderer::new(compositor, evloop);
+        let operations = Rc::new(RefCell::new(vec![]));
+
+        renderer.record::<Payload, _>({
+            let operations = operations.clone();
+            move |operation| operations.borrow_mut().push(operation)
+        });
+
+        let _instance = renderer.render(example(), root);
+
+        // Replaying the operations onto another document after a round trip
+        // through JSON results in the same markup.
+        let json = serde_json::to_string(&operations.take()).unwrap();
+        let operations: Vec<Operation<ContainerID, Payload>> = serde_json::from_str(&json).unwrap();
+
+        let replayed = Node::new("body");
+        let mut compositor = Compositor::new();
+        let mut replayer = Replayer::<Platform>::new(root, compositor.track(replayed.clone()));
+        let mut buffer = compositor.buffer();
+
+        for operation in operations {
+            replayer.apply(&mut buffer, operation);
+        }
+
+        buffer.commit();
+
+        assert!(!recorded.inner_html().is_empty());
+        assert_eq!(replayed.inner_html(), recorded.inner_html());
+    }
+
+    #[test]
+    fn test_render_to_string() {
+        let html = render_to_string(Element::new(
+            Key::new(()),
+            Window::default().into(),
+            Element::empty(),
+        ));
+
+        assert!(html.starts_with("<!DOCTYPE html><html><head>"));
+        assert!(html.ends_with(concat!(
+            "<body><div style=\"display:flex;box-sizing:border-box;position:relative;",
+            "width:100%;height:100%;min-width:0;min-height:0;flex-direction:column\">",
+            "</div></body></html>"
+        )));
+    }
+}
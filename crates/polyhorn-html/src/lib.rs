@@ -0,0 +1,61 @@
+//! This crate implements Polyhorn for static HTML. It renders a tree of
+//! components once to an HTML string that can be used for previews and
+//! server-side rendering. Effects are never run and event handlers are never
+//! attached.
+
+pub use polyhorn_core::{
+    log_renders, observe_renders, render, render_with_executor, stop_observing_renders, untracked,
+    Computed, Context, ContextProvider, Executor, History, HistoryOptions, Key, Link,
+    LocalExecutor, PropsDiff, Receiver, Reference, RenderReason, RenderRecord, Sender, Signal,
+    Slot, State, TestExecutor,
+};
+pub use polyhorn_ui::{assets, color, font, geometry, layout, linalg, physics, styles};
+pub use polyhorn_ui_macros::render;
+
+pub mod components;
+mod document;
+pub mod prelude;
+pub mod raw;
+#[cfg(test)]
+mod tests;
+
+pub use document::{render_to_markup, render_to_string};
+
+/// Re-exports of hooks provided by Polyhorn Core and Polyhorn UI.
+pub mod hooks {
+    pub use polyhorn_core::{
+        use_async, use_context, use_effect, use_history, use_id, use_imperative_handle,
+        use_machine, use_reference, use_state, use_worker, UseAsync, UseContext, UseEffect,
+        UseHistory, UseImperativeHandle, UseMachine, UseReference, UseWorker,
+    };
+    pub use polyhorn_ui::hooks::*;
+}
+
+use raw::Platform;
+
+/// Polyhorn core children type that is specialized for the HTML platform.
+pub type Children = polyhorn_core::Children<Platform>;
+
+/// Polyhorn core element type that is specialized for the HTML platform.
+pub type Element = polyhorn_core::Element<Platform>;
+
+/// Polyhorn core instance type that is specialized for the HTML platform.
+pub type Instance = polyhorn_core::Instance<Platform>;
+
+/// Polyhorn core manager type that is specialized for the HTML platform.
+pub type Manager<'a> = polyhorn_core::Manager<'a, Platform>;
+
+/// Polyhorn core weak type that is specialized for the HTML platform.
+pub type Weak = polyhorn_core::Weak<Platform>;
+
+/// Polyhorn core weak link type that is specialized for the HTML platform.
+pub type WeakLink<'a> = polyhorn_core::WeakLink<'a, Platform>;
+
+/// Polyhorn core weak reference type that is specialized for the HTML
+/// platform.
+pub type WeakReference<T> = polyhorn_core::WeakReference<Platform, T>;
+
+/// Polyhorn core weak state type that is specialized for the HTML platform.
+pub type WeakState<T> = polyhorn_core::WeakState<Platform, T>;
+
+pub use raw::Component;
@@ -0,0 +1,12 @@
+//! This is the Polyhorn for HTML prelude. It includes every type and function
+//! of Polyhorn UI with the exception that it defines its own Component, Element
+//! and Manager that are specialized for the HTML platform.
+
+pub use polyhorn_ui::prelude::*;
+
+pub use polyhorn_macros::poly;
+pub use polyhorn_ui::components::*;
+pub use polyhorn_ui_macros::{asset, style};
+
+pub use super::{Component, Element, Manager};
+pub use crate::components::View;
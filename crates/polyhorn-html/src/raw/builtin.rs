@@ -0,0 +1,154 @@
+use polyhorn_ui::components::ScrollDirection;
+use polyhorn_ui::styles::{ImageViewStyle, ScrollableViewStyle, ScrollbarColor, ViewStyle};
+use polyhorn_ui::text::AttributedText;
+use std::any::Any;
+
+use super::css::string;
+use super::node::escape;
+use super::{Declarations, Node, Platform, ToCss};
+
+/// Returns the markup of the runs of the given text. Text is escaped and runs
+/// with a style of their own are wrapped in a span.
+fn markup<L>(text: &AttributedText<L>) -> String {
+    text.runs()
+        .iter()
+        .map(|run| {
+            let mut css = Declarations::new();
+            run.style.to_css(&mut css);
+
+            if css.is_empty() {
+                escape(&run.text)
+            } else {
+                format!(
+                    "<span style=\"{}\">{}</span>",
+                    escape(&css.to_string()),
+                    escape(&run.text)
+                )
+            }
+        })
+        .collect()
+}
+
+#[derive(Clone, Debug)]
+pub enum Builtin {
+    /// Image with an optional URL. Images without a URL (i.e. placeholders)
+    /// are drawn as an empty view.
+    Image(ImageViewStyle, Option<String>),
+
+    /// Scroll container that clips its contents in the given direction.
+    Scrollable(ScrollableViewStyle, ScrollDirection),
+
+    /// Contents of a scroll container, which can grow beyond the bounds of
+    /// the scroll container in the given direction.
+    ScrollContent(ViewStyle, ScrollDirection),
+
+    /// Text that consists of the given runs.
+    Text(AttributedText<()>),
+
+    View(ViewStyle),
+}
+
+impl polyhorn_core::Builtin<Platform> for Builtin {
+    fn instantiate(&self, _parent: &mut Node, environment: &mut ()) -> Node {
+        let mut node = match self {
+            Builtin::Text(..) => Node::new("span"),
+            _ => Node::new("div"),
+        };
+
+        if let Builtin::Image(..) = self {
+            node.set_attribute("role", "img");
+        }
+
+        self.update(&mut node, environment);
+        node
+    }
+
+    fn update(&self, node: &mut Node, _environment: &mut ()) {
+        let mut css = Declarations::new();
+
+        match self {
+            Builtin::Image(style, url) => {
+                style.view.to_css(&mut css);
+                style.image.to_css(&mut css);
+
+                if let Some(url) = url {
+                    css.push(
+                        match style.image.tint_color {
+                            Some(_) => "mask-image",
+                            None => "background-image",
+                        },
+                        format!("url({})", string(url)),
+                    );
+                }
+            }
+            Builtin::Scrollable(style, direction) => {
+                style.view.to_css(&mut css);
+
+                let (x, y) = match direction {
+                    ScrollDirection::Both => ("auto", "auto"),
+                    ScrollDirection::Horizontal => ("auto", "hidden"),
+                    ScrollDirection::Vertical => ("hidden", "auto"),
+                };
+
+                css.push("overflow-x", x);
+                css.push("overflow-y", y);
+
+                // The color scheme determines the color of scroll bars: a
+                // light color scheme uses dark scroll bars and vice versa.
+                match style.scrollable.scrollbar_color {
+                    ScrollbarColor::Auto => {}
+                    ScrollbarColor::Dark => css.push("color-scheme", "light"),
+                    ScrollbarColor::Light => css.push("color-scheme", "dark"),
+                }
+            }
+            Builtin::ScrollContent(style, direction) => {
+                style.to_css(&mut css);
+
+                if *direction != ScrollDirection::Vertical {
+                    css.push("width", "max-content");
+                }
+            }
+            Builtin::Text(text) => {
+                text.to_css(&mut css);
+                css.push("white-space", "pre-wrap");
+
+                node.set_markup(markup(text));
+            }
+            Builtin::View(style) => style.to_css(&mut css),
+        }
+
+        node.set_style(css);
+    }
+
+    fn as_any(&self) -> Option<&dyn Any> {
+        Some(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use polyhorn_ui::font::FontStyle;
+    use polyhorn_ui::styles::{Inherited, TextStyle};
+    use polyhorn_ui::text::AttributedTextBuilder;
+
+    use super::markup;
+
+    #[test]
+    fn test_markup() {
+        let mut builder = AttributedTextBuilder::new(&TextStyle::default(), &Default::default());
+        builder.push_str("1 < 2 ");
+        builder.push_style(
+            &TextStyle {
+                font_style: Inherited::Specified(FontStyle::Italic),
+                ..Default::default()
+            },
+            &Default::default(),
+        );
+        builder.push_str("& 3");
+
+        assert_eq!(
+            markup(&builder.build()),
+            "1 &lt; 2 <span style=\"font-style:italic\">&amp; 3</span>"
+        );
+    }
+}
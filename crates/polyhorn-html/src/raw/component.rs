@@ -0,0 +1,75 @@
+use as_any::AsAny;
+use polyhorn_core::PropsDiff;
+use std::any::Any;
+use std::rc::Rc;
+
+use super::Platform;
+use crate::{Element, Manager};
+
+/// Platform-specific component trait.
+pub trait Component: AsAny {
+    /// Render function that must be implemented by components.
+    fn render(&self, manager: &mut Manager) -> Element;
+
+    /// Returns the name of this component that is used in render diagnostics.
+    fn type_name(&self) -> &'static str {
+        std::any::type_name::<Self>()
+    }
+
+    /// Compares the props of this component with the props of its previous
+    /// render. Components whose props implement `Debug` and / or `PartialEq`
+    /// can implement this with `PropsDiff::compare` to show up with the props
+    /// that changed in render diagnostics.
+    fn diff_props(&self, _previous: &dyn Any) -> Option<PropsDiff> {
+        None
+    }
+}
+
+/// Opaque reference counted wrapper around a component.
+#[derive(Clone)]
+pub struct OpaqueComponent(Rc<dyn Component>);
+
+impl AsRef<dyn Component> for OpaqueComponent {
+    fn as_ref(&self) -> &dyn Component {
+        self.0.as_ref()
+    }
+}
+
+/// This is a little bit of machinery that is necessary until we have proper
+/// trait aliases in Rust. Ideally, we would be able to alias
+/// `polyhorn_html::Component` to
+/// `polyhorn_core::Component<polyhorn_html::Platform>`, but that's not yet
+/// possible.
+mod machinery {
+    use super::{Component, Element, Manager, OpaqueComponent, Platform, PropsDiff, Rc};
+
+    impl polyhorn_core::Component<Platform> for OpaqueComponent {
+        fn render(&self, manager: &mut Manager) -> Element {
+            self.0.render(manager)
+        }
+
+        fn downcast_ref<T>(&self) -> Option<&T>
+        where
+            T: 'static,
+        {
+            self.0.as_ref().as_any().downcast_ref::<T>()
+        }
+
+        fn type_name(&self) -> &'static str {
+            Component::type_name(self.0.as_ref())
+        }
+
+        fn diff_props(&self, previous: &Self) -> Option<PropsDiff> {
+            self.0.diff_props(previous.0.as_ref().as_any())
+        }
+    }
+
+    impl<T> From<T> for OpaqueComponent
+    where
+        T: Component + 'static,
+    {
+        fn from(value: T) -> Self {
+            OpaqueComponent(Rc::new(value))
+        }
+    }
+}
@@ -0,0 +1,91 @@
+use polyhorn_core::{Command, Composition};
+use serde::{Deserialize, Serialize};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use super::{Node, Platform};
+
+/// Concrete implementation of a compositor that is responsible for adding and
+/// removing HTML nodes. Command buffers are committed synchronously on the
+/// thread that renders the elements.
+#[derive(Clone, Default)]
+pub struct Compositor {
+    buffer: Rc<RefCell<Composition<Platform>>>,
+    counter: Rc<RefCell<usize>>,
+}
+
+impl Compositor {
+    /// Returns a new compositor without any containers.
+    pub fn new() -> Compositor {
+        Default::default()
+    }
+
+    fn next_id(&self) -> ContainerID {
+        let mut counter = self.counter.borrow_mut();
+        let id = ContainerID(*counter);
+        *counter += 1;
+        id
+    }
+
+    pub(crate) fn track(&mut self, container: Node) -> ContainerID {
+        let id = self.next_id();
+        self.buffer.borrow_mut().insert(id, container);
+        id
+    }
+}
+
+impl polyhorn_core::Compositor<Platform> for Compositor {
+    fn buffer(&self) -> CommandBuffer {
+        CommandBuffer {
+            compositor: self.clone(),
+            commands: vec![],
+        }
+    }
+}
+
+/// An opaque ID for containers that can be shared between threads.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub struct ContainerID(usize);
+
+/// Concrete implementation of a command buffer that can buffer commands before
+/// committing them to the compositor.
+pub struct CommandBuffer {
+    compositor: Compositor,
+    commands: Vec<Command<Platform>>,
+}
+
+impl polyhorn_core::CommandBuffer<Platform> for CommandBuffer {
+    fn mount<F>(&mut self, parent_id: ContainerID, initializer: F) -> ContainerID
+    where
+        F: FnOnce(&mut Node, &mut ()) -> Node + Send + 'static,
+    {
+        let id = self.compositor.next_id();
+        self.commands
+            .push(Command::Mount(id, parent_id, Box::new(initializer)));
+        id
+    }
+
+    fn mutate<F>(&mut self, ids: &[ContainerID], mutator: F)
+    where
+        F: FnOnce(&mut [&mut Node], &mut ()) + Send + 'static,
+    {
+        self.commands
+            .push(Command::Mutate(ids.to_owned(), Box::new(mutator)));
+    }
+
+    fn unmount(&mut self, id: ContainerID) {
+        self.commands.push(Command::Unmount(id));
+    }
+
+    fn layout(&mut self) {
+        // Layout is left to the browser.
+    }
+
+    fn commit(self) {
+        let mut buffer = self.compositor.buffer.borrow_mut();
+
+        for command in self.commands {
+            buffer.process(&mut (), command);
+        }
+    }
+}
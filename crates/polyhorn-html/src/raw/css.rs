@@ -0,0 +1,943 @@
+use polyhorn_ui::color::Color;
+use polyhorn_ui::font::{FontFamily, FontSize, FontStyle, FontWeight, GenericFontFamily};
+use polyhorn_ui::geometry::{ByEdge, Dimension};
+use polyhorn_ui::layout::{LayoutAxisX, LayoutDirection};
+use polyhorn_ui::styles::{
+    Align, BorderStyle, BoxShadow, Corner, EllipsisMode, FlexDirection, FlexWrap, Gradient,
+    GradientDirection, ImageStyle, Inherited, Justify, LineHeight, ObjectFit, Overflow, Position,
+    RadialGradientShape, RadialGradientSize, TextAlign, TextDecorationStyle, TextStyle,
+    TextTransform, Transform, ViewStyle, Visibility,
+};
+use polyhorn_ui::text::{AttributedText, ResolvedTextStyle};
+use std::fmt::{Display, Formatter, Result};
+
+/// Ordered list of CSS declarations that is written to the `style` attribute
+/// of an HTML element.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Declarations(Vec<(String, String)>);
+
+impl Declarations {
+    /// Returns a new empty list of declarations.
+    pub fn new() -> Declarations {
+        Default::default()
+    }
+
+    /// Appends a declaration with the given property and value.
+    pub fn push(&mut self, property: &str, value: impl Display) {
+        self.0.push((property.to_owned(), value.to_string()));
+    }
+
+    /// Returns the value of the last declaration of the given property.
+    pub fn get(&self, property: &str) -> Option<&str> {
+        self.0
+            .iter()
+            .rev()
+            .find(|(name, _)| name == property)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Returns a boolean that indicates if this list is empty.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl Display for Declarations {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        for (index, (property, value)) in self.0.iter().enumerate() {
+            if index > 0 {
+                f.write_str(";")?;
+            }
+
+            write!(f, "{}:{}", property, value)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Implemented by styles that can be expressed as inline CSS.
+pub trait ToCss {
+    /// This function should append the declarations that correspond to this
+    /// style to the given list.
+    fn to_css(&self, css: &mut Declarations);
+}
+
+/// Formats the given number with at most 4 decimals (and without a trailing
+/// `.0`).
+pub fn number(value: f32) -> String {
+    // Adding zero turns negative zero into positive zero.
+    ((value * 10000.0).round() / 10000.0 + 0.0).to_string()
+}
+
+/// Returns the CSS length that corresponds to the given dimension, or `None`
+/// if the dimension is undefined or auto.
+pub fn length(dimension: Dimension<f32>) -> Option<String> {
+    match dimension {
+        Dimension::Points(points) => Some(format!("{}px", number(points))),
+        Dimension::Percentage(percentage) => Some(format!("{}%", number(percentage * 100.0))),
+        Dimension::Undefined | Dimension::Auto => None,
+    }
+}
+
+/// Returns the CSS color that corresponds to the given color (in sRGB).
+pub fn color(color: Color) -> String {
+    let srgb = color.to_srgb();
+    let channel = |value: f32| (value.clamp(0.0, 1.0) * 255.0).round() as u8;
+    let (red, green, blue) = (channel(srgb.red), channel(srgb.green), channel(srgb.blue));
+
+    if color.alpha() >= 1.0 {
+        format!("#{:02x}{:02x}{:02x}", red, green, blue)
+    } else {
+        format!("rgba({},{},{},{})", red, green, blue, number(color.alpha()))
+    }
+}
+
+/// Returns a quoted CSS string.
+pub fn string(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Returns the name of each side in the order of the `margin` shorthand
+/// (i.e. top, right, bottom and left) along with its value. Direction
+/// dependent edges are mapped onto the logical `inline-end` and
+/// `inline-start` sides.
+fn sides<T>(edges: &ByEdge<T>) -> [(&'static str, T); 4]
+where
+    T: Copy,
+{
+    let (left, right) = match edges.horizontal {
+        LayoutAxisX::DirectionDependent { leading, trailing } => {
+            (("inline-start", leading), ("inline-end", trailing))
+        }
+        LayoutAxisX::DirectionIndependent { left, right } => (("left", left), ("right", right)),
+    };
+
+    [
+        ("top", edges.vertical.top),
+        right,
+        ("bottom", edges.vertical.bottom),
+        left,
+    ]
+}
+
+/// Returns the CSS shadow that corresponds to the given box shadow.
+fn box_shadow(shadow: &BoxShadow) -> String {
+    format!(
+        "{}{}px {}px {}px {}px {}",
+        if shadow.inset { "inset " } else { "" },
+        number(shadow.offset_x),
+        number(shadow.offset_y),
+        number(shadow.blur_radius),
+        number(shadow.spread_radius),
+        color(shadow.color)
+    )
+}
+
+/// Returns the CSS image function that corresponds to the given gradient.
+fn gradient(gradient: &Gradient) -> String {
+    let mut arguments = vec![];
+
+    match gradient {
+        Gradient::Linear(linear) => arguments.push(match linear.direction {
+            GradientDirection::Angle(angle) => format!("{}deg", number(angle.to_degrees())),
+            GradientDirection::Corner(corner) => match corner {
+                Corner::TopLeft => "to top left".to_owned(),
+                Corner::TopRight => "to top right".to_owned(),
+                Corner::BottomRight => "to bottom right".to_owned(),
+                Corner::BottomLeft => "to bottom left".to_owned(),
+            },
+        }),
+        Gradient::Radial(radial) => {
+            let shape = match radial.shape {
+                RadialGradientShape::Circle => "circle",
+                RadialGradientShape::Ellipse => "ellipse",
+            };
+
+            let size = match radial.size {
+                RadialGradientSize::ClosestSide => "closest-side".to_owned(),
+                RadialGradientSize::ClosestCorner => "closest-corner".to_owned(),
+                RadialGradientSize::FarthestSide => "farthest-side".to_owned(),
+                RadialGradientSize::FarthestCorner => "farthest-corner".to_owned(),
+                RadialGradientSize::Radii(x, _) if radial.shape == RadialGradientShape::Circle => {
+                    length(x).unwrap_or_default()
+                }
+                RadialGradientSize::Radii(x, y) => format!(
+                    "{} {}",
+                    length(x).unwrap_or_default(),
+                    length(y).unwrap_or_default()
+                ),
+            };
+
+            arguments.push(format!(
+                "{} {} at {} {}",
+                shape,
+                size,
+                length(radial.position.x).unwrap_or_else(|| "50%".to_owned()),
+                length(radial.position.y).unwrap_or_else(|| "50%".to_owned())
+            ));
+        }
+    }
+
+    arguments.extend(
+        gradient
+            .stops()
+            .iter()
+            .map(|stop| match stop.position.and_then(length) {
+                Some(position) => format!("{} {}", color(stop.color), position),
+                None => color(stop.color),
+            }),
+    );
+
+    match gradient {
+        Gradient::Linear(_) => format!("linear-gradient({})", arguments.join(",")),
+        Gradient::Radial(_) => format!("radial-gradient({})", arguments.join(",")),
+    }
+}
+
+fn transform(transform: &Transform<f32>) -> String {
+    let mut functions = vec![];
+
+    if transform.matrix != Default::default() {
+        let entries = transform
+            .matrix
+            .columns
+            .iter()
+            .flatten()
+            .map(|&entry| number(entry))
+            .collect::<Vec<_>>();
+
+        functions.push(format!("matrix3d({})", entries.join(",")));
+    }
+
+    // The relative translation is applied after the matrix, which corresponds
+    // to the function that comes after it in CSS.
+    let (x, y) = transform.relative_translation;
+
+    if x != 0.0 || y != 0.0 {
+        functions.push(format!(
+            "translate({}%,{}%)",
+            number(x * 100.0),
+            number(y * 100.0)
+        ));
+    }
+
+    functions.join(" ")
+}
+
+impl ToCss for ViewStyle {
+    fn to_css(&self, css: &mut Declarations) {
+        // Every view is a flexbox container that uses the border-box model.
+        match self.display {
+            polyhorn_ui::styles::Display::None => css.push("display", "none"),
+            _ => css.push("display", "flex"),
+        }
+
+        css.push("box-sizing", "border-box");
+
+        match self.position {
+            Position::Absolute(absolute) => {
+                css.push("position", "absolute");
+
+                for (side, distance) in sides(&absolute.distances).iter() {
+                    if let Some(distance) = length(*distance) {
+                        if side.starts_with("inline") {
+                            css.push(&format!("inset-{}", side), distance);
+                        } else {
+                            css.push(side, distance);
+                        }
+                    }
+                }
+            }
+            Position::Relative(relative) => {
+                // Absolutely positioned children are positioned relative to
+                // their parent.
+                css.push("position", "relative");
+
+                if let Some(basis) = length(relative.flex_basis) {
+                    css.push("flex-basis", basis);
+                }
+
+                if relative.flex_grow != 0.0 {
+                    css.push("flex-grow", number(relative.flex_grow));
+                }
+
+                if relative.flex_shrink != 1.0 {
+                    css.push("flex-shrink", number(relative.flex_shrink));
+                }
+
+                match relative.align_self {
+                    None => {}
+                    Some(Align::Stretch) => css.push("align-self", "stretch"),
+                    Some(Align::Center) => css.push("align-self", "center"),
+                    Some(Align::FlexEnd) => css.push("align-self", "flex-end"),
+                    Some(Align::FlexStart | Align::SpaceBetween | Align::SpaceAround) => {
+                        css.push("align-self", "flex-start")
+                    }
+                }
+            }
+        }
+
+        if let Inherited::Specified(direction) = self.direction {
+            css.push(
+                "direction",
+                match direction {
+                    LayoutDirection::LTR => "ltr",
+                    LayoutDirection::RTL => "rtl",
+                },
+            );
+        }
+
+        if let Some(width) = length(self.size.width) {
+            css.push("width", width);
+        }
+
+        if let Some(height) = length(self.size.height) {
+            css.push("height", height);
+        }
+
+        // In CSS, the automatic minimum size of a flex item is its content
+        // size whereas in Polyhorn, it is zero.
+        css.push(
+            "min-width",
+            length(self.min_size.width).unwrap_or_else(|| "0".to_owned()),
+        );
+
+        css.push(
+            "min-height",
+            length(self.min_size.height).unwrap_or_else(|| "0".to_owned()),
+        );
+
+        if let Some(width) = length(self.max_size.width) {
+            css.push("max-width", width);
+        }
+
+        if let Some(height) = length(self.max_size.height) {
+            css.push("max-height", height);
+        }
+
+        if let Some(aspect_ratio) = self.aspect_ratio {
+            css.push("aspect-ratio", number(aspect_ratio));
+        }
+
+        css.push(
+            "flex-direction",
+            match self.flex_direction {
+                FlexDirection::Column => "column",
+                FlexDirection::ColumnReverse => "column-reverse",
+                FlexDirection::Row => "row",
+                FlexDirection::RowReverse => "row-reverse",
+            },
+        );
+
+        match self.align_items {
+            Align::Stretch => {}
+            Align::FlexStart => css.push("align-items", "flex-start"),
+            Align::Center => css.push("align-items", "center"),
+            Align::FlexEnd => css.push("align-items", "flex-end"),
+            Align::SpaceBetween | Align::SpaceAround => css.push("align-items", "flex-start"),
+        }
+
+        match self.justify_content {
+            Justify::FlexStart => {}
+            Justify::Center => css.push("justify-content", "center"),
+            Justify::FlexEnd => css.push("justify-content", "flex-end"),
+            Justify::SpaceBetween => css.push("justify-content", "space-between"),
+            Justify::SpaceAround => css.push("justify-content", "space-around"),
+            Justify::SpaceEvenly => css.push("justify-content", "space-evenly"),
+        }
+
+        match self.flex_wrap {
+            FlexWrap::NoWrap => {}
+            FlexWrap::Wrap => css.push("flex-wrap", "wrap"),
+            FlexWrap::WrapReverse => css.push("flex-wrap", "wrap-reverse"),
+        }
+
+        // The lines of a container are only aligned if it wraps.
+        if self.flex_wrap != FlexWrap::NoWrap {
+            css.push(
+                "align-content",
+                match self.align_content {
+                    Align::FlexStart => "flex-start",
+                    Align::Center => "center",
+                    Align::FlexEnd => "flex-end",
+                    Align::Stretch => "stretch",
+                    Align::SpaceBetween => "space-between",
+                    Align::SpaceAround => "space-around",
+                },
+            );
+        }
+
+        if let Some(gap) = length(self.row_gap) {
+            css.push("row-gap", gap);
+        }
+
+        if let Some(gap) = length(self.column_gap) {
+            css.push("column-gap", gap);
+        }
+
+        for (side, margin) in sides(&self.margin).iter() {
+            let margin = match margin {
+                Dimension::Auto => Some("auto".to_owned()),
+                &margin => length(margin),
+            };
+
+            if let Some(margin) = margin {
+                css.push(&format!("margin-{}", side), margin);
+            }
+        }
+
+        for (side, padding) in sides(&self.padding).iter() {
+            if let Some(padding) = length(*padding) {
+                css.push(&format!("padding-{}", side), padding);
+            }
+        }
+
+        for (side, border) in sides(&self.border).iter() {
+            // CSS does not support relative border widths.
+            let width = match border.width {
+                Dimension::Points(width) if width > 0.0 => width,
+                _ => continue,
+            };
+
+            css.push(
+                &format!("border-{}-width", side),
+                format!("{}px", number(width)),
+            );
+
+            css.push(
+                &format!("border-{}-style", side),
+                match border.style {
+                    BorderStyle::Solid => "solid",
+                    BorderStyle::Dashed => "dashed",
+                    BorderStyle::Dotted => "dotted",
+                },
+            );
+
+            css.push(&format!("border-{}-color", side), color(border.color));
+        }
+
+        if self.background_color.alpha() > 0.0 {
+            css.push("background-color", color(self.background_color));
+        }
+
+        if let Some(background_image) = &self.background_image {
+            css.push("background-image", gradient(background_image));
+        }
+
+        if !self.box_shadow.is_empty() {
+            let shadows = self.box_shadow.iter().map(box_shadow).collect::<Vec<_>>();
+            css.push("box-shadow", shadows.join(","));
+        }
+
+        let corners = [
+            match self.border_radius.top {
+                LayoutAxisX::DirectionDependent { leading, trailing } => {
+                    [("start-start", leading), ("start-end", trailing)]
+                }
+                LayoutAxisX::DirectionIndependent { left, right } => {
+                    [("top-left", left), ("top-right", right)]
+                }
+            },
+            match self.border_radius.bottom {
+                LayoutAxisX::DirectionDependent { leading, trailing } => {
+                    [("end-start", leading), ("end-end", trailing)]
+                }
+                LayoutAxisX::DirectionIndependent { left, right } => {
+                    [("bottom-left", left), ("bottom-right", right)]
+                }
+            },
+        ];
+
+        for (corner, radius) in corners.iter().flatten() {
+            match (length(radius.horizontal), length(radius.vertical)) {
+                (None, None) => {}
+                (horizontal, vertical) => css.push(
+                    &format!("border-{}-radius", corner),
+                    format!(
+                        "{} {}",
+                        horizontal.unwrap_or_else(|| "0".to_owned()),
+                        vertical.unwrap_or_else(|| "0".to_owned())
+                    ),
+                ),
+            }
+        }
+
+        if self.opacity != 1.0 {
+            css.push("opacity", number(self.opacity));
+        }
+
+        let transforms = self
+            .transform
+            .iter()
+            .map(transform)
+            .filter(|transform| !transform.is_empty())
+            .collect::<Vec<_>>();
+
+        if !transforms.is_empty() {
+            css.push("transform", transforms.join(" "));
+        }
+
+        match self.overflow {
+            Overflow::Visible => {}
+            Overflow::Hidden => css.push("overflow", "hidden"),
+            Overflow::Scroll => css.push("overflow", "scroll"),
+        }
+
+        if self.visibility == Visibility::Hidden {
+            css.push("visibility", "hidden");
+        }
+    }
+}
+
+impl ToCss for TextStyle {
+    fn to_css(&self, css: &mut Declarations) {
+        // Inherited properties are omitted so that they are inherited from the
+        // parent element.
+        if let Inherited::Specified(value) = self.color {
+            css.push("color", color(value));
+        }
+
+        if let Inherited::Specified(family) = self.font_family {
+            css.push(
+                "font-family",
+                match family {
+                    FontFamily::Generic(GenericFontFamily::Serif) => "serif".to_owned(),
+                    FontFamily::Generic(GenericFontFamily::SansSerif) => "sans-serif".to_owned(),
+                    FontFamily::Generic(GenericFontFamily::Monospace) => "monospace".to_owned(),
+                    FontFamily::Named(name) => string(name),
+                },
+            );
+        }
+
+        if let Inherited::Specified(weight) = self.font_weight {
+            css.push(
+                "font-weight",
+                match weight {
+                    FontWeight::Number(weight) => number((weight * 1000.0).clamp(1.0, 1000.0)),
+                    FontWeight::Thin => "100".to_owned(),
+                    FontWeight::ExtraLight => "200".to_owned(),
+                    FontWeight::Light => "300".to_owned(),
+                    FontWeight::Normal => "400".to_owned(),
+                    FontWeight::Medium => "500".to_owned(),
+                    FontWeight::SemiBold => "600".to_owned(),
+                    FontWeight::Bold => "700".to_owned(),
+                    FontWeight::ExtraBold => "800".to_owned(),
+                    FontWeight::Black => "900".to_owned(),
+                },
+            );
+        }
+
+        if let Inherited::Specified(style) = self.font_style {
+            css.push(
+                "font-style",
+                match style {
+                    FontStyle::Normal => "normal",
+                    FontStyle::Oblique => "oblique",
+                    FontStyle::Italic => "italic",
+                },
+            );
+        }
+
+        if let Inherited::Specified(size) = self.font_size {
+            let size = match size {
+                FontSize::ExtraExtraSmall => Some("xx-small".to_owned()),
+                FontSize::ExtraSmall => Some("x-small".to_owned()),
+                FontSize::Small => Some("small".to_owned()),
+                FontSize::Medium => Some("medium".to_owned()),
+                FontSize::Large => Some("large".to_owned()),
+                FontSize::ExtraLarge => Some("x-large".to_owned()),
+                FontSize::ExtraExtraLarge => Some("xx-large".to_owned()),
+                FontSize::ExtraExtraExtraLarge => Some("xxx-large".to_owned()),
+                FontSize::Dimension(dimension) => length(dimension),
+            };
+
+            if let Some(size) = size {
+                css.push("font-size", size);
+            }
+        }
+
+        if let Inherited::Specified(align) = self.text_align {
+            css.push(
+                "text-align",
+                match align {
+                    TextAlign::Left => "left",
+                    TextAlign::Center => "center",
+                    TextAlign::Right => "right",
+                    TextAlign::Justify => "justify",
+                },
+            );
+        }
+
+        if let Inherited::Specified(line_height) = self.line_height {
+            let line_height = match line_height {
+                LineHeight::Normal => Some("normal".to_owned()),
+                LineHeight::Number(value) => Some(number(value)),
+                LineHeight::Dimension(dimension) => length(dimension),
+            };
+
+            if let Some(line_height) = line_height {
+                css.push("line-height", line_height);
+            }
+        }
+
+        if let Inherited::Specified(spacing) = self.letter_spacing {
+            css.push("letter-spacing", format!("{}px", number(spacing)));
+        }
+
+        if let Inherited::Specified(decoration) = self.text_decoration {
+            let mut value = match (decoration.underline, decoration.line_through) {
+                (false, false) => "none".to_owned(),
+                (true, false) => "underline".to_owned(),
+                (false, true) => "line-through".to_owned(),
+                (true, true) => "underline line-through".to_owned(),
+            };
+
+            if !decoration.is_none() {
+                value.push_str(match decoration.style {
+                    TextDecorationStyle::Solid => " solid",
+                    TextDecorationStyle::Double => " double",
+                    TextDecorationStyle::Dotted => " dotted",
+                    TextDecorationStyle::Dashed => " dashed",
+                    TextDecorationStyle::Wavy => " wavy",
+                });
+
+                if let Some(value_color) = decoration.color {
+                    value.push(' ');
+                    value.push_str(&color(value_color));
+                }
+            }
+
+            css.push("text-decoration", value);
+        }
+
+        if let Inherited::Specified(transform) = self.text_transform {
+            css.push(
+                "text-transform",
+                match transform {
+                    TextTransform::None => "none",
+                    TextTransform::Uppercase => "uppercase",
+                    TextTransform::Lowercase => "lowercase",
+                    TextTransform::Capitalize => "capitalize",
+                },
+            );
+        }
+
+        // Browsers can only clamp block-level boxes, and only truncate the end
+        // of the last line.
+        if let Inherited::Specified(lines) = self.number_of_lines {
+            if lines != 0 {
+                css.push("display", "-webkit-box");
+                css.push("-webkit-box-orient", "vertical");
+                css.push("-webkit-line-clamp", lines);
+                css.push("overflow", "hidden");
+            }
+        }
+
+        if let Inherited::Specified(mode) = self.ellipsis_mode {
+            css.push(
+                "text-overflow",
+                match mode {
+                    EllipsisMode::Clip => "clip",
+                    EllipsisMode::Head | EllipsisMode::Middle | EllipsisMode::Tail => "ellipsis",
+                },
+            );
+        }
+
+        if let Inherited::Specified(shadow) = self.text_shadow {
+            css.push(
+                "text-shadow",
+                match shadow {
+                    Some(shadow) => {
+                        let mut value = format!(
+                            "{}px {}px {}px",
+                            number(shadow.offset_x),
+                            number(shadow.offset_y),
+                            number(shadow.blur_radius)
+                        );
+
+                        if let Some(shadow_color) = shadow.color {
+                            value.push(' ');
+                            value.push_str(&color(shadow_color));
+                        }
+
+                        value
+                    }
+                    None => "none".to_owned(),
+                },
+            );
+        }
+    }
+}
+
+/// Returns the given value if it differs from the given default, or inherits
+/// it otherwise.
+fn specified<T>(value: T, default: T) -> Inherited<T>
+where
+    T: PartialEq,
+{
+    match value == default {
+        true => Inherited::Inherited,
+        false => Inherited::Specified(value),
+    }
+}
+
+impl ToCss for ResolvedTextStyle {
+    fn to_css(&self, css: &mut Declarations) {
+        // Properties that have their default value are omitted so that they
+        // are inherited from the document. The text transform has already been
+        // applied to the text of each run and the remaining properties apply
+        // to the paragraph as a whole (see `AttributedText`).
+        let default = ResolvedTextStyle::default();
+
+        TextStyle {
+            color: specified(self.color, default.color),
+            font_family: specified(self.font_family, default.font_family),
+            font_weight: specified(self.font_weight, default.font_weight),
+            font_style: specified(self.font_style, default.font_style),
+            font_size: specified(
+                FontSize::Dimension(Dimension::Points(self.font_size)),
+                FontSize::Dimension(Dimension::Points(default.font_size)),
+            ),
+            line_height: specified(self.line_height, default.line_height),
+            letter_spacing: specified(self.letter_spacing, default.letter_spacing),
+            text_decoration: specified(self.text_decoration, default.text_decoration),
+            text_shadow: specified(self.text_shadow, default.text_shadow),
+            ..Default::default()
+        }
+        .to_css(css)
+    }
+}
+
+impl<L> ToCss for AttributedText<L> {
+    fn to_css(&self, css: &mut Declarations) {
+        let default = ResolvedTextStyle::default();
+
+        TextStyle {
+            text_align: specified(self.text_align(), default.text_align),
+            number_of_lines: specified(self.number_of_lines(), default.number_of_lines),
+            ellipsis_mode: specified(self.ellipsis_mode(), default.ellipsis_mode),
+            ..Default::default()
+        }
+        .to_css(css)
+    }
+}
+
+impl ToCss for ImageStyle {
+    fn to_css(&self, css: &mut Declarations) {
+        let size = match self.object_fit {
+            ObjectFit::Fill => "100% 100%",
+            ObjectFit::None => "auto",
+            ObjectFit::Contain => "contain",
+            ObjectFit::Cover => "cover",
+        };
+
+        // A tinted image is drawn as a mask over its tint color. The image
+        // itself is set by the image builtin (see `background-image` and
+        // `mask-image`).
+        let prefix = match self.tint_color {
+            Some(tint_color) => {
+                css.push("background-color", color(tint_color));
+                "mask"
+            }
+            None => "background",
+        };
+
+        css.push(&format!("{}-size", prefix), size);
+        css.push(&format!("{}-position", prefix), "center");
+        css.push(&format!("{}-repeat", prefix), "no-repeat");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use polyhorn_ui::color::Color;
+    use polyhorn_ui::font::{FontFamily, FontWeight};
+    use polyhorn_ui::geometry::{ByDirection, Dimension};
+    use polyhorn_ui::layout::LayoutAxisX;
+    use polyhorn_ui::physics::Angle;
+    use polyhorn_ui::styles::{
+        Absolute, Align, Border, BoxShadow, ColorStop, FlexDirection, FlexWrap, Gradient,
+        GradientDirection, Inherited, LineHeight, LinearGradient, Position, RadialGradient,
+        RadialGradientShape, RadialGradientSize, Relative, TextDecoration, TextDecorationStyle,
+        TextShadow, TextStyle, Transform, ViewStyle,
+    };
+
+    use super::{Declarations, ToCss};
+
+    fn css<T>(style: T) -> String
+    where
+        T: ToCss,
+    {
+        let mut css = Declarations::new();
+        style.to_css(&mut css);
+        css.to_string()
+    }
+
+    #[test]
+    fn test_view_style() {
+        assert_eq!(
+            css(ViewStyle::default()),
+            "display:flex;box-sizing:border-box;position:relative;min-width:0;min-height:0;flex-direction:column"
+        );
+
+        assert_eq!(
+            css(ViewStyle {
+                display: polyhorn_ui::styles::Display::None,
+                position: Position::Relative(Relative {
+                    align_self: Some(Align::Center),
+                    ..Default::default()
+                }),
+                aspect_ratio: Some(1.5),
+                flex_wrap: FlexWrap::Wrap,
+                row_gap: Dimension::Points(4.0),
+                ..Default::default()
+            }),
+            concat!(
+                "display:none;box-sizing:border-box;position:relative;align-self:center;",
+                "min-width:0;min-height:0;aspect-ratio:1.5;flex-direction:column;",
+                "flex-wrap:wrap;align-content:flex-start;row-gap:4px",
+            )
+        );
+
+        let mut style = ViewStyle {
+            position: Position::Absolute(Absolute::default()),
+            flex_direction: FlexDirection::Row,
+            background_color: Color::rgba(255, 0, 0, 0.5),
+            opacity: 0.25,
+            ..Default::default()
+        };
+
+        if let Position::Absolute(absolute) = &mut style.position {
+            absolute.distances.vertical.top = Dimension::Points(10.0);
+            absolute.distances.horizontal =
+                LayoutAxisX::dependent(Dimension::Percentage(0.5), Dimension::Undefined);
+        }
+
+        style.size.width = Dimension::Percentage(1.0);
+        style.margin.horizontal = LayoutAxisX::independent(Dimension::Auto, Dimension::Points(4.0));
+        style.border.vertical.bottom = Border {
+            width: Dimension::Points(1.0),
+            color: Color::rgb(0, 0, 255),
+            ..Default::default()
+        };
+        style.border_radius.top = LayoutAxisX::independent(
+            ByDirection::with_both(Dimension::Points(8.0)),
+            Default::default(),
+        );
+        style.transform[0] =
+            Transform::with_translation(Dimension::Percentage(-0.5), Dimension::Points(2.0), 0.0);
+
+        assert_eq!(
+            css(style),
+            concat!(
+                "display:flex;box-sizing:border-box;position:absolute;top:10px;",
+                "inset-inline-start:50%;width:100%;min-width:0;min-height:0;",
+                "flex-direction:row;margin-right:4px;margin-left:auto;",
+                "border-bottom-width:1px;border-bottom-style:solid;border-bottom-color:#0000ff;",
+                "background-color:rgba(255,0,0,0.5);border-top-left-radius:8px 8px;opacity:0.25;",
+                "transform:matrix3d(1,0,0,0,0,1,0,0,0,0,1,0,0,2,0,1) translate(-50%,0%)",
+            )
+        );
+    }
+
+    #[test]
+    fn test_view_style_decoration() {
+        let stops: &'static [ColorStop] = Box::leak(Box::new([
+            ColorStop {
+                color: Color::rgb(255, 0, 0),
+                position: None,
+            },
+            ColorStop {
+                color: Color::rgb(0, 0, 255),
+                position: Some(Dimension::Percentage(0.75)),
+            },
+        ]));
+
+        let shadows: &'static [BoxShadow] = Box::leak(Box::new([
+            BoxShadow {
+                offset_y: 2.0,
+                blur_radius: 4.0,
+                ..Default::default()
+            },
+            BoxShadow {
+                spread_radius: -1.0,
+                color: Color::rgba(255, 0, 0, 0.5),
+                inset: true,
+                ..Default::default()
+            },
+        ]));
+
+        assert_eq!(
+            css(ViewStyle {
+                background_image: Some(Gradient::Linear(LinearGradient {
+                    direction: GradientDirection::Angle(Angle::with_degrees(90.0)),
+                    stops,
+                })),
+                box_shadow: shadows,
+                ..Default::default()
+            }),
+            concat!(
+                "display:flex;box-sizing:border-box;position:relative;min-width:0;min-height:0;",
+                "flex-direction:column;background-image:linear-gradient(90deg,#ff0000,#0000ff 75%);",
+                "box-shadow:0px 2px 4px 0px #000000,inset 0px 0px 0px -1px rgba(255,0,0,0.5)",
+            )
+        );
+
+        assert_eq!(
+            css(ViewStyle {
+                background_image: Some(Gradient::Radial(RadialGradient {
+                    shape: RadialGradientShape::Circle,
+                    size: RadialGradientSize::Radii(
+                        Dimension::Points(10.0),
+                        Dimension::Points(10.0)
+                    ),
+                    stops,
+                    ..Default::default()
+                })),
+                ..Default::default()
+            })
+            .rsplit(';')
+            .next(),
+            Some("background-image:radial-gradient(circle 10px at 50% 50%,#ff0000,#0000ff 75%)")
+        );
+    }
+
+    #[test]
+    fn test_text_style() {
+        assert_eq!(css(TextStyle::default()), "");
+
+        assert_eq!(
+            css(TextStyle {
+                color: Inherited::Specified(Color::rgb(17, 34, 51)),
+                font_family: Inherited::Specified(FontFamily::Named("Helvetica \"Neue\"")),
+                font_weight: Inherited::Specified(FontWeight::Number(0.65)),
+                ..Default::default()
+            }),
+            "color:#112233;font-family:\"Helvetica \\\"Neue\\\"\";font-weight:650"
+        );
+
+        assert_eq!(
+            css(TextStyle {
+                line_height: Inherited::Specified(LineHeight::Number(1.5)),
+                text_decoration: Inherited::Specified(TextDecoration {
+                    underline: true,
+                    style: TextDecorationStyle::Wavy,
+                    color: Some(Color::rgb(255, 0, 0)),
+                    ..Default::default()
+                }),
+                number_of_lines: Inherited::Specified(2),
+                text_shadow: Inherited::Specified(Some(TextShadow {
+                    offset_x: 1.0,
+                    offset_y: -1.0,
+                    blur_radius: 2.0,
+                    color: None,
+                })),
+                ..Default::default()
+            }),
+            "line-height:1.5;text-decoration:underline wavy #ff0000;display:-webkit-box;\
+             -webkit-box-orient:vertical;-webkit-line-clamp:2;overflow:hidden;\
+             text-shadow:1px -1px 2px"
+        );
+    }
+}
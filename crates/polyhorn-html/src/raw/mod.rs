@@ -0,0 +1,17 @@
+//! Platform types that render Polyhorn builtins to HTML nodes.
+
+mod builtin;
+mod component;
+mod compositor;
+mod css;
+mod node;
+mod payload;
+mod platform;
+
+pub use builtin::Builtin;
+pub use component::{Component, OpaqueComponent};
+pub use compositor::{CommandBuffer, Compositor, ContainerID};
+pub use css::{Declarations, ToCss};
+pub use node::Node;
+pub use payload::Payload;
+pub use platform::Platform;
@@ -0,0 +1,211 @@
+use std::cell::RefCell;
+use std::fmt::{Display, Formatter, Result};
+use std::rc::{Rc, Weak};
+
+use super::{Declarations, Platform};
+
+struct Inner {
+    tag: &'static str,
+    attributes: Vec<(&'static str, String)>,
+    style: Declarations,
+    markup: String,
+    children: Vec<Node>,
+    parent: Weak<RefCell<Inner>>,
+}
+
+/// Concrete implementation of an HTML-specific container. A node is a
+/// reference counted handle to an HTML element: all clones of a node refer to
+/// the same element.
+#[derive(Clone)]
+pub struct Node(Rc<RefCell<Inner>>);
+
+impl Node {
+    /// Returns a new node for an element with the given tag name.
+    pub fn new(tag: &'static str) -> Node {
+        Node(Rc::new(RefCell::new(Inner {
+            tag,
+            attributes: vec![],
+            style: Declarations::new(),
+            markup: String::new(),
+            children: vec![],
+            parent: Weak::new(),
+        })))
+    }
+
+    /// Returns the tag name of this node.
+    pub fn tag(&self) -> &'static str {
+        self.0.borrow().tag
+    }
+
+    /// Sets the attribute with the given name to the given value, replacing
+    /// any previous value of that attribute.
+    pub fn set_attribute(&mut self, name: &'static str, value: &str) {
+        let mut inner = self.0.borrow_mut();
+
+        match inner.attributes.iter_mut().find(|(key, _)| *key == name) {
+            Some((_, existing)) => *existing = value.to_owned(),
+            None => inner.attributes.push((name, value.to_owned())),
+        }
+    }
+
+    /// Replaces the inline style of this node.
+    pub fn set_style(&mut self, style: Declarations) {
+        self.0.borrow_mut().style = style;
+    }
+
+    /// Replaces the markup that is written before the children of this node.
+    /// The given markup is not escaped.
+    pub fn set_markup(&mut self, markup: String) {
+        self.0.borrow_mut().markup = markup;
+    }
+
+    /// Returns the nodes that are currently mounted onto this node.
+    pub fn children(&self) -> Vec<Node> {
+        self.0.borrow().children.clone()
+    }
+
+    /// Returns the HTML of the contents of this node, excluding the node
+    /// itself.
+    pub fn inner_html(&self) -> String {
+        let inner = self.0.borrow();
+        let mut html = inner.markup.clone();
+
+        for child in &inner.children {
+            html += &child.to_string();
+        }
+
+        html
+    }
+}
+
+impl Display for Node {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        let inner = self.0.borrow();
+
+        write!(f, "<{}", inner.tag)?;
+
+        for (name, value) in &inner.attributes {
+            write!(f, " {}=\"{}\"", name, escape(value))?;
+        }
+
+        if !inner.style.is_empty() {
+            write!(f, " style=\"{}\"", escape(&inner.style.to_string()))?;
+        }
+
+        write!(f, ">{}", inner.markup)?;
+
+        for child in &inner.children {
+            write!(f, "{}", child)?;
+        }
+
+        write!(f, "</{}>", inner.tag)
+    }
+}
+
+impl polyhorn_core::Container<Platform> for Node {
+    fn mount(&mut self, child: &mut Node, _environment: &mut ()) {
+        child.0.borrow_mut().parent = Rc::downgrade(&self.0);
+        self.0.borrow_mut().children.push(child.clone());
+    }
+
+    fn unmount(&mut self) {
+        let parent = std::mem::take(&mut self.0.borrow_mut().parent);
+
+        if let Some(parent) = parent.upgrade() {
+            parent
+                .borrow_mut()
+                .children
+                .retain(|child| !Rc::ptr_eq(&child.0, &self.0));
+        }
+    }
+
+    fn reorder(&mut self, children: &mut [&mut Node], _environment: &mut ()) {
+        let mut inner = self.0.borrow_mut();
+
+        // We only move the given children among the positions that they
+        // already occupy.
+        let slots = inner
+            .children
+            .iter()
+            .enumerate()
+            .filter(|(_, child)| children.iter().any(|other| Rc::ptr_eq(&child.0, &other.0)))
+            .map(|(index, _)| index)
+            .collect::<Vec<_>>();
+
+        for (index, child) in slots.into_iter().zip(children.iter()) {
+            inner.children[index] = Node::clone(child);
+        }
+    }
+}
+
+/// Escapes the given text so that it can be used as the contents of an
+/// element or the value of a (quoted) attribute.
+pub fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+
+    for character in text.chars() {
+        match character {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            character => escaped.push(character),
+        }
+    }
+
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use polyhorn_core::Container;
+
+    use super::{Declarations, Node};
+
+    #[test]
+    fn test_node() {
+        let mut root = Node::new("div");
+        let mut a = Node::new("span");
+        let mut b = Node::new("div");
+        let mut c = Node::new("p");
+
+        a.set_markup("a &amp; b".to_owned());
+        b.set_attribute("title", "\"quoted\"");
+        c.set_attribute("alt", "<c>");
+
+        let mut style = Declarations::new();
+        style.push("color", "red");
+        style.push("font-family", "\"Helvetica Neue\"");
+        b.set_style(style);
+
+        root.mount(&mut a, &mut ());
+        root.mount(&mut b, &mut ());
+        root.mount(&mut c, &mut ());
+
+        assert_eq!(
+            root.inner_html(),
+            concat!(
+                "<span>a &amp; b</span>",
+                "<div title=\"&quot;quoted&quot;\" ",
+                "style=\"color:red;font-family:&quot;Helvetica Neue&quot;\"></div>",
+                "<p alt=\"&lt;c&gt;\"></p>",
+            )
+        );
+
+        root.reorder(&mut [&mut c, &mut a], &mut ());
+        assert_eq!(
+            root.children()
+                .iter()
+                .map(|child| child.tag())
+                .collect::<Vec<_>>(),
+            vec!["p", "div", "span"]
+        );
+
+        b.unmount();
+        assert_eq!(
+            root.to_string(),
+            "<div><p alt=\"&lt;c&gt;\"></p><span>a &amp; b</span></div>"
+        );
+    }
+}
@@ -0,0 +1,50 @@
+use polyhorn_ui::components::ScrollDirection;
+use polyhorn_ui::styles::{ImageViewStyle, ScrollableViewStyle, ViewStyle};
+use polyhorn_ui::text::AttributedText;
+use serde::{Deserialize, Serialize};
+
+use super::{Builtin, Platform};
+
+/// Serializable representation of a builtin that can be recorded and
+/// replayed (see `Renderer::record`).
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum Payload {
+    /// Image with an optional URL.
+    Image(ImageViewStyle, Option<String>),
+
+    /// Scroll container that clips its contents in the given direction.
+    Scrollable(ScrollableViewStyle, ScrollDirection),
+
+    /// Contents of a scroll container.
+    ScrollContent(ViewStyle, ScrollDirection),
+
+    /// Text that consists of the given runs.
+    Text(AttributedText<()>),
+
+    /// View with the given style.
+    View(ViewStyle),
+}
+
+impl polyhorn_core::Payload<Platform> for Payload {
+    type Builtin = Builtin;
+
+    fn encode(builtin: &Builtin) -> Payload {
+        match builtin.clone() {
+            Builtin::Image(style, url) => Payload::Image(style, url),
+            Builtin::Scrollable(style, direction) => Payload::Scrollable(style, direction),
+            Builtin::ScrollContent(style, direction) => Payload::ScrollContent(style, direction),
+            Builtin::Text(text) => Payload::Text(text),
+            Builtin::View(style) => Payload::View(style),
+        }
+    }
+
+    fn decode(self) -> Builtin {
+        match self {
+            Payload::Image(style, url) => Builtin::Image(style, url),
+            Payload::Scrollable(style, direction) => Builtin::Scrollable(style, direction),
+            Payload::ScrollContent(style, direction) => Builtin::ScrollContent(style, direction),
+            Payload::Text(text) => Builtin::Text(text),
+            Payload::View(style) => Builtin::View(style),
+        }
+    }
+}
@@ -0,0 +1,43 @@
+use polyhorn_core::{Disposable, EventLoop, EventLoopHandler, Executor};
+use std::sync::Arc;
+
+use super::{CommandBuffer, Compositor, ContainerID, Node, OpaqueComponent};
+
+/// Non-constructable type that implements the platform trait for HTML.
+pub enum Platform {}
+
+impl polyhorn_core::Platform for Platform {
+    type CommandBuffer = CommandBuffer;
+    type Component = OpaqueComponent;
+    type Compositor = Compositor;
+    type Container = Node;
+    type ContainerID = ContainerID;
+    type Environment = ();
+
+    /// Renders synchronously into the given node. The event loop is never
+    /// driven, which means that effects are not run.
+    fn with_compositor<F>(container: Node, executor: Arc<dyn Executor>, task: F) -> Disposable
+    where
+        F: FnOnce(ContainerID, Compositor, EventLoop) -> Disposable + Send + 'static,
+    {
+        let mut compositor = Compositor::new();
+        let id = compositor.track(container);
+        let (evloop, handler) = EventLoop::new(executor);
+
+        struct Session {
+            // The task is dropped before the handler because the event loop
+            // (which is retained by the task) expects the handler to be alive.
+            _task: Disposable,
+            _handler: EventLoopHandler,
+        }
+
+        impl Drop for Session {
+            fn drop(&mut self) {}
+        }
+
+        Disposable::new(Session {
+            _task: task(id, compositor, evloop),
+            _handler: handler,
+        })
+    }
+}
@@ -0,0 +1,126 @@
+//! Tests of the `poly!` syntax that components of this platform can use.
+
+// `poly!` fills in the props that are not specified with their defaults, even
+// if a component has no other props.
+#![allow(clippy::needless_update)]
+
+use polyhorn_core::{EventLoop, Renderer};
+use polyhorn_ui::styles::ViewStyle;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate as polyhorn;
+use crate::prelude::*;
+use crate::raw::{Builtin, Compositor, ContainerID, Node, Platform};
+use crate::{render_to_markup, Children, Key, Reference, WeakLink, WeakReference};
+
+/// Renders its header slot before the rest of its children.
+#[derive(Default)]
+struct Card {}
+
+impl Component for Card {
+    fn render(&self, manager: &mut Manager) -> Element {
+        let children = Children::new(&manager.children());
+        let header = children.slot("header").cloned();
+
+        Element::fragment(
+            Key::new(()),
+            vec![header.unwrap_or_else(Element::empty), children.into()],
+        )
+    }
+}
+
+#[test]
+fn test_slot() {
+    let slotted = render_to_markup(poly!(
+        <Card>
+            <Text>"Body"</Text>
+            <slot:header>
+                <Text>"Header"</Text>
+            </slot:header>
+        </Card>
+    ));
+
+    let ordered = render_to_markup(poly!(
+        <Card>
+            <Text>"Header"</Text>
+            <Text>"Body"</Text>
+        </Card>
+    ));
+
+    assert!(slotted.contains("Header"));
+    assert_eq!(slotted, ordered);
+}
+
+/// Imperative handle of an input that resolves to the container of its
+/// built-in.
+#[derive(Clone)]
+struct Focus(WeakReference<Option<ContainerID>>);
+
+impl Focus {
+    fn container(&self) -> Option<ContainerID> {
+        self.0.apply(|container| *container).flatten()
+    }
+}
+
+#[derive(Default)]
+struct Input {
+    reference: Option<WeakReference<Option<Focus>>>,
+}
+
+impl Component for Input {
+    fn render(&self, manager: &mut Manager) -> Element {
+        let container = use_reference!(manager, None).weak(manager);
+        use_imperative_handle!(manager, &self.reference, Focus(container.clone()));
+
+        let builtin = Builtin::View(ViewStyle::default());
+
+        poly!(<~builtin ref={Some(container)} />)
+    }
+}
+
+struct Form {
+    probe: Rc<RefCell<Option<Reference<Option<Focus>>>>>,
+}
+
+impl Component for Form {
+    fn render(&self, manager: &mut Manager) -> Element {
+        let handle = use_reference!(manager, None);
+        self.probe.replace(Some(handle));
+
+        let reference = handle.weak(manager);
+
+        // The `ref` of a component is passed as its `reference` prop.
+        poly!(<Input ref={reference} />)
+    }
+}
+
+#[test]
+fn test_forwarded_ref() {
+    let probe = Rc::new(RefCell::new(None));
+
+    let mut compositor = Compositor::new();
+    let root = compositor.track(Node::new("body"));
+
+    let executor = <Platform as polyhorn_core::Platform>::default_executor();
+    let (evloop, _handler) = EventLoop::new(executor);
+    let renderer = Renderer::new(compositor, evloop);
+    let instance = renderer.render(
+        Element::new(
+            Key::new(()),
+            Form {
+                probe: probe.clone(),
+            }
+            .into(),
+            Element::empty(),
+        ),
+        root,
+    );
+
+    let handle = probe.borrow().unwrap();
+    let focus = handle.cloned(&WeakLink::new(&instance, &instance.memory()));
+    let container = focus.and_then(|focus| focus.container());
+
+    assert!(container.is_some());
+    assert_ne!(container, Some(root));
+}
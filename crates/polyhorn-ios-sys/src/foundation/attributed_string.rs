@@ -1,11 +1,46 @@
 use objc::runtime::*;
 use objc::*;
 
-use super::{NSParagraphStyle, NSString};
-use crate::coregraphics::{CGRect, CGSize};
-use crate::uikit::{UIColor, UIFont};
+use std::ops::BitOr;
+
+use super::{NSNumber, NSParagraphStyle, NSString};
+use crate::coregraphics::{CGFloat, CGRect, CGSize};
+use crate::uikit::{NSShadow, UIColor, UIFont};
 use crate::Raw;
 
+/// Constants for the style and pattern of underline and strikethrough lines. A
+/// style can be combined with a pattern using bitwise or.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct NSUnderlineStyle(pub isize);
+
+impl NSUnderlineStyle {
+    /// Do not draw a line.
+    pub const NONE: NSUnderlineStyle = NSUnderlineStyle(0x00);
+
+    /// Draw a single line.
+    pub const SINGLE: NSUnderlineStyle = NSUnderlineStyle(0x01);
+
+    /// Draw a thick line.
+    pub const THICK: NSUnderlineStyle = NSUnderlineStyle(0x02);
+
+    /// Draw a double line.
+    pub const DOUBLE: NSUnderlineStyle = NSUnderlineStyle(0x09);
+
+    /// Draw a line of dots.
+    pub const PATTERN_DOT: NSUnderlineStyle = NSUnderlineStyle(0x0100);
+
+    /// Draw a line of dashes.
+    pub const PATTERN_DASH: NSUnderlineStyle = NSUnderlineStyle(0x0200);
+}
+
+impl BitOr for NSUnderlineStyle {
+    type Output = NSUnderlineStyle;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        NSUnderlineStyle(self.0 | rhs.0)
+    }
+}
+
 /// Attributes that you can apply to text in an attributed string.
 pub struct NSAttributes {
     /// The font of the text. The value of this attribute is a `UIFont` object.
@@ -26,6 +61,29 @@ pub struct NSAttributes {
     /// string uses the default paragraph attributes, as returned by the
     /// `defaultParagraphStyle` of `NSParagraphStyle`.
     pub paragraph_style: NSParagraphStyle,
+
+    /// The number of points by which to adjust kern-pair characters. A value
+    /// of 0 disables additional kerning.
+    pub kern: CGFloat,
+
+    /// The style of the line that is drawn under the text. If
+    /// `NSUnderlineStyle::NONE`, no underline is drawn.
+    pub underline_style: NSUnderlineStyle,
+
+    /// The color of the underline. If not present, the underline is drawn in
+    /// the foreground color of the text.
+    pub underline_color: Option<UIColor>,
+
+    /// The style of the line that is drawn through the text. If
+    /// `NSUnderlineStyle::NONE`, no strikethrough is drawn.
+    pub strikethrough_style: NSUnderlineStyle,
+
+    /// The color of the strikethrough. If not present, the strikethrough is
+    /// drawn in the foreground color of the text.
+    pub strikethrough_color: Option<UIColor>,
+
+    /// The shadow of the text. If not present, no shadow is drawn.
+    pub shadow: Option<NSShadow>,
 }
 
 extern "C" {
@@ -47,6 +105,31 @@ extern "C" {
     /// string uses the default paragraph attributes, as returned by the
     /// `defaultParagraphStyle` of `NSParagraphStyle`.
     static NSParagraphStyleAttributeName: *mut Object;
+
+    /// The number of points by which to adjust kern-pair characters. The value
+    /// of this attribute is an `NSNumber` object containing a floating-point
+    /// value.
+    static NSKernAttributeName: *mut Object;
+
+    /// The style of the underline. The value of this attribute is an
+    /// `NSNumber` object containing an integer.
+    static NSUnderlineStyleAttributeName: *mut Object;
+
+    /// The color of the underline. The value of this attribute is a `UIColor`
+    /// object.
+    static NSUnderlineColorAttributeName: *mut Object;
+
+    /// The style of the strikethrough. The value of this attribute is an
+    /// `NSNumber` object containing an integer.
+    static NSStrikethroughStyleAttributeName: *mut Object;
+
+    /// The color of the strikethrough. The value of this attribute is a
+    /// `UIColor` object.
+    static NSStrikethroughColorAttributeName: *mut Object;
+
+    /// The shadow of the text. The value of this attribute is an `NSShadow`
+    /// object.
+    static NSShadowAttributeName: *mut Object;
 }
 
 impl NSAttributes {
@@ -61,6 +144,40 @@ impl NSAttributes {
                                                  forKey: NSForegroundColorAttributeName];
             let _: () = msg_send![dictionary, setObject: self.paragraph_style.as_raw()
                                                  forKey: NSParagraphStyleAttributeName];
+
+            if self.kern != 0.0 {
+                let kern = NSNumber::from(self.kern);
+                let _: () = msg_send![dictionary, setObject: kern.as_raw()
+                                                     forKey: NSKernAttributeName];
+            }
+
+            if self.underline_style != NSUnderlineStyle::NONE {
+                let style = NSNumber::from(self.underline_style.0);
+                let _: () = msg_send![dictionary, setObject: style.as_raw()
+                                                     forKey: NSUnderlineStyleAttributeName];
+            }
+
+            if let Some(color) = &self.underline_color {
+                let _: () = msg_send![dictionary, setObject: color.as_raw()
+                                                     forKey: NSUnderlineColorAttributeName];
+            }
+
+            if self.strikethrough_style != NSUnderlineStyle::NONE {
+                let style = NSNumber::from(self.strikethrough_style.0);
+                let _: () = msg_send![dictionary, setObject: style.as_raw()
+                                                     forKey: NSStrikethroughStyleAttributeName];
+            }
+
+            if let Some(color) = &self.strikethrough_color {
+                let _: () = msg_send![dictionary, setObject: color.as_raw()
+                                                     forKey: NSStrikethroughColorAttributeName];
+            }
+
+            if let Some(shadow) = &self.shadow {
+                let _: () = msg_send![dictionary, setObject: shadow.as_raw()
+                                                     forKey: NSShadowAttributeName];
+            }
+
             dictionary
         }
     }
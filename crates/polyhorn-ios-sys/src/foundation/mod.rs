@@ -13,9 +13,13 @@ mod string;
 mod value;
 
 pub use array::NSMutableArray;
-pub use attributed_string::{NSAttributedString, NSAttributes, NSMutableAttributedString};
+pub use attributed_string::{
+    NSAttributedString, NSAttributes, NSMutableAttributedString, NSUnderlineStyle,
+};
 pub use data::NSData;
 pub use number::NSNumber;
-pub use paragraph_style::{NSMutableParagraphStyle, NSParagraphStyle, NSTextAlignment};
+pub use paragraph_style::{
+    NSLineBreakMode, NSMutableParagraphStyle, NSParagraphStyle, NSTextAlignment,
+};
 pub use string::NSString;
 pub use value::NSValue;
@@ -26,6 +26,16 @@ impl From<f32> for NSNumber {
     }
 }
 
+impl From<isize> for NSNumber {
+    fn from(value: isize) -> Self {
+        unsafe {
+            let mut object: *mut Object = msg_send![class!(NSNumber), alloc];
+            object = msg_send![object, initWithInteger: value];
+            NSNumber::from_raw(object)
+        }
+    }
+}
+
 impl From<f64> for NSNumber {
     fn from(value: f64) -> Self {
         unsafe {
@@ -1,6 +1,7 @@
 use objc::runtime::*;
 use objc::*;
 
+use crate::coregraphics::CGFloat;
 use crate::Raw;
 
 /// Constants that specify text alignment.
@@ -24,6 +25,36 @@ pub enum NSTextAlignment {
     #[cfg(not(target_os = "ios"))]
     /// Text is visually center-aligned.
     Center = 2,
+
+    /// Text is justified. The last line of a paragraph is aligned naturally.
+    Justified = 3,
+}
+
+/// Constants that specify what happens when a line is too long for its
+/// container.
+#[repr(usize)]
+pub enum NSLineBreakMode {
+    /// Wrapping occurs at word boundaries, unless the word doesn't fit on a
+    /// single line.
+    ByWordWrapping = 0,
+
+    /// Wrapping occurs before the first character that doesn't fit.
+    ByCharWrapping = 1,
+
+    /// Lines don't extend past the edge of the text container.
+    ByClipping = 2,
+
+    /// The line displays so that the end fits in the container and an
+    /// ellipsis glyph indicates the missing text at the beginning of the line.
+    ByTruncatingHead = 3,
+
+    /// The line displays so that the beginning fits in the container and an
+    /// ellipsis glyph indicates the missing text at the end of the line.
+    ByTruncatingTail = 4,
+
+    /// The line displays so that the beginning and end fit in the container
+    /// and an ellipsis glyph indicates the missing text in the middle.
+    ByTruncatingMiddle = 5,
 }
 
 /// The paragraph or ruler attributes for an attributed string.
@@ -53,6 +84,22 @@ impl NSMutableParagraphStyle {
             let _: () = msg_send![self.object, setAlignment: alignment];
         }
     }
+
+    /// Sets the minimum height in points that any line in the receiver
+    /// occupies, regardless of the font size or size of any attached graphic.
+    pub fn set_minimum_line_height(&mut self, height: CGFloat) {
+        unsafe {
+            let _: () = msg_send![self.object, setMinimumLineHeight: height];
+        }
+    }
+
+    /// Sets the maximum height in points that any line in the receiver
+    /// occupies, regardless of the font size or size of any attached graphic.
+    pub fn set_maximum_line_height(&mut self, height: CGFloat) {
+        unsafe {
+            let _: () = msg_send![self.object, setMaximumLineHeight: height];
+        }
+    }
 }
 
 impl Raw for NSMutableParagraphStyle {
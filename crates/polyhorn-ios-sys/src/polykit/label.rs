@@ -2,7 +2,7 @@ use objc::runtime::*;
 use objc::*;
 
 use super::PLYView;
-use crate::foundation::NSAttributedString;
+use crate::foundation::{NSAttributedString, NSLineBreakMode};
 use crate::Raw;
 
 /// A view that displays one or more lines of informational text.
@@ -27,6 +27,21 @@ impl PLYLabel {
         }
     }
 
+    /// Sets the maximum number of lines that the label displays. If 0, the
+    /// number of lines is unlimited.
+    pub fn set_number_of_lines(&mut self, lines: usize) {
+        unsafe {
+            let _: () = msg_send![self.object, setNumberOfLines: lines as isize];
+        }
+    }
+
+    /// Sets the technique for wrapping and truncating the label's text.
+    pub fn set_line_break_mode(&mut self, mode: NSLineBreakMode) {
+        unsafe {
+            let _: () = msg_send![self.object, setLineBreakMode: mode];
+        }
+    }
+
     /// Upcasts this label to a UIView.
     pub fn to_view(&self) -> PLYView {
         unsafe { PLYView::from_raw_retain(self.object) }
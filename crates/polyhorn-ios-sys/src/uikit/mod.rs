@@ -10,6 +10,7 @@ mod font;
 mod geometry;
 mod image;
 mod scroll_view;
+mod shadow;
 mod status_bar;
 
 pub use application::UIApplication;
@@ -18,4 +19,5 @@ pub use font::UIFont;
 pub use geometry::UIEdgeInsets;
 pub use image::UIImage;
 pub use scroll_view::UIScrollViewIndicatorStyle;
+pub use shadow::NSShadow;
 pub use status_bar::UIStatusBarStyle;
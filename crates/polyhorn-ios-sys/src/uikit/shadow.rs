@@ -0,0 +1,67 @@
+use objc::runtime::*;
+use objc::*;
+
+use super::UIColor;
+use crate::coregraphics::{CGFloat, CGSize};
+use crate::Raw;
+
+/// An object that encapsulates the attributes you use to create a drop shadow
+/// during drawing operations.
+pub struct NSShadow {
+    object: *mut Object,
+}
+
+impl NSShadow {
+    /// Initializes a newly allocated shadow without an offset or blur and with
+    /// a black color that has an alpha value of 1/3.
+    pub fn new() -> NSShadow {
+        unsafe {
+            let mut object: *mut Object = msg_send![class!(NSShadow), alloc];
+            object = msg_send![object, init];
+            NSShadow { object }
+        }
+    }
+
+    /// Sets the horizontal and vertical offset of the shadow in points.
+    pub fn set_shadow_offset(&mut self, offset: CGSize) {
+        unsafe {
+            let _: () = msg_send![self.object, setShadowOffset: offset];
+        }
+    }
+
+    /// Sets the blur radius of the shadow in points.
+    pub fn set_shadow_blur_radius(&mut self, radius: CGFloat) {
+        unsafe {
+            let _: () = msg_send![self.object, setShadowBlurRadius: radius];
+        }
+    }
+
+    /// Sets the color of the shadow.
+    pub fn set_shadow_color(&mut self, color: &UIColor) {
+        unsafe {
+            let _: () = msg_send![self.object, setShadowColor: color.as_raw()];
+        }
+    }
+}
+
+impl Raw for NSShadow {
+    unsafe fn from_raw(object: *mut Object) -> Self {
+        NSShadow { object }
+    }
+
+    unsafe fn as_raw(&self) -> *mut Object {
+        self.object
+    }
+}
+
+impl Clone for NSShadow {
+    fn clone(&self) -> Self {
+        unsafe { NSShadow::from_raw_retain(self.as_raw()) }
+    }
+}
+
+impl Drop for NSShadow {
+    fn drop(&mut self) {
+        unsafe { objc_release(self.object) }
+    }
+}
@@ -2,6 +2,7 @@ use polyhorn_core::CommandBuffer;
 use polyhorn_ios_sys::coregraphics::CGRect;
 use polyhorn_ios_sys::polykit::{PLYTextInputView, PLYView};
 use polyhorn_ui::styles::TextStyle;
+use polyhorn_ui::text::ResolvedTextStyle;
 
 use crate::prelude::*;
 use crate::raw::{attributed_string, Builtin, Container, ContainerID, OpaqueContainer};
@@ -38,7 +39,7 @@ impl Component for TextInput {
         let view_ref: Reference<Option<ContainerID>> = use_reference!(manager, None);
 
         let placeholder = self.placeholder.clone();
-        let placeholder_style = self.placeholder_style.clone();
+        let placeholder_style = ResolvedTextStyle::default().cascade(&self.placeholder_style);
 
         use_layout_effect!(manager, move |link, buffer| {
             let id = match view_ref.apply(link, |&mut id| id) {
@@ -1,13 +1,19 @@
+use polyhorn_ios_sys::coregraphics::CGRect;
 use polyhorn_ios_sys::polykit::{
     PLYImageView, PLYKeyboardAvoidingView, PLYLabel, PLYScrollView, PLYTextInputView, PLYView,
     PLYViewController, PLYWindow,
 };
 use polyhorn_ios_sys::uikit::UIApplication;
 use polyhorn_ui::geometry::{Dimension, Size};
-use polyhorn_ui::layout::{LayoutNode, MeasureFunc};
+use polyhorn_ui::layout::LayoutNode;
 use polyhorn_ui::styles::{FlexDirection, Position, Relative, ViewStyle};
+use polyhorn_ui::text::AttributedText;
+use std::any::Any;
 
-use super::{Container, Environment, OpaqueContainer, Platform};
+use super::{
+    attributed_text, line_break_mode, measure_text, Container, Environment, OpaqueContainer,
+    Platform,
+};
 
 /// Defines one of the native views that bridge Polyhorn with iOS's UIKit.
 #[derive(Clone, Debug)]
@@ -20,7 +26,7 @@ pub enum Builtin {
     KeyboardAvoidingView,
 
     /// Renders (rich) text.
-    Label(MeasureFunc),
+    Label(AttributedText<()>),
 
     /// Renders a view in a system-provided modal window.
     Modal,
@@ -128,7 +134,27 @@ impl polyhorn_core::Builtin<Platform> for Builtin {
     fn update(&self, container: &mut OpaqueContainer, _environment: &mut Environment) {
         match self {
             &Builtin::ImageView(style) => container.layout().unwrap().set_style(style),
-            Builtin::Label(measure) => container.layout().unwrap().set_measure(measure.clone()),
+            Builtin::Label(text) => {
+                let layout = container.layout().unwrap().clone();
+                layout.set_measure(measure_text(text));
+
+                if let Some(view) = container.downcast_mut::<PLYLabel>() {
+                    view.set_attributed_text(&attributed_text(text));
+                    view.set_number_of_lines(text.number_of_lines());
+                    view.set_line_break_mode(line_break_mode(text.ellipsis_mode()));
+
+                    view.to_view().set_layout(move || {
+                        let current = layout.current();
+
+                        CGRect::new(
+                            current.origin.x as _,
+                            current.origin.y as _,
+                            current.size.width as _,
+                            current.size.height as _,
+                        )
+                    });
+                }
+            }
             Builtin::Modal => {
                 let view_controller =
                     if let Some(view_controller) = container.downcast_mut::<PLYViewController>() {
@@ -185,4 +211,8 @@ impl polyhorn_core::Builtin<Platform> for Builtin {
             _ => {}
         }
     }
+
+    fn as_any(&self) -> Option<&dyn Any> {
+        Some(self)
+    }
 }
@@ -1,6 +1,7 @@
 use dispatch::Queue;
 use polyhorn_core::{Command, Composition};
 use polyhorn_ui::layout::LayoutTree;
+use serde::{Deserialize, Serialize};
 use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::{Arc, RwLock};
 
@@ -54,7 +55,7 @@ impl polyhorn_core::Compositor<Platform> for Compositor {
 }
 
 /// An opaque ID for containers that can be shared between threads.
-#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
 pub struct ContainerID(usize);
 
 /// Concrete implementation of a command buffer that can buffer commands before
@@ -116,4 +116,29 @@ impl polyhorn_core::Container<Platform> for OpaqueContainer {
 
         self.2.unmount();
     }
+
+    fn reorder(&mut self, children: &mut [&mut OpaqueContainer], _environment: &mut Environment) {
+        // The layout tree can only append children, so we move the given
+        // children to the end in their new order.
+        if let Some(parent) = self.content_layout().or_else(|| self.layout()) {
+            let mut layouter = parent.layouter().write().unwrap();
+
+            for child in children.iter() {
+                if let Some(child) = child.layout() {
+                    layouter
+                        .flexbox_mut()
+                        .remove_child(parent.node(), child.node());
+                    layouter.add_child(parent.node(), child.node());
+                }
+            }
+        }
+
+        // Adding a view that is already a subview of the same superview moves
+        // it to the front, so we remount the views in their new order.
+        for child in children.iter_mut() {
+            if child.container().to_view().is_some() {
+                self.2.mount(child);
+            }
+        }
+    }
 }
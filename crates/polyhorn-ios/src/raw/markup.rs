@@ -1,58 +1,139 @@
+use polyhorn_ios_sys::coregraphics::CGSize;
 use polyhorn_ios_sys::foundation::{
-    NSAttributedString, NSAttributes, NSMutableParagraphStyle, NSTextAlignment,
+    NSAttributedString, NSAttributes, NSLineBreakMode, NSMutableAttributedString,
+    NSMutableParagraphStyle, NSTextAlignment, NSUnderlineStyle,
 };
-use polyhorn_ui::color::{Color, NamedColor};
-use polyhorn_ui::font::{Font, FontFamily, FontSize, FontStyle, FontWeight, GenericFontFamily};
-use polyhorn_ui::styles::{Inherited, TextAlign, TextStyle};
+use polyhorn_ios_sys::uikit::NSShadow;
+use polyhorn_ui::geometry::{Dimension, Size};
+use polyhorn_ui::layout::MeasureFunc;
+use polyhorn_ui::styles::{EllipsisMode, TextAlign, TextDecorationStyle};
+use polyhorn_ui::text::{AttributedText, ResolvedTextStyle};
+use std::sync::Arc;
 
 use crate::raw::Convert;
 
-/// Generates a new `NSAttributedString` for the given text with the given text
-/// style.
-pub fn attributed_string(text: &str, style: &TextStyle) -> NSAttributedString {
+/// Generates a new `NSAttributedString` for the given text with the given
+/// resolved text style.
+pub fn attributed_string(text: &str, style: &ResolvedTextStyle) -> NSAttributedString {
     let mut paragraph_style = NSMutableParagraphStyle::new();
     paragraph_style.set_alignment(match style.text_align {
-        Inherited::Inherited => NSTextAlignment::Left,
-        Inherited::Specified(specified) => match specified {
-            TextAlign::Left => NSTextAlignment::Left,
-            TextAlign::Center => NSTextAlignment::Center,
-            TextAlign::Right => NSTextAlignment::Right,
-        },
+        TextAlign::Left => NSTextAlignment::Left,
+        TextAlign::Center => NSTextAlignment::Center,
+        TextAlign::Right => NSTextAlignment::Right,
+        TextAlign::Justify => NSTextAlignment::Justified,
     });
 
-    let font = Font {
-        family: match style.font_family {
-            Inherited::Inherited => FontFamily::Generic(GenericFontFamily::SansSerif),
-            Inherited::Specified(family) => family,
-        },
-        weight: match style.font_weight {
-            Inherited::Inherited => FontWeight::Normal,
-            Inherited::Specified(weight) => weight,
-        },
-        style: match style.font_style {
-            Inherited::Inherited => FontStyle::Normal,
-            Inherited::Specified(style) => style,
-        },
-        size: match style.font_size {
-            Inherited::Inherited => FontSize::Medium,
-            Inherited::Specified(size) => size,
-        },
+    if let Some(line_height) = style.line_height_points() {
+        paragraph_style.set_minimum_line_height(line_height as _);
+        paragraph_style.set_maximum_line_height(line_height as _);
+    }
+
+    let decoration = style.text_decoration;
+
+    // UIKit does not draw wavy lines, so those fall back to a single line.
+    let line_style = match decoration.style {
+        TextDecorationStyle::Solid | TextDecorationStyle::Wavy => NSUnderlineStyle::SINGLE,
+        TextDecorationStyle::Double => NSUnderlineStyle::DOUBLE,
+        TextDecorationStyle::Dotted => NSUnderlineStyle::SINGLE | NSUnderlineStyle::PATTERN_DOT,
+        TextDecorationStyle::Dashed => NSUnderlineStyle::SINGLE | NSUnderlineStyle::PATTERN_DASH,
     };
 
-    let color = match style.color {
-        Inherited::Inherited => Color::black(),
-        Inherited::Specified(color) => color,
-    }
-    .convert();
+    let line = |enabled: bool| match enabled {
+        true => line_style,
+        false => NSUnderlineStyle::NONE,
+    };
 
-    let style = paragraph_style.into();
+    let shadow = style.text_shadow.map(|text_shadow| {
+        let mut shadow = NSShadow::new();
+        shadow.set_shadow_offset(CGSize::new(
+            text_shadow.offset_x as _,
+            text_shadow.offset_y as _,
+        ));
+        shadow.set_shadow_blur_radius(text_shadow.blur_radius as _);
+        shadow.set_shadow_color(&text_shadow.color.unwrap_or(style.color).convert());
+        shadow
+    });
+
+    let font = style.font();
+    let color = style.color.convert();
+    let paragraph_style = paragraph_style.into();
 
     NSAttributedString::with_attributes(
         &text,
         NSAttributes {
             font: font.convert(),
             foreground_color: color,
-            paragraph_style: style,
+            paragraph_style,
+            kern: style.letter_spacing as _,
+            underline_style: line(decoration.underline),
+            underline_color: decoration.color.map(|color| color.convert()),
+            strikethrough_style: line(decoration.line_through),
+            strikethrough_color: decoration.color.map(|color| color.convert()),
+            shadow,
         },
     )
 }
+
+/// Generates a new `NSAttributedString` for the runs of the given text.
+pub fn attributed_text<L>(text: &AttributedText<L>) -> NSAttributedString {
+    let mut string = NSMutableAttributedString::new();
+
+    for run in text.runs() {
+        string.append_attributed_string(&attributed_string(&run.text, &run.style));
+    }
+
+    string.into()
+}
+
+/// Returns the line break mode that truncates text with the given ellipsis
+/// mode.
+pub fn line_break_mode(mode: EllipsisMode) -> NSLineBreakMode {
+    match mode {
+        EllipsisMode::Clip => NSLineBreakMode::ByClipping,
+        EllipsisMode::Head => NSLineBreakMode::ByTruncatingHead,
+        EllipsisMode::Middle => NSLineBreakMode::ByTruncatingMiddle,
+        EllipsisMode::Tail => NSLineBreakMode::ByTruncatingTail,
+    }
+}
+
+/// Returns a function that measures the given text during layout.
+pub fn measure_text(text: &AttributedText<()>) -> MeasureFunc {
+    let attributed_string = attributed_text(text);
+
+    // Strings are measured without a limit on the number of lines, so the
+    // height is clamped to that of the given number of lines in the style of
+    // the first run.
+    let clamp = match (text.number_of_lines(), text.runs().first()) {
+        (0, _) | (_, None) => None,
+        (lines, Some(run)) => {
+            let text = vec!["X"; lines].join("\n");
+            Some(attributed_string(&text, &run.style))
+        }
+    };
+
+    MeasureFunc::Boxed(Arc::new(move |size| {
+        let min_size = CGSize {
+            width: match size.width {
+                Dimension::Points(width) => width as _,
+                _ => 0.0,
+            },
+            height: match size.height {
+                Dimension::Points(height) => height as _,
+                _ => 0.0,
+            },
+        };
+
+        let target = attributed_string.bounding_rect_with_size(min_size).size;
+        let height = match &clamp {
+            Some(clamp) => target
+                .height
+                .min(clamp.bounding_rect_with_size(min_size).size.height),
+            None => target.height,
+        };
+
+        Size {
+            width: target.width.ceil() as _,
+            height: height.ceil() as _,
+        }
+    }))
+}
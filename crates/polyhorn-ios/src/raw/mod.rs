@@ -9,6 +9,7 @@ mod container;
 mod convert;
 mod environment;
 mod markup;
+mod payload;
 mod platform;
 mod queue;
 
@@ -20,6 +21,7 @@ pub use compositor::{CommandBuffer, Compositor, ContainerID};
 pub use container::{Container, OpaqueContainer};
 pub use convert::Convert;
 pub use environment::Environment;
-pub use markup::attributed_string;
+pub use markup::{attributed_string, attributed_text, line_break_mode, measure_text};
+pub use payload::Payload;
 pub use platform::Platform;
 pub use queue::QueueBound;
@@ -0,0 +1,85 @@
+use polyhorn_ui::styles::ViewStyle;
+use polyhorn_ui::text::AttributedText;
+use serde::{Deserialize, Serialize};
+
+use super::{Builtin, Platform};
+
+/// Serializable representation of a builtin that can be recorded and
+/// replayed (see `Renderer::record`).
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum Payload {
+    /// Renders an image.
+    ImageView(ViewStyle),
+
+    /// Adjusts its layout to the system keyboard.
+    KeyboardAvoidingView,
+
+    /// Renders (rich) text. The measure function of the label is derived from
+    /// its text when the payload is decoded.
+    Label(AttributedText<()>),
+
+    /// Renders a view in a system-provided modal window.
+    Modal,
+
+    /// Implements scrolling gestures.
+    ScrollView {
+        /// This is the style that gets applied to the scroll view itself.
+        self_style: ViewStyle,
+
+        /// This is the style that gets applied to the content of the scroll
+        /// view.
+        content_style: ViewStyle,
+    },
+
+    /// Accepts user input.
+    TextInput,
+
+    /// The base component.
+    View(ViewStyle),
+
+    /// The root component.
+    Window,
+}
+
+impl polyhorn_core::Payload<Platform> for Payload {
+    type Builtin = Builtin;
+
+    fn encode(builtin: &Builtin) -> Payload {
+        match builtin.clone() {
+            Builtin::ImageView(style) => Payload::ImageView(style),
+            Builtin::KeyboardAvoidingView => Payload::KeyboardAvoidingView,
+            Builtin::Label(text) => Payload::Label(text),
+            Builtin::Modal => Payload::Modal,
+            Builtin::ScrollView {
+                self_style,
+                content_style,
+            } => Payload::ScrollView {
+                self_style,
+                content_style,
+            },
+            Builtin::TextInput => Payload::TextInput,
+            Builtin::View(style) => Payload::View(style),
+            Builtin::Window => Payload::Window,
+        }
+    }
+
+    fn decode(self) -> Builtin {
+        match self {
+            Payload::ImageView(style) => Builtin::ImageView(style),
+            Payload::KeyboardAvoidingView => Builtin::KeyboardAvoidingView,
+            Payload::Label(text) => Builtin::Label(text),
+            Payload::Modal => Builtin::Modal,
+            Payload::ScrollView {
+                self_style,
+                content_style,
+            } => Builtin::ScrollView {
+                self_style,
+                content_style,
+            },
+            Payload::TextInput => Builtin::TextInput,
+            Payload::View(style) => Builtin::View(style),
+            Payload::Window => Builtin::Window,
+        }
+    }
+}
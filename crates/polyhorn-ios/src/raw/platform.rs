@@ -1,5 +1,6 @@
-use polyhorn_core::EventLoop;
+use polyhorn_core::{EventLoop, Executor, TokioExecutor};
 use polyhorn_ui::layout::LayoutTree;
+use std::sync::Arc;
 
 use super::{
     CommandBuffer, Compositor, ContainerID, Environment, OpaqueComponent, OpaqueContainer,
@@ -16,13 +17,21 @@ impl polyhorn_core::Platform for Platform {
     type ContainerID = ContainerID;
     type Environment = Environment;
 
-    fn with_compositor<F>(container: Self::Container, task: F) -> polyhorn_core::Disposable
+    fn default_executor() -> Arc<dyn Executor> {
+        Arc::new(TokioExecutor::new())
+    }
+
+    fn with_compositor<F>(
+        container: Self::Container,
+        executor: Arc<dyn Executor>,
+        task: F,
+    ) -> polyhorn_core::Disposable
     where
         F: FnOnce(Self::ContainerID, Self::Compositor, EventLoop) -> polyhorn_core::Disposable
             + Send
             + 'static,
     {
-        use std::sync::{Arc, RwLock};
+        use std::sync::RwLock;
 
         let layout_tree = Arc::new(RwLock::new(LayoutTree::new()));
         let mut compositor = Compositor::new(layout_tree);
@@ -31,14 +40,8 @@ impl polyhorn_core::Platform for Platform {
         std::thread::Builder::new()
             .name("com.glacyr.Polyhorn".to_owned())
             .spawn(move || {
-                let mut runtime = tokio::runtime::Runtime::new().unwrap();
-                runtime.block_on(async move {
-                    let (evloop, handler) = EventLoop::new();
-                    let _compositor = compositor.clone();
-                    let _result = task(id, compositor, evloop);
-
-                    handler.main().await;
-                })
+                let _compositor = compositor.clone();
+                EventLoop::run(executor, move |evloop| task(id, compositor, evloop));
             })
             .unwrap();
 
@@ -1,5 +1,88 @@
 mod poly_impl;
 
+/// Converts JSX-like syntax into an element.
+///
+/// # Named slots
+///
+/// Children can be passed to a named slot of a component, which that component
+/// can retrieve with `Children::slot`.
+///
+/// ```
+/// # extern crate polyhorn_html as polyhorn;
+/// # use polyhorn::prelude::*;
+/// # #[derive(Default)]
+/// # struct Card {}
+/// # impl Component for Card {
+/// #     fn render(&self, manager: &mut Manager) -> Element {
+/// #         manager.children()
+/// #     }
+/// # }
+/// poly!(
+///     <Card>
+///         <slot:header>
+///             <Text>"Header"</Text>
+///         </slot:header>
+///         <Text>"Body"</Text>
+///     </Card>
+/// );
+/// ```
+///
+/// A slot must be closed with the same name.
+///
+/// ```compile_fail
+/// # extern crate polyhorn_html as polyhorn;
+/// # use polyhorn::prelude::*;
+/// # #[derive(Default)]
+/// # struct Card {}
+/// # impl Component for Card {
+/// #     fn render(&self, manager: &mut Manager) -> Element {
+/// #         manager.children()
+/// #     }
+/// # }
+/// poly!(
+///     <Card>
+///         <slot:header></slot:footer>
+///     </Card>
+/// );
+/// ```
+///
+/// A slot can't be a built-in.
+///
+/// ```compile_fail
+/// # extern crate polyhorn_html as polyhorn;
+/// # use polyhorn::prelude::*;
+/// # #[derive(Default)]
+/// # struct Card {}
+/// # impl Component for Card {
+/// #     fn render(&self, manager: &mut Manager) -> Element {
+/// #         manager.children()
+/// #     }
+/// # }
+/// poly!(
+///     <Card>
+///         <~slot:header></~slot:header>
+///     </Card>
+/// );
+/// ```
+///
+/// A slot doesn't accept props.
+///
+/// ```compile_fail
+/// # extern crate polyhorn_html as polyhorn;
+/// # use polyhorn::prelude::*;
+/// # #[derive(Default)]
+/// # struct Card {}
+/// # impl Component for Card {
+/// #     fn render(&self, manager: &mut Manager) -> Element {
+/// #         manager.children()
+/// #     }
+/// # }
+/// poly!(
+///     <Card>
+///         <slot:header title="Header"></slot:header>
+///     </Card>
+/// );
+/// ```
 #[proc_macro]
 pub fn poly(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     poly_impl::poly(input)
@@ -1,4 +1,4 @@
-use proc_macro2::{Span, TokenTree};
+use proc_macro2::{Ident, Span, TokenTree};
 
 use super::tokenizer::Path;
 
@@ -11,6 +11,14 @@ pub enum Error {
     ExpectedOpeningAngle(TokenTree),
     ExpectedPreamble(TokenTree),
     UnrecognizedClosingTag(Path, Path),
+    UnrecognizedClosingSlot(Option<Ident>, Option<Ident>, Path),
+}
+
+fn tag_name(slot: &Option<Ident>, path: &Path) -> String {
+    match slot {
+        Some(slot) => format!("slot:{}", slot),
+        None => path.to_string(),
+    }
 }
 
 impl Error {
@@ -35,6 +43,11 @@ impl Error {
             Error::UnrecognizedClosingTag(expected, actual) => {
                 format!("Expected `{}`, got `{}` instead.", expected, actual)
             }
+            Error::UnrecognizedClosingSlot(expected, actual, path) => format!(
+                "Expected `{}`, got `{}` instead.",
+                tag_name(expected, path),
+                tag_name(actual, path)
+            ),
         }
     }
 
@@ -48,6 +61,7 @@ impl Error {
             Error::ExpectedOpeningAngle(token) => token.span(),
             Error::ExpectedPreamble(token) => token.span(),
             Error::UnrecognizedClosingTag(_, actual) => actual.parts.first().unwrap().ident.span(),
+            Error::UnrecognizedClosingSlot(_, _, path) => path.parts.first().unwrap().ident.span(),
         }
     }
 
@@ -61,6 +75,10 @@ impl Error {
             Error::ExpectedOpeningAngle(token) => token.span(),
             Error::ExpectedPreamble(token) => token.span(),
             Error::UnrecognizedClosingTag(_, actual) => actual.parts.last().unwrap().ident.span(),
+            Error::UnrecognizedClosingSlot(_, actual, path) => actual
+                .as_ref()
+                .map(|actual| actual.span())
+                .unwrap_or_else(|| path.parts.last().unwrap().ident.span()),
         }
     }
 }
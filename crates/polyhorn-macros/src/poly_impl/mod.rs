@@ -33,6 +33,14 @@ fn build_element(open: TagOpen, remaining: &mut Vec<Token>) -> Result<Element, E
                     return Err(Error::UnrecognizedClosingTag(open.path, close.path));
                 }
 
+                if open.slot.as_ref().map(|slot| slot.to_string())
+                    != close.slot.as_ref().map(|slot| slot.to_string())
+                {
+                    return Err(Error::UnrecognizedClosingSlot(
+                        open.slot, close.slot, close.path,
+                    ));
+                }
+
                 return Ok(Element::Regular(RegularElement { open, children }));
             }
         }
@@ -106,6 +114,33 @@ impl Element {
 }
 
 impl RegularElement {
+    fn slot_as_tokenstream(&self, slot: &Ident, children: Vec<TokenStream>) -> TokenStream {
+        let error = if self.open.is_builtin {
+            Some(gen_error(
+                &format!("Slot `{}` cannot be a built-in.", slot),
+                slot.span(),
+                slot.span(),
+            ))
+        } else {
+            self.open.props.first().map(|prop| {
+                gen_error(
+                    &format!("Prop `{}` does not exist on slot: `{}`.", prop.name, slot),
+                    prop.name.span(),
+                    prop.name.span(),
+                )
+            })
+        };
+
+        let name = Literal::string(&slot.to_string());
+
+        quote! {{
+            #error
+            polyhorn::Element::slot(#name, vec![
+                #(#children),*
+            ])
+        }}
+    }
+
     pub fn as_tokenstream(&self) -> TokenStream {
         let path = &self.open.path;
 
@@ -114,6 +149,11 @@ impl RegularElement {
             .iter()
             .map(|child| child.as_tokenstream())
             .collect::<Vec<_>>();
+
+        if let Some(slot) = &self.open.slot {
+            return self.slot_as_tokenstream(slot, children);
+        }
+
         let children = quote! { polyhorn::Element::fragment(polyhorn::Key::from(polyhorn::hooks::use_id!()), vec![
             #(#children),*
         ]) };
@@ -177,6 +217,13 @@ impl RegularElement {
                     continue;
                 }
 
+                // Components receive their reference through a regular prop
+                // named `reference`, because `ref` is a reserved keyword.
+                let name = match prop.name == "ref" {
+                    true => Ident::new("reference", name.span()),
+                    false => name.clone(),
+                };
+
                 let value = value
                     .clone()
                     .unwrap_or(vec![Ident::new("true", name.span()).into()]);
@@ -221,3 +268,76 @@ pub fn poly(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
         Err(error) => gen_error(&error.message(), error.start(), error.end()).into(),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use quote::quote;
+
+    use super::parse;
+
+    /// Returns the expansion of the given input, or the message of the error
+    /// that the input is rejected with.
+    fn expand(input: proc_macro2::TokenStream) -> String {
+        match parse(input) {
+            Ok(element) => element.as_tokenstream().to_string(),
+            Err(error) => error.message(),
+        }
+    }
+
+    #[test]
+    fn test_component_ref() {
+        let expansion = expand(quote! {
+            <Input ref={reference} />
+        });
+
+        assert!(expansion.contains("Input { reference : { reference } . into () ,"));
+        assert!(!expansion.contains("ref :"));
+    }
+
+    #[test]
+    fn test_slot() {
+        let expansion = expand(quote! {
+            <Card>
+                <slot:header>"Header"</slot:header>
+            </Card>
+        });
+
+        assert!(expansion.starts_with("polyhorn :: Element :: new ("));
+        assert!(expansion.contains(
+            "polyhorn :: Element :: slot (\"header\" , vec ! [polyhorn :: Element :: string (\"Header\")])"
+        ));
+        assert!(!expansion.contains("compile_error"));
+    }
+
+    #[test]
+    fn test_slot_closing_tag() {
+        assert_eq!(
+            expand(quote! {
+                <Card>
+                    <slot:header></slot:footer>
+                </Card>
+            }),
+            "Expected `slot:header`, got `slot:footer` instead."
+        );
+    }
+
+    #[test]
+    fn test_slot_builtin() {
+        assert!(expand(quote! {
+            <Card>
+                <~slot:header></~slot:header>
+            </Card>
+        })
+        .contains("compile_error ! { \"Slot `header` cannot be a built-in.\" }"));
+    }
+
+    #[test]
+    fn test_slot_props() {
+        assert!(expand(quote! {
+            <Card>
+                <slot:header title="Header"></slot:header>
+            </Card>
+        })
+        .contains("compile_error ! { \"Prop `title` does not exist on slot: `header`.\" }"));
+    }
+}
@@ -65,6 +65,7 @@ fn parse_prop(input: &mut Peekable<impl Iterator<Item = TokenTree>>) -> Result<P
 pub struct TagOpen {
     pub is_builtin: bool,
     pub path: Path,
+    pub slot: Option<Ident>,
     pub props: Vec<Prop>,
     pub is_self_closing: bool,
 }
@@ -72,6 +73,7 @@ pub struct TagOpen {
 pub struct TagClose {
     pub is_builtin: bool,
     pub path: Path,
+    pub slot: Option<Ident>,
 }
 
 pub struct Colon2([Punct; 2]);
@@ -188,13 +190,29 @@ fn parse_args(
 }
 
 fn parse_path(input: &mut Peekable<impl Iterator<Item = TokenTree>>) -> Result<Path, Error> {
+    let ident = match input.next() {
+        Some(TokenTree::Ident(ident)) => ident,
+        token => return Err(Error::ExpectedIdentifier(token.unwrap())),
+    };
+
+    parse_path_from(ident, input)
+}
+
+fn parse_path_from(
+    first: Ident,
+    input: &mut Peekable<impl Iterator<Item = TokenTree>>,
+) -> Result<Path, Error> {
     let mut parts = vec![];
     let mut puncts = vec![];
+    let mut first = Some(first);
 
     'segments: loop {
-        let ident = match input.next() {
-            Some(TokenTree::Ident(ident)) => ident,
-            token => return Err(Error::ExpectedIdentifier(token.unwrap())),
+        let ident = match first.take() {
+            Some(ident) => ident,
+            None => match input.next() {
+                Some(TokenTree::Ident(ident)) => ident,
+                token => return Err(Error::ExpectedIdentifier(token.unwrap())),
+            },
         };
 
         let mut arguments = PathArguments::None;
@@ -274,6 +292,45 @@ fn parse_path(input: &mut Peekable<impl Iterator<Item = TokenTree>>) -> Result<P
     Ok(Path { parts, puncts })
 }
 
+/// Parses the name of a tag, which is either a path to a component or a named
+/// slot (e.g. `slot:header`).
+fn parse_tag_name(
+    input: &mut Peekable<impl Iterator<Item = TokenTree>>,
+) -> Result<(Path, Option<Ident>), Error> {
+    let ident = match input.next() {
+        Some(TokenTree::Ident(ident)) => ident,
+        token => return Err(Error::ExpectedIdentifier(token.unwrap())),
+    };
+
+    if ident == "slot" {
+        match input.peek() {
+            Some(TokenTree::Punct(punct))
+                if punct.as_char() == ':' && punct.spacing() == Spacing::Alone =>
+            {
+                input.next();
+
+                let name = match input.next() {
+                    Some(TokenTree::Ident(name)) => name,
+                    token => return Err(Error::ExpectedIdentifier(token.unwrap())),
+                };
+
+                let path = Path {
+                    parts: vec![PathSegment {
+                        ident,
+                        arguments: PathArguments::None,
+                    }],
+                    puncts: vec![],
+                };
+
+                return Ok((path, Some(name)));
+            }
+            _ => {}
+        }
+    }
+
+    Ok((parse_path_from(ident, input)?, None))
+}
+
 pub fn parse_token(input: &mut Peekable<impl Iterator<Item = TokenTree>>) -> Result<Token, Error> {
     match input.next() {
         Some(TokenTree::Punct(punct)) if punct.as_char() == '<' => {}
@@ -301,12 +358,16 @@ pub fn parse_token(input: &mut Peekable<impl Iterator<Item = TokenTree>>) -> Res
         }
     }
 
-    let path = parse_path(input)?;
+    let (path, slot) = parse_tag_name(input)?;
 
     if is_closing {
         match input.next() {
             Some(TokenTree::Punct(punct)) if punct.as_char() == '>' => {
-                return Ok(Token::TagClose(TagClose { is_builtin, path }))
+                return Ok(Token::TagClose(TagClose {
+                    is_builtin,
+                    path,
+                    slot,
+                }))
             }
             token => return Err(Error::ExpectedClosingAngle(token.unwrap())),
         }
@@ -339,6 +400,7 @@ pub fn parse_token(input: &mut Peekable<impl Iterator<Item = TokenTree>>) -> Res
     Ok(Token::TagOpen(TagOpen {
         is_builtin,
         path,
+        slot,
         props,
         is_self_closing,
     }))
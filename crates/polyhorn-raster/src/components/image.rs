@@ -0,0 +1,48 @@
+use polyhorn_ui::assets::ImageSource;
+use polyhorn_ui::geometry::{Dimension, Size};
+use polyhorn_ui::queries::ImageQuery;
+use std::sync::Arc;
+use tiny_skia::Pixmap;
+
+use crate::prelude::*;
+use crate::raw::Builtin;
+use crate::Key;
+
+impl Component for Image {
+    fn render(&self, manager: &mut Manager) -> Element {
+        // Only PNG images are decoded. Assets are vector graphics, which are
+        // not rasterized: they take up their intrinsic size but are drawn as
+        // an empty view (just like placeholders).
+        let (bitmap, size) = match &self.source {
+            ImageSource::Asset(asset) => (None, Some(Size::new(asset.width(), asset.height()))),
+            ImageSource::Bytes(bytes) => match Pixmap::decode_png(bytes) {
+                Ok(bitmap) => {
+                    let size = Size::new(bitmap.width() as f32, bitmap.height() as f32);
+                    (Some(Arc::new(bitmap)), Some(size))
+                }
+                Err(_) => (None, None),
+            },
+            ImageSource::Placeholder(size) => (None, Some(*size)),
+        };
+
+        // Images are sized to their intrinsic size unless a size is given.
+        let mut style = self.style;
+
+        if let Some(size) = size {
+            if let Dimension::Auto | Dimension::Undefined = style.view.size.width {
+                style.view.size.width = Dimension::Points(size.width);
+            }
+
+            if let Dimension::Auto | Dimension::Undefined = style.view.size.height {
+                style.view.size.height = Dimension::Points(size.height);
+            }
+        }
+
+        Element::builtin(
+            Key::new(()),
+            Builtin::Image(style, bitmap),
+            manager.children(),
+            None,
+        )
+    }
+}
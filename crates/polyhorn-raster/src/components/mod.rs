@@ -0,0 +1,9 @@
+//! Raster implementations for Polyhorn UI components.
+
+mod image;
+mod scrollable;
+mod text;
+mod view;
+mod window;
+
+pub use view::View;
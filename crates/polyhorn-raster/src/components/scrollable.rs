@@ -0,0 +1,48 @@
+use polyhorn_ui::geometry::{Dimension, Size};
+use polyhorn_ui::styles::{FlexDirection, Overflow, Position, Relative, ViewStyle};
+
+use crate::prelude::*;
+use crate::raw::Builtin;
+use crate::Key;
+
+impl Component for Scrollable {
+    fn render(&self, manager: &mut Manager) -> Element {
+        // Scrollables are painted at their initial scroll offset, which means
+        // that they simply clip their content. The content fills at least the
+        // scrollable and is never shrunk along the scroll direction. Scroll
+        // padding becomes padding of the content. Scroll bars are not painted.
+        let style = ViewStyle {
+            flex_direction: match self.direction {
+                ScrollDirection::Horizontal => FlexDirection::Row,
+                ScrollDirection::Vertical | ScrollDirection::Both => FlexDirection::Column,
+            },
+            overflow: Overflow::Scroll,
+            ..self.style.view
+        };
+
+        let content_style = ViewStyle {
+            position: Position::Relative(Relative {
+                flex_shrink: 0.0,
+                ..Default::default()
+            }),
+            min_size: Size {
+                width: Dimension::Percentage(1.0),
+                height: Dimension::Percentage(1.0),
+            },
+            padding: self.style.scrollable.scroll_padding,
+            ..Default::default()
+        };
+
+        Element::builtin(
+            Key::new(()),
+            Builtin::View(style),
+            Element::builtin(
+                Key::new(()),
+                Builtin::View(content_style),
+                manager.children(),
+                None,
+            ),
+            None,
+        )
+    }
+}
@@ -0,0 +1,19 @@
+use polyhorn_ui::text::AttributedText;
+
+use crate::prelude::*;
+use crate::raw::Builtin;
+use crate::Key;
+
+impl Component for Text {
+    fn render(&self, manager: &mut Manager) -> Element {
+        // Rendered pixmaps can't be pressed, so press listeners are dropped.
+        let text = AttributedText::new(self, &manager.children());
+
+        Element::builtin(
+            Key::new(()),
+            Builtin::Text(text.without_listeners()),
+            Element::fragment(Key::new(()), vec![]),
+            None,
+        )
+    }
+}
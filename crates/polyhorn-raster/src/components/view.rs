@@ -0,0 +1,90 @@
+use polyhorn_ui::geometry::Size;
+use polyhorn_ui::layout::LayoutGuide;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use crate::prelude::*;
+use crate::raw::{Builtin, Platform};
+use crate::{Component, Key};
+
+/// Rasterized user interfaces are never animated, so this type can't be
+/// constructed.
+pub enum AnimationHandle {}
+
+impl std::future::Future for AnimationHandle {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Self::Output> {
+        match *self {}
+    }
+}
+
+impl polyhorn_ui::animation::AnimationHandle for AnimationHandle {}
+
+/// Rasterized user interfaces are never animated, so this type can't be
+/// constructed.
+pub enum Animator {}
+
+impl polyhorn_ui::animation::Animator for Animator {
+    type AnimationHandle = AnimationHandle;
+
+    fn start(&mut self, _animation: polyhorn_ui::animation::Animation) -> Self::AnimationHandle {
+        match *self {}
+    }
+}
+
+/// Views that are rasterized can't be controlled imperatively, so this
+/// type can't be constructed.
+pub enum ViewHandle {}
+
+impl polyhorn_ui::animation::Animatable for ViewHandle {
+    type Animator = Animator;
+    type CommandBuffer = ();
+
+    fn animate<F>(&mut self, _animations: F)
+    where
+        F: FnOnce(&mut Self::Animator) + Send + 'static,
+    {
+        match *self {}
+    }
+
+    fn animate_with_buffer<F>(&mut self, _buffer: &mut Self::CommandBuffer, _animations: F)
+    where
+        F: FnOnce(&mut Self::Animator) + Send + 'static,
+    {
+        match *self {}
+    }
+}
+
+impl polyhorn_ui::handles::ViewHandle for ViewHandle {
+    fn layout_guide(&self) -> LayoutGuide<f32> {
+        match *self {}
+    }
+
+    fn size<F>(&self, _callback: F)
+    where
+        F: FnOnce(Size<f32>) + Send + 'static,
+    {
+        match *self {}
+    }
+
+    fn size_with_buffer<F>(&self, _buffer: &mut Self::CommandBuffer, _callback: F)
+    where
+        F: FnOnce(Size<f32>) + Send + 'static,
+    {
+        match *self {}
+    }
+}
+
+pub type View = polyhorn_ui::components::View<Platform, ViewHandle>;
+
+impl Component for View {
+    fn render(&self, manager: &mut Manager) -> Element {
+        Element::builtin(
+            Key::new(()),
+            Builtin::View(self.style),
+            manager.children(),
+            None,
+        )
+    }
+}
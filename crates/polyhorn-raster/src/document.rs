@@ -0,0 +1,122 @@
+use polyhorn_core::{EventLoop, Renderer};
+use polyhorn_ui::geometry::{Dimension, Size};
+use polyhorn_ui::layout::{LayoutNode, LayoutTree};
+use polyhorn_ui::styles::ViewStyle;
+use std::sync::{Arc, RwLock};
+use tiny_skia::Pixmap;
+
+use crate::raw::{Compositor, Environment, Fonts, Node, Painter, Platform};
+use crate::Element;
+
+/// Renders the given element once into a transparent pixmap of the given size
+/// (in pixels), using the DejaVu fonts that are installed on this system.
+/// Effects are not run.
+pub fn render_to_pixmap(element: Element, size: Size<u32>) -> Pixmap {
+    render_to_pixmap_with_fonts(element, size, Fonts::system())
+}
+
+/// Renders the given element once into a transparent pixmap of the given size
+/// (in pixels), using the given fonts. Effects are not run.
+pub fn render_to_pixmap_with_fonts(element: Element, size: Size<u32>, fonts: Fonts) -> Pixmap {
+    let layout_tree = Arc::new(RwLock::new(LayoutTree::new()));
+
+    // The root of the layout tree has the size of the viewport.
+    let layout = LayoutNode::new(layout_tree.clone());
+    layout.set_style(ViewStyle {
+        size: Size::new(
+            Dimension::Points(size.width as f32),
+            Dimension::Points(size.height as f32),
+        ),
+        ..Default::default()
+    });
+
+    layout_tree.write().unwrap().roots_mut().push(layout.node());
+
+    let root = Node::new(layout, None);
+    let fonts = Arc::new(fonts);
+
+    let mut compositor = Compositor::new(Environment::new(layout_tree, fonts.clone()));
+    let id = compositor.track(root.clone());
+
+    let executor = <Platform as polyhorn_core::Platform>::default_executor();
+    let (evloop, _handler) = EventLoop::new(executor);
+    let renderer = Renderer::new(compositor, evloop);
+    let _instance = renderer.render(element, id);
+
+    let mut pixmap = Pixmap::new(size.width.max(1), size.height.max(1)).unwrap();
+    Painter::new(&fonts).paint(&root, &mut pixmap);
+    pixmap
+}
+
+/// Renders the given element once and returns a PNG of the given size (in
+/// pixels). Effects are not run.
+pub fn render_to_png(element: Element, size: Size<u32>) -> Vec<u8> {
+    render_to_pixmap(element, size)
+        .encode_png()
+        .expect("Encoding a pixmap as PNG should never fail.")
+}
+
+#[cfg(test)]
+mod tests {
+    use polyhorn_ui::color::Color;
+    use polyhorn_ui::geometry::{Dimension, Size};
+    use polyhorn_ui::styles::{Overflow, Position, Relative};
+
+    use super::{render_to_pixmap, render_to_png};
+    use crate::prelude::*;
+    use crate::{Key, Pixmap};
+
+    fn pixel(pixmap: &Pixmap, x: u32, y: u32) -> (u8, u8, u8, u8) {
+        let pixel = pixmap.pixel(x, y).unwrap().demultiply();
+        (pixel.red(), pixel.green(), pixel.blue(), pixel.alpha())
+    }
+
+    #[test]
+    fn test_render_to_pixmap() {
+        let mut window = Window::default();
+        window.style.background_color = Color::rgb(255, 255, 255);
+        window.style.padding.vertical.top = Dimension::Points(10.0);
+        window.style.overflow = Overflow::Hidden;
+
+        let mut red = View::default();
+        red.style.size.height = Dimension::Points(20.0);
+        red.style.background_color = Color::rgb(255, 0, 0);
+
+        let mut green = View::default();
+        green.style.position = Position::Relative(Relative {
+            flex_grow: 1.0,
+            ..Default::default()
+        });
+        green.style.background_color = Color::rgb(0, 128, 0);
+        green.style.opacity = 0.5;
+
+        let element = Element::new(
+            Key::new(()),
+            window.into(),
+            Element::fragment(
+                Key::new(()),
+                vec![
+                    Element::new(Key::new(0), red.into(), Element::empty()),
+                    Element::new(Key::new(1), green.into(), Element::empty()),
+                ],
+            ),
+        );
+
+        let pixmap = render_to_pixmap(element, Size::new(40, 60));
+
+        assert_eq!(pixel(&pixmap, 20, 5), (255, 255, 255, 255));
+        assert_eq!(pixel(&pixmap, 20, 15), (255, 0, 0, 255));
+        assert_eq!(pixel(&pixmap, 20, 45), (128, 192, 128, 255));
+    }
+
+    #[test]
+    fn test_render_to_png() {
+        let png = render_to_png(
+            Element::new(Key::new(()), Window::default().into(), Element::empty()),
+            Size::new(2, 2),
+        );
+
+        assert!(png.starts_with(&[0x89, b'P', b'N', b'G']));
+        assert_eq!(Pixmap::decode_png(&png).unwrap().width(), 2);
+    }
+}
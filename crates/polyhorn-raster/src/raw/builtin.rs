@@ -0,0 +1,52 @@
+use polyhorn_ui::layout::LayoutNode;
+use polyhorn_ui::styles::{ImageViewStyle, ViewStyle};
+use polyhorn_ui::text::{measure_func, AttributedText};
+use std::any::Any;
+use std::sync::Arc;
+use tiny_skia::Pixmap;
+
+use super::{Environment, Node, Platform};
+
+#[derive(Clone, Debug)]
+pub enum Builtin {
+    /// Image with an optional decoded bitmap. Images without a bitmap (e.g.
+    /// placeholders) are drawn as an empty view.
+    Image(ImageViewStyle, Option<Arc<Pixmap>>),
+
+    /// Text that consists of the given runs.
+    Text(AttributedText<()>),
+
+    View(ViewStyle),
+}
+
+impl polyhorn_core::Builtin<Platform> for Builtin {
+    fn instantiate(&self, _parent: &mut Node, environment: &mut Environment) -> Node {
+        let layout = match self {
+            Builtin::Text(..) => LayoutNode::leaf(environment.layout_tree().clone()),
+            _ => LayoutNode::new(environment.layout_tree().clone()),
+        };
+
+        let mut node = Node::new(layout, None);
+        self.update(&mut node, environment);
+        node
+    }
+
+    fn update(&self, node: &mut Node, environment: &mut Environment) {
+        let layout = node.layout();
+
+        match self {
+            Builtin::Image(style, _) => layout.set_style(style.view),
+            Builtin::Text(text) => {
+                let fonts = environment.fonts().clone();
+                layout.set_measure(measure_func(fonts, text.clone()));
+            }
+            Builtin::View(style) => layout.set_style(*style),
+        }
+
+        node.set_builtin(self.clone());
+    }
+
+    fn as_any(&self) -> Option<&dyn Any> {
+        Some(self)
+    }
+}
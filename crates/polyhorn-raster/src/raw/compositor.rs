@@ -0,0 +1,101 @@
+use polyhorn_core::{Command, Composition};
+use serde::{Deserialize, Serialize};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use super::{Environment, Node, Platform};
+
+/// Concrete implementation of a compositor that is responsible for adding and
+/// removing nodes and recomputing their layout. Command buffers are committed
+/// synchronously on the thread that renders the elements.
+#[derive(Clone)]
+pub struct Compositor {
+    environment: Rc<RefCell<Environment>>,
+    buffer: Rc<RefCell<Composition<Platform>>>,
+    counter: Rc<RefCell<usize>>,
+}
+
+impl Compositor {
+    /// Returns a new compositor without any containers that uses the given
+    /// environment.
+    pub fn new(environment: Environment) -> Compositor {
+        Compositor {
+            environment: Rc::new(RefCell::new(environment)),
+            buffer: Default::default(),
+            counter: Default::default(),
+        }
+    }
+
+    fn next_id(&self) -> ContainerID {
+        let mut counter = self.counter.borrow_mut();
+        let id = ContainerID(*counter);
+        *counter += 1;
+        id
+    }
+
+    pub(crate) fn track(&mut self, container: Node) -> ContainerID {
+        let id = self.next_id();
+        self.buffer.borrow_mut().insert(id, container);
+        id
+    }
+}
+
+impl polyhorn_core::Compositor<Platform> for Compositor {
+    fn buffer(&self) -> CommandBuffer {
+        CommandBuffer {
+            compositor: self.clone(),
+            commands: vec![],
+        }
+    }
+}
+
+/// An opaque ID for containers that can be shared between threads.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub struct ContainerID(usize);
+
+/// Concrete implementation of a command buffer that can buffer commands before
+/// committing them to the compositor.
+pub struct CommandBuffer {
+    compositor: Compositor,
+    commands: Vec<Command<Platform>>,
+}
+
+impl polyhorn_core::CommandBuffer<Platform> for CommandBuffer {
+    fn mount<F>(&mut self, parent_id: ContainerID, initializer: F) -> ContainerID
+    where
+        F: FnOnce(&mut Node, &mut Environment) -> Node + Send + 'static,
+    {
+        let id = self.compositor.next_id();
+        self.commands
+            .push(Command::Mount(id, parent_id, Box::new(initializer)));
+        id
+    }
+
+    fn mutate<F>(&mut self, ids: &[ContainerID], mutator: F)
+    where
+        F: FnOnce(&mut [&mut Node], &mut Environment) + Send + 'static,
+    {
+        self.commands
+            .push(Command::Mutate(ids.to_owned(), Box::new(mutator)));
+    }
+
+    fn unmount(&mut self, id: ContainerID) {
+        self.commands.push(Command::Unmount(id));
+    }
+
+    fn layout(&mut self) {
+        let environment = self.compositor.environment.borrow();
+        environment.layout_tree().write().unwrap().recompute_roots();
+    }
+
+    fn commit(self) {
+        let mut environment = self.compositor.environment.borrow_mut();
+        let mut buffer = self.compositor.buffer.borrow_mut();
+
+        for command in self.commands {
+            buffer.process(&mut environment, command);
+        }
+
+        environment.layout_tree().write().unwrap().recompute_roots();
+    }
+}
@@ -0,0 +1,29 @@
+use polyhorn_ui::layout::LayoutTree;
+use std::sync::{Arc, RwLock};
+
+use super::Fonts;
+
+/// Opaque type that wraps the shared layout tree and the fonts that are used
+/// to measure and paint text.
+#[derive(Clone)]
+pub struct Environment {
+    layout_tree: Arc<RwLock<LayoutTree>>,
+    fonts: Arc<Fonts>,
+}
+
+impl Environment {
+    /// Returns a new environment with the given layout tree and fonts.
+    pub fn new(layout_tree: Arc<RwLock<LayoutTree>>, fonts: Arc<Fonts>) -> Environment {
+        Environment { layout_tree, fonts }
+    }
+
+    /// Returns a reference to the shared layout tree.
+    pub fn layout_tree(&self) -> &Arc<RwLock<LayoutTree>> {
+        &self.layout_tree
+    }
+
+    /// Returns a reference to the fonts that are available to text.
+    pub fn fonts(&self) -> &Arc<Fonts> {
+        &self.fonts
+    }
+}
@@ -0,0 +1,22 @@
+//! Platform types that lay out Polyhorn builtins and paint them into a pixmap.
+
+mod builtin;
+mod component;
+mod compositor;
+mod environment;
+mod node;
+mod paint;
+mod paragraph;
+mod payload;
+mod platform;
+
+pub use builtin::Builtin;
+pub use component::{Component, OpaqueComponent};
+pub use compositor::{CommandBuffer, Compositor, ContainerID};
+pub use environment::Environment;
+pub use node::Node;
+pub use paint::Painter;
+pub use paragraph::outlines;
+pub use payload::Payload;
+pub use platform::Platform;
+pub use polyhorn_ui::text::{Fonts, InvalidFont, Line, Paragraph};
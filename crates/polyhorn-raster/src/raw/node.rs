@@ -0,0 +1,110 @@
+use polyhorn_ui::layout::{Algorithm, LayoutNode};
+use std::cell::RefCell;
+use std::rc::{Rc, Weak};
+
+use super::{Builtin, Environment, Platform};
+
+struct Inner {
+    layout: LayoutNode,
+    builtin: Option<Builtin>,
+    children: Vec<Node>,
+    parent: Weak<RefCell<Inner>>,
+}
+
+/// Concrete implementation of a raster-specific container. A node is a
+/// reference counted handle to a node in the layout tree along with the
+/// builtin that is painted within its frame. All clones of a node refer to the
+/// same node.
+#[derive(Clone)]
+pub struct Node(Rc<RefCell<Inner>>);
+
+impl Node {
+    /// Returns a new node with the given layout that paints the given builtin
+    /// (if any).
+    pub fn new(layout: LayoutNode, builtin: Option<Builtin>) -> Node {
+        Node(Rc::new(RefCell::new(Inner {
+            layout,
+            builtin,
+            children: vec![],
+            parent: Weak::new(),
+        })))
+    }
+
+    /// Returns the layout of this node.
+    pub fn layout(&self) -> LayoutNode {
+        self.0.borrow().layout.clone()
+    }
+
+    /// Returns the builtin that is painted within the frame of this node.
+    pub fn builtin(&self) -> Option<Builtin> {
+        self.0.borrow().builtin.clone()
+    }
+
+    /// Replaces the builtin that is painted within the frame of this node.
+    pub fn set_builtin(&mut self, builtin: Builtin) {
+        self.0.borrow_mut().builtin = Some(builtin);
+    }
+
+    /// Returns the nodes that are currently mounted onto this node.
+    pub fn children(&self) -> Vec<Node> {
+        self.0.borrow().children.clone()
+    }
+}
+
+impl polyhorn_core::Container<Platform> for Node {
+    fn mount(&mut self, child: &mut Node, _environment: &mut Environment) {
+        let (parent, layout) = (self.layout(), child.layout());
+
+        parent
+            .layouter()
+            .write()
+            .unwrap()
+            .add_child(parent.node(), layout.node());
+
+        child.0.borrow_mut().parent = Rc::downgrade(&self.0);
+        self.0.borrow_mut().children.push(child.clone());
+    }
+
+    fn unmount(&mut self) {
+        let parent = std::mem::take(&mut self.0.borrow_mut().parent);
+
+        if let Some(parent) = parent.upgrade() {
+            parent
+                .borrow_mut()
+                .children
+                .retain(|child| !Rc::ptr_eq(&child.0, &self.0));
+        }
+
+        let layout = self.layout();
+        layout.layouter().write().unwrap().remove(layout.node());
+    }
+
+    fn reorder(&mut self, children: &mut [&mut Node], _environment: &mut Environment) {
+        let mut inner = self.0.borrow_mut();
+
+        // We only move the given children among the positions that they
+        // already occupy.
+        let slots = inner
+            .children
+            .iter()
+            .enumerate()
+            .filter(|(_, child)| children.iter().any(|other| Rc::ptr_eq(&child.0, &other.0)))
+            .map(|(index, _)| index)
+            .collect::<Vec<_>>();
+
+        for (index, child) in slots.into_iter().zip(children.iter()) {
+            inner.children[index] = Node::clone(child);
+        }
+
+        // The layout tree can only append children, so we re-add all children
+        // in their new order.
+        let mut layouter = inner.layout.layouter().write().unwrap();
+        let parent = inner.layout.node();
+
+        for child in &inner.children {
+            let child = child.layout().node();
+            layouter.flexbox_mut().remove_child(parent, child);
+            layouter.add_child(parent, child);
+        }
+    }
+}
@@ -0,0 +1,1110 @@
+use polyhorn_ui::color::Color;
+use polyhorn_ui::geometry::{ByEdge, Dimension, Size};
+use polyhorn_ui::layout::LayoutDirection;
+use polyhorn_ui::styles::{
+    BorderStyle, BoxShadow, Gradient, GradientGeometry, ImageStyle, Inherited, ObjectFit, Overflow,
+    Transform as StyleTransform, ViewStyle, Visibility,
+};
+use tiny_skia::{
+    BlendMode, ClipMask, ColorU8, FillRule, FilterQuality, GradientStop, LinearGradient, Paint,
+    Path, PathBuilder, Pattern, Pixmap, PixmapPaint, Point, RadialGradient, Rect, Shader,
+    SpreadMode, Stroke, StrokeDash, Transform,
+};
+
+use super::{outlines, Builtin, Fonts, Node, Paragraph};
+
+/// Distance between the start of a circular arc and the control points of the
+/// cubic Bézier curve that approximates it, relative to its radius.
+const KAPPA: f32 = 0.552_284_8;
+
+/// Insets of each side of a box in the order top, right, bottom and left.
+type Insets = [f32; 4];
+
+/// Horizontal and vertical radii of each corner of a box in the order top
+/// left, top right, bottom right and bottom left.
+type Radii = [(f32, f32); 4];
+
+/// Paints trees of nodes into a pixmap. Perspective is not supported: only the
+/// 2D affine part of 3D transforms is applied. Dashed and dotted borders are
+/// painted solid unless all sides have the same width, style and color. The
+/// blur of box shadows is approximated with successive box blurs.
+pub struct Painter<'a> {
+    fonts: &'a Fonts,
+}
+
+impl<'a> Painter<'a> {
+    /// Returns a new painter that paints text with the given fonts.
+    pub fn new(fonts: &'a Fonts) -> Painter<'a> {
+        Painter { fonts }
+    }
+
+    /// Paints the given node and its descendants at their current layout into
+    /// the given pixmap.
+    pub fn paint(&self, node: &Node, pixmap: &mut Pixmap) {
+        self.paint_node(
+            node,
+            pixmap,
+            Transform::identity(),
+            None,
+            LayoutDirection::LTR,
+        );
+    }
+
+    fn paint_node(
+        &self,
+        node: &Node,
+        pixmap: &mut Pixmap,
+        transform: Transform,
+        clip: Option<&ClipMask>,
+        direction: LayoutDirection,
+    ) {
+        let layout = node.layout().current();
+        let transform = transform.pre_translate(layout.origin.x, layout.origin.y);
+
+        let builtin = match node.builtin() {
+            Some(builtin) => builtin,
+            None => {
+                // The root node doesn't paint anything itself.
+                for child in node.children() {
+                    self.paint_node(&child, pixmap, transform, clip, direction);
+                }
+
+                return;
+            }
+        };
+
+        let style = view_style(&builtin);
+
+        if style.visibility == Visibility::Hidden || style.opacity <= 0.0 {
+            return;
+        }
+
+        let direction = match style.direction {
+            Inherited::Inherited => direction,
+            Inherited::Specified(direction) => direction,
+        };
+
+        let transform = transform.pre_concat(local_transform(&style, layout.size));
+
+        // Translucent views are painted into a separate layer first, so that
+        // overlapping descendants don't show through each other.
+        if style.opacity < 1.0 {
+            let mut layer = match Pixmap::new(pixmap.width(), pixmap.height()) {
+                Some(layer) => layer,
+                None => return,
+            };
+
+            self.paint_contents(
+                node,
+                &builtin,
+                layout.size,
+                &mut layer,
+                transform,
+                clip,
+                direction,
+            );
+
+            pixmap.draw_pixmap(
+                0,
+                0,
+                layer.as_ref(),
+                &PixmapPaint {
+                    opacity: style.opacity,
+                    ..Default::default()
+                },
+                Transform::identity(),
+                None,
+            );
+        } else {
+            self.paint_contents(
+                node,
+                &builtin,
+                layout.size,
+                pixmap,
+                transform,
+                clip,
+                direction,
+            );
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn paint_contents(
+        &self,
+        node: &Node,
+        builtin: &Builtin,
+        size: Size<f32>,
+        pixmap: &mut Pixmap,
+        transform: Transform,
+        clip: Option<&ClipMask>,
+        direction: LayoutDirection,
+    ) {
+        self.paint_builtin(builtin, size, pixmap, transform, clip, direction);
+
+        let style = view_style(builtin);
+
+        // Descendants of views that don't have a visible overflow are clipped
+        // to the padding box of those views.
+        let mask = match style.overflow {
+            Overflow::Visible => None,
+            Overflow::Hidden | Overflow::Scroll => {
+                let borders = border_widths(&style, direction);
+                let radii = inset_radii(radii(&style, size, direction), borders);
+                let path = rounded_rect(inset(size, borders), radii);
+
+                match path.and_then(|path| intersect(clip, &path, transform, pixmap)) {
+                    Some(mask) => Some(mask),
+                    None => return,
+                }
+            }
+        };
+
+        for child in node.children() {
+            self.paint_node(&child, pixmap, transform, mask.as_ref().or(clip), direction);
+        }
+    }
+
+    /// Paints the background, border and contents (i.e. bitmap or text) of
+    /// the given builtin within a box of the given size.
+    fn paint_builtin(
+        &self,
+        builtin: &Builtin,
+        size: Size<f32>,
+        pixmap: &mut Pixmap,
+        transform: Transform,
+        clip: Option<&ClipMask>,
+        direction: LayoutDirection,
+    ) {
+        let style = view_style(builtin);
+        let radii = radii(&style, size, direction);
+        let borders = border_widths(&style, direction);
+
+        // The first shadow is painted on top, so shadows are painted in
+        // reverse order. Outer shadows are painted below the background and
+        // inset shadows are painted on top of it.
+        for shadow in style.box_shadow.iter().rev().filter(|shadow| !shadow.inset) {
+            paint_box_shadow(shadow, size, radii, borders, pixmap, transform, clip);
+        }
+
+        if let Some(path) = rounded_rect(Rect::from_xywh(0.0, 0.0, size.width, size.height), radii)
+        {
+            if style.background_color.alpha() > 0.0 {
+                let paint = solid(style.background_color);
+                pixmap.fill_path(&path, &paint, FillRule::Winding, transform, clip);
+            }
+
+            if let Some(shader) = style
+                .background_image
+                .and_then(|gradient| gradient_shader(&gradient, size))
+            {
+                let paint = Paint {
+                    shader,
+                    anti_alias: true,
+                    ..Default::default()
+                };
+
+                pixmap.fill_path(&path, &paint, FillRule::Winding, transform, clip);
+            }
+        }
+
+        for shadow in style.box_shadow.iter().rev().filter(|shadow| shadow.inset) {
+            paint_box_shadow(shadow, size, radii, borders, pixmap, transform, clip);
+        }
+
+        paint_border(&style, size, radii, pixmap, transform, clip, direction);
+
+        let padding = padding(&style, size, direction);
+        let content = inset(
+            size,
+            [
+                borders[0] + padding[0],
+                borders[1] + padding[1],
+                borders[2] + padding[2],
+                borders[3] + padding[3],
+            ],
+        );
+
+        let content = match content {
+            Some(content) => content,
+            None => return,
+        };
+
+        match builtin {
+            Builtin::Image(style, Some(bitmap)) => {
+                paint_image(bitmap, &style.image, content, pixmap, transform, clip)
+            }
+            Builtin::Text(text) => {
+                let paragraph = Paragraph::new(self.fonts, text, Some(content.width()));
+                let transform = transform.pre_translate(content.x(), content.y());
+
+                for (color, path) in outlines(&paragraph, content.width()) {
+                    pixmap.fill_path(&path, &solid(color), FillRule::Winding, transform, clip);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Returns the view style of the given builtin. Text doesn't have a view
+/// style of its own, so it uses the default.
+fn view_style(builtin: &Builtin) -> ViewStyle {
+    match builtin {
+        Builtin::Image(style, _) => style.view,
+        Builtin::Text(..) => ViewStyle::default(),
+        Builtin::View(style) => *style,
+    }
+}
+
+/// Returns the given color in sRGB.
+fn skia_color(color: Color) -> tiny_skia::Color {
+    let srgb = color.to_srgb();
+    let channel = |value: f32| value.clamp(0.0, 1.0);
+
+    tiny_skia::Color::from_rgba(
+        channel(srgb.red),
+        channel(srgb.green),
+        channel(srgb.blue),
+        channel(srgb.alpha),
+    )
+    .unwrap_or(tiny_skia::Color::TRANSPARENT)
+}
+
+/// Returns a paint with the given solid color.
+fn solid(color: Color) -> Paint<'static> {
+    let mut paint = Paint {
+        anti_alias: true,
+        ..Default::default()
+    };
+
+    paint.set_color(skia_color(color));
+    paint
+}
+
+/// Returns a shader that paints the given gradient within a box of the given
+/// size. Returns `None` if the gradient is degenerate (e.g. has no stops).
+fn gradient_shader(gradient: &Gradient, size: Size<f32>) -> Option<Shader<'static>> {
+    let resolved = gradient.resolve(size);
+    let stops = resolved
+        .stops
+        .iter()
+        .map(|&(offset, color)| GradientStop::new(offset, skia_color(color)))
+        .collect::<Vec<_>>();
+
+    match resolved.geometry {
+        GradientGeometry::Linear { start, end } => LinearGradient::new(
+            Point::from_xy(start.x, start.y),
+            Point::from_xy(end.x, end.y),
+            stops,
+            SpreadMode::Pad,
+            Transform::identity(),
+        ),
+        GradientGeometry::Radial { center, radius } => {
+            // Elliptical gradients are circular gradients that are scaled
+            // vertically around their center.
+            let scale = radius.height / radius.width;
+            let center = Point::from_xy(center.x, center.y);
+
+            RadialGradient::new(
+                center,
+                center,
+                radius.width,
+                stops,
+                SpreadMode::Pad,
+                Transform::from_translate(0.0, center.y * (1.0 - scale)).pre_scale(1.0, scale),
+            )
+        }
+    }
+}
+
+/// Paints the given shadow of a box of the given size with the given corner
+/// radii and border widths. Outer shadows are only painted outside the box
+/// and inset shadows are only painted inside its padding box.
+fn paint_box_shadow(
+    shadow: &BoxShadow,
+    size: Size<f32>,
+    radii: Radii,
+    borders: Insets,
+    pixmap: &mut Pixmap,
+    transform: Transform,
+    clip: Option<&ClipMask>,
+) {
+    let mut layer = match Pixmap::new(pixmap.width(), pixmap.height()) {
+        Some(layer) => layer,
+        None => return,
+    };
+
+    let spread = shadow.spread_radius;
+    let paint = solid(shadow.color);
+    let clear = Paint {
+        blend_mode: BlendMode::Clear,
+        anti_alias: true,
+        ..Default::default()
+    };
+
+    // Radii follow the spread of the shadow.
+    let spread_radii = |radii: Radii, spread: f32| {
+        let mut result = radii;
+
+        for radius in result.iter_mut() {
+            if radius.0 > 0.0 && radius.1 > 0.0 {
+                *radius = ((radius.0 + spread).max(0.0), (radius.1 + spread).max(0.0));
+            }
+        }
+
+        result
+    };
+
+    let bounds = Rect::from_xywh(0.0, 0.0, size.width, size.height);
+    let offset = |rect: Rect, spread: f32| {
+        Rect::from_ltrb(
+            rect.left() + shadow.offset_x - spread,
+            rect.top() + shadow.offset_y - spread,
+            rect.right() + shadow.offset_x + spread,
+            rect.bottom() + shadow.offset_y + spread,
+        )
+    };
+
+    let mask = match shadow.inset {
+        false => {
+            if let Some(path) = rounded_rect(
+                bounds.and_then(|bounds| offset(bounds, spread)),
+                spread_radii(radii, spread),
+            ) {
+                layer.fill_path(&path, &paint, FillRule::Winding, transform, None);
+            }
+
+            blur(&mut layer, shadow.blur_radius / 2.0 * scale(transform));
+
+            // The shadow is not painted below the box itself.
+            if let Some(path) = rounded_rect(bounds, radii) {
+                layer.fill_path(&path, &clear, FillRule::Winding, transform, None);
+            }
+
+            None
+        }
+        true => {
+            let padding_box = match inset(size, borders) {
+                Some(padding_box) => padding_box,
+                None => return,
+            };
+            let padding_radii = inset_radii(radii, borders);
+
+            // The shadow is painted around a hole that is offset and shrunk by
+            // the spread. It extends far enough beyond the padding box to
+            // remain opaque at its edges after blurring.
+            let margin = shadow.blur_radius + shadow.offset_x.abs() + shadow.offset_y.abs();
+            let mut builder = PathBuilder::new();
+
+            if let Some(rect) = Rect::from_ltrb(
+                padding_box.left() - margin,
+                padding_box.top() - margin,
+                padding_box.right() + margin,
+                padding_box.bottom() + margin,
+            ) {
+                builder.push_rect(rect.x(), rect.y(), rect.width(), rect.height());
+            }
+
+            if let Some(hole) = offset(padding_box, -spread) {
+                push_rounded_rect(&mut builder, hole, spread_radii(padding_radii, -spread));
+            }
+
+            if let Some(path) = builder.finish() {
+                layer.fill_path(&path, &paint, FillRule::EvenOdd, transform, None);
+            }
+
+            blur(&mut layer, shadow.blur_radius / 2.0 * scale(transform));
+
+            match rounded_rect(Some(padding_box), padding_radii)
+                .and_then(|path| intersect(clip, &path, transform, pixmap))
+            {
+                Some(mask) => Some(mask),
+                None => return,
+            }
+        }
+    };
+
+    pixmap.draw_pixmap(
+        0,
+        0,
+        layer.as_ref(),
+        &PixmapPaint::default(),
+        Transform::identity(),
+        mask.as_ref().or(clip),
+    );
+}
+
+/// Returns the factor by which the given transform scales areas, in one
+/// dimension.
+fn scale(transform: Transform) -> f32 {
+    (transform.sx * transform.sy - transform.kx * transform.ky)
+        .abs()
+        .sqrt()
+}
+
+/// Approximates a gaussian blur with the given standard deviation (in pixels)
+/// by applying three successive box blurs in both directions.
+fn blur(pixmap: &mut Pixmap, sigma: f32) {
+    let size = (sigma * 3.0 * (2.0 * std::f32::consts::PI).sqrt() / 4.0 + 0.5).floor();
+
+    if size < 2.0 {
+        return;
+    }
+
+    let radius = size as usize / 2;
+    let (width, height) = (pixmap.width() as usize, pixmap.height() as usize);
+    let data = pixmap.data_mut();
+
+    for _ in 0..3 {
+        box_blur(data, width, height, radius, 4, width * 4);
+        box_blur(data, height, width, radius, width * 4, 4);
+    }
+}
+
+/// Blurs each line of pixels in the given (premultiplied) RGBA data with a box
+/// of the given radius. Pixels outside the data are transparent. The step is
+/// the distance between pixels within a line and the stride is the distance
+/// between lines.
+fn box_blur(
+    data: &mut [u8],
+    length: usize,
+    lines: usize,
+    radius: usize,
+    step: usize,
+    stride: usize,
+) {
+    let mut line = vec![[0u32; 4]; length];
+    let window = (2 * radius + 1) as u32;
+
+    for index in 0..lines {
+        let pixel = |i: usize| index * stride + i * step;
+
+        for (i, value) in line.iter_mut().enumerate() {
+            for (channel, value) in value.iter_mut().enumerate() {
+                *value = data[pixel(i) + channel] as u32;
+            }
+        }
+
+        let mut sums = [0u32; 4];
+
+        for value in line.iter().take(radius + 1) {
+            for (sum, value) in sums.iter_mut().zip(value) {
+                *sum += value;
+            }
+        }
+
+        for i in 0..length {
+            for (channel, sum) in sums.iter().enumerate() {
+                data[pixel(i) + channel] = (sum / window) as u8;
+            }
+
+            if let Some(value) = line.get(i + radius + 1) {
+                for (sum, value) in sums.iter_mut().zip(value) {
+                    *sum += value;
+                }
+            }
+
+            if i >= radius {
+                for (sum, value) in sums.iter_mut().zip(&line[i - radius]) {
+                    *sum -= value;
+                }
+            }
+        }
+    }
+}
+
+/// Resolves a dimension that is relative to the given length.
+fn resolve(dimension: Dimension<f32>, length: f32) -> f32 {
+    match dimension {
+        Dimension::Points(points) => points,
+        Dimension::Percentage(percentage) => percentage * length,
+        Dimension::Undefined | Dimension::Auto => 0.0,
+    }
+}
+
+/// Returns the value of each side of the given edges.
+fn sides<T>(edges: &ByEdge<T>, direction: LayoutDirection) -> [&T; 4] {
+    [
+        &edges.vertical.top,
+        edges.horizontal.right(direction),
+        &edges.vertical.bottom,
+        edges.horizontal.left(direction),
+    ]
+}
+
+/// Returns the border width of each side of the given view. Relative border
+/// widths are not supported.
+fn border_widths(style: &ViewStyle, direction: LayoutDirection) -> Insets {
+    let widths = sides(&style.border, direction);
+    let width = |index: usize| resolve(widths[index].width, 0.0).max(0.0);
+    [width(0), width(1), width(2), width(3)]
+}
+
+/// Returns the padding of each side of the given view. Relative padding is
+/// resolved against the width of the view.
+fn padding(style: &ViewStyle, size: Size<f32>, direction: LayoutDirection) -> Insets {
+    let padding = sides(&style.padding, direction);
+    let padding = |index: usize| resolve(*padding[index], size.width).max(0.0);
+    [padding(0), padding(1), padding(2), padding(3)]
+}
+
+/// Returns the radii of each corner of the given view. Similar to CSS, all
+/// radii are scaled down if adjacent radii don't fit the size of the view.
+fn radii(style: &ViewStyle, size: Size<f32>, direction: LayoutDirection) -> Radii {
+    let radius = &style.border_radius;
+
+    let corners = [
+        radius.top.left(direction),
+        radius.top.right(direction),
+        radius.bottom.right(direction),
+        radius.bottom.left(direction),
+    ];
+
+    let mut radii = [(0.0, 0.0); 4];
+
+    for (radii, corner) in radii.iter_mut().zip(corners.iter()) {
+        *radii = (
+            resolve(corner.horizontal, size.width).max(0.0),
+            resolve(corner.vertical, size.height).max(0.0),
+        );
+    }
+
+    let [top_left, top_right, bottom_right, bottom_left] = radii;
+
+    let factor = [
+        (size.width, top_left.0 + top_right.0),
+        (size.height, top_right.1 + bottom_right.1),
+        (size.width, bottom_left.0 + bottom_right.0),
+        (size.height, top_left.1 + bottom_left.1),
+    ]
+    .iter()
+    .filter(|(_, sum)| *sum > 0.0)
+    .map(|(length, sum)| length / sum)
+    .fold(1.0, f32::min)
+    .max(0.0);
+
+    for radius in radii.iter_mut() {
+        *radius = (radius.0 * factor, radius.1 * factor);
+    }
+
+    radii
+}
+
+/// Returns the radii of a box that is inset from a box with the given radii.
+fn inset_radii(radii: Radii, insets: Insets) -> Radii {
+    let [top, right, bottom, left] = insets;
+    let inset =
+        |radius: (f32, f32), dx: f32, dy: f32| ((radius.0 - dx).max(0.0), (radius.1 - dy).max(0.0));
+
+    [
+        inset(radii[0], left, top),
+        inset(radii[1], right, top),
+        inset(radii[2], right, bottom),
+        inset(radii[3], left, bottom),
+    ]
+}
+
+/// Returns the rectangle that is inset from a box of the given size, if it is
+/// not empty.
+fn inset(size: Size<f32>, insets: Insets) -> Option<Rect> {
+    let [top, right, bottom, left] = insets;
+    Rect::from_ltrb(left, top, size.width - right, size.height - bottom)
+}
+
+/// Returns the transform of the given view, which is applied around its
+/// center. Only the 2D affine part of the transform is used.
+fn local_transform(style: &ViewStyle, size: Size<f32>) -> Transform {
+    let matrix = StyleTransform::squash(style.transform, size);
+    let columns = matrix.columns;
+
+    let (cx, cy) = (size.width / 2.0, size.height / 2.0);
+
+    Transform::from_translate(cx, cy)
+        .pre_concat(Transform::from_row(
+            columns[0][0],
+            columns[0][1],
+            columns[1][0],
+            columns[1][1],
+            columns[3][0],
+            columns[3][1],
+        ))
+        .pre_translate(-cx, -cy)
+}
+
+/// Appends a rectangle with the given corner radii to the given builder.
+fn push_rounded_rect(builder: &mut PathBuilder, rect: Rect, radii: Radii) {
+    let (left, top, right, bottom) = (rect.left(), rect.top(), rect.right(), rect.bottom());
+    let [top_left, top_right, bottom_right, bottom_left] = radii;
+    let k = 1.0 - KAPPA;
+
+    builder.move_to(left + top_left.0, top);
+    builder.line_to(right - top_right.0, top);
+
+    if top_right != (0.0, 0.0) {
+        builder.cubic_to(
+            right - top_right.0 * k,
+            top,
+            right,
+            top + top_right.1 * k,
+            right,
+            top + top_right.1,
+        );
+    }
+
+    builder.line_to(right, bottom - bottom_right.1);
+
+    if bottom_right != (0.0, 0.0) {
+        builder.cubic_to(
+            right,
+            bottom - bottom_right.1 * k,
+            right - bottom_right.0 * k,
+            bottom,
+            right - bottom_right.0,
+            bottom,
+        );
+    }
+
+    builder.line_to(left + bottom_left.0, bottom);
+
+    if bottom_left != (0.0, 0.0) {
+        builder.cubic_to(
+            left + bottom_left.0 * k,
+            bottom,
+            left,
+            bottom - bottom_left.1 * k,
+            left,
+            bottom - bottom_left.1,
+        );
+    }
+
+    builder.line_to(left, top + top_left.1);
+
+    if top_left != (0.0, 0.0) {
+        builder.cubic_to(
+            left,
+            top + top_left.1 * k,
+            left + top_left.0 * k,
+            top,
+            left + top_left.0,
+            top,
+        );
+    }
+
+    builder.close();
+}
+
+/// Returns a path of a rectangle with the given corner radii.
+fn rounded_rect(rect: Option<Rect>, radii: Radii) -> Option<Path> {
+    let mut builder = PathBuilder::new();
+    push_rounded_rect(&mut builder, rect?, radii);
+    builder.finish()
+}
+
+/// Returns a mask of the intersection of the given mask (if any) with the
+/// given path. Returns `None` if the intersection is empty.
+fn intersect(
+    clip: Option<&ClipMask>,
+    path: &Path,
+    transform: Transform,
+    pixmap: &Pixmap,
+) -> Option<ClipMask> {
+    let path = path.clone().transform(transform)?;
+
+    match clip {
+        Some(clip) => {
+            let mut mask = clip.clone();
+            mask.intersect_path(&path, FillRule::Winding, true)?;
+            Some(mask)
+        }
+        None => {
+            let mut mask = ClipMask::new();
+            mask.set_path(
+                pixmap.width(),
+                pixmap.height(),
+                &path,
+                FillRule::Winding,
+                true,
+            )?;
+            Some(mask)
+        }
+    }
+}
+
+fn paint_border(
+    style: &ViewStyle,
+    size: Size<f32>,
+    radii: Radii,
+    pixmap: &mut Pixmap,
+    transform: Transform,
+    clip: Option<&ClipMask>,
+    direction: LayoutDirection,
+) {
+    let widths = border_widths(style, direction);
+
+    if widths.iter().all(|width| *width == 0.0) {
+        return;
+    }
+
+    let borders = sides(&style.border, direction);
+    let outer = Rect::from_xywh(0.0, 0.0, size.width, size.height);
+    let inner = inset(size, widths);
+
+    let uniform = borders
+        .iter()
+        .all(|border| border.color == borders[0].color && border.style == borders[0].style)
+        && widths.iter().all(|width| *width == widths[0]);
+
+    // Uniform dashed and dotted borders are stroked along the center of the
+    // border.
+    if uniform && borders[0].style != BorderStyle::Solid {
+        let width = widths[0];
+        let center = inset(size, [width / 2.0; 4]);
+        let path = match rounded_rect(center, inset_radii(radii, [width / 2.0; 4])) {
+            Some(path) => path,
+            None => return,
+        };
+
+        let dash = match borders[0].style {
+            BorderStyle::Dashed => vec![width * 3.0, width * 3.0],
+            _ => vec![width, width],
+        };
+
+        let stroke = Stroke {
+            width,
+            dash: StrokeDash::new(dash, 0.0),
+            ..Default::default()
+        };
+
+        pixmap.stroke_path(&path, &solid(borders[0].color), &stroke, transform, clip);
+        return;
+    }
+
+    // The border is the area between the outer and inner rounded rect.
+    let mut builder = PathBuilder::new();
+    push_rounded_rect(&mut builder, outer.unwrap(), radii);
+
+    if let Some(inner) = inner {
+        push_rounded_rect(&mut builder, inner, inset_radii(radii, widths));
+    }
+
+    let ring = match builder.finish() {
+        Some(ring) => ring,
+        None => return,
+    };
+
+    if borders
+        .iter()
+        .all(|border| border.color == borders[0].color)
+    {
+        let paint = solid(borders[0].color);
+        pixmap.fill_path(&ring, &paint, FillRule::EvenOdd, transform, clip);
+        return;
+    }
+
+    // Each side is clipped to the trapezoid between the outer corners of the
+    // box and the corners of its inner box.
+    let [top, right, bottom, left] = widths;
+    let (width, height) = (size.width, size.height);
+
+    let trapezoids = [
+        [(0.0, 0.0), (width, 0.0), (width - right, top), (left, top)],
+        [
+            (width, 0.0),
+            (width, height),
+            (width - right, height - bottom),
+            (width - right, top),
+        ],
+        [
+            (width, height),
+            (0.0, height),
+            (left, height - bottom),
+            (width - right, height - bottom),
+        ],
+        [
+            (0.0, height),
+            (0.0, 0.0),
+            (left, top),
+            (left, height - bottom),
+        ],
+    ];
+
+    for (border, trapezoid) in borders.iter().zip(trapezoids.iter()) {
+        if border.color.alpha() <= 0.0 {
+            continue;
+        }
+
+        let mut builder = PathBuilder::new();
+        builder.move_to(trapezoid[0].0, trapezoid[0].1);
+
+        for point in &trapezoid[1..] {
+            builder.line_to(point.0, point.1);
+        }
+
+        builder.close();
+
+        let mask = match builder
+            .finish()
+            .and_then(|path| intersect(clip, &path, transform, pixmap))
+        {
+            Some(mask) => mask,
+            None => continue,
+        };
+
+        let paint = solid(border.color);
+        pixmap.fill_path(&ring, &paint, FillRule::EvenOdd, transform, Some(&mask));
+    }
+}
+
+fn paint_image(
+    bitmap: &Pixmap,
+    style: &ImageStyle,
+    content: Rect,
+    pixmap: &mut Pixmap,
+    transform: Transform,
+    clip: Option<&ClipMask>,
+) {
+    let (width, height) = (bitmap.width() as f32, bitmap.height() as f32);
+
+    let (scale_x, scale_y) = match style.object_fit {
+        ObjectFit::Fill => (content.width() / width, content.height() / height),
+        ObjectFit::None => (1.0, 1.0),
+        ObjectFit::Contain => {
+            let scale = f32::min(content.width() / width, content.height() / height);
+            (scale, scale)
+        }
+        ObjectFit::Cover => {
+            let scale = f32::max(content.width() / width, content.height() / height);
+            (scale, scale)
+        }
+    };
+
+    // The image is centered within the content box.
+    let x = content.x() + (content.width() - width * scale_x) / 2.0;
+    let y = content.y() + (content.height() - height * scale_y) / 2.0;
+
+    let rect = Rect::from_ltrb(
+        x.max(content.left()),
+        y.max(content.top()),
+        (x + width * scale_x).min(content.right()),
+        (y + height * scale_y).min(content.bottom()),
+    );
+
+    let rect = match rect {
+        Some(rect) => rect,
+        None => return,
+    };
+
+    let tinted = style.tint_color.map(|color| tint(bitmap, color));
+
+    let paint = Paint {
+        shader: Pattern::new(
+            tinted.as_ref().unwrap_or(bitmap).as_ref(),
+            SpreadMode::Pad,
+            FilterQuality::Bicubic,
+            1.0,
+            Transform::from_row(scale_x, 0.0, 0.0, scale_y, x, y),
+        ),
+        ..Default::default()
+    };
+
+    pixmap.fill_rect(rect, &paint, transform, clip);
+}
+
+/// Returns a copy of the given bitmap where every pixel has the given color
+/// but retains its original alpha.
+fn tint(bitmap: &Pixmap, color: Color) -> Pixmap {
+    let srgb = color.to_srgb();
+    let channel = |value: f32| (value.clamp(0.0, 1.0) * 255.0).round() as u8;
+
+    let mut tinted = bitmap.clone();
+
+    for pixel in tinted.pixels_mut() {
+        let alpha = (pixel.alpha() as f32 / 255.0) * srgb.alpha.clamp(0.0, 1.0);
+
+        *pixel = ColorU8::from_rgba(
+            channel(srgb.red),
+            channel(srgb.green),
+            channel(srgb.blue),
+            channel(alpha),
+        )
+        .premultiply();
+    }
+
+    tinted
+}
+
+#[cfg(test)]
+mod tests {
+    use polyhorn_ui::color::Color;
+    use polyhorn_ui::geometry::{ByDirection, Dimension, Size};
+    use polyhorn_ui::layout::{LayoutAxisX, LayoutDirection};
+    use polyhorn_ui::physics::Angle;
+    use polyhorn_ui::styles::{
+        Border, BoxShadow, ColorStop, Gradient, GradientDirection, LinearGradient, ViewStyle,
+    };
+    use tiny_skia::{Pixmap, Transform};
+
+    use super::{radii, Builtin, Fonts, Painter};
+
+    fn pixel(pixmap: &Pixmap, x: u32, y: u32) -> (u8, u8, u8, u8) {
+        let pixel = pixmap.pixel(x, y).unwrap().demultiply();
+        (pixel.red(), pixel.green(), pixel.blue(), pixel.alpha())
+    }
+
+    #[test]
+    fn test_radii() {
+        let mut style = ViewStyle::default();
+        style.border_radius.top = LayoutAxisX::dependent(
+            ByDirection::with_both(Dimension::Points(80.0)),
+            ByDirection::with_both(Dimension::Percentage(0.5)),
+        );
+
+        // The radius of the top leading corner exceeds the height of the
+        // view, so all radii are scaled down by half.
+        let size = Size::new(100.0, 40.0);
+
+        assert_eq!(
+            radii(&style, size, LayoutDirection::LTR),
+            [(40.0, 40.0), (25.0, 10.0), (0.0, 0.0), (0.0, 0.0)]
+        );
+
+        assert_eq!(
+            radii(&style, size, LayoutDirection::RTL),
+            [(25.0, 10.0), (40.0, 40.0), (0.0, 0.0), (0.0, 0.0)]
+        );
+    }
+
+    #[test]
+    fn test_paint_builtin() {
+        let mut style = ViewStyle {
+            background_color: Color::rgb(255, 0, 0),
+            ..Default::default()
+        };
+        style.border.vertical.top = Border {
+            width: Dimension::Points(4.0),
+            color: Color::rgb(0, 0, 255),
+            ..Default::default()
+        };
+        style.border_radius.bottom = LayoutAxisX::independent(
+            ByDirection::with_both(Dimension::Points(10.0)),
+            Default::default(),
+        );
+
+        let mut pixmap = Pixmap::new(20, 20).unwrap();
+        let fonts = Fonts::new();
+
+        Painter::new(&fonts).paint_builtin(
+            &Builtin::View(style),
+            Size::new(20.0, 20.0),
+            &mut pixmap,
+            Transform::from_translate(0.0, 0.0),
+            None,
+            LayoutDirection::LTR,
+        );
+
+        assert_eq!(pixel(&pixmap, 10, 1), (0, 0, 255, 255));
+        assert_eq!(pixel(&pixmap, 10, 10), (255, 0, 0, 255));
+        assert_eq!(pixel(&pixmap, 19, 19), (255, 0, 0, 255));
+
+        // The bottom left corner is rounded.
+        assert_eq!(pixel(&pixmap, 0, 19), (0, 0, 0, 0));
+    }
+
+    #[test]
+    fn test_paint_gradient() {
+        let style = ViewStyle {
+            background_image: Some(Gradient::Linear(LinearGradient {
+                direction: GradientDirection::Angle(Angle::with_degrees(90.0)),
+                stops: Box::leak(Box::new([
+                    ColorStop {
+                        color: Color::rgb(255, 0, 0),
+                        position: Some(Dimension::Percentage(0.25)),
+                    },
+                    ColorStop {
+                        color: Color::rgb(0, 0, 255),
+                        position: Some(Dimension::Percentage(0.75)),
+                    },
+                ])),
+            })),
+            ..Default::default()
+        };
+
+        let mut pixmap = Pixmap::new(20, 20).unwrap();
+        let fonts = Fonts::new();
+
+        Painter::new(&fonts).paint_builtin(
+            &Builtin::View(style),
+            Size::new(20.0, 20.0),
+            &mut pixmap,
+            Transform::identity(),
+            None,
+            LayoutDirection::LTR,
+        );
+
+        assert_eq!(pixel(&pixmap, 2, 10), (255, 0, 0, 255));
+        assert_eq!(pixel(&pixmap, 17, 10), (0, 0, 255, 255));
+
+        let (red, _, blue, _) = pixel(&pixmap, 10, 10);
+        assert!(red > 64 && blue > 64);
+    }
+
+    #[test]
+    fn test_paint_box_shadow() {
+        let style = ViewStyle {
+            background_color: Color::rgb(255, 255, 255),
+            box_shadow: Box::leak(Box::new([
+                BoxShadow {
+                    offset_x: 5.0,
+                    offset_y: 5.0,
+                    ..Default::default()
+                },
+                BoxShadow {
+                    spread_radius: 4.0,
+                    color: Color::rgb(255, 0, 0),
+                    inset: true,
+                    ..Default::default()
+                },
+            ])),
+            ..Default::default()
+        };
+
+        let mut pixmap = Pixmap::new(40, 40).unwrap();
+        let fonts = Fonts::new();
+
+        Painter::new(&fonts).paint_builtin(
+            &Builtin::View(style),
+            Size::new(20.0, 20.0),
+            &mut pixmap,
+            Transform::from_translate(10.0, 10.0),
+            None,
+            LayoutDirection::LTR,
+        );
+
+        // The outer shadow is only painted outside the box.
+        assert_eq!(pixel(&pixmap, 32, 32), (0, 0, 0, 255));
+        assert_eq!(pixel(&pixmap, 5, 5), (0, 0, 0, 0));
+
+        // The inset shadow is painted along the edges inside the box.
+        assert_eq!(pixel(&pixmap, 11, 20), (255, 0, 0, 255));
+        assert_eq!(pixel(&pixmap, 20, 20), (255, 255, 255, 255));
+    }
+
+    #[test]
+    fn test_blur() {
+        let mut pixmap = Pixmap::new(60, 60).unwrap();
+        pixmap.fill(tiny_skia::Color::BLACK);
+
+        for row in pixmap.data_mut().chunks_mut(60 * 4) {
+            row[..30 * 4].iter_mut().for_each(|value| *value = 0);
+        }
+
+        super::blur(&mut pixmap, 4.0);
+
+        // The edge is smoothed symmetrically around its original position.
+        let alpha = |x| pixel(&pixmap, x, 30).3;
+        assert_eq!(alpha(15), 0);
+        assert!(alpha(29) > 96 && alpha(29) < 128);
+        assert!(alpha(30) > 128 && alpha(30) < 160);
+        assert_eq!(alpha(45), 255);
+    }
+}
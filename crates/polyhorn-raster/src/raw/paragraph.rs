@@ -0,0 +1,104 @@
+use polyhorn_ui::color::Color;
+use polyhorn_ui::text::ab_glyph::{Font, OutlineCurve, Point, ScaleFont};
+use polyhorn_ui::text::Paragraph;
+use tiny_skia::{Path, PathBuilder};
+
+/// Returns the outlines of all glyphs in the given paragraph, along with their
+/// color, if the paragraph is aligned within a box of the given width.
+pub fn outlines(paragraph: &Paragraph, width: f32) -> Vec<(Color, Path)> {
+    let mut outlines = vec![];
+
+    for glyph in paragraph.glyphs(width) {
+        let outline = match glyph.font.outline(glyph.id) {
+            Some(outline) => outline,
+            None => continue,
+        };
+
+        let scaled = glyph.font.as_scaled(glyph.scale);
+        let (sx, sy) = (scaled.h_scale_factor(), scaled.v_scale_factor());
+        let (x, baseline) = (glyph.origin.x, glyph.origin.y);
+
+        // Font units point upwards whereas pixels point downwards.
+        let point = |point: Point| (x + point.x * sx, baseline - point.y * sy);
+
+        let mut builder = PathBuilder::new();
+        let mut last = None;
+
+        for curve in &outline.curves {
+            let (start, end) = match curve {
+                OutlineCurve::Line(start, end) => (*start, *end),
+                OutlineCurve::Quad(start, _, end) => (*start, *end),
+                OutlineCurve::Cubic(start, _, _, end) => (*start, *end),
+            };
+
+            // Each contour starts where the previous one doesn't end.
+            if last != Some(start) {
+                if last.is_some() {
+                    builder.close();
+                }
+
+                let (x, y) = point(start);
+                builder.move_to(x, y);
+            }
+
+            match curve {
+                OutlineCurve::Line(_, end) => {
+                    let (x, y) = point(*end);
+                    builder.line_to(x, y);
+                }
+                OutlineCurve::Quad(_, control, end) => {
+                    let ((x1, y1), (x, y)) = (point(*control), point(*end));
+                    builder.quad_to(x1, y1, x, y);
+                }
+                OutlineCurve::Cubic(_, first, second, end) => {
+                    let ((x1, y1), (x2, y2), (x, y)) = (point(*first), point(*second), point(*end));
+                    builder.cubic_to(x1, y1, x2, y2, x, y);
+                }
+            }
+
+            last = Some(end);
+        }
+
+        builder.close();
+
+        if let Some(path) = builder.finish() {
+            outlines.push((glyph.color, path));
+        }
+    }
+
+    outlines
+}
+
+#[cfg(test)]
+mod tests {
+    use polyhorn_ui::styles::{Inherited, TextAlign, TextStyle};
+    use polyhorn_ui::text::AttributedTextBuilder;
+
+    use super::outlines;
+    use crate::raw::{Fonts, Paragraph};
+
+    #[test]
+    fn test_outlines() {
+        let mut fonts = Fonts::new();
+        let font = include_bytes!("../../../polyhorn-ui/tests/fonts/PolyhornTest-Regular.ttf");
+        fonts.load(font.to_vec()).unwrap();
+
+        let style = TextStyle {
+            text_align: Inherited::Specified(TextAlign::Right),
+            ..Default::default()
+        };
+
+        let mut text = AttributedTextBuilder::new(&style, &Default::default());
+        text.push_str("I I");
+
+        let paragraph = Paragraph::new(&fonts, &text.build(), None);
+        let outlines = outlines(&paragraph, 200.0);
+
+        // Whitespace does not produce outlines.
+        assert_eq!(outlines.len(), 2);
+
+        let bounds = outlines[1].1.bounds();
+        assert!(bounds.right() <= 200.0 && bounds.right() > 190.0);
+        assert!(bounds.top() > 0.0 && bounds.bottom() < paragraph.size().height);
+    }
+}
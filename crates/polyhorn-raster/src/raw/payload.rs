@@ -0,0 +1,71 @@
+use polyhorn_ui::styles::{ImageViewStyle, ViewStyle};
+use polyhorn_ui::text::AttributedText;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use tiny_skia::Pixmap;
+
+use super::{Builtin, Platform};
+
+/// Serializable representation of a builtin that can be recorded and
+/// replayed (see `Renderer::record`).
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum Payload {
+    /// Image with an optional PNG-encoded bitmap.
+    Image(ImageViewStyle, Option<Vec<u8>>),
+
+    /// Text that consists of the given runs.
+    Text(AttributedText<()>),
+
+    /// View with the given style.
+    View(ViewStyle),
+}
+
+impl polyhorn_core::Payload<Platform> for Payload {
+    type Builtin = Builtin;
+
+    fn encode(builtin: &Builtin) -> Payload {
+        match builtin {
+            Builtin::Image(style, pixmap) => Payload::Image(
+                *style,
+                pixmap.as_ref().and_then(|pixmap| pixmap.encode_png().ok()),
+            ),
+            Builtin::Text(text) => Payload::Text(text.clone()),
+            &Builtin::View(style) => Payload::View(style),
+        }
+    }
+
+    fn decode(self) -> Builtin {
+        match self {
+            Payload::Image(style, png) => Builtin::Image(
+                style,
+                png.and_then(|png| Pixmap::decode_png(&png).ok())
+                    .map(Arc::new),
+            ),
+            Payload::Text(text) => Builtin::Text(text),
+            Payload::View(style) => Builtin::View(style),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use polyhorn_core::Payload as _;
+    use polyhorn_ui::styles::ImageViewStyle;
+    use std::sync::Arc;
+    use tiny_skia::{Color, Pixmap};
+
+    use super::{Builtin, Payload};
+
+    #[test]
+    fn test_image_round_trip() {
+        let mut pixmap = Pixmap::new(2, 1).unwrap();
+        pixmap.fill(Color::from_rgba8(255, 0, 0, 255));
+
+        let builtin = Builtin::Image(ImageViewStyle::default(), Some(Arc::new(pixmap.clone())));
+
+        match Payload::encode(&builtin).decode() {
+            Builtin::Image(_, Some(decoded)) => assert_eq!(decoded.data(), pixmap.data()),
+            builtin => panic!("Unexpected builtin: {:?}", builtin),
+        }
+    }
+}
@@ -0,0 +1,16 @@
+use crate::prelude::*;
+use crate::raw::Builtin;
+use crate::Key;
+
+impl Component for Image {
+    fn render(&self, manager: &mut Manager) -> Element {
+        // Terminals can't show images, so images are drawn as an empty view
+        // with the size that is given in their style (if any).
+        Element::builtin(
+            Key::new(()),
+            Builtin::View(self.style.view, None),
+            manager.children(),
+            None,
+        )
+    }
+}
@@ -0,0 +1,137 @@
+use polyhorn_ui::geometry::Size;
+use polyhorn_ui::layout::LayoutGuide;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use crate::prelude::*;
+use crate::raw::{Builtin, Event, Listeners, Platform};
+use crate::{Component, Key};
+
+/// Terminal user interfaces are never animated, so this type can't be
+/// constructed.
+pub enum AnimationHandle {}
+
+impl std::future::Future for AnimationHandle {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Self::Output> {
+        match *self {}
+    }
+}
+
+impl polyhorn_ui::animation::AnimationHandle for AnimationHandle {}
+
+/// Terminal user interfaces are never animated, so this type can't be
+/// constructed.
+pub enum Animator {}
+
+impl polyhorn_ui::animation::Animator for Animator {
+    type AnimationHandle = AnimationHandle;
+
+    fn start(&mut self, _animation: polyhorn_ui::animation::Animation) -> Self::AnimationHandle {
+        match *self {}
+    }
+}
+
+/// Views in a terminal can't be controlled imperatively, so this type can't
+/// be constructed.
+pub enum ViewHandle {}
+
+impl polyhorn_ui::animation::Animatable for ViewHandle {
+    type Animator = Animator;
+    type CommandBuffer = ();
+
+    fn animate<F>(&mut self, _animations: F)
+    where
+        F: FnOnce(&mut Self::Animator) + Send + 'static,
+    {
+        match *self {}
+    }
+
+    fn animate_with_buffer<F>(&mut self, _buffer: &mut Self::CommandBuffer, _animations: F)
+    where
+        F: FnOnce(&mut Self::Animator) + Send + 'static,
+    {
+        match *self {}
+    }
+}
+
+impl polyhorn_ui::handles::ViewHandle for ViewHandle {
+    fn layout_guide(&self) -> LayoutGuide<f32> {
+        match *self {}
+    }
+
+    fn size<F>(&self, _callback: F)
+    where
+        F: FnOnce(Size<f32>) + Send + 'static,
+    {
+        match *self {}
+    }
+
+    fn size_with_buffer<F>(&self, _buffer: &mut Self::CommandBuffer, _callback: F)
+    where
+        F: FnOnce(Size<f32>) + Send + 'static,
+    {
+        match *self {}
+    }
+}
+
+pub type View = polyhorn_ui::components::View<Platform, ViewHandle>;
+
+impl Component for View {
+    fn render(&self, manager: &mut Manager) -> Element {
+        let on_pointer_cancel_ref = use_reference!(manager, self.on_pointer_cancel.clone());
+        on_pointer_cancel_ref.replace(manager, self.on_pointer_cancel.clone());
+        let on_pointer_cancel_ref = on_pointer_cancel_ref.weak(manager);
+
+        let on_pointer_down_ref = use_reference!(manager, self.on_pointer_down.clone());
+        on_pointer_down_ref.replace(manager, self.on_pointer_down.clone());
+        let on_pointer_down_ref = on_pointer_down_ref.weak(manager);
+
+        let on_pointer_up_ref = use_reference!(manager, self.on_pointer_up.clone());
+        on_pointer_up_ref.replace(manager, self.on_pointer_up.clone());
+        let on_pointer_up_ref = on_pointer_up_ref.weak(manager);
+
+        let on_layout_ref = use_reference!(manager, self.on_layout.clone());
+        on_layout_ref.replace(manager, self.on_layout.clone());
+        let on_layout_ref = on_layout_ref.weak(manager);
+
+        let tx = use_channel!(manager, move |mut rx| {
+            async move {
+                while let Some(event) = rx.next().await {
+                    match event {
+                        Event::PointerCancel => {
+                            on_pointer_cancel_ref.apply(|listener| listener.emit(()));
+                        }
+                        Event::PointerDown => {
+                            on_pointer_down_ref.apply(|listener| listener.emit(()));
+                        }
+                        Event::PointerUp => {
+                            on_pointer_up_ref.apply(|listener| listener.emit(()));
+                        }
+                        Event::Layout(size) => {
+                            on_layout_ref.apply(|listener| listener.emit(size));
+                        }
+                    }
+                }
+            }
+        });
+
+        // Only views that listen for pointer events can receive focus.
+        let focusable = self.on_pointer_cancel.is_some()
+            || self.on_pointer_down.is_some()
+            || self.on_pointer_up.is_some();
+
+        let listeners = match focusable || self.on_layout.is_some() {
+            true => Some(Listeners::new(tx, focusable)),
+            false => None,
+        };
+
+        Element::builtin(
+            Key::new(()),
+            Builtin::View(self.style, listeners),
+            manager.children(),
+            None,
+        )
+    }
+}
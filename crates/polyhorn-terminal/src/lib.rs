@@ -0,0 +1,66 @@
+//! This crate implements Polyhorn for terminal user interfaces. It lays out a
+//! tree of components in units of character cells and draws it into a grid of
+//! cells: views are drawn with box-drawing borders and ANSI colors and text is
+//! wrapped to the width of its view. Keyboard input moves the focus between
+//! views and is emitted to their pointer event listeners. Because the grid is
+//! a plain value, user interfaces can be tested by comparing grids without a
+//! terminal.
+
+pub use polyhorn_core::{
+    log_renders, observe_renders, render, render_with_executor, stop_observing_renders, untracked,
+    Computed, Context, ContextProvider, Executor, History, HistoryOptions, Key, Link,
+    LocalExecutor, PropsDiff, Receiver, Reference, RenderReason, RenderRecord, Sender, Signal,
+    Slot, State, TestExecutor,
+};
+pub use polyhorn_ui::{assets, color, font, geometry, layout, linalg, physics, styles};
+pub use polyhorn_ui_macros::render;
+
+pub mod components;
+pub mod prelude;
+pub mod raw;
+mod screen;
+mod terminal;
+
+pub use raw::{Cell, Grid};
+pub use screen::{Keystroke, Screen};
+pub use terminal::run;
+
+/// Re-exports of hooks provided by Polyhorn Core and Polyhorn UI.
+pub mod hooks {
+    pub use polyhorn_core::{
+        use_async, use_context, use_effect, use_history, use_id, use_imperative_handle,
+        use_machine, use_reference, use_state, use_worker, UseAsync, UseContext, UseEffect,
+        UseHistory, UseImperativeHandle, UseMachine, UseReference, UseWorker,
+    };
+    pub use polyhorn_ui::hooks::*;
+}
+
+use raw::Platform;
+
+/// Polyhorn core children type that is specialized for the terminal platform.
+pub type Children = polyhorn_core::Children<Platform>;
+
+/// Polyhorn core element type that is specialized for the terminal platform.
+pub type Element = polyhorn_core::Element<Platform>;
+
+/// Polyhorn core instance type that is specialized for the terminal platform.
+pub type Instance = polyhorn_core::Instance<Platform>;
+
+/// Polyhorn core manager type that is specialized for the terminal platform.
+pub type Manager<'a> = polyhorn_core::Manager<'a, Platform>;
+
+/// Polyhorn core weak type that is specialized for the terminal platform.
+pub type Weak = polyhorn_core::Weak<Platform>;
+
+/// Polyhorn core weak link type that is specialized for the terminal platform.
+pub type WeakLink<'a> = polyhorn_core::WeakLink<'a, Platform>;
+
+/// Polyhorn core weak reference type that is specialized for the terminal
+/// platform.
+pub type WeakReference<T> = polyhorn_core::WeakReference<Platform, T>;
+
+/// Polyhorn core weak state type that is specialized for the terminal
+/// platform.
+pub type WeakState<T> = polyhorn_core::WeakState<Platform, T>;
+
+pub use raw::Component;
@@ -0,0 +1,13 @@
+//! This is the Polyhorn for terminals prelude. It includes every type and
+//! function of Polyhorn UI with the exception that it defines its own
+//! Component, Element and Manager that are specialized for the terminal
+//! platform.
+
+pub use polyhorn_ui::prelude::*;
+
+pub use polyhorn_macros::poly;
+pub use polyhorn_ui::components::*;
+pub use polyhorn_ui_macros::{asset, style};
+
+pub use super::{Component, Element, Manager};
+pub use crate::components::View;
@@ -0,0 +1,113 @@
+use polyhorn_core::Sender;
+use polyhorn_ui::geometry::{Dimension, Size};
+use polyhorn_ui::layout::{LayoutNode, MeasureFunc};
+use polyhorn_ui::styles::ViewStyle;
+use polyhorn_ui::text::AttributedText;
+use std::any::Any;
+use std::fmt::{Debug, Formatter, Result};
+use std::sync::Arc;
+
+use super::{Environment, Node, Paragraph, Platform};
+
+/// Event that is emitted to the listeners of a view.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Event {
+    /// Emitted when the user presses escape while a view has focus.
+    PointerCancel,
+
+    /// Emitted when the user presses enter or space while a view has focus.
+    PointerDown,
+
+    /// Emitted right after a pointer down event.
+    PointerUp,
+
+    /// Emitted when a view is layed out with a size (in cells) that differs
+    /// from the size that was previously emitted.
+    Layout(Size<f32>),
+}
+
+/// Handle that emits events to the listeners of a view.
+#[derive(Clone)]
+pub struct Listeners {
+    sender: Sender<Event>,
+    focusable: bool,
+}
+
+impl Listeners {
+    /// Returns new listeners that send events to the given sender. Only views
+    /// that listen for pointer events should be focusable.
+    pub fn new(sender: Sender<Event>, focusable: bool) -> Listeners {
+        Listeners { sender, focusable }
+    }
+
+    /// Returns a boolean that indicates if the view can receive focus.
+    pub fn is_focusable(&self) -> bool {
+        self.focusable
+    }
+
+    /// Emits the given event. Events are handled asynchronously by the event
+    /// loop of the view that listens for them.
+    pub fn emit(&self, event: Event) {
+        let _ = self.sender.clone().try_send(event);
+    }
+}
+
+impl Debug for Listeners {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        f.debug_struct("Listeners")
+            .field("focusable", &self.focusable)
+            .finish()
+    }
+}
+
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug)]
+pub enum Builtin {
+    /// Text that consists of the given runs.
+    Text(AttributedText<()>),
+
+    /// View with the given style and listeners (if it listens for events).
+    View(ViewStyle, Option<Listeners>),
+}
+
+impl polyhorn_core::Builtin<Platform> for Builtin {
+    fn instantiate(&self, _parent: &mut Node, environment: &mut Environment) -> Node {
+        let layout = match self {
+            Builtin::Text(..) => LayoutNode::leaf(environment.layout_tree().clone()),
+            Builtin::View(..) => LayoutNode::new(environment.layout_tree().clone()),
+        };
+
+        let mut node = Node::new(layout, None);
+        self.update(&mut node, environment);
+        node
+    }
+
+    fn update(&self, node: &mut Node, _environment: &mut Environment) {
+        let layout = node.layout();
+
+        match self {
+            Builtin::Text(text) => {
+                let text = text.clone();
+
+                // Text is measured in cells with the width that it's given
+                // (if any).
+                layout.set_measure(MeasureFunc::Boxed(Arc::new(move |size| {
+                    let max_width = match size.width {
+                        Dimension::Points(width) => Some(width.max(0.0).floor() as usize),
+                        _ => None,
+                    };
+
+                    let size = Paragraph::new(&text, max_width).size();
+                    Size::new(size.width as f32, size.height as f32)
+                })));
+            }
+            Builtin::View(style, _) => layout.set_style(*style),
+        }
+
+        node.set_builtin(self.clone());
+    }
+
+    fn as_any(&self) -> Option<&dyn Any> {
+        Some(self)
+    }
+}
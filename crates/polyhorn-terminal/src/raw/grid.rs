@@ -0,0 +1,219 @@
+use polyhorn_ui::color::Color;
+use polyhorn_ui::geometry::Size;
+use std::fmt::{Display, Formatter, Result, Write};
+
+/// Single character cell of a grid along with its colors and attributes.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Cell {
+    /// This is the character that is shown in this cell.
+    pub character: char,
+
+    /// This is the color of the character or `None` if the cell uses the
+    /// default foreground color of the terminal.
+    pub foreground: Option<Color>,
+
+    /// This is the color behind the character or `None` if the cell uses the
+    /// default background color of the terminal.
+    pub background: Option<Color>,
+
+    /// Indicates if the character is shown in a bold font.
+    pub bold: bool,
+
+    /// Indicates if the character is shown in a dim (faint) font.
+    pub dim: bool,
+
+    /// Indicates if the character is shown in an italic font.
+    pub italic: bool,
+
+    /// Indicates if the foreground and background colors are swapped. This is
+    /// used to highlight the view that has focus.
+    pub reverse: bool,
+}
+
+impl Cell {
+    /// Writes the ANSI escape sequence that selects the graphic rendition of
+    /// this cell to the given string.
+    fn write_rendition(&self, output: &mut String) {
+        output.push_str("\x1b[0");
+
+        for (enabled, code) in &[
+            (self.bold, 1),
+            (self.dim, 2),
+            (self.italic, 3),
+            (self.reverse, 7),
+        ] {
+            if *enabled {
+                let _ = write!(output, ";{}", code);
+            }
+        }
+
+        for (color, code) in &[(self.foreground, 38), (self.background, 48)] {
+            if let Some(color) = color {
+                let (red, green, blue) = rgb(*color);
+                let _ = write!(output, ";{};2;{};{};{}", code, red, green, blue);
+            }
+        }
+
+        output.push('m');
+    }
+
+    fn rendition_eq(&self, other: &Cell) -> bool {
+        Cell {
+            character: ' ',
+            ..*self
+        } == Cell {
+            character: ' ',
+            ..*other
+        }
+    }
+}
+
+impl Default for Cell {
+    fn default() -> Self {
+        Cell {
+            character: ' ',
+            foreground: None,
+            background: None,
+            bold: false,
+            dim: false,
+            italic: false,
+            reverse: false,
+        }
+    }
+}
+
+/// Returns the 8-bit sRGB coordinates of the given color.
+fn rgb(color: Color) -> (u8, u8, u8) {
+    let srgb = color.to_srgb();
+    let channel = |value: f32| (value.clamp(0.0, 1.0) * 255.0).round() as u8;
+    (channel(srgb.red), channel(srgb.green), channel(srgb.blue))
+}
+
+/// Two-dimensional grid of character cells. Every character occupies exactly
+/// one cell.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Grid {
+    size: Size<usize>,
+    cells: Vec<Cell>,
+}
+
+impl Grid {
+    /// Returns a new grid of the given size (in columns and rows) where every
+    /// cell is empty.
+    pub fn new(size: Size<usize>) -> Grid {
+        Grid {
+            size,
+            cells: vec![Cell::default(); size.width * size.height],
+        }
+    }
+
+    /// Returns the size of this grid in columns and rows.
+    pub fn size(&self) -> Size<usize> {
+        self.size
+    }
+
+    /// Returns the cell at the given column and row (if it exists).
+    pub fn cell(&self, x: usize, y: usize) -> Option<&Cell> {
+        match x < self.size.width && y < self.size.height {
+            true => self.cells.get(y * self.size.width + x),
+            false => None,
+        }
+    }
+
+    /// Returns a mutable reference to the cell at the given column and row (if
+    /// it exists).
+    pub fn cell_mut(&mut self, x: usize, y: usize) -> Option<&mut Cell> {
+        match x < self.size.width && y < self.size.height {
+            true => self.cells.get_mut(y * self.size.width + x),
+            false => None,
+        }
+    }
+
+    /// Returns the cells of the given row.
+    pub fn row(&self, y: usize) -> &[Cell] {
+        let start = (y * self.size.width).min(self.cells.len());
+        let end = (start + self.size.width).min(self.cells.len());
+        &self.cells[start..end]
+    }
+
+    /// Returns the characters of each row of this grid with ANSI escape
+    /// sequences for their colors and attributes. Rows are separated by a
+    /// carriage return and a line feed, which means that the result can be
+    /// written to a terminal in raw mode as-is.
+    pub fn to_ansi(&self) -> String {
+        let mut output = String::new();
+
+        for y in 0..self.size.height {
+            if y > 0 {
+                output.push_str("\r\n");
+            }
+
+            let mut previous: Option<&Cell> = None;
+
+            for cell in self.row(y) {
+                if !matches!(previous, Some(previous) if previous.rendition_eq(cell)) {
+                    cell.write_rendition(&mut output);
+                }
+
+                output.push(cell.character);
+                previous = Some(cell);
+            }
+
+            output.push_str("\x1b[0m");
+        }
+
+        output
+    }
+}
+
+/// Writes the characters of each row of this grid without colors or
+/// attributes. Trailing whitespace is removed from every row, which makes it
+/// easy to compare grids to string literals in tests.
+impl Display for Grid {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        for y in 0..self.size.height {
+            if y > 0 {
+                f.write_char('\n')?;
+            }
+
+            let row = self.row(y).iter().map(|cell| cell.character);
+            f.write_str(row.collect::<String>().trim_end())?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use polyhorn_ui::color::Color;
+    use polyhorn_ui::geometry::Size;
+
+    use super::{Cell, Grid};
+
+    #[test]
+    fn test_display() {
+        let mut grid = Grid::new(Size::new(4, 2));
+        grid.cell_mut(1, 0).unwrap().character = 'a';
+        grid.cell_mut(0, 1).unwrap().character = 'b';
+
+        assert_eq!(grid.to_string(), " a\nb");
+        assert!(grid.cell_mut(4, 0).is_none());
+    }
+
+    #[test]
+    fn test_to_ansi() {
+        let mut grid = Grid::new(Size::new(3, 1));
+
+        for x in 0..2 {
+            *grid.cell_mut(x, 0).unwrap() = Cell {
+                character: 'x',
+                foreground: Some(Color::rgb(255, 0, 0)),
+                bold: true,
+                ..Default::default()
+            };
+        }
+
+        assert_eq!(grid.to_ansi(), "\x1b[0;1;38;2;255;0;0mxx\x1b[0m \x1b[0m");
+    }
+}
@@ -0,0 +1,24 @@
+//! Platform types that lay out Polyhorn builtins in character cells and draw
+//! them into a grid.
+
+mod builtin;
+mod component;
+mod compositor;
+mod environment;
+mod grid;
+mod node;
+mod paint;
+mod paragraph;
+mod payload;
+mod platform;
+
+pub use builtin::{Builtin, Event, Listeners};
+pub use component::{Component, OpaqueComponent};
+pub use compositor::{CommandBuffer, Compositor, ContainerID};
+pub use environment::Environment;
+pub use grid::{Cell, Grid};
+pub use node::Node;
+pub use paint::Painter;
+pub use paragraph::{base_style, Line, Paragraph};
+pub use payload::Payload;
+pub use platform::Platform;
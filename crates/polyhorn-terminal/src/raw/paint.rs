@@ -0,0 +1,471 @@
+use polyhorn_ui::color::Color;
+use polyhorn_ui::geometry::{ByEdge, Dimension};
+use polyhorn_ui::layout::LayoutDirection;
+use polyhorn_ui::styles::{BorderStyle, Inherited, Overflow, ViewStyle, Visibility};
+
+use super::{Builtin, Cell, Grid, Node, Paragraph};
+
+/// Rectangle of cells. The right and bottom edges are exclusive.
+#[derive(Copy, Clone, Debug, PartialEq)]
+struct Area {
+    left: i64,
+    top: i64,
+    right: i64,
+    bottom: i64,
+}
+
+impl Area {
+    fn width(&self) -> i64 {
+        (self.right - self.left).max(0)
+    }
+
+    fn intersect(&self, other: &Area) -> Area {
+        Area {
+            left: self.left.max(other.left),
+            top: self.top.max(other.top),
+            right: self.right.min(other.right),
+            bottom: self.bottom.min(other.bottom),
+        }
+    }
+
+    fn contains(&self, x: i64, y: i64) -> bool {
+        x >= self.left && x < self.right && y >= self.top && y < self.bottom
+    }
+}
+
+/// Side of a border that is drawn with box-drawing characters.
+#[derive(Copy, Clone)]
+struct Side {
+    style: BorderStyle,
+    color: Color,
+    heavy: bool,
+}
+
+/// Draws trees of nodes into a grid. Positions and sizes are rounded to the
+/// nearest cell. Borders occupy a single cell regardless of their width:
+/// borders that are at least two cells wide are drawn with heavy lines.
+/// Transforms are ignored.
+pub struct Painter<'a> {
+    focus: Option<&'a Node>,
+}
+
+impl<'a> Painter<'a> {
+    /// Returns a new painter that highlights the given node (if any) because
+    /// it has focus.
+    pub fn new(focus: Option<&'a Node>) -> Painter<'a> {
+        Painter { focus }
+    }
+
+    /// Draws the given node and its descendants at their current layout into
+    /// the given grid.
+    pub fn paint(&self, node: &Node, grid: &mut Grid) {
+        let size = grid.size();
+        let clip = Area {
+            left: 0,
+            top: 0,
+            right: size.width as i64,
+            bottom: size.height as i64,
+        };
+
+        self.paint_node(
+            node,
+            grid,
+            (0.0, 0.0),
+            clip,
+            LayoutDirection::LTR,
+            1.0,
+            false,
+        );
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn paint_node(
+        &self,
+        node: &Node,
+        grid: &mut Grid,
+        origin: (f32, f32),
+        clip: Area,
+        direction: LayoutDirection,
+        opacity: f32,
+        focused: bool,
+    ) {
+        let layout = node.layout().current();
+        let origin = (origin.0 + layout.origin.x, origin.1 + layout.origin.y);
+
+        let builtin = match node.builtin() {
+            Some(builtin) => builtin,
+            None => {
+                // The root node doesn't draw anything itself.
+                for child in node.children() {
+                    self.paint_node(&child, grid, origin, clip, direction, opacity, focused);
+                }
+
+                return;
+            }
+        };
+
+        let style = match &builtin {
+            Builtin::Text(..) => ViewStyle::default(),
+            Builtin::View(style, _) => *style,
+        };
+
+        if style.visibility == Visibility::Hidden || style.opacity <= 0.0 {
+            return;
+        }
+
+        let direction = match style.direction {
+            Inherited::Inherited => direction,
+            Inherited::Specified(direction) => direction,
+        };
+
+        let opacity = opacity * style.opacity;
+        let focused = focused || self.focus == Some(node);
+
+        let frame = Area {
+            left: origin.0.round() as i64,
+            top: origin.1.round() as i64,
+            right: (origin.0 + layout.size.width).round() as i64,
+            bottom: (origin.1 + layout.size.height).round() as i64,
+        };
+
+        let visible = frame.intersect(&clip);
+
+        for y in visible.top..visible.bottom {
+            for x in visible.left..visible.right {
+                if let Some(cell) = cell_mut(grid, x, y) {
+                    paint_background(cell, style.background_color, opacity, focused);
+                }
+            }
+        }
+
+        let sides = paint_border(&style, frame, visible, grid, direction, opacity, focused);
+
+        if let Builtin::Text(text) = &builtin {
+            let width = frame.width() as usize;
+            let paragraph = Paragraph::new(text, Some(width));
+
+            for (x, y, text) in paragraph.cells(width) {
+                let (x, y) = (frame.left + x as i64, frame.top + y as i64);
+
+                if !visible.contains(x, y) {
+                    continue;
+                }
+
+                if let Some(cell) = cell_mut(grid, x, y) {
+                    *cell = Cell {
+                        foreground: text
+                            .foreground
+                            .and_then(|color| composite(color, opacity, cell.background)),
+                        background: cell.background,
+                        reverse: focused,
+                        ..text
+                    };
+                }
+            }
+        }
+
+        // Descendants of views that don't have a visible overflow are clipped
+        // to the cells within the border of those views.
+        let clip = match style.overflow {
+            Overflow::Visible => clip,
+            Overflow::Hidden | Overflow::Scroll => {
+                let inset = |side: &Option<Side>| side.is_some() as i64;
+
+                clip.intersect(&Area {
+                    left: frame.left + inset(&sides[3]),
+                    top: frame.top + inset(&sides[0]),
+                    right: frame.right - inset(&sides[1]),
+                    bottom: frame.bottom - inset(&sides[2]),
+                })
+            }
+        };
+
+        for child in node.children() {
+            self.paint_node(&child, grid, origin, clip, direction, opacity, focused);
+        }
+    }
+}
+
+/// Returns a mutable reference to the cell at the given coordinates (if it
+/// exists).
+fn cell_mut(grid: &mut Grid, x: i64, y: i64) -> Option<&mut Cell> {
+    if x < 0 || y < 0 {
+        return None;
+    }
+
+    grid.cell_mut(x as usize, y as usize)
+}
+
+/// Returns the given color with the given opacity composited over the given
+/// background color (if any). Translucent colors are drawn opaque over the
+/// default background color of the terminal because that color is unknown.
+fn composite(color: Color, opacity: f32, background: Option<Color>) -> Option<Color> {
+    let alpha = color.alpha() * opacity;
+
+    if alpha <= 0.0 {
+        return background;
+    }
+
+    match background {
+        Some(background) if alpha < 1.0 => Some(opaque(background.mix(color, alpha))),
+        _ => Some(opaque(color)),
+    }
+}
+
+/// Returns an opaque color with the 8-bit sRGB coordinates of the given color.
+fn opaque(color: Color) -> Color {
+    let srgb = color.to_srgb();
+    let channel = |value: f32| (value.clamp(0.0, 1.0) * 255.0).round() as u8;
+    Color::rgb(channel(srgb.red), channel(srgb.green), channel(srgb.blue))
+}
+
+/// Paints the given background color over the given cell. Opaque backgrounds
+/// hide the character of the cell.
+fn paint_background(cell: &mut Cell, color: Color, opacity: f32, focused: bool) {
+    let alpha = color.alpha() * opacity;
+
+    if alpha >= 1.0 {
+        *cell = Cell {
+            background: Some(opaque(color)),
+            ..Default::default()
+        };
+    } else {
+        cell.background = composite(color, opacity, cell.background);
+    }
+
+    cell.reverse |= focused;
+}
+
+/// Returns the value of each side of the given edges in the order top, right,
+/// bottom and left.
+fn sides<T>(edges: &ByEdge<T>, direction: LayoutDirection) -> [&T; 4] {
+    [
+        &edges.vertical.top,
+        edges.horizontal.right(direction),
+        &edges.vertical.bottom,
+        edges.horizontal.left(direction),
+    ]
+}
+
+/// Returns the given dimension in cells. Percentages are resolved against the
+/// given length.
+fn resolve(dimension: Dimension<f32>, length: f32) -> f32 {
+    match dimension {
+        Dimension::Points(points) => points,
+        Dimension::Percentage(percentage) => percentage * length,
+        Dimension::Undefined | Dimension::Auto => 0.0,
+    }
+}
+
+/// Returns the characters that are used to draw a horizontal line, a vertical
+/// line and the top left, top right, bottom right and bottom left corners.
+fn characters(style: BorderStyle, heavy: bool, rounded: bool) -> (char, char, [char; 4]) {
+    let (horizontal, vertical) = match (style, heavy) {
+        (BorderStyle::Solid, false) => ('─', '│'),
+        (BorderStyle::Solid, true) => ('━', '┃'),
+        (BorderStyle::Dashed, false) => ('╌', '╎'),
+        (BorderStyle::Dashed, true) => ('╍', '╏'),
+        (BorderStyle::Dotted, false) => ('┈', '┊'),
+        (BorderStyle::Dotted, true) => ('┉', '┋'),
+    };
+
+    let corners = match (heavy, rounded) {
+        (true, _) => ['┏', '┓', '┛', '┗'],
+        (false, true) => ['╭', '╮', '╯', '╰'],
+        (false, false) => ['┌', '┐', '┘', '└'],
+    };
+
+    (horizontal, vertical, corners)
+}
+
+/// Draws the border of a view with the given style and frame into the given
+/// grid and returns the sides that were drawn (in the order top, right, bottom
+/// and left). Corners are only drawn where two sides meet and are rounded if
+/// the view has a border radius there (unless both sides are heavy).
+#[allow(clippy::too_many_arguments)]
+fn paint_border(
+    style: &ViewStyle,
+    frame: Area,
+    visible: Area,
+    grid: &mut Grid,
+    direction: LayoutDirection,
+    opacity: f32,
+    focused: bool,
+) -> [Option<Side>; 4] {
+    let width = frame.width() as f32;
+
+    let mut sides = [None; 4];
+
+    for (side, border) in sides
+        .iter_mut()
+        .zip(self::sides(&style.border, direction).iter())
+    {
+        let thickness = resolve(border.width, width).round();
+
+        if thickness >= 1.0 && border.color.alpha() > 0.0 {
+            *side = Some(Side {
+                style: border.style,
+                color: border.color,
+                heavy: thickness >= 2.0,
+            });
+        }
+    }
+
+    let mut draw = |x: i64, y: i64, character: char, color: Color| {
+        if !visible.contains(x, y) {
+            return;
+        }
+
+        if let Some(cell) = cell_mut(grid, x, y) {
+            *cell = Cell {
+                character,
+                foreground: composite(color, opacity, cell.background),
+                background: cell.background,
+                reverse: focused,
+                ..Default::default()
+            };
+        }
+    };
+
+    let (right, bottom) = (frame.right - 1, frame.bottom - 1);
+
+    for (index, y) in [(0, frame.top), (2, bottom)].iter() {
+        if let Some(side) = sides[*index] {
+            let (horizontal, _, _) = characters(side.style, side.heavy, false);
+
+            for x in frame.left..frame.right {
+                draw(x, *y, horizontal, side.color);
+            }
+        }
+    }
+
+    for (index, x) in [(1, right), (3, frame.left)].iter() {
+        if let Some(side) = sides[*index] {
+            let (_, vertical, _) = characters(side.style, side.heavy, false);
+
+            for y in frame.top..frame.bottom {
+                draw(*x, y, vertical, side.color);
+            }
+        }
+    }
+
+    let radius = &style.border_radius;
+    let corners = [
+        (0, 3, frame.left, frame.top, radius.top.left(direction)),
+        (0, 1, right, frame.top, radius.top.right(direction)),
+        (2, 1, right, bottom, radius.bottom.right(direction)),
+        (2, 3, frame.left, bottom, radius.bottom.left(direction)),
+    ];
+
+    for (index, (horizontal, vertical, x, y, radius)) in corners.iter().enumerate() {
+        if let (Some(horizontal), Some(vertical)) = (sides[*horizontal], sides[*vertical]) {
+            let rounded =
+                resolve(radius.horizontal, width) > 0.0 || resolve(radius.vertical, width) > 0.0;
+            let heavy = horizontal.heavy && vertical.heavy;
+            let (_, _, corners) = characters(horizontal.style, heavy, rounded);
+
+            draw(*x, *y, corners[index], horizontal.color);
+        }
+    }
+
+    sides
+}
+
+#[cfg(test)]
+mod tests {
+    use polyhorn_ui::color::Color;
+    use polyhorn_ui::geometry::{ByCorner, ByDirection, ByEdge, Dimension, Size};
+    use polyhorn_ui::layout::{LayoutAxisX, LayoutAxisY, LayoutDirection};
+    use polyhorn_ui::styles::{Border, BorderStyle, ViewStyle};
+
+    use super::{composite, paint_border, Area, Grid};
+
+    #[test]
+    fn test_paint_border() {
+        let border = Border {
+            width: Dimension::Points(1.0),
+            style: BorderStyle::Solid,
+            color: Color::rgb(255, 0, 0),
+        };
+
+        let style = ViewStyle {
+            border: ByEdge {
+                horizontal: LayoutAxisX::dependent(
+                    border,
+                    Border {
+                        width: Dimension::Points(2.0),
+                        ..border
+                    },
+                ),
+                vertical: LayoutAxisY {
+                    top: border,
+                    bottom: border,
+                },
+            },
+            border_radius: ByCorner {
+                all: LayoutAxisY {
+                    top: LayoutAxisX::dependent(
+                        ByDirection::with_both(Dimension::Points(1.0)),
+                        Default::default(),
+                    ),
+                    bottom: Default::default(),
+                },
+            },
+            ..Default::default()
+        };
+
+        let mut grid = Grid::new(Size::new(5, 4));
+        let frame = Area {
+            left: 0,
+            top: 0,
+            right: 4,
+            bottom: 3,
+        };
+
+        let sides = paint_border(
+            &style,
+            frame,
+            frame,
+            &mut grid,
+            LayoutDirection::LTR,
+            1.0,
+            false,
+        );
+
+        assert!(sides.iter().all(Option::is_some));
+        assert_eq!(grid.to_string(), "╭──┐\n│  ┃\n└──┘\n");
+        assert_eq!(
+            grid.cell(0, 0).unwrap().foreground,
+            Some(Color::rgb(255, 0, 0))
+        );
+
+        // In right-to-left layouts, the leading corner is on the right.
+        let mut grid = Grid::new(Size::new(4, 3));
+
+        paint_border(
+            &style,
+            frame,
+            frame,
+            &mut grid,
+            LayoutDirection::RTL,
+            1.0,
+            false,
+        );
+
+        assert_eq!(grid.to_string(), "┌──╮\n┃  │\n└──┘");
+    }
+
+    #[test]
+    fn test_composite() {
+        let black = Color::rgb(0, 0, 0);
+        let white = Color::rgb(255, 255, 255);
+
+        assert_eq!(composite(white, 0.0, Some(black)), Some(black));
+        assert_eq!(composite(white, 0.5, None), Some(white));
+        assert_eq!(composite(white, 1.0, Some(black)), Some(white));
+        assert_eq!(
+            composite(white, 0.25, Some(black)),
+            Some(Color::rgb(137, 137, 137))
+        );
+    }
+}
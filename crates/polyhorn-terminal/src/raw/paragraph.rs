@@ -0,0 +1,289 @@
+use polyhorn_ui::color::Color;
+use polyhorn_ui::font::{FontStyle, FontWeight};
+use polyhorn_ui::geometry::Size;
+use polyhorn_ui::styles::TextAlign;
+use polyhorn_ui::text::{AttributedText, ResolvedTextStyle};
+
+use super::Cell;
+
+/// Returns the style that text in a terminal inherits from. Text without a
+/// color of its own is transparent, which is drawn in the default foreground
+/// color of the terminal. Font families and sizes are ignored because
+/// terminals only have a single font.
+pub fn base_style() -> ResolvedTextStyle {
+    ResolvedTextStyle {
+        color: Color::transparent(),
+        ..Default::default()
+    }
+}
+
+/// Returns an empty cell with the given character in the given style.
+fn cell(style: &ResolvedTextStyle, character: char) -> Cell {
+    let weight = match style.font_weight {
+        FontWeight::Number(weight) => weight * 1000.0,
+        FontWeight::Thin => 100.0,
+        FontWeight::ExtraLight => 200.0,
+        FontWeight::Light => 300.0,
+        FontWeight::Normal => 400.0,
+        FontWeight::Medium => 500.0,
+        FontWeight::SemiBold => 600.0,
+        FontWeight::Bold => 700.0,
+        FontWeight::ExtraBold => 800.0,
+        FontWeight::Black => 900.0,
+    };
+
+    Cell {
+        character,
+        foreground: Some(style.color).filter(|color| color.alpha() > 0.0),
+        bold: weight >= 600.0,
+        dim: weight <= 300.0,
+        italic: style.font_style != FontStyle::Normal,
+        ..Default::default()
+    }
+}
+
+/// Character within a paragraph along with its run.
+#[derive(Copy, Clone)]
+struct Item {
+    run: usize,
+    character: char,
+}
+
+/// Single line of a paragraph.
+pub struct Line {
+    items: Vec<Item>,
+    width: usize,
+}
+
+impl Line {
+    /// Returns the width of this line in cells, excluding trailing
+    /// whitespace.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+}
+
+/// Paragraph of styled text that has been broken into lines.
+pub struct Paragraph {
+    runs: Vec<ResolvedTextStyle>,
+    lines: Vec<Line>,
+    align: TextAlign,
+}
+
+impl Paragraph {
+    /// Lays out the given text. If a maximum width (in cells) is given, lines
+    /// are broken at whitespace so that they do not exceed that width. Words
+    /// that are wider than the maximum width are broken at that width.
+    pub fn new<L>(text: &AttributedText<L>, max_width: Option<usize>) -> Paragraph {
+        let mut runs = vec![];
+        let mut items = vec![];
+
+        for (run, attributed) in text.runs().iter().enumerate() {
+            runs.push(attributed.style);
+
+            // Tabs are replaced by a single space and other control characters
+            // (except for line breaks) are removed.
+            items.extend(
+                attributed
+                    .text
+                    .chars()
+                    .filter_map(|character| match character {
+                        '\n' => Some(Item { run, character }),
+                        '\t' => Some(Item {
+                            run,
+                            character: ' ',
+                        }),
+                        _ if character.is_control() => None,
+                        _ => Some(Item { run, character }),
+                    }),
+            );
+        }
+
+        Paragraph {
+            runs,
+            lines: break_lines(&items, max_width.map(|width| width.max(1))),
+            align: text.text_align(),
+        }
+    }
+
+    /// Returns the lines of this paragraph.
+    pub fn lines(&self) -> &[Line] {
+        &self.lines
+    }
+
+    /// Returns the size (in cells) of the smallest box that contains all
+    /// lines.
+    pub fn size(&self) -> Size<usize> {
+        Size::new(
+            self.lines.iter().map(Line::width).max().unwrap_or(0),
+            self.lines.len(),
+        )
+    }
+
+    /// Returns the column, row and cell of every character in this paragraph
+    /// if the paragraph is aligned within a box of the given width. Cells do
+    /// not have a background color.
+    pub fn cells(&self, width: usize) -> Vec<(usize, usize, Cell)> {
+        let mut cells = vec![];
+
+        for (y, line) in self.lines.iter().enumerate() {
+            let left = match self.align {
+                TextAlign::Left | TextAlign::Justify => 0,
+                TextAlign::Center => width.saturating_sub(line.width) / 2,
+                TextAlign::Right => width.saturating_sub(line.width),
+            };
+
+            // Trailing whitespace is not drawn.
+            for (x, item) in line.items[..line.width].iter().enumerate() {
+                cells.push((left + x, y, cell(&self.runs[item.run], item.character)));
+            }
+        }
+
+        cells
+    }
+}
+
+fn break_lines(items: &[Item], max_width: Option<usize>) -> Vec<Line> {
+    let mut lines = vec![];
+    let mut line: Vec<Item> = vec![];
+    let mut index = 0;
+
+    let finish = |line: &mut Vec<Item>, lines: &mut Vec<Line>| {
+        let width = line
+            .iter()
+            .rposition(|item| !item.character.is_whitespace())
+            .map_or(0, |position| position + 1);
+
+        lines.push(Line {
+            items: std::mem::take(line),
+            width,
+        });
+    };
+
+    while index < items.len() {
+        let item = &items[index];
+
+        if item.character == '\n' {
+            finish(&mut line, &mut lines);
+            index += 1;
+            continue;
+        }
+
+        // A chunk is either a sequence of whitespace or a word.
+        let whitespace = item.character.is_whitespace();
+        let end = items[index..]
+            .iter()
+            .position(|item| item.character == '\n' || item.character.is_whitespace() != whitespace)
+            .map_or(items.len(), |offset| index + offset);
+        let mut chunk = &items[index..end];
+
+        // Words that don't fit move to the next line. Whitespace before the
+        // break stays at the end of the previous line, where it doesn't count
+        // towards its width. Words that don't fit on a line of their own are
+        // broken up.
+        if let (false, Some(max_width)) = (whitespace, max_width) {
+            let occupied = line.iter().any(|item| !item.character.is_whitespace());
+
+            if occupied && line.len() + chunk.len() > max_width {
+                finish(&mut line, &mut lines);
+            }
+
+            while line.len() + chunk.len() > max_width {
+                let (head, tail) = chunk.split_at(max_width.saturating_sub(line.len()));
+                line.extend_from_slice(head);
+                finish(&mut line, &mut lines);
+                chunk = tail;
+            }
+        }
+
+        line.extend_from_slice(chunk);
+        index = end;
+    }
+
+    if !line.is_empty() || matches!(items.last(), Some(item) if item.character == '\n') {
+        finish(&mut line, &mut lines);
+    }
+
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use polyhorn_ui::color::Color;
+    use polyhorn_ui::font::FontWeight;
+    use polyhorn_ui::geometry::Size;
+    use polyhorn_ui::styles::{Inherited, TextAlign, TextStyle};
+    use polyhorn_ui::text::{AttributedText, AttributedTextBuilder};
+
+    use super::{base_style, Paragraph};
+
+    fn attributed(string: &str) -> AttributedText {
+        let mut builder = AttributedTextBuilder::with_base(
+            &base_style(),
+            &Default::default(),
+            &Default::default(),
+        );
+        builder.push_str(string);
+        builder.build()
+    }
+
+    fn text(paragraph: &Paragraph, width: usize) -> Vec<String> {
+        let size = paragraph.size();
+        let mut rows = vec![vec![' '; width.max(size.width)]; size.height];
+
+        for (x, y, cell) in paragraph.cells(width) {
+            rows[y][x] = cell.character;
+        }
+
+        rows.into_iter()
+            .map(|row| row.into_iter().collect::<String>().trim_end().to_owned())
+            .collect()
+    }
+
+    #[test]
+    fn test_paragraph() {
+        let paragraph = Paragraph::new(&attributed("Hello world, this is\nPolyhorn"), Some(11));
+
+        assert_eq!(
+            text(&paragraph, 11),
+            vec!["Hello", "world, this", "is", "Polyhorn"]
+        );
+        assert_eq!(paragraph.size(), Size::new(11, 4));
+
+        // Words that are wider than the maximum width are broken up.
+        let paragraph = Paragraph::new(&attributed("a abcdefgh"), Some(3));
+
+        assert_eq!(text(&paragraph, 3), vec!["a", "abc", "def", "gh"]);
+    }
+
+    #[test]
+    fn test_styles() {
+        let style = TextStyle {
+            text_align: Inherited::Specified(TextAlign::Right),
+            ..Default::default()
+        };
+
+        let mut builder =
+            AttributedTextBuilder::with_base(&base_style(), &style, &Default::default());
+        builder.push_str("a");
+        builder.push_style(
+            &TextStyle {
+                color: Inherited::Specified(Color::rgb(255, 0, 0)),
+                font_weight: Inherited::Specified(FontWeight::Bold),
+                ..Default::default()
+            },
+            &Default::default(),
+        );
+        builder.push_str("b");
+
+        let paragraph = Paragraph::new(&builder.build(), None);
+        let cells = paragraph.cells(4);
+
+        assert_eq!(text(&paragraph, 4), vec!["  ab"]);
+        assert_eq!((cells[0].2.foreground, cells[0].2.bold), (None, false));
+        assert_eq!(
+            (cells[1].2.foreground, cells[1].2.bold),
+            (Some(Color::rgb(255, 0, 0)), true)
+        );
+    }
+}
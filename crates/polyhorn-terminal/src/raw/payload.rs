@@ -0,0 +1,36 @@
+use polyhorn_ui::styles::ViewStyle;
+use polyhorn_ui::text::AttributedText;
+use serde::{Deserialize, Serialize};
+
+use super::{Builtin, Platform};
+
+/// Serializable representation of a builtin that can be recorded and
+/// replayed (see `Renderer::record`). Listeners are not recorded, so
+/// replayed views don't receive events.
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum Payload {
+    /// Text that consists of the given runs.
+    Text(AttributedText<()>),
+
+    /// View with the given style.
+    View(ViewStyle),
+}
+
+impl polyhorn_core::Payload<Platform> for Payload {
+    type Builtin = Builtin;
+
+    fn encode(builtin: &Builtin) -> Payload {
+        match builtin {
+            Builtin::Text(text) => Payload::Text(text.clone()),
+            &Builtin::View(style, _) => Payload::View(style),
+        }
+    }
+
+    fn decode(self) -> Builtin {
+        match self {
+            Payload::Text(text) => Builtin::Text(text),
+            Payload::View(style) => Builtin::View(style, None),
+        }
+    }
+}
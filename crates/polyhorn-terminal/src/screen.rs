@@ -0,0 +1,540 @@
+use futures::executor::LocalPool;
+use futures::task::LocalSpawnExt;
+use polyhorn_core::{EventLoop, Renderer};
+use polyhorn_ui::geometry::{Dimension, Size};
+use polyhorn_ui::layout::{LayoutNode, LayoutTree};
+use polyhorn_ui::styles::{ViewStyle, Visibility};
+use std::sync::{Arc, RwLock};
+
+use crate::raw::{Builtin, Compositor, Environment, Event, Grid, Listeners, Node, Painter};
+use crate::{Element, Instance};
+
+/// Layout events can cause re-renders, which can in turn change the layout.
+/// To prevent views that keep changing their size in response to their own
+/// layout events from blocking the screen, we stop emitting layout events
+/// after this number of consecutive passes.
+const MAX_LAYOUT_PASSES: usize = 8;
+
+/// Key that is pressed by the user.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Keystroke {
+    /// Moves the focus to the next view.
+    Tab,
+
+    /// Moves the focus to the previous view (i.e. shift + tab).
+    BackTab,
+
+    /// Moves the focus to the previous view.
+    Up,
+
+    /// Moves the focus to the next view.
+    Down,
+
+    /// Moves the focus to the previous view.
+    Left,
+
+    /// Moves the focus to the next view.
+    Right,
+
+    /// Presses the view that has focus.
+    Enter,
+
+    /// Cancels pressing the view that has focus and removes the focus.
+    Escape,
+
+    /// Any other character. Spaces press the view that has focus, just like
+    /// enter.
+    Char(char),
+}
+
+/// Interactive user interface that is rendered into a grid of character cells.
+/// A screen drives its own event loop: effects, state changes and events are
+/// processed whenever the screen is updated, which happens automatically
+/// after each keystroke.
+///
+/// Views that listen for pointer events can receive focus. The view that has
+/// focus is highlighted with reversed colors and receives a pointer down and
+/// pointer up event when the user presses enter or space.
+pub struct Screen {
+    _instance: Instance,
+    pool: LocalPool,
+    root: Node,
+    size: Size<usize>,
+    focus: Option<Node>,
+}
+
+impl Screen {
+    /// Renders the given element into a new screen of the given size (in
+    /// columns and rows).
+    pub fn new(element: Element, size: Size<usize>) -> Screen {
+        let layout_tree = Arc::new(RwLock::new(LayoutTree::new()));
+
+        // The root of the layout tree has the size of the screen.
+        let layout = LayoutNode::new(layout_tree.clone());
+        layout.set_style(root_style(size));
+
+        layout_tree.write().unwrap().roots_mut().push(layout.node());
+
+        let root = Node::new(layout, None);
+
+        let mut compositor = Compositor::new(Environment::new(layout_tree));
+        let id = compositor.track(root.clone());
+
+        let executor = <crate::raw::Platform as polyhorn_core::Platform>::default_executor();
+        let (evloop, handler) = EventLoop::new(executor);
+
+        let pool = LocalPool::new();
+        pool.spawner()
+            .spawn_local(handler.main())
+            .expect("Spawning onto a local pool that is alive should never fail.");
+
+        let renderer = Renderer::new(compositor, evloop);
+        let instance = renderer.render(element, id);
+
+        let mut screen = Screen {
+            _instance: instance,
+            pool,
+            root,
+            size,
+            focus: None,
+        };
+
+        screen.update();
+        screen
+    }
+
+    /// Returns the size of this screen in columns and rows.
+    pub fn size(&self) -> Size<usize> {
+        self.size
+    }
+
+    /// Changes the size of this screen to the given number of columns and
+    /// rows and lays out its contents again.
+    pub fn resize(&mut self, size: Size<usize>) {
+        self.size = size;
+
+        let layout = self.root.layout();
+        layout.set_style(root_style(size));
+        layout.layouter().write().unwrap().recompute_roots();
+
+        self.update();
+    }
+
+    /// Returns the view that currently has focus (if any).
+    pub fn focus(&self) -> Option<&Node> {
+        self.focus.as_ref()
+    }
+
+    /// Draws the contents of this screen into a new grid.
+    pub fn grid(&self) -> Grid {
+        let mut grid = Grid::new(self.size);
+        Painter::new(self.focus.as_ref()).paint(&self.root, &mut grid);
+        grid
+    }
+
+    /// Handles the given keystroke and updates the screen. Returns a boolean
+    /// that indicates if the keystroke was handled.
+    pub fn press(&mut self, keystroke: Keystroke) -> bool {
+        let handled = match keystroke {
+            Keystroke::Tab | Keystroke::Down | Keystroke::Right => self.move_focus(true),
+            Keystroke::BackTab | Keystroke::Up | Keystroke::Left => self.move_focus(false),
+            Keystroke::Enter | Keystroke::Char(' ') => match self.listeners() {
+                Some(listeners) => {
+                    listeners.emit(Event::PointerDown);
+                    listeners.emit(Event::PointerUp);
+                    true
+                }
+                None => false,
+            },
+            Keystroke::Escape => match self.listeners() {
+                Some(listeners) => {
+                    listeners.emit(Event::PointerCancel);
+                    self.focus = None;
+                    true
+                }
+                None => false,
+            },
+            Keystroke::Char(_) => false,
+        };
+
+        self.update();
+
+        handled
+    }
+
+    /// Processes pending effects, state changes and events until no further
+    /// progress can be made, and emits layout events to views whose size has
+    /// changed.
+    pub fn update(&mut self) {
+        for _ in 0..MAX_LAYOUT_PASSES {
+            self.pool.run_until_stalled();
+
+            if !report_layout(&self.root) {
+                break;
+            }
+        }
+
+        self.pool.run_until_stalled();
+
+        // The view that has focus may have been removed or may no longer
+        // listen for pointer events.
+        if let Some(focus) = &self.focus {
+            if !self.focusable().contains(focus) {
+                self.focus = None;
+            }
+        }
+    }
+
+    /// Returns the listeners of the view that has focus (if any).
+    fn listeners(&self) -> Option<Listeners> {
+        match self.focus.as_ref()?.builtin() {
+            Some(Builtin::View(_, listeners)) => listeners,
+            _ => None,
+        }
+    }
+
+    /// Returns all visible views that can receive focus in the order in which
+    /// they appear in the tree.
+    fn focusable(&self) -> Vec<Node> {
+        fn visit(node: &Node, results: &mut Vec<Node>) {
+            match node.builtin() {
+                Some(Builtin::View(style, listeners)) => {
+                    if style.visibility == Visibility::Hidden || style.opacity <= 0.0 {
+                        return;
+                    }
+
+                    if matches!(listeners, Some(listeners) if listeners.is_focusable()) {
+                        results.push(node.clone());
+                    }
+                }
+                Some(Builtin::Text(..)) | None => {}
+            }
+
+            for child in node.children() {
+                visit(&child, results);
+            }
+        }
+
+        let mut results = vec![];
+        visit(&self.root, &mut results);
+        results
+    }
+
+    /// Moves the focus to the next (or previous) view that can receive focus.
+    /// If no view has focus, the first (or last) view receives focus.
+    fn move_focus(&mut self, forward: bool) -> bool {
+        let focusable = self.focusable();
+
+        if focusable.is_empty() {
+            return false;
+        }
+
+        let current = self
+            .focus
+            .as_ref()
+            .and_then(|focus| focusable.iter().position(|node| node == focus));
+
+        let count = focusable.len();
+        let index = match (current, forward) {
+            (Some(index), true) => (index + 1) % count,
+            (Some(index), false) => (index + count - 1) % count,
+            (None, true) => 0,
+            (None, false) => count - 1,
+        };
+
+        self.focus = Some(focusable[index].clone());
+        true
+    }
+}
+
+/// Returns the style of the root of the layout tree, which has the size of the
+/// screen.
+fn root_style(size: Size<usize>) -> ViewStyle {
+    ViewStyle {
+        size: Size::new(
+            Dimension::Points(size.width as f32),
+            Dimension::Points(size.height as f32),
+        ),
+        ..Default::default()
+    }
+}
+
+/// Emits a layout event to every view in the given tree whose size has changed
+/// since its previous layout event. Returns a boolean that indicates if any
+/// events were emitted.
+fn report_layout(node: &Node) -> bool {
+    let mut reported = false;
+
+    if let Some(Builtin::View(_, Some(listeners))) = node.builtin() {
+        let size = node.layout().current().size;
+
+        if node.report_size(size) {
+            listeners.emit(Event::Layout(size));
+            reported = true;
+        }
+    }
+
+    for child in node.children() {
+        reported |= report_layout(&child);
+    }
+
+    reported
+}
+
+#[cfg(test)]
+mod tests {
+    use polyhorn_ui::color::Color;
+    use polyhorn_ui::events::EventListener;
+    use polyhorn_ui::geometry::{ByEdge, Dimension, Size};
+    use polyhorn_ui::layout::{LayoutAxisX, LayoutAxisY};
+    use polyhorn_ui::styles::{Border, FlexDirection, Position, Relative, ViewStyle};
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use super::{Keystroke, Screen};
+    use crate::prelude::*;
+    use crate::Key;
+
+    fn bordered() -> ViewStyle {
+        let border = Border {
+            width: Dimension::Points(1.0),
+            color: Color::rgb(255, 255, 255),
+            ..Default::default()
+        };
+
+        ViewStyle {
+            border: ByEdge {
+                horizontal: LayoutAxisX::independent(border, border),
+                vertical: LayoutAxisY {
+                    top: border,
+                    bottom: border,
+                },
+            },
+            padding: ByEdge {
+                horizontal: LayoutAxisX::independent(
+                    Dimension::Points(1.0),
+                    Dimension::Points(1.0),
+                ),
+                vertical: LayoutAxisY {
+                    top: Dimension::Points(1.0),
+                    bottom: Dimension::Points(1.0),
+                },
+            },
+            ..Default::default()
+        }
+    }
+
+    fn text(key: usize, text: &str) -> Element {
+        Element::new(Key::new(key), Text::default().into(), Element::string(text))
+    }
+
+    #[test]
+    fn test_grid() {
+        let window = Window {
+            style: ViewStyle {
+                flex_direction: FlexDirection::Row,
+                ..Default::default()
+            },
+        };
+
+        let mut left = View {
+            style: ViewStyle {
+                background_color: Color::rgb(0, 0, 255),
+                ..bordered()
+            },
+            ..Default::default()
+        };
+        left.style.size.width = Dimension::Points(14.0);
+
+        let right = View {
+            style: ViewStyle {
+                position: Position::Relative(Relative {
+                    flex_grow: 1.0,
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let element = Element::new(
+            Key::new(()),
+            window.into(),
+            Element::fragment(
+                Key::new(()),
+                vec![
+                    Element::new(
+                        Key::new(0),
+                        left.into(),
+                        text(0, "Hello world from Polyhorn"),
+                    ),
+                    Element::new(Key::new(1), right.into(), text(0, "Menu")),
+                ],
+            ),
+        );
+
+        let screen = Screen::new(element, Size::new(20, 6));
+        let grid = screen.grid();
+
+        assert_eq!(
+            grid.to_string(),
+            [
+                "┌────────────┐Menu",
+                "│Hello world │",
+                "│from        │",
+                "│Polyhorn    │",
+                "│            │",
+                "└────────────┘",
+            ]
+            .join("\n")
+        );
+
+        let cell = grid.cell(1, 1).unwrap();
+        assert_eq!(cell.character, 'H');
+        assert_eq!(cell.background, Some(Color::rgb(0, 0, 255)));
+        assert_eq!(
+            grid.cell(0, 0).unwrap().foreground,
+            Some(Color::rgb(255, 255, 255))
+        );
+    }
+
+    #[test]
+    fn test_text_with_other_children() {
+        // Children of a text that are neither texts nor strings are ignored.
+        let element = Element::new(
+            Key::new(()),
+            Window::default().into(),
+            Element::new(
+                Key::new(()),
+                Text::default().into(),
+                Element::fragment(
+                    Key::new(()),
+                    vec![
+                        Element::string("Hello, "),
+                        Element::new(Key::new(0), View::default().into(), text(0, "view")),
+                        Element::context(Key::new(1), Rc::new(0), Element::string("context")),
+                        Element::string("world"),
+                    ],
+                ),
+            ),
+        );
+
+        let screen = Screen::new(element, Size::new(14, 1));
+        assert_eq!(screen.grid().to_string(), "Hello, world");
+    }
+
+    struct Counter {
+        presses: Rc<RefCell<Vec<&'static str>>>,
+    }
+
+    impl Component for Counter {
+        fn render(&self, manager: &mut Manager) -> Element {
+            let count = use_state!(manager, 0);
+            let value = *count.get(manager);
+            let count = count.weak(manager);
+
+            let button = View {
+                on_pointer_up: EventListener::new(move |_| {
+                    count.replace(value + 1);
+                }),
+                ..Default::default()
+            };
+
+            let presses = self.presses.clone();
+            let cancel = View {
+                on_pointer_cancel: EventListener::new(move |_| {
+                    presses.borrow_mut().push("cancel");
+                }),
+                ..Default::default()
+            };
+
+            Element::fragment(
+                Key::new(()),
+                vec![
+                    Element::new(Key::new(0), button.into(), text(0, &format!("{}", value))),
+                    Element::new(Key::new(1), cancel.into(), text(0, "Cancel")),
+                ],
+            )
+        }
+    }
+
+    #[test]
+    fn test_press() {
+        let presses = Rc::new(RefCell::new(vec![]));
+        let counter = Counter {
+            presses: presses.clone(),
+        };
+
+        let element = Element::new(
+            Key::new(()),
+            Window::default().into(),
+            Element::new(Key::new(()), counter.into(), Element::empty()),
+        );
+
+        let mut screen = Screen::new(element, Size::new(8, 2));
+        assert_eq!(screen.grid().to_string(), "0\nCancel");
+
+        // Nothing has focus, so enter isn't handled.
+        assert!(!screen.press(Keystroke::Enter));
+
+        assert!(screen.press(Keystroke::Tab));
+        assert!(screen.grid().cell(0, 0).unwrap().reverse);
+        assert!(!screen.grid().cell(0, 1).unwrap().reverse);
+
+        assert!(screen.press(Keystroke::Enter));
+        assert!(screen.press(Keystroke::Char(' ')));
+        assert_eq!(screen.grid().to_string(), "2\nCancel");
+
+        // The focus wraps around.
+        assert!(screen.press(Keystroke::Tab));
+        assert!(screen.press(Keystroke::Tab));
+        assert!(screen.press(Keystroke::BackTab));
+        assert!(screen.grid().cell(0, 1).unwrap().reverse);
+
+        assert!(screen.press(Keystroke::Escape));
+        assert_eq!(&*presses.borrow(), &["cancel"]);
+        assert!(screen.focus().is_none());
+        assert!(!screen.press(Keystroke::Char('x')));
+    }
+
+    struct Measured {
+        sizes: Rc<RefCell<Vec<Size<f32>>>>,
+    }
+
+    impl Component for Measured {
+        fn render(&self, _manager: &mut Manager) -> Element {
+            let sizes = self.sizes.clone();
+            let mut view = View::default();
+            view.style.size.height = Dimension::Percentage(0.5);
+            view.on_layout = EventListener::new(move |size| sizes.borrow_mut().push(size));
+
+            Element::new(Key::new(()), view.into(), Element::empty())
+        }
+    }
+
+    #[test]
+    fn test_layout() {
+        let sizes = Rc::new(RefCell::new(vec![]));
+        let measured = Measured {
+            sizes: sizes.clone(),
+        };
+
+        let element = Element::new(
+            Key::new(()),
+            Window::default().into(),
+            Element::new(Key::new(()), measured.into(), Element::empty()),
+        );
+
+        let mut screen = Screen::new(element, Size::new(10, 4));
+        screen.resize(Size::new(10, 4));
+        screen.resize(Size::new(12, 6));
+
+        assert_eq!(
+            &*sizes.borrow(),
+            &[Size::new(10.0, 2.0), Size::new(12.0, 3.0)]
+        );
+
+        // Views that only listen for layout events can't receive focus.
+        assert!(!screen.press(Keystroke::Tab));
+    }
+}
@@ -0,0 +1,104 @@
+use crossterm::cursor::{Hide, MoveTo, Show};
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyModifiers};
+use crossterm::style::Print;
+use crossterm::terminal::{self, EnterAlternateScreen, LeaveAlternateScreen};
+use crossterm::{execute, queue};
+use polyhorn_ui::geometry::Size;
+use std::io::{self, Stdout, Write};
+use std::time::Duration;
+
+use crate::{Element, Keystroke, Screen};
+
+/// Timers and other asynchronous work are processed at least this often while
+/// the terminal waits for input.
+const POLL_INTERVAL: Duration = Duration::from_millis(16);
+
+/// Puts the terminal in raw mode and switches to its alternate screen for as
+/// long as it's alive.
+struct Session {
+    stdout: Stdout,
+}
+
+impl Session {
+    fn start() -> io::Result<Session> {
+        let mut stdout = io::stdout();
+
+        terminal::enable_raw_mode().map_err(into_io)?;
+        execute!(stdout, EnterAlternateScreen, Hide).map_err(into_io)?;
+
+        Ok(Session { stdout })
+    }
+}
+
+impl Drop for Session {
+    fn drop(&mut self) {
+        let _ = execute!(self.stdout, Show, LeaveAlternateScreen);
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+fn into_io(error: crossterm::ErrorKind) -> io::Error {
+    match error {
+        crossterm::ErrorKind::IoError(error) => error,
+        error => io::Error::other(error.to_string()),
+    }
+}
+
+/// Returns the keystroke that corresponds to the given key event (if any).
+fn keystroke(event: KeyEvent) -> Option<Keystroke> {
+    Some(match event.code {
+        KeyCode::Tab => Keystroke::Tab,
+        KeyCode::BackTab => Keystroke::BackTab,
+        KeyCode::Up => Keystroke::Up,
+        KeyCode::Down => Keystroke::Down,
+        KeyCode::Left => Keystroke::Left,
+        KeyCode::Right => Keystroke::Right,
+        KeyCode::Enter => Keystroke::Enter,
+        KeyCode::Esc => Keystroke::Escape,
+        KeyCode::Char(character) => Keystroke::Char(character),
+        _ => return None,
+    })
+}
+
+/// Renders the given element into the terminal and handles keyboard input
+/// until the user presses control + C. The screen is only redrawn when its
+/// contents change.
+pub fn run(element: Element) -> io::Result<()> {
+    let (columns, rows) = terminal::size().map_err(into_io)?;
+    let mut screen = Screen::new(element, Size::new(columns as usize, rows as usize));
+
+    let mut session = Session::start()?;
+    let mut previous = None;
+
+    loop {
+        let grid = screen.grid();
+
+        if previous.as_ref() != Some(&grid) {
+            queue!(session.stdout, MoveTo(0, 0), Print(grid.to_ansi())).map_err(into_io)?;
+            session.stdout.flush()?;
+            previous = Some(grid);
+        }
+
+        if event::poll(POLL_INTERVAL).map_err(into_io)? {
+            match event::read().map_err(into_io)? {
+                Event::Key(KeyEvent {
+                    code: KeyCode::Char('c'),
+                    modifiers,
+                }) if modifiers.contains(KeyModifiers::CONTROL) => break,
+                Event::Key(event) => {
+                    if let Some(keystroke) = keystroke(event) {
+                        screen.press(keystroke);
+                    }
+                }
+                Event::Resize(columns, rows) => {
+                    screen.resize(Size::new(columns as usize, rows as usize))
+                }
+                Event::Mouse(_) => {}
+            }
+        }
+
+        screen.update();
+    }
+
+    Ok(())
+}
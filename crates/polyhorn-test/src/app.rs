@@ -20,7 +20,7 @@ impl Component for App {
             let on_render = on_render.weak(manager);
 
             let client = Client::new(std::env::var("POLYHORN_TEST_FEEDBACK_URL").unwrap());
-            let handler = Handler::new(client, element, on_render);
+            let handler = Handler::new(client, manager.executor().clone(), element, on_render);
 
             |mut receiver: Receiver<Request<Automation>>| async move {
                 while let Some(message) = receiver.next().await {
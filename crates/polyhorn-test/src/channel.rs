@@ -1,12 +1,14 @@
 use futures::channel::oneshot::{channel, Sender};
 use polyhorn::prelude::*;
-use polyhorn::WeakReference;
+use polyhorn::{Executor, WeakReference};
+use std::sync::Arc;
 
 use super::automator::{Automation, Request};
 use super::client::{Client, Message};
 
 pub struct Handler {
     client: Client,
+    executor: Arc<dyn Executor>,
     element: WeakReference<Option<Element>>,
     on_render: WeakReference<Option<Sender<()>>>,
 }
@@ -14,11 +16,13 @@ pub struct Handler {
 impl Handler {
     pub fn new(
         client: Client,
+        executor: Arc<dyn Executor>,
         element: WeakReference<Option<Element>>,
         on_render: WeakReference<Option<Sender<()>>>,
     ) -> Handler {
         Handler {
             client,
+            executor,
             element,
             on_render,
         }
@@ -50,7 +54,7 @@ impl Handler {
                     .await;
             }
             Automation::Wait(duration) => {
-                tokio::time::delay_for(duration).await;
+                self.executor.delay(duration).await;
             }
         }
 
@@ -0,0 +1,337 @@
+//! Conversions between the color spaces of CSS Color Module Level 4. All
+//! matrices are taken from the sample code of the specification. CIE XYZ with
+//! a D65 white point is used as the connection space.
+
+/// Three coordinates of a color in a specific color space.
+pub type Coordinates = [f32; 3];
+
+type Matrix = [[f64; 3]; 3];
+
+const LINEAR_SRGB_TO_XYZ: Matrix = [
+    [0.41239079926595934, 0.357584339383878, 0.1804807884018343],
+    [0.21263900587151027, 0.715168678767756, 0.07219231536073371],
+    [0.01933081871559182, 0.11919477979462598, 0.9505321522496607],
+];
+
+const XYZ_TO_LINEAR_SRGB: Matrix = [
+    [3.2409699419045226, -1.537383177570094, -0.4986107602930034],
+    [-0.9692436362808796, 1.8759675015077202, 0.04155505740717559],
+    [
+        0.05563007969699366,
+        -0.20397695888897652,
+        1.0569715142428786,
+    ],
+];
+
+const LINEAR_DISPLAY_P3_TO_XYZ: Matrix = [
+    [0.4865709486482162, 0.26566769316909306, 0.1982172852343625],
+    [0.2289745640697488, 0.6917385218365064, 0.079286914093745],
+    [0.0, 0.04511338185890264, 1.043944368900976],
+];
+
+const XYZ_TO_LINEAR_DISPLAY_P3: Matrix = [
+    [2.493496911941425, -0.9313836179191239, -0.40271078445071684],
+    [
+        -0.8294889695615747,
+        1.7626640603183463,
+        0.023624685841943577,
+    ],
+    [
+        0.03584583024378447,
+        -0.07617238926804182,
+        0.9568845240076872,
+    ],
+];
+
+/// Bradford chromatic adaptation from a D50 white point to D65.
+const D50_TO_D65: Matrix = [
+    [
+        0.9554734527042182,
+        -0.023098536874261423,
+        0.0632593086610217,
+    ],
+    [
+        -0.028369706963208136,
+        1.0099954580058226,
+        0.021041398966943008,
+    ],
+    [
+        0.012314001688319899,
+        -0.020507696433477912,
+        1.3303659366080753,
+    ],
+];
+
+/// Bradford chromatic adaptation from a D65 white point to D50.
+const D65_TO_D50: Matrix = [
+    [
+        1.0479298208405488,
+        0.022946793341019088,
+        -0.05019222954313557,
+    ],
+    [
+        0.029627815688159344,
+        0.990434484573249,
+        -0.01707382502938514,
+    ],
+    [
+        -0.009243058152591178,
+        0.015055144896577895,
+        0.7518742899580008,
+    ],
+];
+
+const XYZ_TO_LMS: Matrix = [
+    [0.819022437996703, 0.3619062600528904, -0.1288737815209879],
+    [0.0329836539323885, 0.9292868615863434, 0.0361446663506424],
+    [0.0481771893596242, 0.2642395317527308, 0.6335478284694309],
+];
+
+const LMS_TO_XYZ: Matrix = [
+    [1.2268798758459243, -0.5578149944602171, 0.2813910456659647],
+    [-0.0405757452148008, 1.112286803280317, -0.0717110580655164],
+    [-0.0763729366746601, -0.4214933324022432, 1.5869240198367816],
+];
+
+const LMS_TO_OKLAB: Matrix = [
+    [0.210454268309314, 0.7936177747023054, -0.0040720430116193],
+    [1.9779985324311684, -2.42859224204858, 0.450593709617411],
+    [0.0259040424655478, 0.7827717124575296, -0.8086757549249088],
+];
+
+const OKLAB_TO_LMS: Matrix = [
+    [1.0, 0.3963377773761749, 0.2158037573099136],
+    [1.0, -0.1055613458156586, -0.0638541728258133],
+    [1.0, -0.0894841775298119, -1.2914855480194092],
+];
+
+/// This is the D50 white point in XYZ.
+const D50: [f64; 3] = [0.3457 / 0.3585, 1.0, (1.0 - 0.3457 - 0.3585) / 0.3585];
+
+const KAPPA: f64 = 24389.0 / 27.0;
+const EPSILON: f64 = 216.0 / 24389.0;
+
+fn multiply(matrix: &Matrix, coordinates: [f64; 3]) -> [f64; 3] {
+    let mut result = [0.0; 3];
+
+    for (result, row) in result.iter_mut().zip(matrix) {
+        *result = row.iter().zip(&coordinates).map(|(a, b)| a * b).sum();
+    }
+
+    result
+}
+
+fn transform(matrix: &Matrix, coordinates: Coordinates) -> Coordinates {
+    let [a, b, c] = multiply(
+        matrix,
+        [
+            coordinates[0] as f64,
+            coordinates[1] as f64,
+            coordinates[2] as f64,
+        ],
+    );
+
+    [a as f32, b as f32, c as f32]
+}
+
+/// Converts the given gamma-encoded sRGB (or Display-P3) channel to linear
+/// light. Values outside the gamut are extended symmetrically.
+pub fn to_linear(value: f32) -> f32 {
+    let magnitude = value.abs();
+
+    if magnitude <= 0.04045 {
+        value / 12.92
+    } else {
+        value.signum() * ((magnitude + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Converts the given linear sRGB (or Display-P3) channel to its gamma-encoded
+/// form. Values outside the gamut are extended symmetrically.
+pub fn from_linear(value: f32) -> f32 {
+    let magnitude = value.abs();
+
+    if magnitude <= 0.0031308 {
+        value * 12.92
+    } else {
+        value.signum() * (1.055 * magnitude.powf(1.0 / 2.4) - 0.055)
+    }
+}
+
+/// Converts linear sRGB to XYZ.
+pub fn linear_srgb_to_xyz(rgb: Coordinates) -> Coordinates {
+    transform(&LINEAR_SRGB_TO_XYZ, rgb)
+}
+
+/// Converts XYZ to linear sRGB.
+pub fn xyz_to_linear_srgb(xyz: Coordinates) -> Coordinates {
+    transform(&XYZ_TO_LINEAR_SRGB, xyz)
+}
+
+/// Converts linear Display-P3 to XYZ.
+pub fn linear_display_p3_to_xyz(rgb: Coordinates) -> Coordinates {
+    transform(&LINEAR_DISPLAY_P3_TO_XYZ, rgb)
+}
+
+/// Converts XYZ to linear Display-P3.
+pub fn xyz_to_linear_display_p3(xyz: Coordinates) -> Coordinates {
+    transform(&XYZ_TO_LINEAR_DISPLAY_P3, xyz)
+}
+
+/// Converts XYZ with a D50 white point to XYZ with a D65 white point.
+pub fn d50_to_d65(xyz: Coordinates) -> Coordinates {
+    transform(&D50_TO_D65, xyz)
+}
+
+/// Converts XYZ with a D65 white point to XYZ with a D50 white point.
+pub fn d65_to_d50(xyz: Coordinates) -> Coordinates {
+    transform(&D65_TO_D50, xyz)
+}
+
+/// Converts CIE Lab (with a D50 white point) to XYZ.
+pub fn lab_to_xyz(lab: Coordinates) -> Coordinates {
+    let [lightness, a, b] = [lab[0] as f64, lab[1] as f64, lab[2] as f64];
+
+    let f1 = (lightness + 16.0) / 116.0;
+    let f0 = a / 500.0 + f1;
+    let f2 = f1 - b / 200.0;
+
+    let component = |f: f64| match f.powi(3) {
+        cube if cube > EPSILON => cube,
+        _ => (116.0 * f - 16.0) / KAPPA,
+    };
+
+    let y = match lightness > KAPPA * EPSILON {
+        true => f1.powi(3),
+        false => lightness / KAPPA,
+    };
+
+    let xyz = [component(f0) * D50[0], y * D50[1], component(f2) * D50[2]];
+    d50_to_d65([xyz[0] as f32, xyz[1] as f32, xyz[2] as f32])
+}
+
+/// Converts XYZ to CIE Lab (with a D50 white point).
+pub fn xyz_to_lab(xyz: Coordinates) -> Coordinates {
+    let xyz = d65_to_d50(xyz);
+
+    let f = |index: usize| match xyz[index] as f64 / D50[index] {
+        value if value > EPSILON => value.cbrt(),
+        value => (KAPPA * value + 16.0) / 116.0,
+    };
+
+    let (f0, f1, f2) = (f(0), f(1), f(2));
+
+    [
+        (116.0 * f1 - 16.0) as f32,
+        (500.0 * (f0 - f1)) as f32,
+        (200.0 * (f1 - f2)) as f32,
+    ]
+}
+
+/// Converts Oklab to XYZ.
+pub fn oklab_to_xyz(oklab: Coordinates) -> Coordinates {
+    let lms = multiply(
+        &OKLAB_TO_LMS,
+        [oklab[0] as f64, oklab[1] as f64, oklab[2] as f64],
+    );
+    let [x, y, z] = multiply(
+        &LMS_TO_XYZ,
+        [lms[0].powi(3), lms[1].powi(3), lms[2].powi(3)],
+    );
+
+    [x as f32, y as f32, z as f32]
+}
+
+/// Converts XYZ to Oklab.
+pub fn xyz_to_oklab(xyz: Coordinates) -> Coordinates {
+    let lms = multiply(&XYZ_TO_LMS, [xyz[0] as f64, xyz[1] as f64, xyz[2] as f64]);
+    let [l, a, b] = multiply(&LMS_TO_OKLAB, [lms[0].cbrt(), lms[1].cbrt(), lms[2].cbrt()]);
+
+    [l as f32, a as f32, b as f32]
+}
+
+/// Converts the polar form of a Lab-like color space (i.e. lightness, chroma
+/// and hue in degrees) to its rectangular form.
+pub fn polar_to_rectangular(lch: Coordinates) -> Coordinates {
+    let (sin, cos) = lch[2].to_radians().sin_cos();
+    [lch[0], lch[1] * cos, lch[1] * sin]
+}
+
+/// Converts the rectangular form of a Lab-like color space to its polar form
+/// (i.e. lightness, chroma and hue in degrees).
+pub fn rectangular_to_polar(lab: Coordinates) -> Coordinates {
+    let hue = lab[2].atan2(lab[1]).to_degrees();
+    [lab[0], lab[1].hypot(lab[2]), hue.rem_euclid(360.0)]
+}
+
+/// Converts gamma-encoded sRGB to HSL, where the hue is in degrees and the
+/// saturation and lightness are fractions.
+pub fn srgb_to_hsl(rgb: Coordinates) -> Coordinates {
+    let [red, green, blue] = rgb;
+    let max = red.max(green).max(blue);
+    let min = red.min(green).min(blue);
+    let lightness = (max + min) / 2.0;
+    let delta = max - min;
+
+    if delta == 0.0 {
+        return [0.0, 0.0, lightness];
+    }
+
+    let saturation = match lightness {
+        lightness if lightness == 0.0 || lightness == 1.0 => 0.0,
+        lightness => delta / (1.0 - (2.0 * lightness - 1.0).abs()),
+    };
+
+    let hue = if max == red {
+        (green - blue) / delta + if green < blue { 6.0 } else { 0.0 }
+    } else if max == green {
+        (blue - red) / delta + 2.0
+    } else {
+        (red - green) / delta + 4.0
+    };
+
+    [hue * 60.0, saturation, lightness]
+}
+
+/// Converts HSL to gamma-encoded sRGB.
+pub fn hsl_to_srgb(hsl: Coordinates) -> Coordinates {
+    let [hue, saturation, lightness] = hsl;
+    let hue = hue.rem_euclid(360.0);
+
+    let channel = |n: f32| {
+        let k = (n + hue / 30.0) % 12.0;
+        let a = saturation * lightness.min(1.0 - lightness);
+        lightness - a * (k - 3.0).min(9.0 - k).clamp(-1.0, 1.0)
+    };
+
+    [channel(0.0), channel(8.0), channel(4.0)]
+}
+
+/// Converts HWB to gamma-encoded sRGB, where the hue is in degrees and the
+/// whiteness and blackness are fractions.
+pub fn hwb_to_srgb(hwb: Coordinates) -> Coordinates {
+    let [hue, whiteness, blackness] = hwb;
+
+    if whiteness + blackness >= 1.0 {
+        let gray = whiteness / (whiteness + blackness);
+        return [gray, gray, gray];
+    }
+
+    let rgb = hsl_to_srgb([hue, 1.0, 0.5]);
+    let scale = 1.0 - whiteness - blackness;
+
+    [
+        rgb[0] * scale + whiteness,
+        rgb[1] * scale + whiteness,
+        rgb[2] * scale + whiteness,
+    ]
+}
+
+/// Converts gamma-encoded sRGB to HWB.
+pub fn srgb_to_hwb(rgb: Coordinates) -> Coordinates {
+    let hue = srgb_to_hsl(rgb)[0];
+    let whiteness = rgb[0].min(rgb[1]).min(rgb[2]);
+    let blackness = 1.0 - rgb[0].max(rgb[1]).max(rgb[2]);
+
+    [hue, whiteness, blackness]
+}
@@ -2,9 +2,12 @@
 
 use palette::rgb::{Rgb, Rgba};
 use palette::{Alpha, ConvertInto, Hsl, Lab, Mix};
+use std::marker::PhantomData;
 
+mod convert;
 pub mod encoding;
 mod named;
+mod parse;
 
 /// Nonlinear Display-P3.
 pub type DisplayP3<T = f32> = Rgb<encoding::DisplayP3, T>;
@@ -15,7 +18,8 @@ pub type DisplayP3a<T = f32> = Rgba<encoding::DisplayP3, T>;
 pub use palette::{Srgb, Srgba};
 
 #[derive(Copy, Clone, Debug, PartialEq)]
-enum ColorComponents {
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub(crate) enum ColorComponents {
     Transparent,
 
     /// sRGB is the default color space in CSS for colors specified with the
@@ -23,39 +27,68 @@ enum ColorComponents {
     /// color space is (similar to) sRGB. As always, Apple is sparse on details.
     /// It apparently also is the default on Android devices. Note that is the
     /// default even for devices that support wide gamut: that's opt-in.
-    StandardRGB(Srgb),
+    StandardRGB(#[cfg_attr(feature = "serde", serde(with = "rgb"))] Srgb),
 
     /// Display P3 is another color space using the RGB model.
-    DisplayP3(DisplayP3),
+    DisplayP3(#[cfg_attr(feature = "serde", serde(with = "rgb"))] DisplayP3),
+
+    /// CIE Lab with a D50 white point is used for colors specified with the
+    /// `lab(...)` or `lch(...)` syntax. The coordinates are lightness (between
+    /// 0 and 100), a and b.
+    Lab([f32; 3]),
+
+    /// Oklab is used for colors specified with the `oklab(...)` or
+    /// `oklch(...)` syntax. The coordinates are lightness (between 0 and 1), a
+    /// and b.
+    Oklab([f32; 3]),
+
+    /// CIE XYZ with a D65 white point is used for colors specified with the
+    /// `color(xyz ...)` syntax.
+    Xyz([f32; 3]),
 }
 
 /// A color in a color-space with an associated alpha channel.
 #[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Color {
-    components: ColorComponents,
-    alpha: f32,
+    pub(crate) components: ColorComponents,
+    pub(crate) alpha: f32,
 }
 
 impl Color {
     /// This function returns a new translucent color in the sRGB color space
     /// with the given coordinates and alpha channel.
-    pub fn rgba(red: u8, green: u8, blue: u8, alpha: f32) -> Color {
-        Color {
-            components: ColorComponents::StandardRGB(Srgb::new(
-                red as f32 / 255.0,
-                green as f32 / 255.0,
-                blue as f32 / 255.0,
-            )),
+    pub const fn rgba(red: u8, green: u8, blue: u8, alpha: f32) -> Color {
+        Self::srgba(
+            red as f32 / 255.0,
+            green as f32 / 255.0,
+            blue as f32 / 255.0,
             alpha,
-        }
+        )
     }
 
     /// This function returns a new opaque color in the sRGB color space with
     /// the given coordinates.
-    pub fn rgb(red: u8, green: u8, blue: u8) -> Color {
+    pub const fn rgb(red: u8, green: u8, blue: u8) -> Color {
         Self::rgba(red, green, blue, 1.0)
     }
 
+    /// This function returns a new translucent color in the sRGB color space
+    /// with the given coordinates (between 0.0 and 1.0) and alpha channel.
+    pub const fn srgba(red: f32, green: f32, blue: f32, alpha: f32) -> Color {
+        // This constructor is a `const fn` so that the `style!` macro can use
+        // it in constant slices (e.g. box shadows).
+        Color {
+            components: ColorComponents::StandardRGB(Rgb {
+                red,
+                green,
+                blue,
+                standard: PhantomData,
+            }),
+            alpha,
+        }
+    }
+
     /// This function returns a new translucent color in the sRGB color space
     /// with the given hex-encoded coordinates.
     pub fn hexa(hex: u32, alpha: f32) -> Color {
@@ -92,28 +125,144 @@ impl Color {
 
     /// This function returns a new translucent color in the Display-P3 color
     /// space with the given coordinates and alpha channel.
-    pub fn display_p3_rgba(red: u8, green: u8, blue: u8, alpha: f32) -> Color {
-        Color {
-            components: ColorComponents::DisplayP3(DisplayP3::new(
-                red as f32 / 255.0,
-                green as f32 / 255.0,
-                blue as f32 / 255.0,
-            )),
+    pub const fn display_p3_rgba(red: u8, green: u8, blue: u8, alpha: f32) -> Color {
+        Self::display_p3a(
+            red as f32 / 255.0,
+            green as f32 / 255.0,
+            blue as f32 / 255.0,
             alpha,
-        }
+        )
     }
 
     /// This functions returns a new opaque color in the Display-P3 color space
     /// with the given coordinates.
-    pub fn display_p3_rgb(red: u8, green: u8, blue: u8) -> Color {
+    pub const fn display_p3_rgb(red: u8, green: u8, blue: u8) -> Color {
         Self::display_p3_rgba(red, green, blue, 1.0)
     }
 
+    /// This function returns a new translucent color in the Display-P3 color
+    /// space with the given coordinates (between 0.0 and 1.0) and alpha
+    /// channel.
+    pub const fn display_p3a(red: f32, green: f32, blue: f32, alpha: f32) -> Color {
+        Color {
+            components: ColorComponents::DisplayP3(Rgb {
+                red,
+                green,
+                blue,
+                standard: PhantomData,
+            }),
+            alpha,
+        }
+    }
+
+    /// This function returns a new translucent color in the sRGB color space
+    /// with the given coordinates after converting from the alternative HWB
+    /// representation to RGB. Whiteness and blackness are between 0.0 and 1.0.
+    pub fn hwba(hue: f32, whiteness: f32, blackness: f32, alpha: f32) -> Color {
+        let [red, green, blue] = convert::hwb_to_srgb([hue, whiteness, blackness]);
+
+        Color {
+            components: ColorComponents::StandardRGB(Srgb::new(red, green, blue)),
+            alpha,
+        }
+    }
+
+    /// This function returns a new opaque color in the sRGB color space with
+    /// the given coordinates after converting from the alternative HWB
+    /// representation to RGB.
+    pub fn hwb(hue: f32, whiteness: f32, blackness: f32) -> Color {
+        Self::hwba(hue, whiteness, blackness, 1.0)
+    }
+
+    /// This function returns a new translucent color in the CIE Lab color
+    /// space (with a D50 white point) with the given lightness (between 0.0
+    /// and 100.0) and a and b coordinates.
+    pub const fn laba(lightness: f32, a: f32, b: f32, alpha: f32) -> Color {
+        Color {
+            components: ColorComponents::Lab([lightness, a, b]),
+            alpha,
+        }
+    }
+
+    /// This function returns a new opaque color in the CIE Lab color space
+    /// with the given coordinates.
+    pub const fn lab(lightness: f32, a: f32, b: f32) -> Color {
+        Self::laba(lightness, a, b, 1.0)
+    }
+
+    /// This function returns a new translucent color in the CIE Lab color
+    /// space with the given coordinates after converting from the alternative
+    /// LCH representation (with a hue in degrees) to Lab.
+    pub fn lcha(lightness: f32, chroma: f32, hue: f32, alpha: f32) -> Color {
+        Color {
+            components: ColorComponents::Lab(convert::polar_to_rectangular([
+                lightness, chroma, hue,
+            ])),
+            alpha,
+        }
+    }
+
+    /// This function returns a new opaque color in the CIE Lab color space
+    /// with the given coordinates after converting from the alternative LCH
+    /// representation to Lab.
+    pub fn lch(lightness: f32, chroma: f32, hue: f32) -> Color {
+        Self::lcha(lightness, chroma, hue, 1.0)
+    }
+
+    /// This function returns a new translucent color in the Oklab color space
+    /// with the given lightness (between 0.0 and 1.0) and a and b coordinates.
+    pub const fn oklaba(lightness: f32, a: f32, b: f32, alpha: f32) -> Color {
+        Color {
+            components: ColorComponents::Oklab([lightness, a, b]),
+            alpha,
+        }
+    }
+
+    /// This function returns a new opaque color in the Oklab color space with
+    /// the given coordinates.
+    pub const fn oklab(lightness: f32, a: f32, b: f32) -> Color {
+        Self::oklaba(lightness, a, b, 1.0)
+    }
+
+    /// This function returns a new translucent color in the Oklab color space
+    /// with the given coordinates after converting from the alternative Oklch
+    /// representation (with a hue in degrees) to Oklab.
+    pub fn oklcha(lightness: f32, chroma: f32, hue: f32, alpha: f32) -> Color {
+        Color {
+            components: ColorComponents::Oklab(convert::polar_to_rectangular([
+                lightness, chroma, hue,
+            ])),
+            alpha,
+        }
+    }
+
+    /// This function returns a new opaque color in the Oklab color space with
+    /// the given coordinates after converting from the alternative Oklch
+    /// representation to Oklab.
+    pub fn oklch(lightness: f32, chroma: f32, hue: f32) -> Color {
+        Self::oklcha(lightness, chroma, hue, 1.0)
+    }
+
+    /// This function returns a new translucent color in the CIE XYZ color
+    /// space (with a D65 white point) with the given coordinates.
+    pub const fn xyza(x: f32, y: f32, z: f32, alpha: f32) -> Color {
+        Color {
+            components: ColorComponents::Xyz([x, y, z]),
+            alpha,
+        }
+    }
+
+    /// This function returns a new opaque color in the CIE XYZ color space
+    /// with the given coordinates.
+    pub const fn xyz(x: f32, y: f32, z: f32) -> Color {
+        Self::xyza(x, y, z, 1.0)
+    }
+
     /// This function returns an unbiased transparent color. Specifically, when
     /// blending a non-transparent color with a transparent color, the resulting
     /// color will have the same color space and coordinate as the
     /// non-transparent color, and only the alpha channel will be blended.
-    pub fn transparent() -> Color {
+    pub const fn transparent() -> Color {
         Color {
             components: ColorComponents::Transparent,
             alpha: 0.0,
@@ -152,6 +301,15 @@ impl Color {
                 }
                 ColorComponents::DisplayP3(rgb) => rgb,
                 ColorComponents::Transparent => DisplayP3::new(0.0, 0.0, 0.0),
+                _ => {
+                    let [red, green, blue] = convert::xyz_to_linear_display_p3(self.to_xyz());
+
+                    DisplayP3::new(
+                        convert::from_linear(red),
+                        convert::from_linear(green),
+                        convert::from_linear(blue),
+                    )
+                }
             },
             alpha: self.alpha,
         }
@@ -168,11 +326,41 @@ impl Color {
                     lab.convert_into()
                 }
                 ColorComponents::Transparent => Srgb::new(0.0, 0.0, 0.0),
+                _ => {
+                    let [red, green, blue] = convert::xyz_to_linear_srgb(self.to_xyz());
+
+                    Srgb::new(
+                        convert::from_linear(red),
+                        convert::from_linear(green),
+                        convert::from_linear(blue),
+                    )
+                }
             },
             alpha: self.alpha,
         }
     }
 
+    /// This function converts the color to the CIE XYZ color space (with a D65
+    /// white point) and returns its coordinates, without the alpha channel.
+    pub(crate) fn to_xyz(self) -> [f32; 3] {
+        match self.components {
+            ColorComponents::Transparent => [0.0, 0.0, 0.0],
+            ColorComponents::StandardRGB(rgb) => convert::linear_srgb_to_xyz([
+                convert::to_linear(rgb.red),
+                convert::to_linear(rgb.green),
+                convert::to_linear(rgb.blue),
+            ]),
+            ColorComponents::DisplayP3(rgb) => convert::linear_display_p3_to_xyz([
+                convert::to_linear(rgb.red),
+                convert::to_linear(rgb.green),
+                convert::to_linear(rgb.blue),
+            ]),
+            ColorComponents::Lab(lab) => convert::lab_to_xyz(lab),
+            ColorComponents::Oklab(oklab) => convert::oklab_to_xyz(oklab),
+            ColorComponents::Xyz(xyz) => xyz,
+        }
+    }
+
     /// Converts this color to sRGB and returns the resulting hex code.
     pub fn to_hex(&self) -> u32 {
         let srgb = self.to_srgb();
@@ -189,7 +377,39 @@ impl Default for Color {
     }
 }
 
+/// Serializes RGB colors as their coordinates, regardless of their standard.
+#[cfg(feature = "serde")]
+mod rgb {
+    use palette::rgb::{Rgb, RgbStandard};
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use std::marker::PhantomData;
+
+    pub fn serialize<S, R>(rgb: &Rgb<R, f32>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        R: RgbStandard,
+    {
+        [rgb.red, rgb.green, rgb.blue].serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D, R>(deserializer: D) -> Result<Rgb<R, f32>, D::Error>
+    where
+        D: Deserializer<'de>,
+        R: RgbStandard,
+    {
+        let [red, green, blue] = <[f32; 3]>::deserialize(deserializer)?;
+
+        Ok(Rgb {
+            red,
+            green,
+            blue,
+            standard: PhantomData,
+        })
+    }
+}
+
 pub use named::NamedColor;
+pub use parse::ParseColorError;
 
 #[cfg(test)]
 mod tests {
@@ -189,24 +189,17 @@ pub trait NamedColor: FromStr {
 macro_rules! color {
     ($hex: literal, $name:ident, $s:ident) => {
         if $s == stringify!($name) {
-            return Ok(Color::hex($hex));
+            return Some(Color::hex($hex));
         }
     };
 }
 
-#[derive(Copy, Clone, Debug, Eq, PartialEq)]
-pub enum Error {
-    UnknownColorName,
-}
-
-impl FromStr for Color {
-    type Err = Error;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        colors!(s);
+/// Returns the CSS-compliant named color with the given (lowercase) name, if
+/// one exists.
+pub fn from_name(name: &str) -> Option<Color> {
+    colors!(name);
 
-        Err(Error::UnknownColorName)
-    }
+    None
 }
 
 macro_rules! color {
@@ -0,0 +1,834 @@
+//! Parser for the color syntax of CSS Color Module Level 4.
+
+use std::fmt;
+use std::str::FromStr;
+
+use super::{convert, named, Color};
+
+/// Error that is returned when a color cannot be parsed.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum ParseColorError {
+    /// The color is a name that is not one of the CSS named colors.
+    UnknownColorName,
+
+    /// The color is a function that is not supported.
+    UnknownFunction,
+
+    /// The color is specified in (or mixed in) a color space that is not
+    /// supported.
+    UnknownColorSpace,
+
+    /// The color is a supported function, but its arguments are invalid.
+    InvalidArguments,
+
+    /// The color cannot be tokenized, is an invalid hex color or consists of
+    /// more than one value.
+    InvalidSyntax,
+}
+
+impl fmt::Display for ParseColorError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            ParseColorError::UnknownColorName => "Unknown color name.",
+            ParseColorError::UnknownFunction => "Unknown color function.",
+            ParseColorError::UnknownColorSpace => "Unknown color space.",
+            ParseColorError::InvalidArguments => "Invalid color arguments.",
+            ParseColorError::InvalidSyntax => "Invalid color.",
+        })
+    }
+}
+
+impl std::error::Error for ParseColorError {}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Token<'a> {
+    Ident(&'a str),
+    Function(&'a str, Vec<Token<'a>>),
+    Hash(&'a str),
+    Number(f32),
+    Percentage(f32),
+    Dimension(f32, &'a str),
+    Comma,
+    Slash,
+}
+
+struct Lexer<'a> {
+    input: &'a str,
+    position: usize,
+}
+
+impl<'a> Lexer<'a> {
+    fn peek_nth(&self, n: usize) -> Option<char> {
+        self.input[self.position..].chars().nth(n)
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.peek_nth(0)
+    }
+
+    fn take_while<F>(&mut self, predicate: F) -> &'a str
+    where
+        F: Fn(char) -> bool,
+    {
+        let start = self.position;
+
+        while let Some(c) = self.peek() {
+            if !predicate(c) {
+                break;
+            }
+
+            self.position += c.len_utf8();
+        }
+
+        &self.input[start..self.position]
+    }
+
+    /// Returns all tokens until the end of the input or, if nested, until the
+    /// closing parenthesis of the current function.
+    fn tokens(&mut self, nested: bool) -> Result<Vec<Token<'a>>, ParseColorError> {
+        let mut tokens = vec![];
+
+        loop {
+            self.take_while(char::is_whitespace);
+
+            let token = match self.peek() {
+                None if nested => return Err(ParseColorError::InvalidSyntax),
+                None => return Ok(tokens),
+                Some(')') if nested => {
+                    self.position += 1;
+                    return Ok(tokens);
+                }
+                Some(',') => {
+                    self.position += 1;
+                    Token::Comma
+                }
+                Some('/') => {
+                    self.position += 1;
+                    Token::Slash
+                }
+                Some('#') => {
+                    self.position += 1;
+                    Token::Hash(self.take_while(|c| c.is_ascii_alphanumeric()))
+                }
+                Some(_) if self.is_number() => self.number()?,
+                Some(c) if c.is_ascii_alphabetic() || c == '-' => {
+                    let name = self.take_while(|c| c.is_ascii_alphanumeric() || c == '-');
+
+                    match self.peek() {
+                        Some('(') => {
+                            self.position += 1;
+                            Token::Function(name, self.tokens(true)?)
+                        }
+                        _ => Token::Ident(name),
+                    }
+                }
+                Some(_) => return Err(ParseColorError::InvalidSyntax),
+            };
+
+            tokens.push(token);
+        }
+    }
+
+    fn is_number(&self) -> bool {
+        let digit = |c: Option<char>| matches!(c, Some(c) if c.is_ascii_digit());
+
+        match self.peek() {
+            Some('+') | Some('-') => {
+                digit(self.peek_nth(1))
+                    || (self.peek_nth(1) == Some('.') && digit(self.peek_nth(2)))
+            }
+            Some('.') => digit(self.peek_nth(1)),
+            c => digit(c),
+        }
+    }
+
+    fn number(&mut self) -> Result<Token<'a>, ParseColorError> {
+        let start = self.position;
+
+        if let Some('+') | Some('-') = self.peek() {
+            self.position += 1;
+        }
+
+        self.take_while(|c| c.is_ascii_digit());
+
+        if self.peek() == Some('.') {
+            self.position += 1;
+            self.take_while(|c| c.is_ascii_digit());
+        }
+
+        // An `e` is only part of the number if it is followed by an exponent,
+        // so that e.g. `1em` is still lexed as a dimension.
+        if let Some('e') | Some('E') = self.peek() {
+            let offset = match self.peek_nth(1) {
+                Some('+') | Some('-') => 2,
+                _ => 1,
+            };
+
+            if matches!(self.peek_nth(offset), Some(c) if c.is_ascii_digit()) {
+                self.position += offset;
+                self.take_while(|c| c.is_ascii_digit());
+            }
+        }
+
+        let value = self.input[start..self.position]
+            .parse::<f32>()
+            .map_err(|_| ParseColorError::InvalidSyntax)?;
+
+        Ok(match self.peek() {
+            Some('%') => {
+                self.position += 1;
+                Token::Percentage(value)
+            }
+            Some(c) if c.is_ascii_alphabetic() => {
+                Token::Dimension(value, self.take_while(|c| c.is_ascii_alphabetic()))
+            }
+            _ => Token::Number(value),
+        })
+    }
+}
+
+/// Color space in which `color-mix()` interpolates.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+enum Space {
+    Srgb,
+    SrgbLinear,
+    DisplayP3,
+    Lab,
+    Oklab,
+    Xyz,
+    XyzD50,
+    Hsl,
+    Hwb,
+    Lch,
+    Oklch,
+}
+
+impl Space {
+    fn from_name(name: &str) -> Option<Space> {
+        Some(match name {
+            "srgb" => Space::Srgb,
+            "srgb-linear" => Space::SrgbLinear,
+            "display-p3" => Space::DisplayP3,
+            "lab" => Space::Lab,
+            "oklab" => Space::Oklab,
+            "xyz" | "xyz-d65" => Space::Xyz,
+            "xyz-d50" => Space::XyzD50,
+            "hsl" => Space::Hsl,
+            "hwb" => Space::Hwb,
+            "lch" => Space::Lch,
+            "oklch" => Space::Oklch,
+            _ => return None,
+        })
+    }
+
+    /// Returns the index of the hue if this is a polar color space.
+    fn hue(self) -> Option<usize> {
+        match self {
+            Space::Hsl | Space::Hwb => Some(0),
+            Space::Lch | Space::Oklch => Some(2),
+            _ => None,
+        }
+    }
+
+    /// Returns true if the hue of the given coordinates is powerless, i.e. if
+    /// its chroma (or saturation) is effectively zero.
+    fn is_achromatic(self, coordinates: [f32; 3]) -> bool {
+        match self {
+            Space::Hsl => coordinates[1] < 1e-4,
+            Space::Hwb => coordinates[1] + coordinates[2] >= 1.0 - 1e-4,
+            Space::Lch => coordinates[1] < 1e-2,
+            Space::Oklch => coordinates[1] < 1e-4,
+            _ => false,
+        }
+    }
+
+    fn coordinates(self, color: &Color) -> [f32; 3] {
+        let srgb = || {
+            let rgb = color.to_srgb();
+            [rgb.red, rgb.green, rgb.blue]
+        };
+
+        let xyz = color.to_xyz();
+
+        match self {
+            Space::Srgb => srgb(),
+            Space::SrgbLinear => convert::xyz_to_linear_srgb(xyz),
+            Space::DisplayP3 => {
+                let [red, green, blue] = convert::xyz_to_linear_display_p3(xyz);
+
+                [
+                    convert::from_linear(red),
+                    convert::from_linear(green),
+                    convert::from_linear(blue),
+                ]
+            }
+            Space::Lab => convert::xyz_to_lab(xyz),
+            Space::Oklab => convert::xyz_to_oklab(xyz),
+            Space::Xyz => xyz,
+            Space::XyzD50 => convert::d65_to_d50(xyz),
+            Space::Hsl => convert::srgb_to_hsl(srgb()),
+            Space::Hwb => convert::srgb_to_hwb(srgb()),
+            Space::Lch => convert::rectangular_to_polar(convert::xyz_to_lab(xyz)),
+            Space::Oklch => convert::rectangular_to_polar(convert::xyz_to_oklab(xyz)),
+        }
+    }
+
+    fn color(self, coordinates: [f32; 3], alpha: f32) -> Color {
+        let [a, b, c] = coordinates;
+
+        match self {
+            Space::Srgb => srgba([a, b, c], alpha),
+            Space::SrgbLinear => srgba(
+                [
+                    convert::from_linear(a),
+                    convert::from_linear(b),
+                    convert::from_linear(c),
+                ],
+                alpha,
+            ),
+            Space::DisplayP3 => Color::display_p3a(a, b, c, alpha),
+            Space::Lab => Color::laba(a, b, c, alpha),
+            Space::Oklab => Color::oklaba(a, b, c, alpha),
+            Space::Xyz => Color::xyza(a, b, c, alpha),
+            Space::XyzD50 => {
+                let [x, y, z] = convert::d50_to_d65(coordinates);
+                Color::xyza(x, y, z, alpha)
+            }
+            Space::Hsl => srgba(convert::hsl_to_srgb(coordinates), alpha),
+            Space::Hwb => srgba(convert::hwb_to_srgb(coordinates), alpha),
+            Space::Lch => Color::lcha(a, b, c, alpha),
+            Space::Oklch => Color::oklcha(a, b, c, alpha),
+        }
+    }
+}
+
+/// Method that `color-mix()` uses to interpolate hues.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+enum HueMethod {
+    Shorter,
+    Longer,
+    Increasing,
+    Decreasing,
+}
+
+impl HueMethod {
+    /// Adjusts both hues (in degrees) so that linear interpolation between
+    /// them follows the arc that corresponds to this method.
+    fn fixup(self, first: f32, second: f32) -> (f32, f32) {
+        let (mut first, mut second) = (first.rem_euclid(360.0), second.rem_euclid(360.0));
+        let difference = second - first;
+
+        match self {
+            HueMethod::Shorter if difference > 180.0 => first += 360.0,
+            HueMethod::Shorter if difference < -180.0 => second += 360.0,
+            HueMethod::Longer if difference > 0.0 && difference < 180.0 => first += 360.0,
+            HueMethod::Longer if difference > -180.0 && difference <= 0.0 => second += 360.0,
+            HueMethod::Increasing if difference < 0.0 => second += 360.0,
+            HueMethod::Decreasing if difference > 0.0 => first += 360.0,
+            _ => {}
+        }
+
+        (first, second)
+    }
+}
+
+fn srgba(rgb: [f32; 3], alpha: f32) -> Color {
+    Color::srgba(rgb[0], rgb[1], rgb[2], alpha)
+}
+
+fn is_keyword(token: &Token, keyword: &str) -> bool {
+    match token {
+        Token::Ident(name) => name.eq_ignore_ascii_case(keyword),
+        _ => false,
+    }
+}
+
+/// Parses a number or a percentage, where 100% corresponds to the given
+/// reference value. Missing components (i.e. `none`) resolve to zero.
+fn number(token: &Token, reference: f32) -> Result<f32, ParseColorError> {
+    match *token {
+        Token::Number(value) => Ok(value),
+        Token::Percentage(value) => Ok(value / 100.0 * reference),
+        _ if is_keyword(token, "none") => Ok(0.0),
+        _ => Err(ParseColorError::InvalidArguments),
+    }
+}
+
+/// Parses a hue and returns it in degrees.
+fn hue(token: &Token) -> Result<f32, ParseColorError> {
+    match *token {
+        Token::Number(value) => Ok(value),
+        Token::Dimension(value, unit) => match unit.to_ascii_lowercase().as_str() {
+            "deg" => Ok(value),
+            "rad" => Ok(value.to_degrees()),
+            "grad" => Ok(value * 0.9),
+            "turn" => Ok(value * 360.0),
+            _ => Err(ParseColorError::InvalidArguments),
+        },
+        _ if is_keyword(token, "none") => Ok(0.0),
+        _ => Err(ParseColorError::InvalidArguments),
+    }
+}
+
+/// Parses the arguments of a color function: three components followed by an
+/// optional alpha channel. If legacy, the arguments may also be separated by
+/// commas.
+fn arguments<'a>(
+    tokens: &'a [Token<'a>],
+    legacy: bool,
+) -> Result<([&'a Token<'a>; 3], f32), ParseColorError> {
+    let (components, alpha) = match tokens {
+        [a, b, c] => ([a, b, c], None),
+        [a, b, c, Token::Slash, alpha] => ([a, b, c], Some(alpha)),
+        [a, Token::Comma, b, Token::Comma, c] if legacy => ([a, b, c], None),
+        [a, Token::Comma, b, Token::Comma, c, Token::Comma, alpha] if legacy => {
+            ([a, b, c], Some(alpha))
+        }
+        _ => return Err(ParseColorError::InvalidArguments),
+    };
+
+    let alpha = match alpha {
+        Some(alpha) => number(alpha, 1.0)?.clamp(0.0, 1.0),
+        None => 1.0,
+    };
+
+    Ok((components, alpha))
+}
+
+fn parse_hex(hex: &str) -> Result<Color, ParseColorError> {
+    let digits = hex
+        .chars()
+        .map(|c| c.to_digit(16).map(|digit| digit as u8))
+        .collect::<Option<Vec<_>>>()
+        .ok_or(ParseColorError::InvalidSyntax)?;
+
+    let channels = match digits.len() {
+        3 | 4 => digits.iter().map(|digit| digit * 17).collect::<Vec<_>>(),
+        6 | 8 => digits
+            .chunks(2)
+            .map(|pair| pair[0] * 16 + pair[1])
+            .collect(),
+        _ => return Err(ParseColorError::InvalidSyntax),
+    };
+
+    let alpha = channels.get(3).map_or(1.0, |&alpha| alpha as f32 / 255.0);
+
+    Ok(Color::rgba(channels[0], channels[1], channels[2], alpha))
+}
+
+fn parse_rgb(tokens: &[Token]) -> Result<Color, ParseColorError> {
+    let ([red, green, blue], alpha) = arguments(tokens, true)?;
+    let channel = |token| number(token, 255.0).map(|value| (value / 255.0).clamp(0.0, 1.0));
+
+    Ok(srgba(
+        [channel(red)?, channel(green)?, channel(blue)?],
+        alpha,
+    ))
+}
+
+fn parse_hsl(tokens: &[Token]) -> Result<Color, ParseColorError> {
+    let ([h, s, l], alpha) = arguments(tokens, true)?;
+    let fraction = |token| number(token, 100.0).map(|value| (value / 100.0).clamp(0.0, 1.0));
+
+    Ok(srgba(
+        convert::hsl_to_srgb([hue(h)?, fraction(s)?, fraction(l)?]),
+        alpha,
+    ))
+}
+
+fn parse_hwb(tokens: &[Token]) -> Result<Color, ParseColorError> {
+    let ([h, w, b], alpha) = arguments(tokens, false)?;
+    let fraction = |token| number(token, 100.0).map(|value| (value / 100.0).clamp(0.0, 1.0));
+
+    Ok(Color::hwba(hue(h)?, fraction(w)?, fraction(b)?, alpha))
+}
+
+fn parse_lab(tokens: &[Token]) -> Result<Color, ParseColorError> {
+    let ([l, a, b], alpha) = arguments(tokens, false)?;
+    let lightness = number(l, 100.0)?.clamp(0.0, 100.0);
+
+    Ok(Color::laba(
+        lightness,
+        number(a, 125.0)?,
+        number(b, 125.0)?,
+        alpha,
+    ))
+}
+
+fn parse_lch(tokens: &[Token]) -> Result<Color, ParseColorError> {
+    let ([l, c, h], alpha) = arguments(tokens, false)?;
+    let lightness = number(l, 100.0)?.clamp(0.0, 100.0);
+    let chroma = number(c, 150.0)?.max(0.0);
+
+    Ok(Color::lcha(lightness, chroma, hue(h)?, alpha))
+}
+
+fn parse_oklab(tokens: &[Token]) -> Result<Color, ParseColorError> {
+    let ([l, a, b], alpha) = arguments(tokens, false)?;
+    let lightness = number(l, 1.0)?.clamp(0.0, 1.0);
+
+    Ok(Color::oklaba(
+        lightness,
+        number(a, 0.4)?,
+        number(b, 0.4)?,
+        alpha,
+    ))
+}
+
+fn parse_oklch(tokens: &[Token]) -> Result<Color, ParseColorError> {
+    let ([l, c, h], alpha) = arguments(tokens, false)?;
+    let lightness = number(l, 1.0)?.clamp(0.0, 1.0);
+    let chroma = number(c, 0.4)?.max(0.0);
+
+    Ok(Color::oklcha(lightness, chroma, hue(h)?, alpha))
+}
+
+fn parse_color_function(tokens: &[Token]) -> Result<Color, ParseColorError> {
+    let (space, tokens) = match tokens {
+        [Token::Ident(space), tokens @ ..] => (space.to_ascii_lowercase(), tokens),
+        _ => return Err(ParseColorError::InvalidArguments),
+    };
+
+    let space = match Space::from_name(&space) {
+        Some(space) if space.hue().is_none() && space != Space::Lab && space != Space::Oklab => {
+            space
+        }
+        _ => return Err(ParseColorError::UnknownColorSpace),
+    };
+
+    let ([a, b, c], alpha) = arguments(tokens, false)?;
+
+    Ok(space.color([number(a, 1.0)?, number(b, 1.0)?, number(c, 1.0)?], alpha))
+}
+
+fn parse_interpolation(tokens: &[Token]) -> Result<(Space, HueMethod), ParseColorError> {
+    let (space, tokens) = match tokens {
+        [keyword, Token::Ident(space), tokens @ ..] if is_keyword(keyword, "in") => {
+            (space.to_ascii_lowercase(), tokens)
+        }
+        _ => return Err(ParseColorError::InvalidArguments),
+    };
+
+    let space = Space::from_name(&space).ok_or(ParseColorError::UnknownColorSpace)?;
+
+    let method = match tokens {
+        [] => HueMethod::Shorter,
+        [Token::Ident(method), keyword] if space.hue().is_some() && is_keyword(keyword, "hue") => {
+            match method.to_ascii_lowercase().as_str() {
+                "shorter" => HueMethod::Shorter,
+                "longer" => HueMethod::Longer,
+                "increasing" => HueMethod::Increasing,
+                "decreasing" => HueMethod::Decreasing,
+                _ => return Err(ParseColorError::InvalidArguments),
+            }
+        }
+        _ => return Err(ParseColorError::InvalidArguments),
+    };
+
+    Ok((space, method))
+}
+
+fn parse_mix_argument(tokens: &[Token]) -> Result<(Color, Option<f32>), ParseColorError> {
+    let (color, percentage) = match tokens {
+        [color] => (color, None),
+        [color, Token::Percentage(percentage)] | [Token::Percentage(percentage), color] => {
+            (color, Some(*percentage))
+        }
+        _ => return Err(ParseColorError::InvalidArguments),
+    };
+
+    match percentage {
+        Some(percentage) if !(0.0..=100.0).contains(&percentage) => {
+            Err(ParseColorError::InvalidArguments)
+        }
+        _ => Ok((
+            parse(color)?,
+            percentage.map(|percentage| percentage / 100.0),
+        )),
+    }
+}
+
+fn parse_color_mix(tokens: &[Token]) -> Result<Color, ParseColorError> {
+    let arguments = tokens
+        .split(|token| token == &Token::Comma)
+        .collect::<Vec<_>>();
+
+    let (space, method, first, second) = match arguments.as_slice() {
+        [interpolation, first, second] => {
+            let (space, method) = parse_interpolation(interpolation)?;
+            (
+                space,
+                method,
+                parse_mix_argument(first)?,
+                parse_mix_argument(second)?,
+            )
+        }
+        _ => return Err(ParseColorError::InvalidArguments),
+    };
+
+    let ((first, p1), (second, p2)) = (first, second);
+
+    let (p1, p2) = match (p1, p2) {
+        (None, None) => (0.5, 0.5),
+        (Some(p1), None) => (p1, 1.0 - p1),
+        (None, Some(p2)) => (1.0 - p2, p2),
+        (Some(p1), Some(p2)) => (p1, p2),
+    };
+
+    let sum = p1 + p2;
+
+    if sum == 0.0 {
+        return Err(ParseColorError::InvalidArguments);
+    }
+
+    // If the percentages add up to less than 100%, the result is made
+    // translucent accordingly.
+    let (p1, p2, multiplier) = (p1 / sum, p2 / sum, sum.min(1.0));
+
+    let mut a = space.coordinates(&first);
+    let mut b = space.coordinates(&second);
+
+    if let Some(index) = space.hue() {
+        match (space.is_achromatic(a), space.is_achromatic(b)) {
+            (true, false) => a[index] = b[index],
+            (false, true) => b[index] = a[index],
+            _ => {}
+        }
+
+        let (first, second) = method.fixup(a[index], b[index]);
+        a[index] = first;
+        b[index] = second;
+    }
+
+    let alpha = first.alpha * p1 + second.alpha * p2;
+    let mut coordinates = [0.0; 3];
+
+    for (i, coordinate) in coordinates.iter_mut().enumerate() {
+        if Some(i) == space.hue() {
+            *coordinate = a[i] * p1 + b[i] * p2;
+        } else if alpha != 0.0 {
+            // Interpolation happens with premultiplied alpha.
+            *coordinate = (a[i] * first.alpha * p1 + b[i] * second.alpha * p2) / alpha;
+        }
+    }
+
+    Ok(space.color(coordinates, alpha * multiplier))
+}
+
+fn parse(token: &Token) -> Result<Color, ParseColorError> {
+    match token {
+        Token::Ident(name) => match name.to_ascii_lowercase().as_str() {
+            "transparent" => Ok(Color::transparent()),
+            name => named::from_name(name).ok_or(ParseColorError::UnknownColorName),
+        },
+        Token::Hash(hex) => parse_hex(hex),
+        Token::Function(name, tokens) => match name.to_ascii_lowercase().as_str() {
+            "rgb" | "rgba" => parse_rgb(tokens),
+            "hsl" | "hsla" => parse_hsl(tokens),
+            "hwb" => parse_hwb(tokens),
+            "lab" => parse_lab(tokens),
+            "lch" => parse_lch(tokens),
+            "oklab" => parse_oklab(tokens),
+            "oklch" => parse_oklch(tokens),
+            "color" => parse_color_function(tokens),
+            "color-mix" => parse_color_mix(tokens),
+            _ => Err(ParseColorError::UnknownFunction),
+        },
+        _ => Err(ParseColorError::InvalidSyntax),
+    }
+}
+
+impl FromStr for Color {
+    type Err = ParseColorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let tokens = Lexer {
+            input: s,
+            position: 0,
+        }
+        .tokens(false)?;
+
+        match tokens.as_slice() {
+            [token] => parse(token),
+            _ => Err(ParseColorError::InvalidSyntax),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_srgba(color: &str, expected: [f32; 4]) {
+        let rgba = Color::from_str(color).unwrap().to_srgb();
+        let actual = [rgba.red, rgba.green, rgba.blue, rgba.alpha];
+
+        for (actual, expected) in actual.iter().zip(&expected) {
+            assert!(
+                (actual - expected).abs() < 0.005,
+                "{} = {:?} != {:?}",
+                color,
+                actual,
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_named() {
+        assert_eq!(Color::from_str("coral"), Ok(Color::rgb(255, 127, 80)));
+        assert_eq!(Color::from_str("Coral"), Ok(Color::rgb(255, 127, 80)));
+        assert_eq!(Color::from_str("transparent"), Ok(Color::transparent()));
+    }
+
+    #[test]
+    fn test_hex() {
+        assert_eq!(Color::from_str("#fff"), Ok(Color::rgb(255, 255, 255)));
+        assert_eq!(
+            Color::from_str("#0f08"),
+            Ok(Color::rgba(0, 255, 0, 136.0 / 255.0))
+        );
+        assert_eq!(Color::from_str("#abcdef"), Ok(Color::hex(0xabcdef)));
+        assert_eq!(
+            Color::from_str("#ABCDEF80"),
+            Ok(Color::hexa(0xabcdef, 128.0 / 255.0))
+        );
+        assert_eq!(
+            Color::from_str("#abcde"),
+            Err(ParseColorError::InvalidSyntax)
+        );
+        assert_eq!(Color::from_str("#ggg"), Err(ParseColorError::InvalidSyntax));
+    }
+
+    #[test]
+    fn test_rgb() {
+        assert_eq!(
+            Color::from_str("rgb(255 0 0 / 50%)"),
+            Ok(Color::rgba(255, 0, 0, 0.5))
+        );
+        assert_eq!(
+            Color::from_str("rgba(255, 0, 0, 0.5)"),
+            Ok(Color::rgba(255, 0, 0, 0.5))
+        );
+        assert_eq!(
+            Color::from_str("rgb(100% 0% 300)"),
+            Ok(Color::rgb(255, 0, 255))
+        );
+        assert_eq!(Color::from_str("rgb(none 0 0)"), Ok(Color::rgb(0, 0, 0)));
+        assert_eq!(
+            Color::from_str("rgb(255, 0 0)"),
+            Err(ParseColorError::InvalidArguments)
+        );
+        assert_eq!(
+            Color::from_str("rgb(255 0)"),
+            Err(ParseColorError::InvalidArguments)
+        );
+    }
+
+    #[test]
+    fn test_hsl_hwb() {
+        assert_srgba("hsl(120deg 100% 50%)", [0.0, 1.0, 0.0, 1.0]);
+        assert_srgba("hsla(0.5turn, 100%, 50%, 0.25)", [0.0, 1.0, 1.0, 0.25]);
+        assert_srgba("hsl(240 100 50 / 10%)", [0.0, 0.0, 1.0, 0.1]);
+        assert_srgba("hwb(0 0% 0%)", [1.0, 0.0, 0.0, 1.0]);
+        assert_srgba("hwb(120 20% 20%)", [0.2, 0.8, 0.2, 1.0]);
+        assert_srgba("hwb(0 60% 60%)", [0.5, 0.5, 0.5, 1.0]);
+        assert_eq!(
+            Color::from_str("hwb(0, 0%, 0%)"),
+            Err(ParseColorError::InvalidArguments)
+        );
+    }
+
+    #[test]
+    fn test_lab() {
+        assert_srgba("lab(54.29% 80.82 69.89)", [1.0, 0.0, 0.0, 1.0]);
+        assert_srgba("lab(100 0 0)", [1.0, 1.0, 1.0, 1.0]);
+        assert_srgba("lch(54.29 106.84 40.85deg / 0.5)", [1.0, 0.0, 0.0, 0.5]);
+        assert_srgba("oklab(1 0 0)", [1.0, 1.0, 1.0, 1.0]);
+        assert_srgba("oklab(62.8% 0.225 0.1259)", [1.0, 0.0, 0.0, 1.0]);
+        assert_srgba("oklch(62.8% 0.2577 29.23)", [1.0, 0.0, 0.0, 1.0]);
+        assert_srgba("oklch(0.452 0.313 264.05)", [0.0, 0.0, 1.0, 1.0]);
+    }
+
+    #[test]
+    fn test_color_function() {
+        assert_eq!(
+            Color::from_str("color(display-p3 1 0 0)"),
+            Ok(Color::display_p3_rgb(255, 0, 0))
+        );
+        assert_eq!(
+            Color::from_str("color(srgb 100% 0 0 / 0.5)"),
+            Ok(Color::rgba(255, 0, 0, 0.5))
+        );
+        assert_srgba(
+            "color(srgb-linear 0.2140 0.2140 0.2140)",
+            [0.5, 0.5, 0.5, 1.0],
+        );
+        assert_srgba("color(xyz 0.9505 1 1.089)", [1.0, 1.0, 1.0, 1.0]);
+        assert_srgba("color(xyz-d50 0.9642 1 0.8251)", [1.0, 1.0, 1.0, 1.0]);
+        assert_eq!(
+            Color::from_str("color(rec2020 1 0 0)"),
+            Err(ParseColorError::UnknownColorSpace)
+        );
+    }
+
+    #[test]
+    fn test_color_mix() {
+        assert_srgba("color-mix(in srgb, red, blue)", [0.5, 0.0, 0.5, 1.0]);
+        assert_srgba("color-mix(in srgb, red 25%, blue)", [0.25, 0.0, 0.75, 1.0]);
+        assert_srgba("color-mix(in srgb, red, 25% blue)", [0.75, 0.0, 0.25, 1.0]);
+        assert_srgba(
+            "color-mix(in srgb, red 20%, blue 30%)",
+            [0.4, 0.0, 0.6, 0.5],
+        );
+        assert_srgba(
+            "color-mix(in srgb, red 60%, blue 60%)",
+            [0.5, 0.0, 0.5, 1.0],
+        );
+        assert_srgba("color-mix(in srgb, red, transparent)", [1.0, 0.0, 0.0, 0.5]);
+        assert_srgba(
+            "color-mix(in srgb-linear, white, black)",
+            [0.735, 0.735, 0.735, 1.0],
+        );
+        assert_srgba("color-mix(in hsl, red, lime)", [1.0, 1.0, 0.0, 1.0]);
+        assert_srgba(
+            "color-mix(in hsl longer hue, red, lime)",
+            [0.0, 0.0, 1.0, 1.0],
+        );
+        assert_srgba("color-mix(in hsl, white, blue)", [0.625, 0.625, 0.875, 1.0]);
+        assert_srgba("color-mix(in oklab, #fff, #000)", [0.39, 0.39, 0.39, 1.0]);
+        assert_srgba(
+            "color-mix(in srgb, color-mix(in srgb, red, blue), blue)",
+            [0.25, 0.0, 0.75, 1.0],
+        );
+        assert_eq!(
+            Color::from_str("color-mix(in srgb, red 0%, blue 0%)"),
+            Err(ParseColorError::InvalidArguments)
+        );
+        assert_eq!(
+            Color::from_str("color-mix(in rec2020, red, blue)"),
+            Err(ParseColorError::UnknownColorSpace)
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(
+            Color::from_str("foo"),
+            Err(ParseColorError::UnknownColorName)
+        );
+        assert_eq!(
+            Color::from_str("foo(1 2 3)"),
+            Err(ParseColorError::UnknownFunction)
+        );
+        assert_eq!(
+            Color::from_str("red blue"),
+            Err(ParseColorError::InvalidSyntax)
+        );
+        assert_eq!(
+            Color::from_str("rgb(1 2 3"),
+            Err(ParseColorError::InvalidSyntax)
+        );
+        assert_eq!(Color::from_str(""), Err(ParseColorError::InvalidSyntax));
+    }
+}
@@ -11,7 +11,7 @@ mod window;
 
 pub use image::Image;
 pub use modal::Modal;
-pub use scrollable::Scrollable;
+pub use scrollable::{ScrollDirection, Scrollable};
 pub use status_bar::{StatusBar, StatusBarStyle};
 pub use text::Text;
 pub use view::View;
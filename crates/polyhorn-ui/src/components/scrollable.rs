@@ -4,6 +4,7 @@ use crate::handles::ScrollableHandle;
 use crate::styles::ScrollableViewStyle;
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ScrollDirection {
     Both,
     Horizontal,
@@ -1,3 +1,4 @@
+use crate::events::EventListener;
 use crate::styles::TextStyle;
 
 /// Renders (rich) text to the screen.
@@ -8,4 +9,8 @@ pub struct Text {
     /// contained within another view. They are not honored if this text is
     /// nested in another text.
     pub style: TextStyle,
+
+    /// Called when the user presses this text. Nested texts without a
+    /// listener of their own inherit the listener of their parent.
+    pub on_press: EventListener<()>,
 }
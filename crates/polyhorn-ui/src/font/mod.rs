@@ -7,6 +7,7 @@ use crate::geometry::Dimension;
 
 /// Controls the thickness of outlines rendered to a text element.
 #[derive(Copy, Clone, Debug, PartialEq, EnumString)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum FontWeight {
     /// Accepts a custom normalized font weight scaled between 0 and 1.0
     #[strum(disabled)]
@@ -68,6 +69,7 @@ impl Default for FontWeight {
 /// system-provided typeface in a particular category that looks best on a
 /// user's display. The default generic font family is SansSerif.
 #[derive(Copy, Clone, Debug, Eq, PartialEq, EnumString)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum GenericFontFamily {
     /// This generic font style has serifs attached to the glyphs. Serifs are
     /// those pointy spikes at the extremes of (mostly) uppercase letters. This
@@ -95,12 +97,44 @@ impl Default for GenericFontFamily {
 
 /// Controls the font family that produces the glyphs that are drawn for a text.
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound(deserialize = "S: FontName")))]
 pub enum FontFamily<S = &'static str> {
     /// This is a system-dependent font family in the given generic category.
     Generic(GenericFontFamily),
 
     /// This is a specific font family referred to by its PostScript name.
-    Named(S),
+    Named(#[cfg_attr(feature = "serde", serde(deserialize_with = "FontName::deserialize"))] S),
+}
+
+/// Name of a named font family that can be deserialized. Deserializing a
+/// `&'static str` leaks the name.
+#[cfg(feature = "serde")]
+pub trait FontName: Sized {
+    /// Deserializes a font family name.
+    fn deserialize<'de, D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>;
+}
+
+#[cfg(feature = "serde")]
+impl FontName for &'static str {
+    fn deserialize<'de, D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        crate::leak::str(deserializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl FontName for String {
+    fn deserialize<'de, D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        serde::Deserialize::deserialize(deserializer)
+    }
 }
 
 impl<S> FromStr for FontFamily<S> {
@@ -121,6 +155,7 @@ impl<S> FromStr for FontFamily<S> {
 /// are an inexpensive way to get a slanted version of a normal font, whereas
 /// italic fonts might cost as much time to make as the original version.
 #[derive(Copy, Clone, Debug, Eq, PartialEq, EnumString)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum FontStyle {
     /// The normal font style is the default font style for text.
     #[strum(serialize = "normal")]
@@ -143,6 +178,7 @@ impl Default for FontStyle {
 
 /// Controls the size of text that is rendered to the screen.
 #[derive(Copy, Clone, Debug, PartialEq, EnumString)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum FontSize {
     /// This font size is 3/5th of the medium font size.
     #[strum(serialize = "extra-extra-small")]
@@ -186,6 +222,7 @@ pub enum FontSize {
 
 /// Controls the rendering of text to screen.
 #[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Font {
     /// Controls the font family that is used to render text to screen.
     pub family: FontFamily,
@@ -7,6 +7,7 @@ use crate::layout::{LayoutAxisX, LayoutAxisY};
 
 /// Simple wrapper around the coordinates of a 2D object.
 #[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Point<T> {
     /// This is the horizontal coordinate of this point.
     pub x: T,
@@ -24,6 +25,7 @@ impl<T> Point<T> {
 
 /// Simple wrapper around the horizontal and vertical dimensions of a 2D object.
 #[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Size<T> {
     /// This is the horizontal component of a size.
     pub width: T,
@@ -41,6 +43,7 @@ impl<T> Size<T> {
 
 /// Represents an absolute or relative dimension.
 #[derive(Copy, Clone, Debug, Eq, PartialEq, EnumString)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Dimension<T> {
     /// This is the default value for a dimension and resembles
     /// `Dimension:::Points(0.0)` and `Dimension::Percentage(0.0)`.
@@ -84,6 +87,7 @@ impl<T> Default for Dimension<T> {
 /// assert_eq!(by_corner.top.left(LayoutDirection::RTL), &30.0);
 /// ```
 #[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ByCorner<T> {
     /// This is a nested field that contains a potentially layout direction
     /// dependent horizontal axis in a layout direction independent vertical
@@ -151,6 +155,7 @@ impl<T> DerefMut for ByCorner<T> {
 /// This is a wrapper that contains a value of the given type for each direction
 /// (i.e. horizontal and vertical).
 #[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ByDirection<T> {
     /// This field contains a value of the given type for the horizontal
     /// dimension.
@@ -178,6 +183,7 @@ where
 /// This is a wrapper that contains a value of the given type for each edge of a
 /// rectangle.
 #[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ByEdge<T> {
     /// This field contains the horizontal edges (i.e. either left and right, or
     /// leading and trailing edges).
@@ -0,0 +1,262 @@
+use std::collections::HashMap;
+
+use super::Algorithm;
+use crate::geometry::{Dimension, Size};
+use crate::layout::{Layout, MeasureFunc};
+use crate::styles::ViewStyle;
+
+/// Frames that differ by at most this many points are considered equal, since
+/// some algorithms round their layouts to whole points.
+pub(crate) const TOLERANCE: f32 = 0.5;
+
+/// Returns a boolean that indicates if both layouts are equal within the
+/// tolerance of [`TOLERANCE`].
+pub(crate) fn approx_eq(a: Layout, b: Layout) -> bool {
+    [
+        (a.origin.x, b.origin.x),
+        (a.origin.y, b.origin.y),
+        (a.size.width, b.size.width),
+        (a.size.height, b.size.height),
+    ]
+    .iter()
+    .all(|(a, b)| (a - b).abs() <= TOLERANCE)
+}
+
+/// Debugging algorithm that computes every layout with two algorithms and
+/// reports the nodes whose frames differ. The layouts of the first algorithm
+/// are the ones that are returned.
+pub struct Dual<A, B>
+where
+    A: Algorithm,
+    B: Algorithm,
+{
+    first: A,
+    second: B,
+    children: Children<A::Node, B::Node>,
+    divergences: Vec<Divergence<A::Node, B::Node>>,
+}
+
+type Children<A, B> = HashMap<DualNode<A, B>, Vec<DualNode<A, B>>>;
+
+/// Handle to a node within both algorithms of a dual algorithm.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct DualNode<A, B>(pub A, pub B);
+
+/// Node whose frame differs between both algorithms of a dual algorithm.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Divergence<A, B> {
+    /// This is the node whose frame differs.
+    pub node: DualNode<A, B>,
+
+    /// This is the frame of the node that was computed by the first algorithm.
+    pub first: Layout,
+
+    /// This is the frame of the node that was computed by the second
+    /// algorithm.
+    pub second: Layout,
+}
+
+impl<A, B> Dual<A, B>
+where
+    A: Algorithm,
+    B: Algorithm,
+{
+    /// Returns a reference to the first algorithm.
+    pub fn first(&self) -> &A {
+        &self.first
+    }
+
+    /// Returns a reference to the second algorithm.
+    pub fn second(&self) -> &B {
+        &self.second
+    }
+
+    /// Returns the nodes whose frames differed between both algorithms the last
+    /// time a layout was computed, in depth-first order.
+    pub fn divergences(&self) -> &[Divergence<A::Node, B::Node>] {
+        &self.divergences
+    }
+
+    fn compare(&mut self, node: DualNode<A::Node, B::Node>) {
+        let first = self.first.layout(node.0);
+        let second = self.second.layout(node.1);
+
+        if !approx_eq(first, second) {
+            self.divergences.push(Divergence {
+                node,
+                first,
+                second,
+            });
+        }
+
+        for child in self.children.get(&node).cloned().unwrap_or_default() {
+            self.compare(child);
+        }
+    }
+}
+
+impl<A, B> Algorithm for Dual<A, B>
+where
+    A: Algorithm,
+    B: Algorithm,
+{
+    type Node = DualNode<A::Node, B::Node>;
+
+    fn new() -> Self {
+        Dual {
+            first: A::new(),
+            second: B::new(),
+            children: HashMap::new(),
+            divergences: vec![],
+        }
+    }
+
+    fn new_node(&mut self, style: ViewStyle, children: &[Self::Node]) -> Self::Node {
+        let first = children.iter().map(|child| child.0).collect::<Vec<_>>();
+        let second = children.iter().map(|child| child.1).collect::<Vec<_>>();
+
+        let node = DualNode(
+            self.first.new_node(style, &first),
+            self.second.new_node(style, &second),
+        );
+
+        self.children.insert(node, children.to_vec());
+
+        node
+    }
+
+    fn new_leaf(&mut self, style: ViewStyle, measure: MeasureFunc) -> Self::Node {
+        let node = DualNode(
+            self.first.new_leaf(style, measure.clone()),
+            self.second.new_leaf(style, measure),
+        );
+
+        self.children.insert(node, vec![]);
+
+        node
+    }
+
+    fn add_child(&mut self, parent: Self::Node, child: Self::Node) {
+        self.first.add_child(parent.0, child.0);
+        self.second.add_child(parent.1, child.1);
+        self.children.entry(parent).or_default().push(child);
+    }
+
+    fn remove_child(&mut self, parent: Self::Node, child: Self::Node) {
+        self.first.remove_child(parent.0, child.0);
+        self.second.remove_child(parent.1, child.1);
+
+        if let Some(children) = self.children.get_mut(&parent) {
+            children.retain(|&node| node != child);
+        }
+    }
+
+    fn child_count(&self, parent: Self::Node) -> usize {
+        self.first.child_count(parent.0)
+    }
+
+    fn remove(&mut self, node: Self::Node) {
+        self.first.remove(node.0);
+        self.second.remove(node.1);
+        self.children.remove(&node);
+    }
+
+    fn set_style(&mut self, node: Self::Node, style: ViewStyle) {
+        self.first.set_style(node.0, style);
+        self.second.set_style(node.1, style);
+    }
+
+    fn set_measure(&mut self, node: Self::Node, measure: MeasureFunc) {
+        self.first.set_measure(node.0, measure.clone());
+        self.second.set_measure(node.1, measure);
+    }
+
+    fn compute_layout(&mut self, node: Self::Node, size: Size<Dimension<f32>>) {
+        self.first.compute_layout(node.0, size);
+        self.second.compute_layout(node.1, size);
+
+        self.divergences.clear();
+        self.compare(node);
+    }
+
+    fn layout(&self, node: Self::Node) -> Layout {
+        self.first.layout(node.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Algorithm, Dual};
+    use crate::geometry::{Dimension, Size};
+    use crate::layout::algorithm::Flexbox;
+    use crate::styles::{FlexDirection, ViewStyle};
+
+    fn points(width: f32, height: f32) -> Size<Dimension<f32>> {
+        Size::new(Dimension::Points(width), Dimension::Points(height))
+    }
+
+    #[test]
+    fn test_identical() {
+        let mut dual = Dual::<Flexbox, Flexbox>::new();
+
+        let child = dual.new_node(
+            ViewStyle {
+                size: points(20.0, 10.0),
+                ..Default::default()
+            },
+            &[],
+        );
+
+        let root = dual.new_node(
+            ViewStyle {
+                flex_direction: FlexDirection::Row,
+                ..Default::default()
+            },
+            &[child],
+        );
+
+        dual.compute_layout(root, points(100.0, 100.0));
+
+        assert!(dual.divergences().is_empty());
+        assert_eq!(dual.layout(child), dual.second().layout(child.1));
+        assert_eq!(dual.child_count(root), 1);
+
+        dual.remove_child(root, child);
+        dual.remove(child);
+        assert_eq!(dual.child_count(root), 0);
+    }
+
+    #[cfg(all(feature = "layout-stretch", feature = "layout-taffy"))]
+    #[test]
+    fn test_divergence() {
+        use super::DualNode;
+        use crate::layout::algorithm::{stretch, taffy};
+        use crate::styles::{Display, GridTrack};
+
+        let mut dual = Dual::<stretch::Flexbox, taffy::Flexbox>::new();
+
+        let first = dual.new_node(Default::default(), &[]);
+        let second = dual.new_node(Default::default(), &[]);
+
+        // Stretch doesn't support grids, so it lays out these children in a
+        // column instead.
+        let root = dual.new_node(
+            ViewStyle {
+                display: Display::Grid,
+                grid_template_columns: &[GridTrack::Fraction(1.0), GridTrack::Fraction(1.0)],
+                ..Default::default()
+            },
+            &[first, second],
+        );
+
+        dual.compute_layout(root, points(100.0, 100.0));
+
+        let nodes = dual
+            .divergences()
+            .iter()
+            .map(|divergence| divergence.node)
+            .collect::<Vec<DualNode<_, _>>>();
+
+        assert_eq!(nodes, vec![first, second]);
+    }
+}
@@ -0,0 +1,493 @@
+use std::collections::HashMap;
+
+use super::Algorithm;
+use crate::geometry::{ByEdge, Dimension, Size};
+use crate::layout::{Layout, LayoutAxisX, LayoutDirection, MeasureFunc};
+use crate::styles::{Display, FlexDirection, FlexWrap, Inherited, Position, ViewStyle};
+
+/// Wrapping flexboxes are laid out at most this many times until their lines
+/// are stable. Usually, every pass settles at least one line.
+const MAX_PASSES: usize = 8;
+
+/// Algorithm that emulates gaps for an algorithm that doesn't support them
+/// (i.e. Yoga and Stretch) by adding them to the margins of children. Along the
+/// main axis, the gap goes into the leading margin of every child that doesn't
+/// start a line, except that a child that starts a line (other than the first)
+/// carries the gap in its trailing margin instead. This way, each child is
+/// wrapped exactly when it doesn't fit on its line together with the gap in
+/// front of it. Along the cross axis, the gap goes into the leading margin of
+/// every child that isn't on the first line. Lines are only known after the
+/// layout has been computed, so flexboxes that wrap are laid out again until
+/// their lines are stable.
+///
+/// Only gaps in points are emulated, and they are not added to margins that
+/// are `auto` or a percentage. A child that is alone on a line (other than the
+/// first) is aligned as if it were followed by the gap.
+pub struct Gaps<A>
+where
+    A: Algorithm,
+{
+    algorithm: A,
+    nodes: HashMap<A::Node, Entry<A::Node>>,
+}
+
+struct Entry<N> {
+    /// This is the style of this node without gaps.
+    style: ViewStyle,
+
+    /// This is the style of this node that is passed to the algorithm.
+    applied: ViewStyle,
+
+    parent: Option<N>,
+    children: Vec<N>,
+
+    /// This is the index of the line that this node is on within its parent.
+    line: usize,
+
+    /// This is a boolean that indicates if this node is the first node on its
+    /// line.
+    starts_line: bool,
+}
+
+impl<N> Entry<N> {
+    fn new(style: ViewStyle, children: Vec<N>) -> Entry<N> {
+        Entry {
+            style,
+            applied: style,
+            parent: None,
+            children,
+            line: 0,
+            starts_line: true,
+        }
+    }
+}
+
+#[derive(Copy, Clone)]
+enum Edge {
+    Leading,
+    Trailing,
+    Top,
+    Bottom,
+}
+
+impl Edge {
+    fn opposite(self) -> Edge {
+        match self {
+            Edge::Leading => Edge::Trailing,
+            Edge::Trailing => Edge::Leading,
+            Edge::Top => Edge::Bottom,
+            Edge::Bottom => Edge::Top,
+        }
+    }
+}
+
+/// Returns a boolean that indicates if a node with the given style takes part
+/// in the layout of the lines of its parent.
+fn is_in_flow(style: &ViewStyle) -> bool {
+    matches!(style.position, Position::Relative(_)) && style.display != Display::None
+}
+
+fn is_row(style: &ViewStyle) -> bool {
+    matches!(
+        style.flex_direction,
+        FlexDirection::Row | FlexDirection::RowReverse
+    )
+}
+
+/// Returns the gaps (in points) along the main and cross axes of a flexbox with
+/// the given style.
+fn gaps(style: &ViewStyle) -> (f32, f32) {
+    let points = |dimension| match dimension {
+        Dimension::Points(points) => points,
+        _ => 0.0,
+    };
+
+    match is_row(style) {
+        true => (points(style.column_gap), points(style.row_gap)),
+        false => (points(style.row_gap), points(style.column_gap)),
+    }
+}
+
+/// Returns the edges of the children of a flexbox with the given style at
+/// which its lines start along the main and cross axes.
+fn edges(style: &ViewStyle) -> (Edge, Edge) {
+    let main = match style.flex_direction {
+        FlexDirection::Row => Edge::Leading,
+        FlexDirection::RowReverse => Edge::Trailing,
+        FlexDirection::Column => Edge::Top,
+        FlexDirection::ColumnReverse => Edge::Bottom,
+    };
+
+    let cross = match (is_row(style), style.flex_wrap == FlexWrap::WrapReverse) {
+        (true, false) => Edge::Top,
+        (true, true) => Edge::Bottom,
+        (false, false) => Edge::Leading,
+        (false, true) => Edge::Trailing,
+    };
+
+    (main, cross)
+}
+
+fn add_margin(
+    margin: &mut ByEdge<Dimension<f32>>,
+    edge: Edge,
+    gap: f32,
+    direction: LayoutDirection,
+) {
+    let ltr = direction == LayoutDirection::LTR;
+
+    let dimension = match (edge, &mut margin.horizontal) {
+        (Edge::Top, _) => &mut margin.vertical.top,
+        (Edge::Bottom, _) => &mut margin.vertical.bottom,
+        (Edge::Leading, LayoutAxisX::DirectionDependent { leading, .. }) => leading,
+        (Edge::Trailing, LayoutAxisX::DirectionDependent { trailing, .. }) => trailing,
+        (Edge::Leading, LayoutAxisX::DirectionIndependent { left, right }) => match ltr {
+            true => left,
+            false => right,
+        },
+        (Edge::Trailing, LayoutAxisX::DirectionIndependent { left, right }) => match ltr {
+            true => right,
+            false => left,
+        },
+    };
+
+    *dimension = match *dimension {
+        Dimension::Undefined => Dimension::Points(gap),
+        Dimension::Points(points) => Dimension::Points(points + gap),
+        dimension => dimension,
+    };
+}
+
+fn resolve(direction: Inherited<LayoutDirection>, parent: LayoutDirection) -> LayoutDirection {
+    match direction {
+        Inherited::Inherited => parent,
+        Inherited::Specified(direction) => direction,
+    }
+}
+
+impl<A> Gaps<A>
+where
+    A: Algorithm,
+{
+    /// Returns a reference to the algorithm that lays out the nodes.
+    pub fn algorithm(&self) -> &A {
+        &self.algorithm
+    }
+
+    fn insert(&mut self, node: A::Node, entry: Entry<A::Node>) {
+        for child in &entry.children {
+            if let Some(child) = self.nodes.get_mut(child) {
+                child.parent = Some(node);
+            }
+        }
+
+        self.nodes.insert(node, entry);
+    }
+
+    /// Returns the direction that the given node inherits from its ancestors.
+    fn inherited_direction(&self, node: A::Node) -> LayoutDirection {
+        match self.nodes.get(&node).and_then(|entry| entry.parent) {
+            Some(parent) => resolve(
+                self.nodes[&parent].style.direction,
+                self.inherited_direction(parent),
+            ),
+            None => LayoutDirection::LTR,
+        }
+    }
+
+    /// Puts the children of the given node and its descendants on a single
+    /// line.
+    fn reset(&mut self, node: A::Node) {
+        let mut first = true;
+
+        for child in self.nodes[&node].children.clone() {
+            let entry = self.nodes.get_mut(&child).unwrap();
+            entry.line = 0;
+            entry.starts_line = first && is_in_flow(&entry.style);
+            first &= !entry.starts_line;
+
+            self.reset(child);
+        }
+    }
+
+    /// Passes the styles of the descendants of the given node, including their
+    /// gaps, to the algorithm.
+    fn apply(&mut self, node: A::Node, direction: LayoutDirection) {
+        let style = self.nodes[&node].style;
+        let direction = resolve(style.direction, direction);
+        let (main_gap, cross_gap) = gaps(&style);
+        let (main_edge, cross_edge) = edges(&style);
+
+        // This is a boolean that indicates if the previous child carries the
+        // gap in its trailing margin.
+        let mut carries_gap = false;
+
+        for child in self.nodes[&node].children.clone() {
+            let entry = self.nodes.get_mut(&child).unwrap();
+            let mut effective = entry.style;
+
+            if is_in_flow(&entry.style) {
+                let edge = match (entry.starts_line, entry.line, carries_gap) {
+                    (true, 0, _) => None,
+                    (true, _, _) => Some(main_edge.opposite()),
+                    (false, _, true) => None,
+                    (false, _, false) => Some(main_edge),
+                };
+
+                carries_gap = entry.starts_line && entry.line > 0;
+
+                if let (Some(edge), true) = (edge, main_gap != 0.0) {
+                    add_margin(&mut effective.margin, edge, main_gap, direction);
+                }
+
+                if cross_gap != 0.0 && entry.line > 0 {
+                    add_margin(&mut effective.margin, cross_edge, cross_gap, direction);
+                }
+            }
+
+            if effective != entry.applied {
+                entry.applied = effective;
+                self.algorithm.set_style(child, effective);
+            }
+
+            self.apply(child, direction);
+        }
+    }
+
+    /// Derives the lines of the children of wrapping flexboxes from their
+    /// layouts and returns a boolean that indicates if any of them changed.
+    fn break_lines(&mut self, node: A::Node) -> bool {
+        let style = self.nodes[&node].style;
+        let children = self.nodes[&node].children.clone();
+        let mut changed = false;
+
+        if style.flex_wrap != FlexWrap::NoWrap && gaps(&style) != (0.0, 0.0) {
+            let row = is_row(&style);
+            let mut line: Option<(usize, f32, f32)> = None;
+
+            for &child in &children {
+                if !is_in_flow(&self.nodes[&child].style) {
+                    continue;
+                }
+
+                let layout = self.algorithm.layout(child);
+                let (start, end) = match row {
+                    true => (layout.origin.y, layout.origin.y + layout.size.height),
+                    false => (layout.origin.x, layout.origin.x + layout.size.width),
+                };
+
+                // Lines are stacked along the cross axis, so a child starts a
+                // new line if it is entirely beyond the current line. Note
+                // that lines can be stacked in either direction.
+                let (index, starts_line) = match line {
+                    None => (0, true),
+                    Some((index, line_start, line_end)) => {
+                        match (start >= line_end && end > line_end)
+                            || (end <= line_start && start < line_start)
+                        {
+                            true => (index + 1, true),
+                            false => (index, false),
+                        }
+                    }
+                };
+
+                line = Some(match (starts_line, line) {
+                    (false, Some((_, line_start, line_end))) => {
+                        (index, line_start.min(start), line_end.max(end))
+                    }
+                    _ => (index, start, end),
+                });
+
+                let entry = self.nodes.get_mut(&child).unwrap();
+                changed |= entry.line != index || entry.starts_line != starts_line;
+                entry.line = index;
+                entry.starts_line = starts_line;
+            }
+        }
+
+        for child in children {
+            changed |= self.break_lines(child);
+        }
+
+        changed
+    }
+}
+
+impl<A> Algorithm for Gaps<A>
+where
+    A: Algorithm,
+{
+    type Node = A::Node;
+
+    fn new() -> Self {
+        Gaps {
+            algorithm: A::new(),
+            nodes: HashMap::new(),
+        }
+    }
+
+    fn new_node(&mut self, style: ViewStyle, children: &[Self::Node]) -> Self::Node {
+        let node = self.algorithm.new_node(style, children);
+        self.insert(node, Entry::new(style, children.to_vec()));
+        node
+    }
+
+    fn new_leaf(&mut self, style: ViewStyle, measure: MeasureFunc) -> Self::Node {
+        let node = self.algorithm.new_leaf(style, measure);
+        self.insert(node, Entry::new(style, vec![]));
+        node
+    }
+
+    fn add_child(&mut self, parent: Self::Node, child: Self::Node) {
+        self.algorithm.add_child(parent, child);
+
+        if let Some(entry) = self.nodes.get_mut(&parent) {
+            entry.children.push(child);
+        }
+
+        if let Some(entry) = self.nodes.get_mut(&child) {
+            entry.parent = Some(parent);
+        }
+    }
+
+    fn remove_child(&mut self, parent: Self::Node, child: Self::Node) {
+        self.algorithm.remove_child(parent, child);
+
+        if let Some(entry) = self.nodes.get_mut(&parent) {
+            entry.children.retain(|&node| node != child);
+        }
+
+        // The gaps of a child depend on its parent, so they are removed along
+        // with the child.
+        if let Some(entry) = self.nodes.get_mut(&child) {
+            entry.parent = None;
+
+            if entry.applied != entry.style {
+                entry.applied = entry.style;
+                self.algorithm.set_style(child, entry.style);
+            }
+        }
+    }
+
+    fn child_count(&self, parent: Self::Node) -> usize {
+        self.algorithm.child_count(parent)
+    }
+
+    fn remove(&mut self, node: Self::Node) {
+        self.algorithm.remove(node);
+
+        if let Some(entry) = self.nodes.remove(&node) {
+            if let Some(parent) = entry.parent.and_then(|parent| self.nodes.get_mut(&parent)) {
+                parent.children.retain(|&child| child != node);
+            }
+
+            for child in entry.children {
+                if let Some(child) = self.nodes.get_mut(&child) {
+                    child.parent = None;
+                }
+            }
+        }
+    }
+
+    fn set_style(&mut self, node: Self::Node, style: ViewStyle) {
+        // Gaps are added again when the layout is computed.
+        self.algorithm.set_style(node, style);
+
+        if let Some(entry) = self.nodes.get_mut(&node) {
+            entry.style = style;
+            entry.applied = style;
+        }
+    }
+
+    fn set_measure(&mut self, node: Self::Node, measure: MeasureFunc) {
+        self.algorithm.set_measure(node, measure);
+    }
+
+    fn compute_layout(&mut self, node: Self::Node, size: Size<Dimension<f32>>) {
+        let direction = self.inherited_direction(node);
+
+        self.reset(node);
+        self.apply(node, direction);
+
+        for pass in 1.. {
+            self.algorithm.compute_layout(node, size);
+
+            if pass == MAX_PASSES || !self.break_lines(node) {
+                break;
+            }
+
+            self.apply(node, direction);
+        }
+    }
+
+    fn layout(&self, node: Self::Node) -> Layout {
+        self.algorithm.layout(node)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Algorithm;
+    use crate::geometry::{Dimension, Point, Size};
+    use crate::layout::algorithm::Flexbox;
+    use crate::layout::Layout;
+    use crate::styles::{FlexDirection, FlexWrap, ViewStyle};
+
+    fn points(width: f32, height: f32) -> Size<Dimension<f32>> {
+        Size::new(Dimension::Points(width), Dimension::Points(height))
+    }
+
+    fn origin(layout: Layout) -> Point<f32> {
+        layout.origin
+    }
+
+    #[test]
+    fn test_lines() {
+        // Gaps are emulated by the flexbox unless it supports them itself.
+        let mut gaps = Flexbox::new();
+
+        let sized = ViewStyle {
+            size: points(30.0, 10.0),
+            ..Default::default()
+        };
+
+        let children = (0..3)
+            .map(|_| gaps.new_node(sized, &[]))
+            .collect::<Vec<_>>();
+
+        let root = gaps.new_node(
+            ViewStyle {
+                flex_direction: FlexDirection::Row,
+                flex_wrap: FlexWrap::Wrap,
+                row_gap: Dimension::Points(5.0),
+                column_gap: Dimension::Points(10.0),
+                ..Default::default()
+            },
+            &children,
+        );
+
+        gaps.compute_layout(root, points(100.0, 100.0));
+
+        // The third child would fit on the first line without its gap, but it
+        // is wrapped onto the second line nevertheless.
+        let origins = children
+            .iter()
+            .map(|&child| origin(gaps.layout(child)))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            origins,
+            vec![
+                Point { x: 0.0, y: 0.0 },
+                Point { x: 40.0, y: 0.0 },
+                Point { x: 0.0, y: 15.0 },
+            ]
+        );
+
+        // Once removed, the child is laid out without gaps.
+        gaps.remove_child(root, children[2]);
+        gaps.compute_layout(children[2], points(100.0, 100.0));
+        assert_eq!(origin(gaps.layout(children[2])), Point { x: 0.0, y: 0.0 });
+        assert_eq!(gaps.child_count(root), 2);
+    }
+}
@@ -1,15 +1,82 @@
+/// Flexbox implementation powered by Stretch.
 #[cfg(feature = "layout-stretch")]
 pub mod stretch;
 
+/// Flexbox and grid implementation powered by Taffy.
+#[cfg(feature = "layout-taffy")]
+pub mod taffy;
+
 /// Flexbox implementation powered by Yoga (the default).
 #[cfg(feature = "layout-yoga")]
 pub mod yoga;
 
-#[cfg(feature = "layout-stretch")]
-pub use self::stretch::*;
+mod dual;
+mod gaps;
+
+pub(crate) use self::dual::approx_eq;
+pub use self::dual::{Divergence, Dual, DualNode};
+pub use self::gaps::Gaps;
+
+#[cfg(not(any(
+    feature = "layout-yoga",
+    feature = "layout-stretch",
+    feature = "layout-taffy"
+)))]
+compile_error!(
+    "At least one layout algorithm must be enabled: `layout-yoga`, `layout-stretch` or \
+     `layout-taffy`."
+);
+
+// Multiple algorithms can be enabled at the same time (e.g. to compare them),
+// in which case the layout tree uses Yoga, Stretch and Taffy, in that order of
+// precedence.
+#[cfg(all(feature = "layout-stretch", not(feature = "layout-yoga")))]
+use self::stretch as primary;
+
+#[cfg(all(
+    feature = "layout-taffy",
+    not(any(feature = "layout-yoga", feature = "layout-stretch"))
+))]
+use self::taffy as primary;
 
 #[cfg(feature = "layout-yoga")]
-pub use self::yoga::*;
+use self::yoga as primary;
+
+#[cfg(not(feature = "layout-dual"))]
+pub use self::primary::{Flexbox, Node};
+
+// With `layout-dual`, the layout tree also computes every layout with the
+// enabled algorithm of the lowest precedence and reports the nodes whose frames
+// differ.
+#[cfg(all(feature = "layout-dual", feature = "layout-taffy"))]
+use self::taffy as secondary;
+
+#[cfg(all(
+    feature = "layout-dual",
+    feature = "layout-stretch",
+    not(feature = "layout-taffy")
+))]
+use self::stretch as secondary;
+
+#[cfg(all(
+    feature = "layout-dual",
+    not(any(
+        all(
+            feature = "layout-yoga",
+            any(feature = "layout-stretch", feature = "layout-taffy")
+        ),
+        all(feature = "layout-stretch", feature = "layout-taffy")
+    ))
+))]
+compile_error!("The `layout-dual` feature requires two layout algorithms to be enabled.");
+
+/// Flexbox that computes every layout with two algorithms.
+#[cfg(feature = "layout-dual")]
+pub type Flexbox = Dual<primary::Flexbox, secondary::Flexbox>;
+
+/// Handle to a node within both algorithms of the flexbox.
+#[cfg(feature = "layout-dual")]
+pub type Node = DualNode<primary::Node, secondary::Node>;
 
 use std::hash::Hash;
 
@@ -52,9 +119,38 @@ pub trait Algorithm {
     fn set_measure(&mut self, node: Self::Node, measure: MeasureFunc);
 
     /// Computes the layout of a node within this flexbox within the given
-    /// size.
+    /// size. The node is laid out as a root: in each dimension in which the
+    /// given size is definite, a node without a size of its own (i.e. `auto`
+    /// or undefined) is stretched to fill it, which is what Yoga does.
+    /// Algorithms that only treat the given size as available space emulate
+    /// this with `fill_root`.
     fn compute_layout(&mut self, node: Self::Node, size: Size<Dimension<f32>>);
 
     /// Returns the computed layout of a node within this flexbox.
     fn layout(&self, node: Self::Node) -> Layout;
 }
+
+/// Returns the size that a root node should temporarily be given to fill the
+/// available size (see [`Algorithm::compute_layout`]), or `None` if the node
+/// already has a size of its own in each dimension in which the available size
+/// is definite.
+#[cfg(any(feature = "layout-stretch", feature = "layout-taffy"))]
+pub(crate) fn fill_root<D>(
+    own: Size<D>,
+    available: Size<Dimension<f32>>,
+    is_auto: impl Fn(&D) -> bool,
+    points: impl Fn(f32) -> D,
+) -> Option<Size<D>> {
+    let fill = |own: D, available| match available {
+        Dimension::Points(available) if is_auto(&own) => (points(available), true),
+        _ => (own, false),
+    };
+
+    let (width, filled_width) = fill(own.width, available.width);
+    let (height, filled_height) = fill(own.height, available.height);
+
+    match filled_width || filled_height {
+        true => Some(Size { width, height }),
+        false => None,
+    }
+}
@@ -1,131 +1,238 @@
 use stretch::geometry::{Rect, Size};
-use stretch::node::MeasureFunc;
-use stretch::style::{AlignItems, Dimension, FlexDirection, JustifyContent, PositionType, Style};
+use stretch::number::Number;
+use stretch::style::{
+    AlignContent, AlignItems, AlignSelf, Dimension, Direction, Display, FlexDirection, FlexWrap,
+    JustifyContent, Overflow, PositionType, Style,
+};
+
+use crate::geometry::ByEdge;
+use crate::layout::{LayoutAxisX, LayoutDirection, MeasureFunc};
+use crate::styles::{Align, Inherited, Justify, Position, ViewStyle};
 
 pub trait IntoStretch<T> {
     fn into_stretch(self) -> T;
 }
 
-impl IntoStretch<Style> for crate::Style {
+impl IntoStretch<Style> for ViewStyle {
     fn into_stretch(self) -> Style {
+        let (position_type, position, flex_basis, flex_grow, flex_shrink, align_self) =
+            match self.position {
+                Position::Absolute(absolute) => (
+                    PositionType::Absolute,
+                    absolute.distances.into_stretch(),
+                    Dimension::Auto,
+                    0.0,
+                    0.0,
+                    AlignSelf::Auto,
+                ),
+                Position::Relative(relative) => (
+                    PositionType::Relative,
+                    Default::default(),
+                    match relative.flex_basis {
+                        // Yoga treats an undefined flex basis as `auto`.
+                        crate::geometry::Dimension::Undefined => Dimension::Auto,
+                        flex_basis => flex_basis.into_stretch(),
+                    },
+                    relative.flex_grow,
+                    relative.flex_shrink,
+                    relative.align_self.into_stretch(),
+                ),
+            };
+
+        // Note that Stretch doesn't support gaps, which are emulated by `Gaps`
+        // instead.
         Style {
-            position_type: self.position.into_stretch(),
+            display: self.display.into_stretch(),
+            position_type,
+            direction: self.direction.into_stretch(),
             flex_direction: self.flex_direction.into_stretch(),
+            flex_wrap: self.flex_wrap.into_stretch(),
+            overflow: self.overflow.into_stretch(),
             align_items: self.align_items.into_stretch(),
+            align_self,
+            align_content: self.align_content.into_stretch(),
             justify_content: self.justify_content.into_stretch(),
-            flex_basis: self.flex_basis.into_stretch(),
-            flex_grow: self.flex_grow,
-            flex_shrink: self.flex_shrink,
-            min_size: Size {
-                width: self.min_size.width.into_stretch(),
-                height: self.min_size.height.into_stretch(),
-            },
-            size: Size {
-                width: self.size.width.into_stretch(),
-                height: self.size.height.into_stretch(),
-            },
-            max_size: Size {
-                width: self.max_size.width.into_stretch(),
-                height: self.max_size.height.into_stretch(),
-            },
-            padding: Rect {
-                top: self.padding.top.into_stretch(),
-                end: self.padding.trailing.into_stretch(),
-                bottom: self.padding.bottom.into_stretch(),
-                start: self.padding.leading.into_stretch(),
-            },
-            margin: Rect {
-                top: self.margin.top.into_stretch(),
-                end: self.margin.trailing.into_stretch(),
-                bottom: self.margin.bottom.into_stretch(),
-                start: self.margin.leading.into_stretch(),
+            position,
+            margin: self.margin.into_stretch(),
+            padding: self.padding.into_stretch(),
+            flex_grow,
+            flex_shrink,
+            flex_basis,
+            size: self.size.into_stretch(),
+            min_size: self.min_size.into_stretch(),
+            max_size: self.max_size.into_stretch(),
+            aspect_ratio: match self.aspect_ratio {
+                Some(aspect_ratio) => Number::Defined(aspect_ratio),
+                None => Number::Undefined,
             },
             ..Default::default()
         }
     }
 }
 
-impl IntoStretch<Dimension> for polyhorn_style::Dimension {
+impl IntoStretch<Dimension> for crate::geometry::Dimension<f32> {
     fn into_stretch(self) -> Dimension {
         match self {
-            polyhorn_style::Dimension::Auto => Dimension::Auto,
-            polyhorn_style::Dimension::Undefined => Dimension::Undefined,
-            polyhorn_style::Dimension::Pixels(pixels) => Dimension::Points(pixels),
-            polyhorn_style::Dimension::Percent(percent) => Dimension::Percent(percent),
+            crate::geometry::Dimension::Undefined => Dimension::Undefined,
+            crate::geometry::Dimension::Auto => Dimension::Auto,
+            crate::geometry::Dimension::Points(points) => Dimension::Points(points),
+            crate::geometry::Dimension::Percentage(percentage) => Dimension::Percent(percentage),
         }
     }
 }
 
-impl IntoStretch<PositionType> for polyhorn_style::Position {
-    fn into_stretch(self) -> PositionType {
+impl IntoStretch<Size<Dimension>> for crate::geometry::Size<crate::geometry::Dimension<f32>> {
+    fn into_stretch(self) -> Size<Dimension> {
+        Size {
+            width: self.width.into_stretch(),
+            height: self.height.into_stretch(),
+        }
+    }
+}
+
+impl IntoStretch<Rect<Dimension>> for ByEdge<crate::geometry::Dimension<f32>> {
+    fn into_stretch(self) -> Rect<Dimension> {
+        // Stretch lays out every node from left to right, which means that
+        // leading and trailing edges are the left and right edges.
+        let (start, end) = match self.horizontal {
+            LayoutAxisX::DirectionDependent { leading, trailing } => (leading, trailing),
+            LayoutAxisX::DirectionIndependent { left, right } => (left, right),
+        };
+
+        Rect {
+            start: start.into_stretch(),
+            end: end.into_stretch(),
+            top: self.vertical.top.into_stretch(),
+            bottom: self.vertical.bottom.into_stretch(),
+        }
+    }
+}
+
+impl IntoStretch<Direction> for Inherited<LayoutDirection> {
+    fn into_stretch(self) -> Direction {
         match self {
-            polyhorn_style::Position::Relative => PositionType::Relative,
-            polyhorn_style::Position::Absolute => PositionType::Absolute,
+            Inherited::Inherited => Direction::Inherit,
+            Inherited::Specified(LayoutDirection::LTR) => Direction::LTR,
+            Inherited::Specified(LayoutDirection::RTL) => Direction::RTL,
         }
     }
 }
 
-impl IntoStretch<FlexDirection> for polyhorn_style::FlexDirection {
+impl IntoStretch<Display> for crate::styles::Display {
+    fn into_stretch(self) -> Display {
+        match self {
+            // Stretch doesn't support grids, so we lay them out as flexboxes.
+            crate::styles::Display::Flex | crate::styles::Display::Grid => Display::Flex,
+            crate::styles::Display::None => Display::None,
+        }
+    }
+}
+
+impl IntoStretch<FlexDirection> for crate::styles::FlexDirection {
     fn into_stretch(self) -> FlexDirection {
         match self {
-            polyhorn_style::FlexDirection::Column => FlexDirection::Column,
-            polyhorn_style::FlexDirection::Row => FlexDirection::Row,
+            crate::styles::FlexDirection::Column => FlexDirection::Column,
+            crate::styles::FlexDirection::ColumnReverse => FlexDirection::ColumnReverse,
+            crate::styles::FlexDirection::Row => FlexDirection::Row,
+            crate::styles::FlexDirection::RowReverse => FlexDirection::RowReverse,
         }
     }
 }
 
-impl IntoStretch<AlignItems> for polyhorn_style::AlignItems {
+impl IntoStretch<AlignItems> for Align {
     fn into_stretch(self) -> AlignItems {
         match self {
-            polyhorn_style::AlignItems::Center => AlignItems::Center,
-            polyhorn_style::AlignItems::Stretch => AlignItems::Stretch,
-            polyhorn_style::AlignItems::FlexStart => AlignItems::FlexStart,
-            polyhorn_style::AlignItems::FlexEnd => AlignItems::FlexEnd,
+            Align::FlexStart => AlignItems::FlexStart,
+            Align::Center => AlignItems::Center,
+            Align::FlexEnd => AlignItems::FlexEnd,
+            Align::Stretch => AlignItems::Stretch,
+            Align::SpaceBetween | Align::SpaceAround => AlignItems::FlexStart,
+        }
+    }
+}
+
+impl IntoStretch<AlignSelf> for Option<Align> {
+    fn into_stretch(self) -> AlignSelf {
+        match self {
+            None => AlignSelf::Auto,
+            Some(Align::FlexStart) => AlignSelf::FlexStart,
+            Some(Align::Center) => AlignSelf::Center,
+            Some(Align::FlexEnd) => AlignSelf::FlexEnd,
+            Some(Align::Stretch) => AlignSelf::Stretch,
+            Some(Align::SpaceBetween) | Some(Align::SpaceAround) => AlignSelf::FlexStart,
+        }
+    }
+}
+
+impl IntoStretch<AlignContent> for Align {
+    fn into_stretch(self) -> AlignContent {
+        match self {
+            Align::FlexStart => AlignContent::FlexStart,
+            Align::Center => AlignContent::Center,
+            Align::FlexEnd => AlignContent::FlexEnd,
+            Align::Stretch => AlignContent::Stretch,
+            Align::SpaceBetween => AlignContent::SpaceBetween,
+            Align::SpaceAround => AlignContent::SpaceAround,
+        }
+    }
+}
+
+impl IntoStretch<FlexWrap> for crate::styles::FlexWrap {
+    fn into_stretch(self) -> FlexWrap {
+        match self {
+            crate::styles::FlexWrap::NoWrap => FlexWrap::NoWrap,
+            crate::styles::FlexWrap::Wrap => FlexWrap::Wrap,
+            crate::styles::FlexWrap::WrapReverse => FlexWrap::WrapReverse,
         }
     }
 }
 
-impl IntoStretch<JustifyContent> for polyhorn_style::JustifyContent {
+impl IntoStretch<JustifyContent> for Justify {
     fn into_stretch(self) -> JustifyContent {
         match self {
-            polyhorn_style::JustifyContent::Center => JustifyContent::Center,
-            polyhorn_style::JustifyContent::FlexStart => JustifyContent::FlexStart,
-            polyhorn_style::JustifyContent::FlexEnd => JustifyContent::FlexEnd,
-            polyhorn_style::JustifyContent::SpaceBetween => JustifyContent::SpaceBetween,
+            Justify::FlexStart => JustifyContent::FlexStart,
+            Justify::Center => JustifyContent::Center,
+            Justify::FlexEnd => JustifyContent::FlexEnd,
+            Justify::SpaceBetween => JustifyContent::SpaceBetween,
+            Justify::SpaceAround => JustifyContent::SpaceAround,
+            Justify::SpaceEvenly => JustifyContent::SpaceEvenly,
         }
     }
 }
 
-impl IntoStretch<MeasureFunc> for crate::MeasureFunc {
-    fn into_stretch(self) -> MeasureFunc {
+impl IntoStretch<Overflow> for crate::styles::Overflow {
+    fn into_stretch(self) -> Overflow {
         match self {
-            crate::MeasureFunc::Boxed(measure) => {
-                stretch::node::MeasureFunc::Boxed(Box::new(move |size| {
-                    let size = measure(crate::Size {
-                        width: match size.width {
-                            stretch::number::Number::Defined(pixels) => {
-                                polyhorn_style::Dimension::Pixels(pixels)
-                            }
-                            stretch::number::Number::Undefined => {
-                                polyhorn_style::Dimension::Undefined
-                            }
-                        },
-                        height: match size.height {
-                            stretch::number::Number::Defined(pixels) => {
-                                polyhorn_style::Dimension::Pixels(pixels)
-                            }
-                            stretch::number::Number::Undefined => {
-                                polyhorn_style::Dimension::Undefined
-                            }
-                        },
-                    });
-
-                    stretch::geometry::Size {
-                        width: size.width,
-                        height: size.height,
-                    }
-                }))
-            }
+            crate::styles::Overflow::Visible => Overflow::Visible,
+            crate::styles::Overflow::Hidden => Overflow::Hidden,
+            crate::styles::Overflow::Scroll => Overflow::Scroll,
+        }
+    }
+}
+
+impl IntoStretch<crate::geometry::Dimension<f32>> for Number {
+    fn into_stretch(self) -> crate::geometry::Dimension<f32> {
+        match self {
+            Number::Defined(points) => crate::geometry::Dimension::Points(points),
+            Number::Undefined => crate::geometry::Dimension::Undefined,
+        }
+    }
+}
+
+impl IntoStretch<stretch::node::MeasureFunc> for MeasureFunc {
+    fn into_stretch(self) -> stretch::node::MeasureFunc {
+        match self {
+            MeasureFunc::Boxed(measure) => Box::new(move |size| {
+                let size = measure(crate::geometry::Size {
+                    width: size.width.into_stretch(),
+                    height: size.height.into_stretch(),
+                });
+
+                Ok(Size {
+                    width: size.width,
+                    height: size.height,
+                })
+            }),
         }
     }
 }
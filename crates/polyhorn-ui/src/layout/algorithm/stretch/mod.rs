@@ -1,23 +1,29 @@
-use polyhorn_ui::geometry::{Dimension, Point, Size};
-use polyhorn_ui::styles::ViewStyle;
+use stretch::number::Number;
 
-use super::Algorithm;
-use crate::{Layout, MeasureFunc};
+use super::{fill_root, Algorithm, Gaps};
+use crate::geometry::{Dimension, Point, Size};
+use crate::layout::{Layout, MeasureFunc};
+use crate::styles::ViewStyle;
 
 mod convert;
 
-pub use convert::IntoStretch;
+use convert::IntoStretch;
 
-pub struct Flexbox(stretch::Stretch);
+/// Concrete flexbox implementation powered by Stretch, which emulates gaps.
+pub type Flexbox = Gaps<Stretch>;
 
+/// Flexbox implementation powered by Stretch that ignores gaps.
+pub struct Stretch(stretch::Stretch);
+
+/// Handle to a node within the Stretch forest.
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
 pub struct Node(stretch::node::Node);
 
-impl Algorithm for Flexbox {
+impl Algorithm for Stretch {
     type Node = Node;
 
-    fn new() -> Flexbox {
-        Flexbox(stretch::Stretch::new())
+    fn new() -> Stretch {
+        Stretch(stretch::Stretch::new())
     }
 
     fn new_node(&mut self, style: ViewStyle, children: &[Self::Node]) -> Self::Node {
@@ -25,7 +31,7 @@ impl Algorithm for Flexbox {
             self.0
                 .new_node(
                     style.into_stretch(),
-                    &children.into_iter().map(|node| node.0).collect::<Vec<_>>(),
+                    children.iter().map(|node| node.0).collect(),
                 )
                 .unwrap(),
         )
@@ -66,21 +72,51 @@ impl Algorithm for Flexbox {
     }
 
     fn compute_layout(&mut self, node: Self::Node, size: Size<Dimension<f32>>) {
+        let number = |dimension| match dimension {
+            Dimension::Points(points) => Number::Defined(points),
+            _ => Number::Undefined,
+        };
+
+        // Stretch only treats the given size as the space that is available
+        // to the node.
+        let style = *self.0.style(node.0).unwrap();
+        let filled = fill_root(
+            Size::new(style.size.width, style.size.height),
+            size,
+            |dimension| {
+                matches!(
+                    dimension,
+                    stretch::style::Dimension::Auto | stretch::style::Dimension::Undefined
+                )
+            },
+            stretch::style::Dimension::Points,
+        );
+
+        if let Some(filled) = filled {
+            let style = stretch::style::Style {
+                size: stretch::geometry::Size {
+                    width: filled.width,
+                    height: filled.height,
+                },
+                ..style
+            };
+
+            self.0.set_style(node.0, style).unwrap();
+        }
+
         self.0
             .compute_layout(
                 node.0,
                 stretch::geometry::Size {
-                    width: match size.width {
-                        Dimension::Points(pixels) => stretch::number::Number::Defined(pixels),
-                        _ => stretch::number::Number::Undefined,
-                    },
-                    height: match size.height {
-                        Dimension::Points(pixels) => stretch::number::Number::Defined(pixels),
-                        _ => stretch::number::Number::Undefined,
-                    },
+                    width: number(size.width),
+                    height: number(size.height),
                 },
             )
             .unwrap();
+
+        if filled.is_some() {
+            self.0.set_style(node.0, style).unwrap();
+        }
     }
 
     fn layout(&self, node: Self::Node) -> Layout {
@@ -98,3 +134,190 @@ impl Algorithm for Flexbox {
         }
     }
 }
+
+/// Send and sync are not implemented for `stretch::Stretch` because it stores
+/// boxed measure functions, but those only wrap our own measure functions,
+/// which are required to be send and sync.
+unsafe impl Send for Stretch {}
+unsafe impl Sync for Stretch {}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::{Algorithm, Flexbox};
+    use crate::geometry::{ByEdge, Dimension, Point, Size};
+    use crate::layout::{Layout, LayoutAxisX, LayoutAxisY, MeasureFunc};
+    use crate::styles::{Absolute, Align, FlexDirection, Justify, Position, Relative, ViewStyle};
+
+    fn points(width: f32, height: f32) -> Size<Dimension<f32>> {
+        Size::new(Dimension::Points(width), Dimension::Points(height))
+    }
+
+    fn layout(x: f32, y: f32, width: f32, height: f32) -> Layout {
+        Layout {
+            origin: Point { x, y },
+            size: Size { width, height },
+        }
+    }
+
+    #[test]
+    fn test_flex() {
+        let mut flexbox = Flexbox::new();
+
+        let fixed = flexbox.new_node(
+            ViewStyle {
+                size: points(20.0, 10.0),
+                margin: ByEdge {
+                    horizontal: LayoutAxisX::dependent(
+                        Dimension::Points(5.0),
+                        Dimension::Undefined,
+                    ),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            &[],
+        );
+
+        let grow = flexbox.new_node(
+            ViewStyle {
+                position: Position::Relative(Relative {
+                    flex_grow: 1.0,
+                    ..Default::default()
+                }),
+                size: Size::new(Dimension::Percentage(0.25), Dimension::Undefined),
+                ..Default::default()
+            },
+            &[],
+        );
+
+        let root = flexbox.new_node(
+            ViewStyle {
+                flex_direction: FlexDirection::RowReverse,
+                align_items: Align::Center,
+                padding: ByEdge {
+                    vertical: LayoutAxisY {
+                        top: Dimension::Points(10.0),
+                        bottom: Dimension::Points(10.0),
+                    },
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            &[fixed, grow],
+        );
+
+        flexbox.compute_layout(root, points(100.0, 50.0));
+
+        assert_eq!(flexbox.layout(root), layout(0.0, 0.0, 100.0, 50.0));
+        assert_eq!(flexbox.layout(fixed), layout(80.0, 20.0, 20.0, 10.0));
+        assert_eq!(flexbox.layout(grow), layout(0.0, 25.0, 75.0, 0.0));
+    }
+
+    #[test]
+    fn test_justify() {
+        let mut flexbox = Flexbox::new();
+
+        let children = (0..3)
+            .map(|_| {
+                flexbox.new_node(
+                    ViewStyle {
+                        size: points(10.0, 10.0),
+                        ..Default::default()
+                    },
+                    &[],
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let root = flexbox.new_node(
+            ViewStyle {
+                flex_direction: FlexDirection::Row,
+                justify_content: Justify::SpaceEvenly,
+                ..Default::default()
+            },
+            &children,
+        );
+
+        flexbox.compute_layout(root, points(70.0, 10.0));
+
+        let origins = children
+            .iter()
+            .map(|&child| flexbox.layout(child).origin.x)
+            .collect::<Vec<_>>();
+
+        assert_eq!(origins, vec![10.0, 30.0, 50.0]);
+    }
+
+    #[test]
+    fn test_absolute() {
+        let mut flexbox = Flexbox::new();
+
+        let absolute = flexbox.new_node(
+            ViewStyle {
+                position: Position::Absolute(Absolute {
+                    distances: ByEdge {
+                        horizontal: LayoutAxisX::independent(
+                            Dimension::Points(10.0),
+                            Dimension::Points(20.0),
+                        ),
+                        vertical: LayoutAxisY {
+                            top: Dimension::Undefined,
+                            bottom: Dimension::Percentage(0.5),
+                        },
+                    },
+                }),
+                size: Size::new(Dimension::Undefined, Dimension::Points(10.0)),
+                ..Default::default()
+            },
+            &[],
+        );
+
+        let root = flexbox.new_node(Default::default(), &[absolute]);
+
+        flexbox.compute_layout(root, points(100.0, 100.0));
+
+        assert_eq!(flexbox.layout(absolute), layout(10.0, 40.0, 70.0, 10.0));
+    }
+
+    #[test]
+    fn test_measure() {
+        let mut flexbox = Flexbox::new();
+
+        // This leaf wraps its contents (10 by 10 points) to its width.
+        let leaf = flexbox.new_leaf(
+            Default::default(),
+            MeasureFunc::Boxed(Arc::new(|size| match size.width {
+                Dimension::Points(width) if width < 100.0 => Size::new(width, 1000.0 / width),
+                _ => Size::new(100.0, 10.0),
+            })),
+        );
+
+        let root = flexbox.new_node(
+            ViewStyle {
+                align_items: Align::FlexStart,
+                ..Default::default()
+            },
+            &[leaf],
+        );
+
+        flexbox.compute_layout(root, points(200.0, 200.0));
+        assert_eq!(flexbox.layout(leaf), layout(0.0, 0.0, 100.0, 10.0));
+
+        flexbox.set_style(
+            root,
+            ViewStyle {
+                size: points(50.0, 200.0),
+                ..Default::default()
+            },
+        );
+
+        flexbox.compute_layout(root, points(200.0, 200.0));
+        assert_eq!(flexbox.layout(leaf), layout(0.0, 0.0, 50.0, 20.0));
+        assert_eq!(flexbox.child_count(root), 1);
+
+        flexbox.remove_child(root, leaf);
+        assert_eq!(flexbox.child_count(root), 0);
+    }
+}
@@ -0,0 +1,274 @@
+use taffy::geometry::{Line, Rect, Size};
+use taffy::style::{
+    AlignContent, AlignItems, Dimension, Display, FlexDirection, FlexWrap, GridPlacement,
+    GridTemplateComponent, JustifyContent, LengthPercentage, LengthPercentageAuto, Overflow,
+    Position, Style, TrackSizingFunction,
+};
+use taffy::style_helpers::{
+    fr, length, line, percent, span, TaffyAuto, TaffyMaxContent, TaffyMinContent,
+};
+
+use crate::geometry::ByEdge;
+use crate::layout::LayoutAxisX;
+use crate::styles::{Align, GridTrack, Justify, ViewStyle};
+
+pub trait IntoTaffy<T> {
+    fn into_taffy(self) -> T;
+}
+
+impl IntoTaffy<Style> for ViewStyle {
+    fn into_taffy(self) -> Style {
+        let (position, inset, flex_basis, flex_grow, flex_shrink, align_self) = match self.position
+        {
+            crate::styles::Position::Absolute(absolute) => (
+                Position::Absolute,
+                absolute.distances.into_taffy(),
+                Dimension::auto(),
+                0.0,
+                1.0,
+                None,
+            ),
+            crate::styles::Position::Relative(relative) => (
+                Position::Relative,
+                Rect::auto(),
+                relative.flex_basis.into_taffy(),
+                relative.flex_grow,
+                relative.flex_shrink,
+                relative.align_self,
+            ),
+        };
+
+        let overflow = self.overflow.into_taffy();
+
+        Style {
+            display: self.display.into_taffy(),
+            position,
+            inset,
+            overflow: taffy::geometry::Point {
+                x: overflow,
+                y: overflow,
+            },
+            size: self.size.into_taffy(),
+            min_size: self.min_size.into_taffy(),
+            max_size: self.max_size.into_taffy(),
+            // Yoga treats undefined margins as zero, rather than `auto`.
+            margin: into_rect(self.margin, |dimension| match dimension {
+                crate::geometry::Dimension::Undefined => LengthPercentageAuto::length(0.0),
+                dimension => dimension.into_taffy(),
+            }),
+            padding: self.padding.into_taffy(),
+            aspect_ratio: self.aspect_ratio,
+            align_items: Some(self.align_items.into_taffy()),
+            align_self: align_self.map(IntoTaffy::into_taffy),
+            align_content: Some(self.align_content.into_taffy()),
+            justify_content: Some(self.justify_content.into_taffy()),
+            gap: Size {
+                width: self.column_gap.into_taffy(),
+                height: self.row_gap.into_taffy(),
+            },
+            flex_direction: self.flex_direction.into_taffy(),
+            flex_wrap: self.flex_wrap.into_taffy(),
+            flex_basis,
+            flex_grow,
+            flex_shrink,
+            grid_template_rows: self.grid_template_rows.into_taffy(),
+            grid_template_columns: self.grid_template_columns.into_taffy(),
+            grid_row: self.grid_area.row.into_taffy(),
+            grid_column: self.grid_area.column.into_taffy(),
+            ..Default::default()
+        }
+    }
+}
+
+impl IntoTaffy<Dimension> for crate::geometry::Dimension<f32> {
+    fn into_taffy(self) -> Dimension {
+        match self {
+            // Yoga treats an undefined dimension (e.g. flex basis) as `auto`.
+            crate::geometry::Dimension::Undefined => Dimension::auto(),
+            crate::geometry::Dimension::Auto => Dimension::auto(),
+            crate::geometry::Dimension::Points(points) => Dimension::length(points),
+            crate::geometry::Dimension::Percentage(percentage) => Dimension::percent(percentage),
+        }
+    }
+}
+
+impl IntoTaffy<LengthPercentageAuto> for crate::geometry::Dimension<f32> {
+    fn into_taffy(self) -> LengthPercentageAuto {
+        match self {
+            crate::geometry::Dimension::Undefined => LengthPercentageAuto::auto(),
+            crate::geometry::Dimension::Auto => LengthPercentageAuto::auto(),
+            crate::geometry::Dimension::Points(points) => LengthPercentageAuto::length(points),
+            crate::geometry::Dimension::Percentage(percentage) => {
+                LengthPercentageAuto::percent(percentage)
+            }
+        }
+    }
+}
+
+impl IntoTaffy<LengthPercentage> for crate::geometry::Dimension<f32> {
+    fn into_taffy(self) -> LengthPercentage {
+        match self {
+            crate::geometry::Dimension::Points(points) => LengthPercentage::length(points),
+            crate::geometry::Dimension::Percentage(percentage) => {
+                LengthPercentage::percent(percentage)
+            }
+            _ => LengthPercentage::length(0.0),
+        }
+    }
+}
+
+impl IntoTaffy<Size<Dimension>> for crate::geometry::Size<crate::geometry::Dimension<f32>> {
+    fn into_taffy(self) -> Size<Dimension> {
+        Size {
+            width: self.width.into_taffy(),
+            height: self.height.into_taffy(),
+        }
+    }
+}
+
+impl<T> IntoTaffy<Rect<T>> for ByEdge<crate::geometry::Dimension<f32>>
+where
+    crate::geometry::Dimension<f32>: IntoTaffy<T>,
+{
+    fn into_taffy(self) -> Rect<T> {
+        into_rect(self, IntoTaffy::into_taffy)
+    }
+}
+
+fn into_rect<T, F>(edges: ByEdge<crate::geometry::Dimension<f32>>, convert: F) -> Rect<T>
+where
+    F: Fn(crate::geometry::Dimension<f32>) -> T,
+{
+    // Taffy lays out every node from left to right, which means that leading
+    // and trailing edges are the left and right edges.
+    let (left, right) = match edges.horizontal {
+        LayoutAxisX::DirectionDependent { leading, trailing } => (leading, trailing),
+        LayoutAxisX::DirectionIndependent { left, right } => (left, right),
+    };
+
+    Rect {
+        left: convert(left),
+        right: convert(right),
+        top: convert(edges.vertical.top),
+        bottom: convert(edges.vertical.bottom),
+    }
+}
+
+impl IntoTaffy<Display> for crate::styles::Display {
+    fn into_taffy(self) -> Display {
+        match self {
+            crate::styles::Display::Flex => Display::Flex,
+            crate::styles::Display::Grid => Display::Grid,
+            crate::styles::Display::None => Display::None,
+        }
+    }
+}
+
+impl IntoTaffy<FlexDirection> for crate::styles::FlexDirection {
+    fn into_taffy(self) -> FlexDirection {
+        match self {
+            crate::styles::FlexDirection::Column => FlexDirection::Column,
+            crate::styles::FlexDirection::ColumnReverse => FlexDirection::ColumnReverse,
+            crate::styles::FlexDirection::Row => FlexDirection::Row,
+            crate::styles::FlexDirection::RowReverse => FlexDirection::RowReverse,
+        }
+    }
+}
+
+impl IntoTaffy<AlignItems> for Align {
+    fn into_taffy(self) -> AlignItems {
+        match self {
+            Align::FlexStart => AlignItems::FlexStart,
+            Align::Center => AlignItems::Center,
+            Align::FlexEnd => AlignItems::FlexEnd,
+            Align::Stretch => AlignItems::Stretch,
+            Align::SpaceBetween | Align::SpaceAround => AlignItems::FlexStart,
+        }
+    }
+}
+
+impl IntoTaffy<AlignContent> for Align {
+    fn into_taffy(self) -> AlignContent {
+        match self {
+            Align::FlexStart => AlignContent::FlexStart,
+            Align::Center => AlignContent::Center,
+            Align::FlexEnd => AlignContent::FlexEnd,
+            Align::Stretch => AlignContent::Stretch,
+            Align::SpaceBetween => AlignContent::SpaceBetween,
+            Align::SpaceAround => AlignContent::SpaceAround,
+        }
+    }
+}
+
+impl IntoTaffy<FlexWrap> for crate::styles::FlexWrap {
+    fn into_taffy(self) -> FlexWrap {
+        match self {
+            crate::styles::FlexWrap::NoWrap => FlexWrap::NoWrap,
+            crate::styles::FlexWrap::Wrap => FlexWrap::Wrap,
+            crate::styles::FlexWrap::WrapReverse => FlexWrap::WrapReverse,
+        }
+    }
+}
+
+impl IntoTaffy<JustifyContent> for Justify {
+    fn into_taffy(self) -> JustifyContent {
+        match self {
+            Justify::FlexStart => JustifyContent::FlexStart,
+            Justify::Center => JustifyContent::Center,
+            Justify::FlexEnd => JustifyContent::FlexEnd,
+            Justify::SpaceBetween => JustifyContent::SpaceBetween,
+            Justify::SpaceAround => JustifyContent::SpaceAround,
+            Justify::SpaceEvenly => JustifyContent::SpaceEvenly,
+        }
+    }
+}
+
+impl IntoTaffy<Overflow> for crate::styles::Overflow {
+    fn into_taffy(self) -> Overflow {
+        match self {
+            crate::styles::Overflow::Visible => Overflow::Visible,
+            crate::styles::Overflow::Hidden => Overflow::Hidden,
+            crate::styles::Overflow::Scroll => Overflow::Scroll,
+        }
+    }
+}
+
+impl IntoTaffy<TrackSizingFunction> for GridTrack {
+    fn into_taffy(self) -> TrackSizingFunction {
+        match self {
+            GridTrack::Auto => TrackSizingFunction::AUTO,
+            GridTrack::Points(points) => length(points),
+            GridTrack::Percentage(percentage) => percent(percentage),
+            GridTrack::Fraction(fraction) => fr(fraction),
+            GridTrack::MinContent => TrackSizingFunction::MIN_CONTENT,
+            GridTrack::MaxContent => TrackSizingFunction::MAX_CONTENT,
+        }
+    }
+}
+
+impl IntoTaffy<Vec<GridTemplateComponent<String>>> for &[GridTrack] {
+    fn into_taffy(self) -> Vec<GridTemplateComponent<String>> {
+        self.iter()
+            .map(|track| GridTemplateComponent::Single(track.into_taffy()))
+            .collect()
+    }
+}
+
+impl IntoTaffy<GridPlacement> for crate::styles::GridPlacement {
+    fn into_taffy(self) -> GridPlacement {
+        match self {
+            crate::styles::GridPlacement::Auto => GridPlacement::Auto,
+            crate::styles::GridPlacement::Line(index) => line(index),
+            crate::styles::GridPlacement::Span(tracks) => span(tracks),
+        }
+    }
+}
+
+impl IntoTaffy<Line<GridPlacement>> for crate::styles::GridLine {
+    fn into_taffy(self) -> Line<GridPlacement> {
+        Line {
+            start: self.start.into_taffy(),
+            end: self.end.into_taffy(),
+        }
+    }
+}
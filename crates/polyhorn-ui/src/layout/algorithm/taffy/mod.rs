@@ -0,0 +1,326 @@
+use taffy::style::AvailableSpace;
+use taffy::{NodeId, TaffyTree, TraversePartialTree};
+
+use super::{fill_root, Algorithm};
+use crate::geometry::{Dimension, Point, Size};
+use crate::layout::{Layout, MeasureFunc};
+use crate::styles::ViewStyle;
+
+mod convert;
+
+use convert::IntoTaffy;
+
+/// Concrete flexbox and grid implementation powered by Taffy.
+pub struct Flexbox(TaffyTree<MeasureFunc>);
+
+/// Handle to a node within the Taffy tree.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Node(NodeId);
+
+impl Algorithm for Flexbox {
+    type Node = Node;
+
+    fn new() -> Flexbox {
+        let mut tree = TaffyTree::new();
+
+        // Other algorithms don't round layouts either.
+        tree.disable_rounding();
+
+        Flexbox(tree)
+    }
+
+    fn new_node(&mut self, style: ViewStyle, children: &[Self::Node]) -> Self::Node {
+        let children = children.iter().map(|node| node.0).collect::<Vec<_>>();
+
+        Node(
+            self.0
+                .new_with_children(style.into_taffy(), &children)
+                .unwrap(),
+        )
+    }
+
+    fn new_leaf(&mut self, style: ViewStyle, measure: MeasureFunc) -> Self::Node {
+        Node(
+            self.0
+                .new_leaf_with_context(style.into_taffy(), measure)
+                .unwrap(),
+        )
+    }
+
+    fn add_child(&mut self, parent: Self::Node, child: Self::Node) {
+        self.0.add_child(parent.0, child.0).unwrap();
+    }
+
+    fn remove_child(&mut self, parent: Self::Node, child: Self::Node) {
+        self.0.remove_child(parent.0, child.0).unwrap();
+    }
+
+    fn child_count(&self, parent: Self::Node) -> usize {
+        self.0.child_count(parent.0)
+    }
+
+    fn remove(&mut self, node: Self::Node) {
+        let _ = self.0.remove(node.0);
+    }
+
+    fn set_style(&mut self, node: Self::Node, style: ViewStyle) {
+        self.0.set_style(node.0, style.into_taffy()).unwrap();
+    }
+
+    fn set_measure(&mut self, node: Self::Node, measure: MeasureFunc) {
+        self.0.set_node_context(node.0, Some(measure)).unwrap();
+    }
+
+    fn compute_layout(&mut self, node: Self::Node, size: Size<Dimension<f32>>) {
+        let available = |dimension| match dimension {
+            Dimension::Points(points) => AvailableSpace::Definite(points),
+            _ => AvailableSpace::MaxContent,
+        };
+
+        // Taffy only treats the given size as the space that is available to
+        // the node.
+        let style = self.0.style(node.0).unwrap().clone();
+        let filled = fill_root(
+            Size::new(style.size.width, style.size.height),
+            size,
+            |dimension| dimension.is_auto(),
+            taffy::style::Dimension::length,
+        );
+
+        if let Some(filled) = filled {
+            let style = taffy::style::Style {
+                size: taffy::geometry::Size {
+                    width: filled.width,
+                    height: filled.height,
+                },
+                ..style.clone()
+            };
+
+            self.0.set_style(node.0, style).unwrap();
+        }
+
+        self.0
+            .compute_layout_with_measure(
+                node.0,
+                taffy::geometry::Size {
+                    width: available(size.width),
+                    height: available(size.height),
+                },
+                |known, available, _, measure, _| {
+                    let measure = match measure {
+                        Some(MeasureFunc::Boxed(measure)) => measure,
+                        None => return taffy::geometry::Size::ZERO,
+                    };
+
+                    let constraint = |known: Option<f32>, available| match (known, available) {
+                        (Some(points), _) | (None, AvailableSpace::Definite(points)) => {
+                            Dimension::Points(points)
+                        }
+                        _ => Dimension::Undefined,
+                    };
+
+                    let size = measure(Size {
+                        width: constraint(known.width, available.width),
+                        height: constraint(known.height, available.height),
+                    });
+
+                    taffy::geometry::Size {
+                        width: known.width.unwrap_or(size.width),
+                        height: known.height.unwrap_or(size.height),
+                    }
+                },
+            )
+            .unwrap();
+
+        if filled.is_some() {
+            self.0.set_style(node.0, style).unwrap();
+        }
+    }
+
+    fn layout(&self, node: Self::Node) -> Layout {
+        let layout = self.0.layout(node.0).unwrap();
+
+        Layout {
+            origin: Point {
+                x: layout.location.x,
+                y: layout.location.y,
+            },
+            size: Size {
+                width: layout.size.width,
+                height: layout.size.height,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::{Algorithm, Flexbox};
+    use crate::geometry::{Dimension, Point, Size};
+    use crate::layout::{Layout, MeasureFunc};
+    use crate::styles::{
+        Align, Display, FlexDirection, GridArea, GridLine, GridPlacement, GridTrack, Justify,
+        Position, Relative, ViewStyle,
+    };
+
+    fn points(width: f32, height: f32) -> Size<Dimension<f32>> {
+        Size::new(Dimension::Points(width), Dimension::Points(height))
+    }
+
+    fn layout(x: f32, y: f32, width: f32, height: f32) -> Layout {
+        Layout {
+            origin: Point { x, y },
+            size: Size { width, height },
+        }
+    }
+
+    #[test]
+    fn test_flex() {
+        let mut flexbox = Flexbox::new();
+
+        let fixed = flexbox.new_node(
+            ViewStyle {
+                size: points(20.0, 10.0),
+                ..Default::default()
+            },
+            &[],
+        );
+
+        let grow = flexbox.new_node(
+            ViewStyle {
+                position: Position::Relative(Relative {
+                    flex_grow: 1.0,
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
+            &[],
+        );
+
+        let root = flexbox.new_node(
+            ViewStyle {
+                flex_direction: FlexDirection::Row,
+                justify_content: Justify::SpaceBetween,
+                column_gap: Dimension::Points(10.0),
+                ..Default::default()
+            },
+            &[fixed, grow],
+        );
+
+        flexbox.compute_layout(root, points(100.0, 50.0));
+
+        assert_eq!(flexbox.layout(root), layout(0.0, 0.0, 100.0, 50.0));
+        assert_eq!(flexbox.layout(fixed), layout(0.0, 0.0, 20.0, 10.0));
+        assert_eq!(flexbox.layout(grow), layout(30.0, 0.0, 70.0, 50.0));
+    }
+
+    #[test]
+    fn test_grid() {
+        let mut flexbox = Flexbox::new();
+
+        let items = (0..3)
+            .map(|_| flexbox.new_node(Default::default(), &[]))
+            .collect::<Vec<_>>();
+
+        // This item spans both columns of the last row.
+        let footer = flexbox.new_node(
+            ViewStyle {
+                grid_area: GridArea {
+                    row: GridLine {
+                        start: GridPlacement::Line(-2),
+                        end: GridPlacement::Auto,
+                    },
+                    column: GridLine {
+                        start: GridPlacement::Line(1),
+                        end: GridPlacement::Span(2),
+                    },
+                },
+                ..Default::default()
+            },
+            &[],
+        );
+
+        let mut children = items.clone();
+        children.push(footer);
+
+        let root = flexbox.new_node(
+            ViewStyle {
+                display: Display::Grid,
+                grid_template_columns: &[GridTrack::Points(20.0), GridTrack::Fraction(1.0)],
+                grid_template_rows: &[
+                    GridTrack::Percentage(0.25),
+                    GridTrack::Fraction(1.0),
+                    GridTrack::Points(10.0),
+                ],
+                row_gap: Dimension::Points(5.0),
+                column_gap: Dimension::Points(10.0),
+                ..Default::default()
+            },
+            &children,
+        );
+
+        flexbox.compute_layout(root, points(100.0, 100.0));
+
+        let layouts = items
+            .iter()
+            .map(|&item| flexbox.layout(item))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            layouts,
+            vec![
+                layout(0.0, 0.0, 20.0, 25.0),
+                layout(30.0, 0.0, 70.0, 25.0),
+                layout(0.0, 30.0, 20.0, 55.0),
+            ]
+        );
+
+        assert_eq!(flexbox.layout(footer), layout(0.0, 90.0, 100.0, 10.0));
+    }
+
+    #[test]
+    fn test_measure() {
+        let mut flexbox = Flexbox::new();
+
+        // This leaf wraps its contents (10 by 10 points) to its width.
+        let leaf = flexbox.new_leaf(
+            Default::default(),
+            MeasureFunc::Boxed(Arc::new(|size| match size.width {
+                Dimension::Points(width) if width < 100.0 => Size::new(width, 1000.0 / width),
+                _ => Size::new(100.0, 10.0),
+            })),
+        );
+
+        let root = flexbox.new_node(
+            ViewStyle {
+                display: Display::Grid,
+                grid_template_columns: &[GridTrack::Points(50.0)],
+                align_content: Align::Stretch,
+                ..Default::default()
+            },
+            &[leaf],
+        );
+
+        flexbox.compute_layout(root, points(200.0, 200.0));
+        assert_eq!(flexbox.layout(leaf), layout(0.0, 0.0, 50.0, 200.0));
+
+        flexbox.set_style(
+            root,
+            ViewStyle {
+                display: Display::Grid,
+                grid_template_columns: &[GridTrack::Points(50.0)],
+                grid_template_rows: &[GridTrack::MinContent],
+                ..Default::default()
+            },
+        );
+
+        flexbox.compute_layout(root, points(200.0, 200.0));
+        assert_eq!(flexbox.layout(leaf), layout(0.0, 0.0, 50.0, 20.0));
+        assert_eq!(flexbox.child_count(root), 1);
+
+        flexbox.remove_child(root, leaf);
+        assert_eq!(flexbox.child_count(root), 0);
+    }
+}
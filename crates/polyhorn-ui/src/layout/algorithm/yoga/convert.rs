@@ -1,10 +1,31 @@
 use crate::geometry::Dimension;
-use crate::styles::{Align, FlexDirection, Justify, Overflow};
+use crate::layout::LayoutDirection;
+use crate::styles::{Align, Display, FlexDirection, FlexWrap, Inherited, Justify, Overflow};
 
 pub trait IntoYoga<T> {
     fn into_yoga(self) -> T;
 }
 
+impl IntoYoga<yoga::Display> for Display {
+    fn into_yoga(self) -> yoga::Display {
+        match self {
+            // Yoga doesn't support grids, so we lay them out as flexboxes.
+            Display::Flex | Display::Grid => yoga::Display::Flex,
+            Display::None => yoga::Display::None,
+        }
+    }
+}
+
+impl IntoYoga<yoga::Direction> for Inherited<LayoutDirection> {
+    fn into_yoga(self) -> yoga::Direction {
+        match self {
+            Inherited::Inherited => yoga::Direction::Inherit,
+            Inherited::Specified(LayoutDirection::LTR) => yoga::Direction::LTR,
+            Inherited::Specified(LayoutDirection::RTL) => yoga::Direction::RTL,
+        }
+    }
+}
+
 impl IntoYoga<yoga::FlexDirection> for FlexDirection {
     fn into_yoga(self) -> yoga::FlexDirection {
         match self {
@@ -16,6 +37,16 @@ impl IntoYoga<yoga::FlexDirection> for FlexDirection {
     }
 }
 
+impl IntoYoga<yoga::Wrap> for FlexWrap {
+    fn into_yoga(self) -> yoga::Wrap {
+        match self {
+            FlexWrap::NoWrap => yoga::Wrap::NoWrap,
+            FlexWrap::Wrap => yoga::Wrap::Wrap,
+            FlexWrap::WrapReverse => yoga::Wrap::WrapReverse,
+        }
+    }
+}
+
 impl IntoYoga<yoga::Align> for Align {
     fn into_yoga(self) -> yoga::Align {
         match self {
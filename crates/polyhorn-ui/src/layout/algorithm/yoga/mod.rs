@@ -2,7 +2,7 @@ use std::cell::RefCell;
 use std::collections::HashMap;
 use std::sync::Mutex;
 
-use super::Algorithm;
+use super::{Algorithm, Gaps};
 use crate::geometry::{Dimension, Point, Size};
 use crate::layout::{Layout, LayoutAxisX, MeasureFunc};
 use crate::styles::{Position, ViewStyle};
@@ -11,13 +11,16 @@ mod convert;
 
 use convert::IntoYoga;
 
-/// Concrete flexbox implementation powered by Yoga.
-pub struct Flexbox {
+/// Concrete flexbox implementation powered by Yoga, which emulates gaps.
+pub type Flexbox = Gaps<Yoga>;
+
+/// Flexbox implementation powered by Yoga that ignores gaps.
+pub struct Yoga {
     counter: usize,
     nodes: Mutex<HashMap<usize, RefCell<yoga::Node>>>,
 }
 
-impl Flexbox {
+impl Yoga {
     fn next_id(&mut self) -> usize {
         let id = self.counter;
         self.counter += 1;
@@ -29,11 +32,11 @@ impl Flexbox {
 #[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
 pub struct Node(usize);
 
-impl Algorithm for Flexbox {
+impl Algorithm for Yoga {
     type Node = Node;
 
     fn new() -> Self {
-        Flexbox {
+        Yoga {
             counter: 0,
             nodes: Default::default(),
         }
@@ -110,6 +113,7 @@ impl Algorithm for Flexbox {
         match style.position {
             Position::Absolute(absolute) => {
                 node.set_position_type(yoga::PositionType::Absolute);
+                node.set_align_self(yoga::Align::Auto);
 
                 node.set_position(yoga::Edge::Top, absolute.distances.vertical.top.into_yoga());
                 node.set_position(
@@ -133,12 +137,24 @@ impl Algorithm for Flexbox {
                 node.set_flex_basis(relative.flex_basis.into_yoga());
                 node.set_flex_grow(relative.flex_grow);
                 node.set_flex_shrink(relative.flex_shrink);
+                node.set_align_self(match relative.align_self {
+                    Some(align_self) => align_self.into_yoga(),
+                    None => yoga::Align::Auto,
+                });
             }
         };
 
+        node.set_display(style.display.into_yoga());
+        node.set_direction(style.direction.into_yoga());
         node.set_flex_direction(style.flex_direction.into_yoga());
+        node.set_flex_wrap(style.flex_wrap.into_yoga());
         node.set_align_items(style.align_items.into_yoga());
         node.set_justify_content(style.justify_content.into_yoga());
+        node.set_align_content(style.align_content.into_yoga());
+
+        // Yoga uses NaN for an undefined aspect ratio. Note that this version
+        // of Yoga doesn't support gaps, which are emulated by `Gaps` instead.
+        node.set_aspect_ratio(style.aspect_ratio.unwrap_or(f32::NAN));
 
         node.set_min_width(style.min_size.width.into_yoga());
         node.set_width(style.size.width.into_yoga());
@@ -253,6 +269,6 @@ impl Algorithm for Flexbox {
 }
 
 /// Send and sync are not implemented for `yoga::Node` but they can be sent
-/// between threads and they are synced by the mutex of Flexbox.
-unsafe impl Send for Flexbox {}
-unsafe impl Sync for Flexbox {}
+/// between threads and they are synced by the mutex of Yoga.
+unsafe impl Send for Yoga {}
+unsafe impl Sync for Yoga {}
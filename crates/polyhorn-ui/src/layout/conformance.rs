@@ -0,0 +1,832 @@
+//! Conformance suite that checks the frames computed by a layout algorithm
+//! against a corpus of view trees with known layouts. Yoga is the reference
+//! implementation: the expected frames are the ones that Yoga computes.
+
+use std::sync::Arc;
+
+use super::algorithm::approx_eq;
+use super::{Algorithm, Layout, LayoutAxisX, LayoutAxisY, LayoutDirection, MeasureFunc};
+use crate::geometry::{ByEdge, Dimension, Point, Size};
+use crate::styles::{
+    Absolute, Align, Display, FlexDirection, FlexWrap, Inherited, Justify, Position, Relative,
+    ViewStyle,
+};
+
+/// Measure function of a leaf within the conformance suite.
+pub type Measure = fn(Size<Dimension<f32>>) -> Size<f32>;
+
+/// Node of a view tree within the conformance suite along with its expected
+/// frame.
+#[derive(Clone, Debug)]
+pub struct Fixture {
+    /// This is the style of this node.
+    pub style: ViewStyle,
+
+    /// If present, this node is a leaf with the given measure function.
+    pub measure: Option<Measure>,
+
+    /// These are the children of this node.
+    pub children: Vec<Fixture>,
+
+    /// This is the frame of this node that an algorithm should compute,
+    /// relative to its parent.
+    pub expected: Layout,
+}
+
+/// View tree within the conformance suite and the size of the container that
+/// it is laid out in.
+#[derive(Clone, Debug)]
+pub struct Case {
+    /// This is a unique name of this case.
+    pub name: &'static str,
+
+    /// This is the size of the container that the root is laid out in.
+    pub size: Size<Dimension<f32>>,
+
+    /// This is the root of the view tree.
+    pub root: Fixture,
+}
+
+/// Node whose computed frame does not match its expected frame.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Mismatch {
+    /// This is the name of the case that contains the node.
+    pub case: &'static str,
+
+    /// These are the indices of the node and its ancestors among their
+    /// siblings, starting at the root (which has an empty path).
+    pub path: Vec<usize>,
+
+    /// This is the frame that the algorithm should have computed.
+    pub expected: Layout,
+
+    /// This is the frame that the algorithm actually computed.
+    pub actual: Layout,
+}
+
+impl Case {
+    /// Lays out this case with the given algorithm and returns the nodes whose
+    /// frames don't match their expected frames, in depth-first order.
+    pub fn check<A>(&self) -> Vec<Mismatch>
+    where
+        A: Algorithm,
+    {
+        let mut flexbox = A::new();
+        let mut nodes = vec![];
+        let root = build(&mut flexbox, &self.root, &mut vec![], &mut nodes);
+
+        flexbox.compute_layout(root, self.size);
+
+        nodes
+            .into_iter()
+            .filter_map(|(path, node, expected)| {
+                let actual = flexbox.layout(node);
+
+                match approx_eq(actual, expected) {
+                    true => None,
+                    false => Some(Mismatch {
+                        case: self.name,
+                        path,
+                        expected,
+                        actual,
+                    }),
+                }
+            })
+            .collect()
+    }
+}
+
+/// Builds the given fixture and its descendants, and collects the path, node
+/// and expected frame of each of them in depth-first order.
+fn build<A>(
+    flexbox: &mut A,
+    fixture: &Fixture,
+    path: &mut Vec<usize>,
+    nodes: &mut Vec<(Vec<usize>, A::Node, Layout)>,
+) -> A::Node
+where
+    A: Algorithm,
+{
+    let index = nodes.len();
+
+    let node = match fixture.measure {
+        Some(measure) => flexbox.new_leaf(fixture.style, MeasureFunc::Boxed(Arc::new(measure))),
+        None => {
+            let mut children = vec![];
+
+            for (i, child) in fixture.children.iter().enumerate() {
+                path.push(i);
+                children.push(build(flexbox, child, path, nodes));
+                path.pop();
+            }
+
+            flexbox.new_node(fixture.style, &children)
+        }
+    };
+
+    // Insert this node before its descendants to keep depth-first order.
+    nodes.insert(index, (path.clone(), node, fixture.expected));
+    node
+}
+
+fn frame(x: f32, y: f32, width: f32, height: f32) -> Layout {
+    Layout {
+        origin: Point { x, y },
+        size: Size { width, height },
+    }
+}
+
+fn points(width: f32, height: f32) -> Size<Dimension<f32>> {
+    Size::new(Dimension::Points(width), Dimension::Points(height))
+}
+
+fn edges(
+    top: Dimension<f32>,
+    right: Dimension<f32>,
+    bottom: Dimension<f32>,
+    left: Dimension<f32>,
+) -> ByEdge<Dimension<f32>> {
+    ByEdge {
+        horizontal: LayoutAxisX::independent(left, right),
+        vertical: LayoutAxisY { top, bottom },
+    }
+}
+
+fn all(value: f32) -> ByEdge<Dimension<f32>> {
+    let value = Dimension::Points(value);
+    edges(value, value, value, value)
+}
+
+fn absolute(distances: ByEdge<Dimension<f32>>) -> Position {
+    Position::Absolute(Absolute { distances })
+}
+
+fn grow(flex_grow: f32) -> Position {
+    Position::Relative(Relative {
+        flex_grow,
+        ..Default::default()
+    })
+}
+
+fn node(style: ViewStyle, expected: Layout, children: Vec<Fixture>) -> Fixture {
+    Fixture {
+        style,
+        measure: None,
+        children,
+        expected,
+    }
+}
+
+fn leaf(style: ViewStyle, measure: Measure, expected: Layout) -> Fixture {
+    Fixture {
+        style,
+        measure: Some(measure),
+        children: vec![],
+        expected,
+    }
+}
+
+fn sized(width: f32, height: f32, expected: Layout) -> Fixture {
+    node(
+        ViewStyle {
+            size: points(width, height),
+            ..Default::default()
+        },
+        expected,
+        vec![],
+    )
+}
+
+/// Measures a paragraph of text that is 120 points wide and wraps at any width
+/// into lines that are 10 points tall.
+fn paragraph(size: Size<Dimension<f32>>) -> Size<f32> {
+    match size.width {
+        Dimension::Points(width) if width > 0.0 && width < 120.0 => {
+            Size::new(width, 10.0 * (120.0 / width).ceil())
+        }
+        _ => Size::new(120.0, 10.0),
+    }
+}
+
+/// Measures an image that is 30 by 10 points.
+fn image(_: Size<Dimension<f32>>) -> Size<f32> {
+    Size::new(30.0, 10.0)
+}
+
+/// Returns the corpus of the conformance suite. Every case is laid out in a
+/// container of 100 by 100 points.
+pub fn corpus() -> Vec<Case> {
+    let case = |name, root| Case {
+        name,
+        size: points(100.0, 100.0),
+        root,
+    };
+
+    let root = frame(0.0, 0.0, 100.0, 100.0);
+
+    vec![
+        case(
+            "row-grow",
+            node(
+                ViewStyle {
+                    flex_direction: FlexDirection::Row,
+                    ..Default::default()
+                },
+                root,
+                vec![
+                    sized(20.0, 10.0, frame(0.0, 0.0, 20.0, 10.0)),
+                    node(
+                        ViewStyle {
+                            position: grow(1.0),
+                            ..Default::default()
+                        },
+                        frame(20.0, 0.0, 80.0, 100.0),
+                        vec![],
+                    ),
+                ],
+            ),
+        ),
+        case(
+            "column-justify-center",
+            node(
+                ViewStyle {
+                    justify_content: Justify::Center,
+                    ..Default::default()
+                },
+                root,
+                vec![
+                    sized(10.0, 10.0, frame(0.0, 40.0, 10.0, 10.0)),
+                    sized(10.0, 10.0, frame(0.0, 50.0, 10.0, 10.0)),
+                ],
+            ),
+        ),
+        case(
+            "row-align-center",
+            node(
+                ViewStyle {
+                    flex_direction: FlexDirection::Row,
+                    align_items: Align::Center,
+                    ..Default::default()
+                },
+                root,
+                vec![
+                    sized(20.0, 10.0, frame(0.0, 45.0, 20.0, 10.0)),
+                    sized(10.0, 30.0, frame(20.0, 35.0, 10.0, 30.0)),
+                ],
+            ),
+        ),
+        case(
+            "row-space-between",
+            node(
+                ViewStyle {
+                    flex_direction: FlexDirection::Row,
+                    justify_content: Justify::SpaceBetween,
+                    ..Default::default()
+                },
+                root,
+                vec![
+                    sized(10.0, 10.0, frame(0.0, 0.0, 10.0, 10.0)),
+                    sized(10.0, 10.0, frame(45.0, 0.0, 10.0, 10.0)),
+                    sized(10.0, 10.0, frame(90.0, 0.0, 10.0, 10.0)),
+                ],
+            ),
+        ),
+        case(
+            "padding-margin",
+            node(
+                ViewStyle {
+                    padding: all(10.0),
+                    ..Default::default()
+                },
+                root,
+                vec![
+                    node(
+                        ViewStyle {
+                            margin: all(5.0),
+                            size: Size::new(Dimension::Auto, Dimension::Points(20.0)),
+                            ..Default::default()
+                        },
+                        frame(15.0, 15.0, 70.0, 20.0),
+                        vec![],
+                    ),
+                    node(
+                        ViewStyle {
+                            margin: edges(
+                                Dimension::Points(5.0),
+                                Dimension::Undefined,
+                                Dimension::Undefined,
+                                Dimension::Undefined,
+                            ),
+                            size: points(10.0, 10.0),
+                            ..Default::default()
+                        },
+                        frame(10.0, 45.0, 10.0, 10.0),
+                        vec![],
+                    ),
+                ],
+            ),
+        ),
+        case(
+            "percentages",
+            node(
+                Default::default(),
+                root,
+                vec![
+                    node(
+                        ViewStyle {
+                            size: Size::new(
+                                Dimension::Percentage(0.5),
+                                Dimension::Percentage(0.25),
+                            ),
+                            ..Default::default()
+                        },
+                        frame(0.0, 0.0, 50.0, 25.0),
+                        vec![node(
+                            ViewStyle {
+                                size: Size::new(
+                                    Dimension::Percentage(0.5),
+                                    Dimension::Percentage(0.5),
+                                ),
+                                ..Default::default()
+                            },
+                            frame(0.0, 0.0, 25.0, 12.5),
+                            vec![],
+                        )],
+                    ),
+                    node(
+                        ViewStyle {
+                            margin: edges(
+                                Dimension::Undefined,
+                                Dimension::Undefined,
+                                Dimension::Undefined,
+                                Dimension::Percentage(0.1),
+                            ),
+                            size: Size::new(Dimension::Percentage(0.1), Dimension::Percentage(0.1)),
+                            ..Default::default()
+                        },
+                        frame(10.0, 25.0, 10.0, 10.0),
+                        vec![],
+                    ),
+                ],
+            ),
+        ),
+        case(
+            "row-shrink",
+            node(
+                ViewStyle {
+                    flex_direction: FlexDirection::Row,
+                    ..Default::default()
+                },
+                root,
+                vec![
+                    node(
+                        ViewStyle {
+                            size: Size::new(Dimension::Points(80.0), Dimension::Auto),
+                            ..Default::default()
+                        },
+                        frame(0.0, 0.0, 50.0, 100.0),
+                        vec![],
+                    ),
+                    node(
+                        ViewStyle {
+                            size: Size::new(Dimension::Points(80.0), Dimension::Auto),
+                            ..Default::default()
+                        },
+                        frame(50.0, 0.0, 50.0, 100.0),
+                        vec![],
+                    ),
+                ],
+            ),
+        ),
+        case(
+            "min-max",
+            node(
+                ViewStyle {
+                    align_items: Align::FlexStart,
+                    ..Default::default()
+                },
+                root,
+                vec![
+                    node(
+                        ViewStyle {
+                            size: points(10.0, 10.0),
+                            min_size: Size::new(Dimension::Points(30.0), Dimension::Auto),
+                            ..Default::default()
+                        },
+                        frame(0.0, 0.0, 30.0, 10.0),
+                        vec![],
+                    ),
+                    node(
+                        ViewStyle {
+                            position: grow(1.0),
+                            size: Size::new(Dimension::Points(10.0), Dimension::Auto),
+                            max_size: Size::new(Dimension::Auto, Dimension::Points(40.0)),
+                            ..Default::default()
+                        },
+                        frame(0.0, 10.0, 10.0, 40.0),
+                        vec![],
+                    ),
+                ],
+            ),
+        ),
+        case(
+            "absolute",
+            node(
+                Default::default(),
+                root,
+                vec![
+                    node(
+                        ViewStyle {
+                            position: absolute(edges(
+                                Dimension::Points(10.0),
+                                Dimension::Undefined,
+                                Dimension::Undefined,
+                                Dimension::Points(20.0),
+                            )),
+                            size: points(30.0, 40.0),
+                            ..Default::default()
+                        },
+                        frame(20.0, 10.0, 30.0, 40.0),
+                        vec![],
+                    ),
+                    node(
+                        ViewStyle {
+                            position: absolute(edges(
+                                Dimension::Undefined,
+                                Dimension::Points(10.0),
+                                Dimension::Points(10.0),
+                                Dimension::Undefined,
+                            )),
+                            size: points(20.0, 20.0),
+                            ..Default::default()
+                        },
+                        frame(70.0, 70.0, 20.0, 20.0),
+                        vec![],
+                    ),
+                    node(
+                        ViewStyle {
+                            position: absolute(edges(
+                                Dimension::Points(0.0),
+                                Dimension::Points(10.0),
+                                Dimension::Undefined,
+                                Dimension::Points(10.0),
+                            )),
+                            size: Size::new(Dimension::Auto, Dimension::Points(10.0)),
+                            ..Default::default()
+                        },
+                        frame(10.0, 0.0, 80.0, 10.0),
+                        vec![],
+                    ),
+                    node(
+                        ViewStyle {
+                            position: absolute(edges(
+                                Dimension::Percentage(0.5),
+                                Dimension::Undefined,
+                                Dimension::Undefined,
+                                Dimension::Percentage(0.5),
+                            )),
+                            size: points(10.0, 10.0),
+                            ..Default::default()
+                        },
+                        frame(50.0, 50.0, 10.0, 10.0),
+                        vec![],
+                    ),
+                    sized(10.0, 10.0, frame(0.0, 0.0, 10.0, 10.0)),
+                ],
+            ),
+        ),
+        case(
+            "measured-leaves",
+            node(
+                ViewStyle {
+                    align_items: Align::FlexStart,
+                    ..Default::default()
+                },
+                root,
+                vec![
+                    leaf(Default::default(), paragraph, frame(0.0, 0.0, 100.0, 20.0)),
+                    leaf(Default::default(), image, frame(0.0, 20.0, 30.0, 10.0)),
+                    leaf(
+                        ViewStyle {
+                            size: Size::new(Dimension::Points(60.0), Dimension::Auto),
+                            ..Default::default()
+                        },
+                        paragraph,
+                        frame(0.0, 30.0, 60.0, 20.0),
+                    ),
+                ],
+            ),
+        ),
+        case(
+            "nested",
+            node(
+                Default::default(),
+                root,
+                vec![
+                    node(
+                        ViewStyle {
+                            flex_direction: FlexDirection::Row,
+                            size: Size::new(Dimension::Auto, Dimension::Points(20.0)),
+                            ..Default::default()
+                        },
+                        frame(0.0, 0.0, 100.0, 20.0),
+                        vec![
+                            node(
+                                ViewStyle {
+                                    position: grow(1.0),
+                                    ..Default::default()
+                                },
+                                frame(0.0, 0.0, 50.0, 20.0),
+                                vec![],
+                            ),
+                            node(
+                                ViewStyle {
+                                    position: grow(1.0),
+                                    ..Default::default()
+                                },
+                                frame(50.0, 0.0, 50.0, 20.0),
+                                vec![],
+                            ),
+                        ],
+                    ),
+                    node(
+                        ViewStyle {
+                            position: grow(1.0),
+                            align_items: Align::Center,
+                            justify_content: Justify::Center,
+                            ..Default::default()
+                        },
+                        frame(0.0, 20.0, 100.0, 80.0),
+                        vec![sized(10.0, 10.0, frame(45.0, 35.0, 10.0, 10.0))],
+                    ),
+                ],
+            ),
+        ),
+        case(
+            "rtl-row",
+            node(
+                ViewStyle {
+                    direction: Inherited::Specified(LayoutDirection::RTL),
+                    flex_direction: FlexDirection::Row,
+                    align_items: Align::FlexStart,
+                    ..Default::default()
+                },
+                root,
+                vec![
+                    node(
+                        ViewStyle {
+                            margin: ByEdge {
+                                horizontal: LayoutAxisX::dependent(
+                                    Dimension::Points(10.0),
+                                    Dimension::Undefined,
+                                ),
+                                vertical: Default::default(),
+                            },
+                            size: points(20.0, 10.0),
+                            ..Default::default()
+                        },
+                        frame(70.0, 0.0, 20.0, 10.0),
+                        vec![],
+                    ),
+                    sized(30.0, 10.0, frame(40.0, 0.0, 30.0, 10.0)),
+                ],
+            ),
+        ),
+        case(
+            "rtl-absolute",
+            node(
+                ViewStyle {
+                    direction: Inherited::Specified(LayoutDirection::RTL),
+                    ..Default::default()
+                },
+                root,
+                vec![node(
+                    ViewStyle {
+                        position: absolute(ByEdge {
+                            horizontal: LayoutAxisX::dependent(
+                                Dimension::Points(10.0),
+                                Dimension::Undefined,
+                            ),
+                            vertical: LayoutAxisY {
+                                top: Dimension::Points(0.0),
+                                bottom: Dimension::Undefined,
+                            },
+                        }),
+                        size: points(20.0, 20.0),
+                        ..Default::default()
+                    },
+                    frame(70.0, 0.0, 20.0, 20.0),
+                    vec![],
+                )],
+            ),
+        ),
+        case(
+            "row-wrap",
+            node(
+                ViewStyle {
+                    flex_direction: FlexDirection::Row,
+                    flex_wrap: FlexWrap::Wrap,
+                    ..Default::default()
+                },
+                root,
+                vec![
+                    sized(40.0, 10.0, frame(0.0, 0.0, 40.0, 10.0)),
+                    sized(40.0, 10.0, frame(40.0, 0.0, 40.0, 10.0)),
+                    sized(40.0, 10.0, frame(0.0, 10.0, 40.0, 10.0)),
+                ],
+            ),
+        ),
+        case(
+            "row-wrap-gap",
+            node(
+                ViewStyle {
+                    flex_direction: FlexDirection::Row,
+                    flex_wrap: FlexWrap::Wrap,
+                    row_gap: Dimension::Points(5.0),
+                    column_gap: Dimension::Points(10.0),
+                    ..Default::default()
+                },
+                root,
+                vec![
+                    sized(40.0, 10.0, frame(0.0, 0.0, 40.0, 10.0)),
+                    sized(40.0, 10.0, frame(50.0, 0.0, 40.0, 10.0)),
+                    sized(40.0, 10.0, frame(0.0, 15.0, 40.0, 10.0)),
+                    sized(40.0, 10.0, frame(50.0, 15.0, 40.0, 10.0)),
+                    sized(40.0, 10.0, frame(0.0, 30.0, 40.0, 10.0)),
+                ],
+            ),
+        ),
+        case(
+            "column-gap",
+            node(
+                ViewStyle {
+                    row_gap: Dimension::Points(10.0),
+                    ..Default::default()
+                },
+                root,
+                vec![
+                    sized(10.0, 10.0, frame(0.0, 0.0, 10.0, 10.0)),
+                    sized(10.0, 10.0, frame(0.0, 20.0, 10.0, 10.0)),
+                ],
+            ),
+        ),
+        case(
+            "row-wrap-align-content-center",
+            node(
+                ViewStyle {
+                    flex_direction: FlexDirection::Row,
+                    flex_wrap: FlexWrap::Wrap,
+                    align_content: Align::Center,
+                    ..Default::default()
+                },
+                root,
+                vec![
+                    sized(40.0, 10.0, frame(0.0, 40.0, 40.0, 10.0)),
+                    sized(40.0, 10.0, frame(40.0, 40.0, 40.0, 10.0)),
+                    sized(40.0, 10.0, frame(0.0, 50.0, 40.0, 10.0)),
+                ],
+            ),
+        ),
+        case(
+            "align-self",
+            node(
+                Default::default(),
+                root,
+                vec![
+                    node(
+                        ViewStyle {
+                            position: Position::Relative(Relative {
+                                align_self: Some(Align::Center),
+                                ..Default::default()
+                            }),
+                            size: points(20.0, 10.0),
+                            ..Default::default()
+                        },
+                        frame(40.0, 0.0, 20.0, 10.0),
+                        vec![],
+                    ),
+                    node(
+                        ViewStyle {
+                            position: Position::Relative(Relative {
+                                align_self: Some(Align::FlexEnd),
+                                ..Default::default()
+                            }),
+                            size: points(10.0, 10.0),
+                            ..Default::default()
+                        },
+                        frame(90.0, 10.0, 10.0, 10.0),
+                        vec![],
+                    ),
+                ],
+            ),
+        ),
+        case(
+            "aspect-ratio",
+            node(
+                ViewStyle {
+                    align_items: Align::FlexStart,
+                    ..Default::default()
+                },
+                root,
+                vec![node(
+                    ViewStyle {
+                        size: Size::new(Dimension::Points(40.0), Dimension::Undefined),
+                        aspect_ratio: Some(2.0),
+                        ..Default::default()
+                    },
+                    frame(0.0, 0.0, 40.0, 20.0),
+                    vec![],
+                )],
+            ),
+        ),
+        case(
+            "display-none",
+            node(
+                ViewStyle {
+                    flex_direction: FlexDirection::Row,
+                    ..Default::default()
+                },
+                root,
+                vec![
+                    sized(20.0, 10.0, frame(0.0, 0.0, 20.0, 10.0)),
+                    node(
+                        ViewStyle {
+                            display: Display::None,
+                            size: points(30.0, 10.0),
+                            ..Default::default()
+                        },
+                        frame(0.0, 0.0, 0.0, 0.0),
+                        vec![sized(10.0, 10.0, frame(0.0, 0.0, 0.0, 0.0))],
+                    ),
+                    sized(20.0, 10.0, frame(20.0, 0.0, 20.0, 10.0)),
+                ],
+            ),
+        ),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{corpus, Algorithm};
+
+    /// Checks that the given algorithm conforms to every case in the corpus,
+    /// except for the given cases that it is known to diverge from.
+    fn assert_conforms<A>(divergences: &[&str])
+    where
+        A: Algorithm,
+    {
+        let corpus = corpus();
+
+        for name in divergences {
+            let case = corpus.iter().find(|case| case.name == *name).unwrap();
+
+            // If this fails, the divergence has been fixed and the case should
+            // be removed from the list.
+            assert!(!case.check::<A>().is_empty(), "{} conforms", name);
+        }
+
+        let mismatches = corpus
+            .iter()
+            .filter(|case| !divergences.contains(&case.name))
+            .flat_map(|case| case.check::<A>())
+            .collect::<Vec<_>>();
+
+        assert!(mismatches.is_empty(), "{:#?}", mismatches);
+    }
+
+    #[test]
+    fn test_unique_names() {
+        let corpus = corpus();
+
+        for (i, case) in corpus.iter().enumerate() {
+            assert!(corpus[..i].iter().all(|other| other.name != case.name));
+        }
+    }
+
+    #[cfg(feature = "layout-yoga")]
+    #[test]
+    fn test_yoga() {
+        assert_conforms::<crate::layout::algorithm::yoga::Flexbox>(&[]);
+    }
+
+    #[cfg(feature = "layout-stretch")]
+    #[test]
+    fn test_stretch() {
+        // Stretch ignores the layout direction, doesn't pass the available
+        // space to measure functions (which means that text doesn't wrap) and
+        // doesn't derive the main size of a node from its aspect ratio.
+        assert_conforms::<crate::layout::algorithm::stretch::Flexbox>(&[
+            "measured-leaves",
+            "aspect-ratio",
+            "rtl-row",
+            "rtl-absolute",
+        ]);
+    }
+
+    #[cfg(feature = "layout-taffy")]
+    #[test]
+    fn test_taffy() {
+        // Taffy doesn't support right-to-left layouts.
+        assert_conforms::<crate::layout::algorithm::taffy::Flexbox>(&["rtl-row", "rtl-absolute"]);
+    }
+}
@@ -8,6 +8,7 @@ use crate::geometry::Size;
 
 /// Implementations of the flexbox algorithm.
 pub mod algorithm;
+pub mod conformance;
 mod tree;
 
 pub use algorithm::Algorithm;
@@ -45,6 +46,7 @@ where
 /// Represents the layout direction of a language (i.e. left-to-right vs.
 /// right-to-left).
 #[derive(Copy, Clone, Debug, Eq, PartialEq, EnumString)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum LayoutDirection {
     /// Left to right. This is the most commonly used direction in most
     /// languages.
@@ -58,6 +60,7 @@ pub enum LayoutDirection {
 
 /// Represents a potentially direction dependent horizontal layout axis.
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum LayoutAxisX<T> {
     /// A horizontal axis that depends on the layout direction.
     DirectionDependent {
@@ -134,6 +137,7 @@ where
 
 /// Represents a direction independent vertical layout axis.
 #[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LayoutAxisY<T> {
     /// Contains the horizontal axis for the top edge.
     pub top: T,
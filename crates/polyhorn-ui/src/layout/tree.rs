@@ -5,6 +5,12 @@ use super::algorithm::{Algorithm, Flexbox, Node};
 use crate::geometry::{Dimension, Point, Size};
 use crate::styles::ViewStyle;
 
+/// Log target that the layout tree reports divergences between layout
+/// algorithms to (at the warning level) if the `layout-dual` feature is
+/// enabled.
+#[cfg(feature = "layout-dual")]
+const LOG_TARGET: &str = "polyhorn::layout";
+
 /// Measure function that is called to obtain the intrinsic content size of a
 /// leaf node (e.g. images or text).
 #[derive(Clone)]
@@ -20,6 +26,7 @@ impl std::fmt::Debug for MeasureFunc {
 }
 
 /// Computed layout of a node.
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct Layout {
     /// The origin of a node.
     pub origin: Point<f32>,
@@ -87,7 +94,9 @@ impl LayoutTree {
         self.flexbox.remove(node);
     }
 
-    /// Recomputes the layout of all roots of the layout tree.
+    /// Recomputes the layout of all roots of the layout tree. With the
+    /// `layout-dual` feature, the nodes whose frames differ between both
+    /// algorithms are reported to the `polyhorn::layout` log target.
     pub fn recompute_roots(&mut self) {
         for node in self.roots().to_owned() {
             let size = self.flexbox().layout(node).size;
@@ -99,6 +108,17 @@ impl LayoutTree {
                     height: Dimension::Points(size.height),
                 },
             );
+
+            #[cfg(feature = "layout-dual")]
+            for divergence in self.flexbox().divergences() {
+                log::warn!(
+                    target: LOG_TARGET,
+                    "Layout of {:?} differs between algorithms: {:?} != {:?}",
+                    divergence.node,
+                    divergence.first,
+                    divergence.second
+                );
+            }
         }
     }
 }
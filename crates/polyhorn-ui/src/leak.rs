@@ -0,0 +1,34 @@
+//! Deserialization of the `&'static` data that styles refer to. Styles are
+//! usually produced by the `style!` macro, which embeds this data in the
+//! binary. Deserialized styles instead leak their data, which is fine for
+//! the limited number of distinct styles that an app uses. Empty slices
+//! (i.e. the common case) don't allocate.
+
+use serde::{Deserialize, Deserializer};
+
+/// Deserializes a sequence into a leaked slice.
+pub fn slice<'de, D, T>(deserializer: D) -> Result<&'static [T], D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    let items = Vec::<T>::deserialize(deserializer)?;
+
+    match items.is_empty() {
+        true => Ok(&[]),
+        false => Ok(Box::leak(items.into_boxed_slice())),
+    }
+}
+
+/// Deserializes a string into a leaked string.
+pub fn str<'de, D>(deserializer: D) -> Result<&'static str, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let string = String::deserialize(deserializer)?;
+
+    match string.is_empty() {
+        true => Ok(""),
+        false => Ok(Box::leak(string.into_boxed_str())),
+    }
+}
@@ -23,6 +23,10 @@ pub mod physics;
 pub mod prelude;
 pub mod queries;
 pub mod styles;
+pub mod text;
+
+#[cfg(feature = "serde")]
+mod leak;
 
 #[cfg(feature = "macros")]
 pub mod macros;
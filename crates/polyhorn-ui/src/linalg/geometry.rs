@@ -3,6 +3,7 @@ use std::ops::{Index, IndexMut};
 
 /// A point in a 3D space.
 #[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Point3D<T> {
     /// The x-coordinate of this point.
     pub x: T,
@@ -41,6 +42,7 @@ where
 
 /// A 3D rotation quaternion.
 #[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Quaternion3D<T> {
     /// The x-element of this quaternion.
     pub x: T,
@@ -4,6 +4,7 @@ use super::{Point3D, Quaternion3D};
 
 /// Rank 4 row-major transformation matrix for 3D objects.
 #[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Transform3D<T> {
     /// This field contains the entries of this matrix in column-major order.
     pub columns: [[T; 4]; 4],
@@ -70,24 +70,48 @@ where
         }
 
         let value = match name.as_str() {
+            "align-content" => PropertyValue::AlignContent(Parser::parse_enum(value)?),
             "align-items" => PropertyValue::AlignItems(Parser::parse_enum(value)?),
+            "align-self" => PropertyValue::AlignSelf(Parser::parse_align_self(value)?),
+            "aspect-ratio" => PropertyValue::AspectRatio(Parser::parse_aspect_ratio(value)?),
             "background-color" => PropertyValue::BackgroundColor(Parser::parse_color(value)?),
+            "background-image" => {
+                PropertyValue::BackgroundImage(Parser::parse_background_image(value)?)
+            }
             "border" => PropertyValue::Border(Parser::parse_border(value)?),
             "border-radius" => PropertyValue::BorderRadius(Parser::parse_border_radius(value)?),
             "bottom" => PropertyValue::Bottom(Parser::parse_dimension(value)?),
+            "box-shadow" => PropertyValue::BoxShadow(Parser::parse_box_shadow(value)?),
             "color" => PropertyValue::Color(Parser::parse_inherited_color(value)?),
+            "column-gap" => PropertyValue::ColumnGap(Parser::parse_dimension(value)?),
             "direction" => PropertyValue::Direction(Parser::parse_enum(value)?),
+            "display" => PropertyValue::Display(Parser::parse_enum(value)?),
+            "ellipsis-mode" => PropertyValue::EllipsisMode(Parser::parse_enum(value)?),
             "flex-basis" => PropertyValue::FlexBasis(Parser::parse_dimension(value)?),
             "flex-direction" => PropertyValue::FlexDirection(Parser::parse_enum(value)?),
             "flex-grow" => PropertyValue::FlexGrow(Parser::parse_number(value)?),
             "flex-shrink" => PropertyValue::FlexShrink(Parser::parse_number(value)?),
+            "flex-wrap" => PropertyValue::FlexWrap(Parser::parse_enum(value)?),
             "font-family" => PropertyValue::FontFamily(Parser::parse_font_family(value)?),
             "font-size" => PropertyValue::FontSize(Parser::parse_font_size(value)?),
             "font-style" => PropertyValue::FontStyle(Parser::parse_enum(value)?),
             "font-weight" => PropertyValue::FontWeight(Parser::parse_font_weight(value)?),
+            "gap" => {
+                let (row, column) = Parser::parse_gap(value)?;
+                PropertyValue::Gap(row, column)
+            }
+            "grid-area" => PropertyValue::GridArea(Parser::parse_grid_area(value)?),
+            "grid-template-columns" => {
+                PropertyValue::GridTemplateColumns(Parser::parse_grid_template(value)?)
+            }
+            "grid-template-rows" => {
+                PropertyValue::GridTemplateRows(Parser::parse_grid_template(value)?)
+            }
             "height" => PropertyValue::Height(Parser::parse_dimension(value)?),
             "justify-content" => PropertyValue::JustifyContent(Parser::parse_enum(value)?),
             "left" => PropertyValue::Left(Parser::parse_dimension(value)?),
+            "letter-spacing" => PropertyValue::LetterSpacing(Parser::parse_letter_spacing(value)?),
+            "line-height" => PropertyValue::LineHeight(Parser::parse_line_height(value)?),
             "margin" => {
                 PropertyValue::Margin(Parser::parse_by_edge(value, Parser::take_dimension)?)
             }
@@ -95,6 +119,9 @@ where
             "max-width" => PropertyValue::MaxWidth(Parser::parse_dimension(value)?),
             "min-height" => PropertyValue::MinHeight(Parser::parse_dimension(value)?),
             "min-width" => PropertyValue::MinWidth(Parser::parse_dimension(value)?),
+            "number-of-lines" => {
+                PropertyValue::NumberOfLines(Parser::parse_number_of_lines(value)?)
+            }
             "object-fit" => PropertyValue::ObjectFit(Parser::parse_enum(value)?),
             "opacity" => PropertyValue::Opacity(Parser::parse_number(value)?),
             "overflow" => PropertyValue::Overflow(Parser::parse_enum(value)?),
@@ -103,7 +130,13 @@ where
             }
             "position" => PropertyValue::Position(Parser::parse_enum(value)?),
             "right" => PropertyValue::Right(Parser::parse_dimension(value)?),
+            "row-gap" => PropertyValue::RowGap(Parser::parse_dimension(value)?),
             "text-align" => PropertyValue::TextAlign(Parser::parse_enum(value)?),
+            "text-decoration" => {
+                PropertyValue::TextDecoration(Parser::parse_text_decoration(value)?)
+            }
+            "text-shadow" => PropertyValue::TextShadow(Parser::parse_text_shadow(value)?),
+            "text-transform" => PropertyValue::TextTransform(Parser::parse_enum(value)?),
             "tint-color" => PropertyValue::TintColor(Parser::parse_color(value)?),
             "transform" => PropertyValue::Transform(Parser::parse_transform(value)?),
             "top" => PropertyValue::Top(Parser::parse_dimension(value)?),
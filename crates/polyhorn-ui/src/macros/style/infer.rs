@@ -27,36 +27,56 @@ impl StyleKind {
         S: TokenStream,
     {
         match &property.value {
+            PropertyValue::AlignContent(_) => StyleKind::View,
             PropertyValue::AlignItems(_) => StyleKind::View,
+            PropertyValue::AlignSelf(_) => StyleKind::View,
+            PropertyValue::AspectRatio(_) => StyleKind::View,
             PropertyValue::BackgroundColor(_) => StyleKind::View,
+            PropertyValue::BackgroundImage(_) => StyleKind::View,
             PropertyValue::Border(_) => StyleKind::View,
             PropertyValue::BorderRadius(_) => StyleKind::View,
             PropertyValue::Bottom(_) => StyleKind::View,
+            PropertyValue::BoxShadow(_) => StyleKind::View,
             PropertyValue::Color(_) => StyleKind::Text,
+            PropertyValue::ColumnGap(_) => StyleKind::View,
             PropertyValue::Direction(_) => StyleKind::View,
+            PropertyValue::Display(_) => StyleKind::View,
+            PropertyValue::EllipsisMode(_) => StyleKind::Text,
             PropertyValue::FlexBasis(_) => StyleKind::View,
             PropertyValue::FlexDirection(_) => StyleKind::View,
             PropertyValue::FlexGrow(_) => StyleKind::View,
             PropertyValue::FlexShrink(_) => StyleKind::View,
+            PropertyValue::FlexWrap(_) => StyleKind::View,
             PropertyValue::FontFamily(_) => StyleKind::Text,
             PropertyValue::FontSize(_) => StyleKind::Text,
             PropertyValue::FontStyle(_) => StyleKind::Text,
             PropertyValue::FontWeight(_) => StyleKind::Text,
+            PropertyValue::Gap(_, _) => StyleKind::View,
+            PropertyValue::GridArea(_) => StyleKind::View,
+            PropertyValue::GridTemplateColumns(_) => StyleKind::View,
+            PropertyValue::GridTemplateRows(_) => StyleKind::View,
             PropertyValue::Height(_) => StyleKind::View,
             PropertyValue::JustifyContent(_) => StyleKind::View,
             PropertyValue::Left(_) => StyleKind::View,
+            PropertyValue::LetterSpacing(_) => StyleKind::Text,
+            PropertyValue::LineHeight(_) => StyleKind::Text,
             PropertyValue::Margin(_) => StyleKind::View,
             PropertyValue::MaxHeight(_) => StyleKind::View,
             PropertyValue::MaxWidth(_) => StyleKind::View,
             PropertyValue::MinHeight(_) => StyleKind::View,
             PropertyValue::MinWidth(_) => StyleKind::View,
+            PropertyValue::NumberOfLines(_) => StyleKind::Text,
             PropertyValue::ObjectFit(_) => StyleKind::Image,
             PropertyValue::Opacity(_) => StyleKind::View,
             PropertyValue::Overflow(_) => StyleKind::View,
             PropertyValue::Padding(_) => StyleKind::View,
             PropertyValue::Position(_) => StyleKind::View,
             PropertyValue::Right(_) => StyleKind::View,
+            PropertyValue::RowGap(_) => StyleKind::View,
             PropertyValue::TextAlign(_) => StyleKind::Text,
+            PropertyValue::TextDecoration(_) => StyleKind::Text,
+            PropertyValue::TextShadow(_) => StyleKind::Text,
+            PropertyValue::TextTransform(_) => StyleKind::Text,
             PropertyValue::TintColor(_) => StyleKind::Image,
             PropertyValue::Top(_) => StyleKind::View,
             PropertyValue::Transform(_) => StyleKind::View,
@@ -1,15 +1,20 @@
 use casco::domain::{Comma, GroupedBy, Parentheses, Parse, SeparatedBy};
-use casco::stream::{MultiSpan, Spanned, TokenStream, TokenTree};
+use casco::stream::{Delimiter, Group, MultiSpan, Punct, Spanned, TokenStream, TokenTree};
 use derivative::Derivative;
 use std::marker::PhantomData;
 use std::str::FromStr;
 
-use crate::color::Color;
+use crate::color::{Color, ParseColorError};
 use crate::font::{FontFamily, FontSize, FontWeight};
-use crate::geometry::{ByCorner, ByDirection, ByEdge, Dimension};
+use crate::geometry::{ByCorner, ByDirection, ByEdge, Dimension, Point};
 use crate::layout::{LayoutAxisX, LayoutAxisY};
 use crate::physics::Angle;
-use crate::styles::{Border, Inherited, Transform, TransformBuilder};
+use crate::styles::{
+    Align, Border, BoxShadow, ColorStop, Corner, Gradient, GradientDirection, GridArea, GridLine,
+    GridPlacement, GridTrack, Inherited, LineHeight, LinearGradient, RadialGradient,
+    RadialGradientShape, RadialGradientSize, TextDecoration, TextShadow, Transform,
+    TransformBuilder,
+};
 
 /// A non-constructible type that provides a generic namespace for the parser
 /// functions.
@@ -117,7 +122,97 @@ where
     /// given slice is malformed or if tokens remain in the slice even after
     /// successfully parsing a color.
     pub fn parse_color(tokens: &[TokenTree<S>]) -> Result<Color, ParseError<S>> {
-        Self::parse_enum(tokens)
+        let span = MultiSpan::new(tokens);
+        let (css, remaining) = match Self::take_color_css(tokens) {
+            Some(result) => result,
+            None => return Err(ParseError::UnexpectedToken(tokens[0].span())),
+        };
+
+        if let Some(first) = remaining.first() {
+            return Err(ParseError::UnexpectedToken(first.span()));
+        }
+
+        Color::from_str(&css).map_err(|error| match error {
+            ParseColorError::UnknownColorName => ParseError::UnknownVariant(span),
+            error => ParseError::InvalidColor(span, error),
+        })
+    }
+
+    /// Attempts to consume one or more tokens from the given slice that make up
+    /// a color. If no color could be consumed, this function returns `None`
+    /// and the original slice of tokens.
+    pub fn take_color<'a>(tokens: &'a [TokenTree<S>]) -> (Option<Color>, &'a [TokenTree<S>]) {
+        match Self::take_color_css(tokens) {
+            Some((css, remaining)) => match Color::from_str(&css) {
+                Ok(color) => (Some(color), remaining),
+                Err(_) => (None, tokens),
+            },
+            None => (None, tokens),
+        }
+    }
+
+    /// Consumes the tokens at the start of the given slice that could make up a
+    /// color (i.e. a hex color, a name or a function) and serializes them to
+    /// CSS, so that they can be parsed with `Color::from_str`. Returns `None`
+    /// if the slice does not start with a color.
+    fn take_color_css<'a>(tokens: &'a [TokenTree<S>]) -> Option<(String, &'a [TokenTree<S>])> {
+        match tokens {
+            [TokenTree::Punct(punct), TokenTree::Ident(hex), remaining @ ..]
+                if punct.as_char() == '#' =>
+            {
+                Some((format!("#{}", hex.to_string()), remaining))
+            }
+            [TokenTree::Punct(punct), TokenTree::Literal(hex), remaining @ ..]
+                if punct.as_char() == '#' =>
+            {
+                Some((format!("#{}", hex.to_string()), remaining))
+            }
+            [TokenTree::Ident(_), ..] => match casco::domain::name(tokens) {
+                (Some(name), [TokenTree::Group(group), remaining @ ..])
+                    if group.delimiter() == Delimiter::Parenthesis =>
+                {
+                    let arguments = group.stream().into_iter().collect::<Vec<_>>();
+                    Some((format!("{}({})", name, Self::to_css(&arguments)), remaining))
+                }
+                (name, remaining) => name.map(|name| (name, remaining)),
+            },
+            _ => None,
+        }
+    }
+
+    /// Serializes the given slice of tokens (i.e. the arguments of a color
+    /// function) to CSS.
+    fn to_css(mut tokens: &[TokenTree<S>]) -> String {
+        let mut css = String::new();
+        let mut glued = true;
+
+        while let Some(token) = tokens.first() {
+            let (string, remaining, glues) = match (Self::take_color_css(tokens), token) {
+                (Some((string, remaining)), _) => (string, remaining, false),
+                (None, TokenTree::Punct(punct)) => match punct.as_char() {
+                    // Rust lexes `.5` as a punct followed by a literal.
+                    '.' => ("0.".to_owned(), &tokens[1..], true),
+                    c @ '#' | c @ '-' | c @ '+' => (c.to_string(), &tokens[1..], true),
+                    c => (c.to_string(), &tokens[1..], false),
+                },
+                (None, TokenTree::Group(group)) => {
+                    let stream = group.stream().into_iter().collect::<Vec<_>>();
+                    (format!("({})", Self::to_css(&stream)), &tokens[1..], false)
+                }
+                (None, TokenTree::Literal(literal)) => (literal.to_string(), &tokens[1..], false),
+                (None, TokenTree::Ident(ident)) => (ident.to_string(), &tokens[1..], false),
+            };
+
+            if !glued && string != "%" && string != "," {
+                css.push(' ');
+            }
+
+            css += &string;
+            glued = glues;
+            tokens = remaining;
+        }
+
+        css
     }
 
     /// Parses an inheritable color from the given slice of tokens. Returns an
@@ -183,6 +278,227 @@ where
         Err(ParseError::UnexpectedToken(tokens[0].span()))
     }
 
+    /// Parses a line height from the given slice of tokens, which is either
+    /// `normal`, a number (i.e. a multiple of the font size) or a dimension.
+    /// Returns an error if the given slice is malformed or if tokens remain in
+    /// the slice even after successfully parsing a line height.
+    pub fn parse_line_height(
+        tokens: &[TokenTree<S>],
+    ) -> Result<Inherited<LineHeight>, ParseError<S>> {
+        match tokens {
+            [TokenTree::Ident(ident)] if ident.to_string().as_str() == "inherit" => {
+                return Ok(Inherited::Inherited)
+            }
+            [TokenTree::Ident(ident)] if ident.to_string().as_str() == "normal" => {
+                return Ok(Inherited::Specified(LineHeight::Normal))
+            }
+            _ => {}
+        }
+
+        if let (Some(number), []) = casco::domain::number(tokens) {
+            return Ok(Inherited::Specified(LineHeight::Number(number)));
+        }
+
+        Self::parse_dimension(tokens)
+            .map(|dimension| Inherited::Specified(LineHeight::Dimension(dimension)))
+    }
+
+    /// Parses a letter spacing from the given slice of tokens, which is either
+    /// `normal` (i.e. no additional spacing) or a number of points. Returns an
+    /// error if the given slice is malformed or if tokens remain in the slice
+    /// even after successfully parsing a letter spacing.
+    pub fn parse_letter_spacing(tokens: &[TokenTree<S>]) -> Result<Inherited<f32>, ParseError<S>> {
+        match tokens {
+            [TokenTree::Ident(ident)] if ident.to_string().as_str() == "inherit" => {
+                Ok(Inherited::Inherited)
+            }
+            [TokenTree::Ident(ident)] if ident.to_string().as_str() == "normal" => {
+                Ok(Inherited::Specified(0.0))
+            }
+            _ => match Self::parse_dimension(tokens)? {
+                Dimension::Points(points) => Ok(Inherited::Specified(points)),
+                _ => Err(ParseError::UnrecognizedUnit(tokens[0].span())),
+            },
+        }
+    }
+
+    /// Parses the maximum number of lines of a text from the given slice of
+    /// tokens, which is either `none` (i.e. unlimited) or a positive integer.
+    /// Returns an error if the given slice is malformed or if tokens remain in
+    /// the slice even after successfully parsing a number of lines.
+    pub fn parse_number_of_lines(
+        tokens: &[TokenTree<S>],
+    ) -> Result<Inherited<usize>, ParseError<S>> {
+        match tokens {
+            [TokenTree::Ident(ident)] if ident.to_string().as_str() == "inherit" => {
+                Ok(Inherited::Inherited)
+            }
+            [TokenTree::Ident(ident)] if ident.to_string().as_str() == "none" => {
+                Ok(Inherited::Specified(0))
+            }
+            _ => match Self::parse_number(tokens)? {
+                lines if lines >= 1.0 && lines.fract() == 0.0 => {
+                    Ok(Inherited::Specified(lines as usize))
+                }
+                _ => Err(ParseError::UnexpectedToken(tokens[0].span())),
+            },
+        }
+    }
+
+    /// Parses a text decoration from the given slice of tokens, which is
+    /// either `none` or any combination of `underline` and `line-through`,
+    /// optionally followed by a style and a color. Returns an error if the
+    /// given slice is malformed or if tokens remain in the slice even after
+    /// successfully parsing a text decoration.
+    pub fn parse_text_decoration(
+        mut tokens: &[TokenTree<S>],
+    ) -> Result<Inherited<TextDecoration>, ParseError<S>> {
+        match tokens {
+            [TokenTree::Ident(ident)] if ident.to_string().as_str() == "inherit" => {
+                return Ok(Inherited::Inherited)
+            }
+            [TokenTree::Ident(ident)] if ident.to_string().as_str() == "none" => {
+                return Ok(Inherited::Specified(TextDecoration::none()))
+            }
+            _ => {}
+        }
+
+        let mut decoration = TextDecoration::none();
+        let mut style = None;
+
+        while !tokens.is_empty() {
+            let (name, remaining) = match casco::domain::name(tokens) {
+                (Some(name), remaining) => (name, remaining),
+                _ => break,
+            };
+
+            match name.as_str() {
+                "underline" if !decoration.underline => decoration.underline = true,
+                "line-through" if !decoration.line_through => decoration.line_through = true,
+                name => match (style, name.parse()) {
+                    (None, Ok(parsed)) => style = Some(parsed),
+                    _ => break,
+                },
+            }
+
+            tokens = remaining;
+        }
+
+        decoration.style = style.unwrap_or_default();
+
+        if !tokens.is_empty() {
+            decoration.color = Some(Self::parse_color(tokens)?);
+        }
+
+        Ok(Inherited::Specified(decoration))
+    }
+
+    /// Attempts to consume one or more tokens from the given slice that make up
+    /// a length in points. If no length could be consumed, this function
+    /// returns `None` and the original slice of tokens.
+    fn take_points<'a>(tokens: &'a [TokenTree<S>]) -> (Option<f32>, &'a [TokenTree<S>]) {
+        if tokens.is_empty() {
+            return (None, tokens);
+        }
+
+        match Self::take_dimension(tokens) {
+            (Some(Dimension::Points(points)), remaining) => (Some(points), remaining),
+            _ => (None, tokens),
+        }
+    }
+
+    /// Parses a text shadow from the given slice of tokens, which is either
+    /// `none` or a horizontal and vertical offset, optionally followed by a
+    /// blur radius and a color. Returns an error if the given slice is
+    /// malformed or if tokens remain in the slice even after successfully
+    /// parsing a text shadow.
+    pub fn parse_text_shadow(
+        tokens: &[TokenTree<S>],
+    ) -> Result<Inherited<Option<TextShadow>>, ParseError<S>> {
+        match tokens {
+            [TokenTree::Ident(ident)] if ident.to_string().as_str() == "inherit" => {
+                return Ok(Inherited::Inherited)
+            }
+            [TokenTree::Ident(ident)] if ident.to_string().as_str() == "none" => {
+                return Ok(Inherited::Specified(None))
+            }
+            _ => {}
+        }
+
+        let span = MultiSpan::new(tokens);
+
+        let (offset_x, offset_y, remaining) = match Self::take_points(tokens) {
+            (Some(offset_x), remaining) => match Self::take_points(remaining) {
+                (Some(offset_y), remaining) => (offset_x, offset_y, remaining),
+                _ if remaining.is_empty() => return Err(ParseError::TooFewArguments(span)),
+                _ => return Err(ParseError::UnexpectedToken(remaining[0].span())),
+            },
+            _ => return Err(ParseError::UnexpectedToken(tokens[0].span())),
+        };
+
+        let (blur_radius, remaining) = match Self::take_points(remaining) {
+            (Some(blur_radius), remaining) => (blur_radius, remaining),
+            (None, remaining) => (0.0, remaining),
+        };
+
+        let color = match remaining {
+            [] => None,
+            remaining => Some(Self::parse_color(remaining)?),
+        };
+
+        Ok(Inherited::Specified(Some(TextShadow {
+            offset_x,
+            offset_y,
+            blur_radius,
+            color,
+        })))
+    }
+
+    /// Parses the alignment of a single item from the given slice of tokens,
+    /// which is either `auto` (in which case the item is aligned according to
+    /// its ancestor) or a variant of `Align`. Returns an error if the given
+    /// slice is malformed or if tokens remain in the slice even after
+    /// successfully parsing an alignment.
+    pub fn parse_align_self(tokens: &[TokenTree<S>]) -> Result<Option<Align>, ParseError<S>> {
+        match tokens {
+            [TokenTree::Ident(ident)] if ident.to_string().as_str() == "auto" => Ok(None),
+            _ => Self::parse_enum(tokens).map(Some),
+        }
+    }
+
+    /// Parses an aspect ratio from the given slice of tokens, which is either
+    /// `auto`, a single number or two numbers separated by a slash (i.e. the
+    /// width and height). Returns an error if the given slice is malformed, if
+    /// the ratio is not positive or if tokens remain in the slice even after
+    /// successfully parsing an aspect ratio.
+    pub fn parse_aspect_ratio(tokens: &[TokenTree<S>]) -> Result<Option<f32>, ParseError<S>> {
+        let span = MultiSpan::new(tokens);
+
+        let (width, remaining) = match tokens {
+            [TokenTree::Ident(ident)] if ident.to_string().as_str() == "auto" => return Ok(None),
+            _ => match casco::domain::number(tokens) {
+                (Some(width), remaining) => (width, remaining),
+                _ => return Err(ParseError::UnexpectedToken(tokens[0].span())),
+            },
+        };
+
+        let ratio = match remaining {
+            [] => width,
+            [TokenTree::Punct(punct), remaining @ ..] if punct.as_char() == '/' => {
+                match remaining {
+                    [] => return Err(ParseError::TooFewArguments(span)),
+                    _ => width / Self::parse_number(remaining)?,
+                }
+            }
+            _ => return Err(ParseError::UnexpectedToken(remaining[0].span())),
+        };
+
+        match ratio.is_finite() && ratio > 0.0 {
+            true => Ok(Some(ratio)),
+            false => Err(ParseError::UnexpectedToken(tokens[0].span())),
+        }
+    }
+
     /// Parses a font size from the given slice of tokens. Returns an error if
     /// the given slice is malformed or if tokens remain in the slice even after
     /// successfully parsing a font size.
@@ -208,7 +524,7 @@ where
         if let (Some(dim), remaining) = casco::domain::unit_dim(tokens) {
             return (
                 Some(match (dim.suffix.as_str(), dim.value) {
-                    ("%", value) => Dimension::Percentage(value),
+                    ("%", value) => Dimension::Percentage(value / 100.0),
                     ("px", value) => Dimension::Points(value),
                     ("", value) if value == 0.0 => Dimension::Points(0.0),
                     _ => return (None, tokens),
@@ -388,6 +704,601 @@ where
         }
     }
 
+    /// Splits the given slice of tokens at each comma. Commas that are nested
+    /// in groups (e.g. the arguments of a function) are not split at.
+    fn split_commas(tokens: &[TokenTree<S>]) -> Vec<&[TokenTree<S>]> {
+        tokens
+            .split(|token| match token {
+                TokenTree::Punct(punct) => punct.as_char() == ',',
+                _ => false,
+            })
+            .collect()
+    }
+
+    /// Parses a list of box shadows from the given slice of tokens, which is
+    /// either `none` or a comma-separated list of shadows. Each shadow consists
+    /// of a horizontal and vertical offset, optionally followed by a blur
+    /// radius and a spread radius, and optionally preceded or followed by a
+    /// color and the `inset` keyword. Returns an error if the given slice is
+    /// malformed.
+    pub fn parse_box_shadow(tokens: &[TokenTree<S>]) -> Result<Vec<BoxShadow>, ParseError<S>> {
+        match tokens {
+            [TokenTree::Ident(ident)] if ident.to_string().as_str() == "none" => return Ok(vec![]),
+            _ => {}
+        }
+
+        Self::split_commas(tokens)
+            .into_iter()
+            .map(|tokens| {
+                let span = MultiSpan::new(tokens);
+                let mut remaining = tokens;
+                let mut shadow = BoxShadow::default();
+                let mut lengths = vec![];
+                let mut color = None;
+
+                // The lengths of a shadow must be adjacent, so no more lengths
+                // can follow once the first length is followed by something
+                // else.
+                let mut closed = false;
+
+                while let Some(first) = remaining.first() {
+                    match remaining {
+                        [TokenTree::Ident(ident), rest @ ..]
+                            if ident.to_string().as_str() == "inset" && !shadow.inset =>
+                        {
+                            shadow.inset = true;
+                            closed = !lengths.is_empty();
+                            remaining = rest;
+                            continue;
+                        }
+                        _ => {}
+                    }
+
+                    match Self::take_points(remaining) {
+                        (Some(_), _) if closed || lengths.len() == 4 => {
+                            return Err(ParseError::UnexpectedToken(first.span()))
+                        }
+                        // The blur radius cannot be negative.
+                        (Some(length), _) if lengths.len() == 2 && length < 0.0 => {
+                            return Err(ParseError::UnexpectedToken(first.span()))
+                        }
+                        (Some(length), rest) => {
+                            lengths.push(length);
+                            remaining = rest;
+                            continue;
+                        }
+                        (None, _) => {}
+                    }
+
+                    match (color, Self::take_color(remaining)) {
+                        (None, (Some(parsed), rest)) => {
+                            color = Some(parsed);
+                            closed = !lengths.is_empty();
+                            remaining = rest;
+                        }
+                        _ => return Err(ParseError::UnexpectedToken(first.span())),
+                    }
+                }
+
+                match lengths.as_slice() {
+                    [] | [_] => return Err(ParseError::TooFewArguments(span)),
+                    lengths => {
+                        shadow.offset_x = lengths[0];
+                        shadow.offset_y = lengths[1];
+                        shadow.blur_radius = lengths.get(2).copied().unwrap_or_default();
+                        shadow.spread_radius = lengths.get(3).copied().unwrap_or_default();
+                    }
+                }
+
+                shadow.color = color.unwrap_or(shadow.color);
+
+                Ok(shadow)
+            })
+            .collect()
+    }
+
+    /// Parses a background image from the given slice of tokens, which is
+    /// either `none`, `linear-gradient(...)` or `radial-gradient(...)`.
+    /// Returns an error if the given slice is malformed or if tokens remain in
+    /// the slice even after successfully parsing a background image.
+    pub fn parse_background_image(
+        tokens: &[TokenTree<S>],
+    ) -> Result<Option<Gradient>, ParseError<S>> {
+        let (name, remaining) = match casco::domain::name(tokens) {
+            (Some(name), remaining) => (name, remaining),
+            _ => return Err(ParseError::UnexpectedToken(tokens[0].span())),
+        };
+
+        let group = match (name.as_str(), remaining) {
+            ("none", []) => return Ok(None),
+            (_, [TokenTree::Group(group)]) if group.delimiter() == Delimiter::Parenthesis => group,
+            (_, []) => return Err(ParseError::UnknownVariant(MultiSpan::new(tokens))),
+            (_, remaining) => return Err(ParseError::UnexpectedToken(remaining[0].span())),
+        };
+
+        let arguments = group.stream().into_iter().collect::<Vec<_>>();
+        let mut arguments = Self::split_commas(&arguments);
+        let span = MultiSpan::single(group.span());
+
+        match name.as_str() {
+            "linear-gradient" => {
+                let direction = match Self::parse_gradient_direction(arguments[0])? {
+                    Some(direction) => {
+                        arguments.remove(0);
+                        direction
+                    }
+                    None => Default::default(),
+                };
+
+                Ok(Some(Gradient::Linear(LinearGradient {
+                    direction,
+                    stops: Self::parse_color_stops(&arguments, span)?,
+                })))
+            }
+            "radial-gradient" => {
+                let mut gradient = match Self::parse_radial_gradient_shape(arguments[0])? {
+                    Some(gradient) => {
+                        arguments.remove(0);
+                        gradient
+                    }
+                    None => RadialGradient::default(),
+                };
+
+                gradient.stops = Self::parse_color_stops(&arguments, span)?;
+
+                Ok(Some(Gradient::Radial(gradient)))
+            }
+            _ => Err(ParseError::UnknownVariant(MultiSpan::new(tokens))),
+        }
+    }
+
+    /// Parses the direction of a linear gradient from the given slice of
+    /// tokens, which is either an angle or `to` followed by one or two sides.
+    /// Returns `None` if the slice does not start with a direction (i.e.
+    /// because it contains the first color stop instead).
+    fn parse_gradient_direction(
+        tokens: &[TokenTree<S>],
+    ) -> Result<Option<GradientDirection>, ParseError<S>> {
+        let sides = match tokens {
+            [TokenTree::Ident(ident), sides @ ..] if ident.to_string().as_str() == "to" => sides,
+            _ => {
+                return match <Angle<f32> as Parse<S>>::parse(tokens) {
+                    (Some(angle), []) => Ok(Some(GradientDirection::Angle(angle))),
+                    _ => Ok(None),
+                }
+            }
+        };
+
+        let sides = sides
+            .iter()
+            .map(|side| match side {
+                TokenTree::Ident(ident) => match ident.to_string().as_str() {
+                    "top" => Ok((0, -1)),
+                    "right" => Ok((1, 0)),
+                    "bottom" => Ok((0, 1)),
+                    "left" => Ok((-1, 0)),
+                    _ => Err(ParseError::UnexpectedToken(side.span())),
+                },
+                _ => Err(ParseError::UnexpectedToken(side.span())),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let degrees = |degrees| Ok(Some(GradientDirection::Angle(Angle::with_degrees(degrees))));
+        let corner = |corner| Ok(Some(GradientDirection::Corner(corner)));
+
+        match sides.as_slice() {
+            [] => Err(ParseError::TooFewArguments(MultiSpan::new(tokens))),
+            [(0, -1)] => degrees(0.0),
+            [(1, 0)] => degrees(90.0),
+            [(0, 1)] => degrees(180.0),
+            [(-1, 0)] => degrees(270.0),
+            [(a, b), (c, d)] if a * c == 0 && b * d == 0 => match (a + c, b + d) {
+                (1, -1) => corner(Corner::TopRight),
+                (1, 1) => corner(Corner::BottomRight),
+                (-1, 1) => corner(Corner::BottomLeft),
+                (-1, -1) => corner(Corner::TopLeft),
+                _ => Err(ParseError::UnexpectedToken(tokens[2].span())),
+            },
+            [_, _] => Err(ParseError::UnexpectedToken(tokens[2].span())),
+            _ => Err(ParseError::TooManyArguments(MultiSpan::new(tokens))),
+        }
+    }
+
+    /// Parses the shape, size and position of a radial gradient from the given
+    /// slice of tokens. Returns `None` if the slice does not start with any of
+    /// these (i.e. because it contains the first color stop instead).
+    fn parse_radial_gradient_shape(
+        tokens: &[TokenTree<S>],
+    ) -> Result<Option<RadialGradient>, ParseError<S>> {
+        if let (Some(_), _) = Self::take_color(tokens) {
+            return Ok(None);
+        }
+
+        let span = MultiSpan::new(tokens);
+        let mut gradient = RadialGradient::default();
+        let mut shape = None;
+        let mut size = None;
+        let mut radii = vec![];
+        let mut remaining = tokens;
+
+        while let Some(first) = remaining.first() {
+            match first {
+                TokenTree::Ident(ident) if ident.to_string().as_str() == "at" => break,
+                _ => {}
+            }
+
+            if let (Some(parsed), rest) = Self::take_enum::<RadialGradientShape>(remaining) {
+                if shape.replace(parsed).is_some() {
+                    return Err(ParseError::UnexpectedToken(first.span()));
+                }
+
+                remaining = rest;
+                continue;
+            }
+
+            if let (Some(parsed), rest) = Self::take_enum::<RadialGradientSize>(remaining) {
+                if size.replace(parsed).is_some() || !radii.is_empty() {
+                    return Err(ParseError::UnexpectedToken(first.span()));
+                }
+
+                remaining = rest;
+                continue;
+            }
+
+            match Self::take_dimension(remaining) {
+                (Some(radius @ Dimension::Points(_)), rest)
+                | (Some(radius @ Dimension::Percentage(_)), rest)
+                    if size.is_none() && radii.len() < 2 =>
+                {
+                    radii.push(radius);
+                    remaining = rest;
+                }
+                _ => return Err(ParseError::UnexpectedToken(first.span())),
+            }
+        }
+
+        gradient.shape = match (shape, radii.len()) {
+            (Some(shape), 0) => shape,
+            (None, 0) => RadialGradientShape::Ellipse,
+            // A single radius must be a length and can only be used for
+            // circles, while two radii can only be used for ellipses.
+            (None, 1) | (Some(RadialGradientShape::Circle), 1)
+                if matches!(radii[0], Dimension::Points(_)) =>
+            {
+                RadialGradientShape::Circle
+            }
+            (None, 2) | (Some(RadialGradientShape::Ellipse), 2) => RadialGradientShape::Ellipse,
+            _ => return Err(ParseError::UnexpectedToken(tokens[0].span())),
+        };
+
+        gradient.size = match radii.as_slice() {
+            [] => size.unwrap_or_default(),
+            [radius] => RadialGradientSize::Radii(*radius, *radius),
+            radii => RadialGradientSize::Radii(radii[0], radii[1]),
+        };
+
+        if let [_, position @ ..] = remaining {
+            gradient.position = Self::parse_position(position, span)?;
+        }
+
+        Ok(Some(gradient))
+    }
+
+    /// Parses a position within a rectangle from the given slice of tokens,
+    /// which consists of one or two keywords (i.e. `left`, `center`, `right`,
+    /// `top` or `bottom`) or dimensions. Omitted coordinates are centered.
+    /// Returns an error if the given slice is malformed or if tokens remain in
+    /// the slice even after successfully parsing a position.
+    pub fn parse_position(
+        tokens: &[TokenTree<S>],
+        span: MultiSpan<S>,
+    ) -> Result<Point<Dimension<f32>>, ParseError<S>> {
+        // Each coordinate is either horizontal, vertical or both (if it is
+        // centered or a dimension).
+        let mut coordinates = vec![];
+        let mut remaining = tokens;
+
+        while let Some(first) = remaining.first() {
+            let coordinate = match first {
+                TokenTree::Ident(ident) => match ident.to_string().as_str() {
+                    "left" => (Some(true), Dimension::Percentage(0.0)),
+                    "right" => (Some(true), Dimension::Percentage(1.0)),
+                    "top" => (Some(false), Dimension::Percentage(0.0)),
+                    "bottom" => (Some(false), Dimension::Percentage(1.0)),
+                    "center" => (None, Dimension::Percentage(0.5)),
+                    _ => return Err(ParseError::UnexpectedToken(first.span())),
+                },
+                _ => match Self::take_dimension(remaining) {
+                    (Some(dimension @ Dimension::Points(_)), rest)
+                    | (Some(dimension @ Dimension::Percentage(_)), rest) => {
+                        coordinates.push((None, dimension));
+                        remaining = rest;
+                        continue;
+                    }
+                    _ => return Err(ParseError::UnexpectedToken(first.span())),
+                },
+            };
+
+            coordinates.push(coordinate);
+            remaining = &remaining[1..];
+        }
+
+        let center = Dimension::Percentage(0.5);
+
+        match coordinates.as_slice() {
+            [] => Err(ParseError::TooFewArguments(span)),
+            [(Some(false), y)] => Ok(Point::new(center, *y)),
+            [(_, x)] => Ok(Point::new(*x, center)),
+            [(Some(false), y), (Some(true), x)]
+            | [(Some(false), y), (None, x)]
+            | [(None, y), (Some(true), x)] => Ok(Point::new(*x, *y)),
+            [(Some(true), x), (Some(false), y)]
+            | [(Some(true), x), (None, y)]
+            | [(None, x), (Some(false), y)]
+            | [(None, x), (None, y)] => Ok(Point::new(*x, *y)),
+            [_, _] => Err(ParseError::UnexpectedToken(tokens[1].span())),
+            _ => Err(ParseError::TooManyArguments(span)),
+        }
+    }
+
+    /// Parses the color stops of a gradient from the given comma-separated
+    /// slices of tokens. Each stop consists of a color, optionally followed by
+    /// one or two positions (in which case the color is repeated at both
+    /// positions). Returns an error if any of the stops is malformed or if
+    /// there are fewer than two stops.
+    fn parse_color_stops(
+        arguments: &[&[TokenTree<S>]],
+        span: MultiSpan<S>,
+    ) -> Result<&'static [ColorStop], ParseError<S>> {
+        let mut stops = vec![];
+
+        for tokens in arguments {
+            let (color, mut remaining) = match Self::take_color(tokens) {
+                (Some(color), remaining) => (color, remaining),
+                _ if tokens.is_empty() => return Err(ParseError::TooFewArguments(span)),
+                _ => return Err(ParseError::UnexpectedToken(tokens[0].span())),
+            };
+
+            let mut positions = vec![];
+
+            while let Some(first) = remaining.first() {
+                match Self::take_dimension(remaining) {
+                    (Some(position @ Dimension::Points(_)), rest)
+                    | (Some(position @ Dimension::Percentage(_)), rest)
+                        if positions.len() < 2 =>
+                    {
+                        positions.push(Some(position));
+                        remaining = rest;
+                    }
+                    _ => return Err(ParseError::UnexpectedToken(first.span())),
+                }
+            }
+
+            if positions.is_empty() {
+                positions.push(None);
+            }
+
+            stops.extend(
+                positions
+                    .into_iter()
+                    .map(|position| ColorStop { color, position }),
+            );
+        }
+
+        if stops.len() < 2 {
+            return Err(ParseError::TooFewArguments(span));
+        }
+
+        // The gradient is only used to generate code, where the stops end up
+        // in a static slice, so this leaks a few bytes at compile time.
+        Ok(Box::leak(stops.into_boxed_slice()))
+    }
+
+    /// Parses the row gap and column gap of a grid from the given slice of
+    /// tokens. If only one dimension is given, it is used for both gaps.
+    /// Returns an error if the given slice is malformed or if tokens remain in
+    /// the slice even after successfully parsing both gaps.
+    pub fn parse_gap(
+        tokens: &[TokenTree<S>],
+    ) -> Result<(Dimension<f32>, Dimension<f32>), ParseError<S>> {
+        let span = MultiSpan::new(tokens);
+
+        let (row, remaining) = match Self::take_dimension(tokens) {
+            (Some(row), remaining) => (row, remaining),
+            _ => return Err(ParseError::UnexpectedToken(tokens[0].span())),
+        };
+
+        if remaining.is_empty() {
+            return Ok((row, row));
+        }
+
+        match Self::take_dimension(remaining) {
+            (Some(column), []) => Ok((row, column)),
+            (Some(_), _) => Err(ParseError::TooManyArguments(span)),
+            (None, _) => Err(ParseError::UnexpectedToken(remaining[0].span())),
+        }
+    }
+
+    /// Attempts to consume one or more tokens from the given slice that make up
+    /// the size of a grid track. If no track could be consumed, this function
+    /// returns `None` and the original slice of tokens.
+    pub fn take_grid_track<'a>(
+        tokens: &'a [TokenTree<S>],
+    ) -> (Option<GridTrack>, &'a [TokenTree<S>]) {
+        if let (Some(name), remaining) = casco::domain::name(tokens) {
+            return match name.as_str() {
+                "auto" => (Some(GridTrack::Auto), remaining),
+                "min-content" => (Some(GridTrack::MinContent), remaining),
+                "max-content" => (Some(GridTrack::MaxContent), remaining),
+                _ => (None, tokens),
+            };
+        }
+
+        if let (Some(dim), remaining) = casco::domain::unit_dim(tokens) {
+            return (
+                Some(match (dim.suffix.as_str(), dim.value) {
+                    ("%", value) => GridTrack::Percentage(value / 100.0),
+                    ("px", value) => GridTrack::Points(value),
+                    ("fr", value) => GridTrack::Fraction(value),
+                    ("", value) if value == 0.0 => GridTrack::Points(0.0),
+                    _ => return (None, tokens),
+                }),
+                remaining,
+            );
+        }
+
+        (None, tokens)
+    }
+
+    /// Parses the sizes of the rows or columns of a grid from the given slice
+    /// of tokens. Tracks can be repeated with `repeat(count, tracks...)`.
+    /// Returns an error if the given slice is malformed.
+    pub fn parse_grid_template(
+        mut tokens: &[TokenTree<S>],
+    ) -> Result<Vec<GridTrack>, ParseError<S>> {
+        match tokens {
+            [TokenTree::Ident(ident)] if ident.to_string() == "none" => return Ok(vec![]),
+            _ => {}
+        };
+
+        let mut tracks = vec![];
+
+        while !tokens.is_empty() {
+            match tokens {
+                [TokenTree::Ident(ident), TokenTree::Group(group), remaining @ ..]
+                    if ident.to_string() == "repeat"
+                        && group.delimiter() == Delimiter::Parenthesis =>
+                {
+                    let arguments = group.stream().into_iter().collect::<Vec<_>>();
+                    tracks.extend(Self::parse_grid_repetition(&arguments, group.span())?);
+                    tokens = remaining;
+                }
+                _ => match Self::take_grid_track(tokens) {
+                    (Some(track), remaining) => {
+                        tracks.push(track);
+                        tokens = remaining;
+                    }
+                    (None, _) => return Err(ParseError::UnexpectedToken(tokens[0].span())),
+                },
+            }
+        }
+
+        Ok(tracks)
+    }
+
+    fn parse_grid_repetition(
+        tokens: &[TokenTree<S>],
+        span: S::Span,
+    ) -> Result<Vec<GridTrack>, ParseError<S>> {
+        let (count, remaining) = match casco::domain::number(tokens) {
+            (Some(count), remaining) if count >= 1.0 && count.fract() == 0.0 => {
+                (count as usize, remaining)
+            }
+            _ => {
+                return Err(ParseError::UnexpectedToken(
+                    tokens.first().map_or(span, |token| token.span()),
+                ))
+            }
+        };
+
+        let remaining = match remaining.first() {
+            Some(TokenTree::Punct(punct)) if punct.as_char() == ',' => &remaining[1..],
+            Some(token) => return Err(ParseError::UnexpectedToken(token.span())),
+            None => return Err(ParseError::TooFewArguments(MultiSpan::single(span))),
+        };
+
+        let tracks = Self::parse_grid_template(remaining)?;
+
+        if tracks.is_empty() {
+            return Err(ParseError::TooFewArguments(MultiSpan::single(span)));
+        }
+
+        Ok(tracks.repeat(count))
+    }
+
+    /// Attempts to consume one or more tokens from the given slice that make up
+    /// the start or end of a grid area (i.e. `auto`, a line index or `span`
+    /// followed by a number of tracks). If no placement could be consumed,
+    /// this function returns `None` and the original slice of tokens.
+    pub fn take_grid_placement<'a>(
+        tokens: &'a [TokenTree<S>],
+    ) -> (Option<GridPlacement>, &'a [TokenTree<S>]) {
+        let integer = |tokens| match casco::domain::unit_dim::<S, f32>(tokens) {
+            (Some(dim), remaining) if dim.suffix.is_empty() && dim.value.fract() == 0.0 => {
+                (Some(dim.value), remaining)
+            }
+            _ => (None, tokens),
+        };
+
+        match tokens {
+            [TokenTree::Ident(ident), remaining @ ..] if ident.to_string() == "auto" => {
+                (Some(GridPlacement::Auto), remaining)
+            }
+            [TokenTree::Ident(ident), remaining @ ..] if ident.to_string() == "span" => {
+                match integer(remaining) {
+                    (Some(span), remaining) if span >= 1.0 => {
+                        (Some(GridPlacement::Span(span as u16)), remaining)
+                    }
+                    _ => (None, tokens),
+                }
+            }
+            _ => match integer(tokens) {
+                (Some(line), remaining) if line != 0.0 => {
+                    (Some(GridPlacement::Line(line as i16)), remaining)
+                }
+                _ => (None, tokens),
+            },
+        }
+    }
+
+    /// Parses a grid area from the given slice of tokens. The area consists of
+    /// up to 4 placements separated by slashes, in the order row start, column
+    /// start, row end and column end. Omitted placements are `auto`. Returns
+    /// an error if the given slice is malformed.
+    pub fn parse_grid_area(mut tokens: &[TokenTree<S>]) -> Result<GridArea, ParseError<S>> {
+        let span = MultiSpan::new(tokens);
+        let mut placements = vec![];
+
+        loop {
+            match Self::take_grid_placement(tokens) {
+                (Some(placement), remaining) => {
+                    placements.push(placement);
+                    tokens = remaining;
+                }
+                (None, _) => {
+                    return Err(ParseError::UnexpectedToken(
+                        tokens
+                            .first()
+                            .map_or(span.last().unwrap(), |token| token.span()),
+                    ))
+                }
+            }
+
+            match tokens.first() {
+                Some(TokenTree::Punct(punct)) if punct.as_char() == '/' => tokens = &tokens[1..],
+                Some(token) => return Err(ParseError::UnexpectedToken(token.span())),
+                None => break,
+            }
+        }
+
+        if placements.len() > 4 {
+            return Err(ParseError::TooManyArguments(span));
+        }
+
+        placements.resize(4, GridPlacement::Auto);
+
+        Ok(GridArea {
+            row: GridLine {
+                start: placements[0],
+                end: placements[2],
+            },
+            column: GridLine {
+                start: placements[1],
+                end: placements[3],
+            },
+        })
+    }
+
     /// Parses an angle from the given slice of tokens. Returns an error if the
     /// given slice is malformed or if tokens remain in the slice even after
     /// successfully parsing an angle.
@@ -615,4 +1526,9 @@ where
     /// multi-part properties. For example, this happens if 5 (instead of 2 or
     /// 4) arguments are provided to margin or padding.
     TooManyArguments(MultiSpan<S>),
+
+    /// This error is emitted when the parser encounters a color that is
+    /// malformed. For example, this happens if a color function has too few
+    /// arguments.
+    InvalidColor(MultiSpan<S>, ParseColorError),
 }
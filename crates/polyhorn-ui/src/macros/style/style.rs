@@ -8,8 +8,9 @@ use crate::font::{FontFamily, FontSize, FontStyle, FontWeight};
 use crate::geometry::{ByEdge, Size};
 use crate::layout::{LayoutAxisX, LayoutAxisY, LayoutDirection};
 use crate::styles::{
-    Absolute, ImageStyle, ImageViewStyle, Inherited, ObjectFit, Position, Relative,
-    ScrollableStyle, ScrollableViewStyle, TextAlign, TextStyle, ViewStyle,
+    Absolute, BoxShadow, EllipsisMode, GridTrack, ImageStyle, ImageViewStyle, Inherited,
+    LineHeight, ObjectFit, Position, Relative, ScrollableStyle, ScrollableViewStyle, TextAlign,
+    TextDecoration, TextShadow, TextStyle, TextTransform, ViewStyle,
 };
 
 /// Controls the appearance of a built-in component.
@@ -134,6 +135,8 @@ where
         let mut flex_basis = self.track_with(Relative::default().flex_basis);
         let mut flex_grow = self.track_with(Relative::default().flex_grow);
         let mut flex_shrink = self.track_with(Relative::default().flex_shrink);
+        let mut align_self = self.track_with(Relative::default().align_self);
+        let mut display = self.track_with(ViewStyle::default().display);
         let mut direction = self.track::<Inherited<LayoutDirection>>();
         let mut height = self.track_with(ViewStyle::default().size.height);
         let mut width = self.track_with(ViewStyle::default().size.width);
@@ -141,14 +144,28 @@ where
         let mut min_width = self.track_with(ViewStyle::default().min_size.width);
         let mut max_height = self.track_with(ViewStyle::default().max_size.height);
         let mut max_width = self.track_with(ViewStyle::default().max_size.width);
+        let mut aspect_ratio = self.track_with(ViewStyle::default().aspect_ratio);
         let mut flex_direction = self.track_with(ViewStyle::default().flex_direction);
+        let mut flex_wrap = self.track_with(ViewStyle::default().flex_wrap);
         let mut align_items = self.track_with(ViewStyle::default().align_items);
         let mut justify_content = self.track_with(ViewStyle::default().justify_content);
+        let mut align_content = self.track_with(ViewStyle::default().align_content);
+        let mut grid_template_rows = self.track::<Vec<GridTrack>>();
+        let mut grid_template_columns = self.track::<Vec<GridTrack>>();
+        let mut grid_area = self.track_with(ViewStyle::default().grid_area);
+        let mut row_gap = self.track_with(ViewStyle::default().row_gap);
+        let mut column_gap = self.track_with(ViewStyle::default().column_gap);
+
+        // Overriding one of the gaps that is set by the `gap` shorthand doesn't
+        // make the shorthand unused, because it still sets the other gap.
+        let mut gap_shorthand = (false, false);
         let mut margin = self.track_with(ViewStyle::default().margin);
         let mut border = self.track_with(ViewStyle::default().border);
         let mut border_radius = self.track_with(ViewStyle::default().border_radius);
         let mut padding = self.track_with(ViewStyle::default().padding);
         let mut background_color = self.track_with(ViewStyle::default().background_color);
+        let mut background_image = self.track_with(ViewStyle::default().background_image);
+        let mut box_shadow = self.track::<Vec<BoxShadow>>();
         let mut opacity = self.track_with(ViewStyle::default().opacity);
         let mut transform = self.track_with(ViewStyle::default().transform);
         let mut overflow = self.track_with(ViewStyle::default().overflow);
@@ -176,6 +193,12 @@ where
                 PropertyValue::FlexShrink(value) => {
                     self.replace(&mut flex_shrink, *value, property.value_span)
                 }
+                PropertyValue::AlignSelf(value) => {
+                    self.replace(&mut align_self, *value, property.value_span)
+                }
+                PropertyValue::Display(value) => {
+                    self.replace(&mut display, *value, property.value_span)
+                }
                 PropertyValue::Direction(value) => {
                     self.replace(&mut direction, *value, property.value_span)
                 }
@@ -197,15 +220,60 @@ where
                 PropertyValue::MaxWidth(value) => {
                     self.replace(&mut max_width, *value, property.value_span)
                 }
+                PropertyValue::AspectRatio(value) => {
+                    self.replace(&mut aspect_ratio, *value, property.value_span)
+                }
                 PropertyValue::FlexDirection(value) => {
                     self.replace(&mut flex_direction, *value, property.value_span)
                 }
+                PropertyValue::FlexWrap(value) => {
+                    self.replace(&mut flex_wrap, *value, property.value_span)
+                }
                 PropertyValue::AlignItems(value) => {
                     self.replace(&mut align_items, *value, property.value_span)
                 }
                 PropertyValue::JustifyContent(value) => {
                     self.replace(&mut justify_content, *value, property.value_span)
                 }
+                PropertyValue::AlignContent(value) => {
+                    self.replace(&mut align_content, *value, property.value_span)
+                }
+                PropertyValue::GridTemplateRows(value) => {
+                    self.replace(&mut grid_template_rows, value.clone(), property.value_span)
+                }
+                PropertyValue::GridTemplateColumns(value) => self.replace(
+                    &mut grid_template_columns,
+                    value.clone(),
+                    property.value_span,
+                ),
+                PropertyValue::GridArea(value) => {
+                    self.replace(&mut grid_area, *value, property.value_span)
+                }
+                PropertyValue::Gap(row, column) => {
+                    // Both gaps share the span of a previous shorthand, which
+                    // should only be reported once.
+                    if gap_shorthand == (true, true) {
+                        column_gap.previous_span = None;
+                    }
+
+                    self.replace(&mut row_gap, *row, property.value_span);
+                    self.replace(&mut column_gap, *column, property.value_span);
+                    gap_shorthand = (true, true);
+                }
+                PropertyValue::RowGap(value) => {
+                    if std::mem::take(&mut gap_shorthand.0) && gap_shorthand.1 {
+                        row_gap.previous_span = None;
+                    }
+
+                    self.replace(&mut row_gap, *value, property.value_span)
+                }
+                PropertyValue::ColumnGap(value) => {
+                    if std::mem::take(&mut gap_shorthand.1) && gap_shorthand.0 {
+                        column_gap.previous_span = None;
+                    }
+
+                    self.replace(&mut column_gap, *value, property.value_span)
+                }
                 PropertyValue::Margin(value) => {
                     self.replace(&mut margin, *value, property.value_span)
                 }
@@ -221,6 +289,12 @@ where
                 PropertyValue::BackgroundColor(value) => {
                     self.replace(&mut background_color, *value, property.value_span)
                 }
+                PropertyValue::BackgroundImage(value) => {
+                    self.replace(&mut background_image, *value, property.value_span)
+                }
+                PropertyValue::BoxShadow(value) => {
+                    self.replace(&mut box_shadow, value.clone(), property.value_span)
+                }
                 PropertyValue::Opacity(value) => {
                     self.replace(&mut opacity, *value, property.value_span)
                 }
@@ -257,24 +331,38 @@ where
                     flex_basis: flex_basis.value,
                     flex_grow: flex_grow.value,
                     flex_shrink: flex_shrink.value,
+                    align_self: align_self.value,
                 }
             }),
         };
 
         ViewStyle {
             position,
+            display: display.value,
             direction: direction.value,
             size: Size::new(width.value, height.value),
             min_size: Size::new(min_width.value, min_height.value),
             max_size: Size::new(max_width.value, max_height.value),
+            aspect_ratio: aspect_ratio.value,
             flex_direction: flex_direction.value,
+            flex_wrap: flex_wrap.value,
             align_items: align_items.value,
             justify_content: justify_content.value,
+            align_content: align_content.value,
+            // The style is only used to generate code, where the tracks end up
+            // in a static slice, so this leaks a few bytes at compile time.
+            grid_template_rows: Box::leak(grid_template_rows.value.into_boxed_slice()),
+            grid_template_columns: Box::leak(grid_template_columns.value.into_boxed_slice()),
+            grid_area: grid_area.value,
+            row_gap: row_gap.value,
+            column_gap: column_gap.value,
             margin: margin.value,
             border: border.value,
             border_radius: border_radius.value,
             padding: padding.value,
             background_color: background_color.value,
+            background_image: background_image.value,
+            box_shadow: Box::leak(box_shadow.value.into_boxed_slice()),
             opacity: opacity.value,
             transform: transform.value,
             overflow: overflow.value,
@@ -289,6 +377,13 @@ where
         let mut font_style = self.track::<Inherited<FontStyle>>();
         let mut font_size = self.track::<Inherited<FontSize>>();
         let mut text_align = self.track::<Inherited<TextAlign>>();
+        let mut line_height = self.track::<Inherited<LineHeight>>();
+        let mut letter_spacing = self.track::<Inherited<f32>>();
+        let mut text_decoration = self.track::<Inherited<TextDecoration>>();
+        let mut text_transform = self.track::<Inherited<TextTransform>>();
+        let mut number_of_lines = self.track::<Inherited<usize>>();
+        let mut ellipsis_mode = self.track::<Inherited<EllipsisMode>>();
+        let mut text_shadow = self.track::<Inherited<Option<TextShadow>>>();
 
         for property in properties {
             match &property.value {
@@ -310,6 +405,27 @@ where
                 PropertyValue::TextAlign(value) => {
                     self.replace(&mut text_align, *value, property.value_span)
                 }
+                PropertyValue::LineHeight(value) => {
+                    self.replace(&mut line_height, *value, property.value_span)
+                }
+                PropertyValue::LetterSpacing(value) => {
+                    self.replace(&mut letter_spacing, *value, property.value_span)
+                }
+                PropertyValue::TextDecoration(value) => {
+                    self.replace(&mut text_decoration, *value, property.value_span)
+                }
+                PropertyValue::TextTransform(value) => {
+                    self.replace(&mut text_transform, *value, property.value_span)
+                }
+                PropertyValue::NumberOfLines(value) => {
+                    self.replace(&mut number_of_lines, *value, property.value_span)
+                }
+                PropertyValue::EllipsisMode(value) => {
+                    self.replace(&mut ellipsis_mode, *value, property.value_span)
+                }
+                PropertyValue::TextShadow(value) => {
+                    self.replace(&mut text_shadow, *value, property.value_span)
+                }
                 _ => {}
             }
         }
@@ -321,6 +437,13 @@ where
             font_style: font_style.value,
             font_size: font_size.value,
             text_align: text_align.value,
+            line_height: line_height.value,
+            letter_spacing: letter_spacing.value,
+            text_decoration: text_decoration.value,
+            text_transform: text_transform.value,
+            number_of_lines: number_of_lines.value,
+            ellipsis_mode: ellipsis_mode.value,
+            text_shadow: text_shadow.value,
         }
     }
 
@@ -4,12 +4,16 @@ use casco::stream::MultiSpan;
 use super::{Diagnostic, Style, StyleBuilder};
 use crate::color::{Color, NamedColor};
 use crate::font::{FontFamily, FontSize, FontStyle, FontWeight, GenericFontFamily};
-use crate::geometry::{ByEdge, Dimension, Size};
+use crate::geometry::{ByEdge, Dimension, Point, Size};
 use crate::layout::{LayoutAxisX, LayoutAxisY, LayoutDirection};
 use crate::macros::style::Driver;
+use crate::physics::Angle;
 use crate::styles::{
-    Absolute, Align, Border, BorderStyle, FlexDirection, ImageStyle, Inherited, Justify, ObjectFit,
-    Position, Relative, TextAlign, TextStyle, ViewStyle,
+    Absolute, Align, Border, BorderStyle, BoxShadow, ColorStop, Corner, Display, EllipsisMode,
+    FlexDirection, FlexWrap, Gradient, GradientDirection, GridArea, GridLine, GridPlacement,
+    GridTrack, ImageStyle, Inherited, Justify, LineHeight, LinearGradient, ObjectFit, Position,
+    RadialGradient, RadialGradientShape, RadialGradientSize, Relative, TextAlign, TextDecoration,
+    TextDecorationStyle, TextShadow, TextStyle, TextTransform, ViewStyle,
 };
 
 fn build(input: &str) -> (Style, Vec<Diagnostic<TokenStream>>) {
@@ -209,6 +213,201 @@ mod text {
             )
         )
     }
+
+    #[test]
+    fn test_text_align_justify() {
+        assert_eq!(
+            build("text-align: justify;"),
+            (
+                Style::Text(TextStyle {
+                    text_align: Inherited::Specified(TextAlign::Justify),
+                    ..Default::default()
+                }),
+                vec![]
+            )
+        )
+    }
+
+    #[test]
+    fn test_line_height() {
+        let line_height = |input| match build(input) {
+            (Style::Text(style), diagnostics) if diagnostics.is_empty() => style.line_height,
+            result => panic!("Unexpected result: {:?}", result),
+        };
+
+        assert_eq!(
+            line_height("line-height: normal;"),
+            Inherited::Specified(LineHeight::Normal)
+        );
+        assert_eq!(
+            line_height("line-height: 1.5;"),
+            Inherited::Specified(LineHeight::Number(1.5))
+        );
+        assert_eq!(
+            line_height("line-height: 24px;"),
+            Inherited::Specified(LineHeight::Dimension(Dimension::Points(24.0)))
+        );
+        assert_eq!(
+            line_height("line-height: 150%;"),
+            Inherited::Specified(LineHeight::Dimension(Dimension::Percentage(1.5)))
+        );
+        assert_eq!(line_height("line-height: inherit;"), Inherited::Inherited);
+    }
+
+    #[test]
+    fn test_letter_spacing() {
+        assert_eq!(
+            build("letter-spacing: -0.5px;"),
+            (
+                Style::Text(TextStyle {
+                    letter_spacing: Inherited::Specified(-0.5),
+                    ..Default::default()
+                }),
+                vec![]
+            )
+        );
+
+        assert_eq!(
+            build("letter-spacing: normal;"),
+            (
+                Style::Text(TextStyle {
+                    letter_spacing: Inherited::Specified(0.0),
+                    ..Default::default()
+                }),
+                vec![]
+            )
+        );
+    }
+
+    #[test]
+    fn test_text_decoration() {
+        assert_eq!(
+            build("text-decoration: underline line-through dotted red;"),
+            (
+                Style::Text(TextStyle {
+                    text_decoration: Inherited::Specified(TextDecoration {
+                        underline: true,
+                        line_through: true,
+                        style: TextDecorationStyle::Dotted,
+                        color: Some(Color::red()),
+                    }),
+                    ..Default::default()
+                }),
+                vec![]
+            )
+        );
+
+        assert_eq!(
+            build("text-decoration: underline;"),
+            (
+                Style::Text(TextStyle {
+                    text_decoration: Inherited::Specified(TextDecoration {
+                        underline: true,
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                }),
+                vec![]
+            )
+        );
+
+        assert_eq!(
+            build("text-decoration: none;"),
+            (
+                Style::Text(TextStyle {
+                    text_decoration: Inherited::Specified(TextDecoration::none()),
+                    ..Default::default()
+                }),
+                vec![]
+            )
+        );
+    }
+
+    #[test]
+    fn test_text_transform() {
+        assert_eq!(
+            build("text-transform: uppercase;"),
+            (
+                Style::Text(TextStyle {
+                    text_transform: Inherited::Specified(TextTransform::Uppercase),
+                    ..Default::default()
+                }),
+                vec![]
+            )
+        )
+    }
+
+    #[test]
+    fn test_number_of_lines() {
+        assert_eq!(
+            build("number-of-lines: 2; ellipsis-mode: middle;"),
+            (
+                Style::Text(TextStyle {
+                    number_of_lines: Inherited::Specified(2),
+                    ellipsis_mode: Inherited::Specified(EllipsisMode::Middle),
+                    ..Default::default()
+                }),
+                vec![]
+            )
+        );
+
+        assert_eq!(
+            build("number-of-lines: none;"),
+            (
+                Style::Text(TextStyle {
+                    number_of_lines: Inherited::Specified(0),
+                    ..Default::default()
+                }),
+                vec![]
+            )
+        );
+    }
+
+    #[test]
+    fn test_text_shadow() {
+        assert_eq!(
+            build("text-shadow: 1px -2px 3px blue;"),
+            (
+                Style::Text(TextStyle {
+                    text_shadow: Inherited::Specified(Some(TextShadow {
+                        offset_x: 1.0,
+                        offset_y: -2.0,
+                        blur_radius: 3.0,
+                        color: Some(Color::blue()),
+                    })),
+                    ..Default::default()
+                }),
+                vec![]
+            )
+        );
+
+        assert_eq!(
+            build("text-shadow: 0px 1px;"),
+            (
+                Style::Text(TextStyle {
+                    text_shadow: Inherited::Specified(Some(TextShadow {
+                        offset_x: 0.0,
+                        offset_y: 1.0,
+                        blur_radius: 0.0,
+                        color: None,
+                    })),
+                    ..Default::default()
+                }),
+                vec![]
+            )
+        );
+
+        assert_eq!(
+            build("text-shadow: none;"),
+            (
+                Style::Text(TextStyle {
+                    text_shadow: Inherited::Specified(None),
+                    ..Default::default()
+                }),
+                vec![]
+            )
+        );
+    }
 }
 
 mod view {
@@ -511,6 +710,86 @@ mod view {
         )
     }
 
+    #[test]
+    fn test_flex_wrap() {
+        assert_eq!(
+            build("flex-wrap: wrap-reverse;"),
+            (
+                Style::View(ViewStyle {
+                    flex_wrap: FlexWrap::WrapReverse,
+                    ..Default::default()
+                }),
+                vec![]
+            )
+        )
+    }
+
+    #[test]
+    fn test_align_content() {
+        assert_eq!(
+            build("align-content: space-between;"),
+            (
+                Style::View(ViewStyle {
+                    align_content: Align::SpaceBetween,
+                    ..Default::default()
+                }),
+                vec![]
+            )
+        )
+    }
+
+    #[test]
+    fn test_align_self() {
+        assert_eq!(
+            build("align-self: center;"),
+            (
+                Style::View(ViewStyle {
+                    position: Position::Relative(Relative {
+                        align_self: Some(Align::Center),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                }),
+                vec![]
+            )
+        );
+
+        assert_eq!(
+            build("align-self: auto;"),
+            (Style::View(Default::default()), vec![])
+        )
+    }
+
+    #[test]
+    fn test_aspect_ratio() {
+        assert_eq!(
+            build("aspect-ratio: 16 / 8;"),
+            (
+                Style::View(ViewStyle {
+                    aspect_ratio: Some(2.0),
+                    ..Default::default()
+                }),
+                vec![]
+            )
+        );
+
+        assert_eq!(
+            build("aspect-ratio: 1.5;"),
+            (
+                Style::View(ViewStyle {
+                    aspect_ratio: Some(1.5),
+                    ..Default::default()
+                }),
+                vec![]
+            )
+        );
+
+        assert_eq!(
+            build("aspect-ratio: auto;"),
+            (Style::View(Default::default()), vec![])
+        )
+    }
+
     #[test]
     fn test_margin_single() {
         assert_eq!(
@@ -692,6 +971,247 @@ mod view {
         )
     }
 
+    #[test]
+    fn test_background_color_syntax() {
+        let cases = vec![
+            ("#fff", Color::rgb(255, 255, 255)),
+            ("#abcd", Color::rgba(0xaa, 0xbb, 0xcc, 221.0 / 255.0)),
+            ("#123456", Color::hex(0x123456)),
+            ("rgb(255 0 0 / 50%)", Color::rgba(255, 0, 0, 0.5)),
+            ("rgba(0, 0, 255, .25)", Color::rgba(0, 0, 255, 0.25)),
+            ("lab(50% -20 .5)", Color::lab(50.0, -20.0, 0.5)),
+            ("oklch(0.5 0.1 120deg)", Color::oklch(0.5, 0.1, 120.0)),
+            ("transparent", Color::transparent()),
+        ];
+
+        for (input, color) in cases {
+            assert_eq!(
+                build(&format!("background-color: {};", input)),
+                (
+                    Style::View(ViewStyle {
+                        background_color: color,
+                        ..Default::default()
+                    }),
+                    vec![]
+                ),
+                "{}",
+                input
+            );
+        }
+
+        let (style, _) = build("background-color: color-mix(in srgb, red, blue 25%);");
+        let color = match style {
+            Style::View(style) => style.background_color.to_srgb(),
+            _ => unreachable!(),
+        };
+
+        assert_eq!((color.red, color.green, color.blue), (0.75, 0.0, 0.25));
+    }
+
+    #[test]
+    fn test_background_color_precision() {
+        use quote::{quote, ToTokens};
+        use std::str::FromStr;
+
+        let emitted = |input: &str| match build(&format!("background-color: {};", input)) {
+            (Style::View(style), diagnostics) if diagnostics.is_empty() => {
+                style.background_color.to_token_stream().to_string()
+            }
+            result => panic!("Unexpected result: {:?}", result),
+        };
+
+        for input in &["rgb(50% 50% 50%)", "hsl(120 40% 30% / 0.5)"] {
+            let rgb = Color::from_str(input).unwrap().to_srgb();
+            let (red, green, blue, alpha) = (rgb.red, rgb.green, rgb.blue, rgb.alpha);
+
+            assert_eq!(
+                emitted(input),
+                quote! { polyhorn::color::Color::srgba(#red, #green, #blue, #alpha) }.to_string()
+            );
+        }
+
+        // The lexer that is used in these tests splits `p3` into two tokens, so
+        // this color is only parsed at runtime.
+        let p3 = Color::from_str("color(display-p3 0.25 0.5 0.75)").unwrap();
+        let (red, green, blue, alpha) = (0.25f32, 0.5f32, 0.75f32, 1.0f32);
+
+        assert_eq!(
+            p3.to_token_stream().to_string(),
+            quote! { polyhorn::color::Color::display_p3a(#red, #green, #blue, #alpha) }.to_string()
+        );
+    }
+
+    #[test]
+    fn test_box_shadow() {
+        assert_eq!(
+            build("box-shadow: 0 2px 4px -1px black, inset red 1px 1px;"),
+            (
+                Style::View(ViewStyle {
+                    box_shadow: Box::leak(Box::new([
+                        BoxShadow {
+                            offset_x: 0.0,
+                            offset_y: 2.0,
+                            blur_radius: 4.0,
+                            spread_radius: -1.0,
+                            color: Color::black(),
+                            inset: false,
+                        },
+                        BoxShadow {
+                            offset_x: 1.0,
+                            offset_y: 1.0,
+                            blur_radius: 0.0,
+                            spread_radius: 0.0,
+                            color: Color::red(),
+                            inset: true,
+                        },
+                    ])),
+                    ..Default::default()
+                }),
+                vec![]
+            )
+        );
+
+        assert_eq!(
+            build("box-shadow: 0 1px rgba(0, 0, 0, 0.5);"),
+            (
+                Style::View(ViewStyle {
+                    box_shadow: Box::leak(Box::new([BoxShadow {
+                        offset_y: 1.0,
+                        color: Color::rgba(0, 0, 0, 0.5),
+                        ..Default::default()
+                    }])),
+                    ..Default::default()
+                }),
+                vec![]
+            )
+        );
+
+        assert_eq!(
+            build("box-shadow: none;"),
+            (Style::View(Default::default()), vec![])
+        );
+    }
+
+    #[test]
+    fn test_background_image_linear() {
+        assert_eq!(
+            build("background-image: linear-gradient(45deg, red, blue 75%);"),
+            (
+                Style::View(ViewStyle {
+                    background_image: Some(Gradient::Linear(LinearGradient {
+                        direction: GradientDirection::Angle(Angle::with_degrees(45.0)),
+                        stops: Box::leak(Box::new([
+                            ColorStop {
+                                color: Color::red(),
+                                position: None,
+                            },
+                            ColorStop {
+                                color: Color::blue(),
+                                position: Some(Dimension::Percentage(0.75)),
+                            },
+                        ])),
+                    })),
+                    ..Default::default()
+                }),
+                vec![]
+            )
+        );
+
+        assert_eq!(
+            build("background-image: linear-gradient(to left top, red 0 10px, blue);"),
+            (
+                Style::View(ViewStyle {
+                    background_image: Some(Gradient::Linear(LinearGradient {
+                        direction: GradientDirection::Corner(Corner::TopLeft),
+                        stops: Box::leak(Box::new([
+                            ColorStop {
+                                color: Color::red(),
+                                position: Some(Dimension::Points(0.0)),
+                            },
+                            ColorStop {
+                                color: Color::red(),
+                                position: Some(Dimension::Points(10.0)),
+                            },
+                            ColorStop {
+                                color: Color::blue(),
+                                position: None,
+                            },
+                        ])),
+                    })),
+                    ..Default::default()
+                }),
+                vec![]
+            )
+        );
+    }
+
+    #[test]
+    fn test_background_image_radial() {
+        let stops: &'static [ColorStop] = Box::leak(Box::new([
+            ColorStop {
+                color: Color::white(),
+                position: None,
+            },
+            ColorStop {
+                color: Color::black(),
+                position: None,
+            },
+        ]));
+
+        assert_eq!(
+            build("background-image: radial-gradient(white, black);"),
+            (
+                Style::View(ViewStyle {
+                    background_image: Some(Gradient::Radial(RadialGradient {
+                        stops,
+                        ..Default::default()
+                    })),
+                    ..Default::default()
+                }),
+                vec![]
+            )
+        );
+
+        assert_eq!(
+            build(
+                "background-image: radial-gradient(circle closest-side at left 25%, white, black);"
+            ),
+            (
+                Style::View(ViewStyle {
+                    background_image: Some(Gradient::Radial(RadialGradient {
+                        shape: RadialGradientShape::Circle,
+                        size: RadialGradientSize::ClosestSide,
+                        position: Point::new(
+                            Dimension::Percentage(0.0),
+                            Dimension::Percentage(0.25)
+                        ),
+                        stops,
+                    })),
+                    ..Default::default()
+                }),
+                vec![]
+            )
+        );
+
+        assert_eq!(
+            build("background-image: radial-gradient(20px 50%, white, black);"),
+            (
+                Style::View(ViewStyle {
+                    background_image: Some(Gradient::Radial(RadialGradient {
+                        size: RadialGradientSize::Radii(
+                            Dimension::Points(20.0),
+                            Dimension::Percentage(0.5)
+                        ),
+                        stops,
+                        ..Default::default()
+                    })),
+                    ..Default::default()
+                }),
+                vec![]
+            )
+        );
+    }
+
     #[test]
     fn test_opacity() {
         assert_eq!(
@@ -705,4 +1225,171 @@ mod view {
             )
         )
     }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            build("display: grid;"),
+            (
+                Style::View(ViewStyle {
+                    display: Display::Grid,
+                    ..Default::default()
+                }),
+                vec![]
+            )
+        );
+
+        assert_eq!(
+            build("display: none;"),
+            (
+                Style::View(ViewStyle {
+                    display: Display::None,
+                    ..Default::default()
+                }),
+                vec![]
+            )
+        )
+    }
+
+    #[test]
+    fn test_gap() {
+        assert_eq!(
+            build("gap: 10px;"),
+            (
+                Style::View(ViewStyle {
+                    row_gap: Dimension::Points(10.0),
+                    column_gap: Dimension::Points(10.0),
+                    ..Default::default()
+                }),
+                vec![]
+            )
+        );
+
+        assert_eq!(
+            build("gap: 10px 5%;"),
+            (
+                Style::View(ViewStyle {
+                    row_gap: Dimension::Points(10.0),
+                    column_gap: Dimension::Percentage(0.05),
+                    ..Default::default()
+                }),
+                vec![]
+            )
+        )
+    }
+
+    #[test]
+    fn test_row_column_gap() {
+        assert_eq!(
+            build("row-gap: 10px; column-gap: 5%;"),
+            (
+                Style::View(ViewStyle {
+                    row_gap: Dimension::Points(10.0),
+                    column_gap: Dimension::Percentage(0.05),
+                    ..Default::default()
+                }),
+                vec![]
+            )
+        );
+
+        // The shorthand still sets the column gap, so it isn't unused.
+        assert_eq!(
+            build("gap: 10px; row-gap: 5px;"),
+            (
+                Style::View(ViewStyle {
+                    row_gap: Dimension::Points(5.0),
+                    column_gap: Dimension::Points(10.0),
+                    ..Default::default()
+                }),
+                vec![]
+            )
+        );
+
+        assert_eq!(
+            build("gap: 10px; row-gap: 5px; column-gap: 5px;"),
+            (
+                Style::View(ViewStyle {
+                    row_gap: Dimension::Points(5.0),
+                    column_gap: Dimension::Points(5.0),
+                    ..Default::default()
+                }),
+                vec![Diagnostic::UnusedProperty(MultiSpan::single(Span::new(
+                    5, 9
+                )))]
+            )
+        );
+
+        assert_eq!(
+            build("gap: 10px; gap: 5px;"),
+            (
+                Style::View(ViewStyle {
+                    row_gap: Dimension::Points(5.0),
+                    column_gap: Dimension::Points(5.0),
+                    ..Default::default()
+                }),
+                vec![Diagnostic::UnusedProperty(MultiSpan::single(Span::new(
+                    5, 9
+                )))]
+            )
+        )
+    }
+
+    #[test]
+    fn test_grid_template_columns() {
+        assert_eq!(
+            build("grid-template-columns: 100px repeat(2, 1fr auto) min-content 25%;"),
+            (
+                Style::View(ViewStyle {
+                    grid_template_columns: &[
+                        GridTrack::Points(100.0),
+                        GridTrack::Fraction(1.0),
+                        GridTrack::Auto,
+                        GridTrack::Fraction(1.0),
+                        GridTrack::Auto,
+                        GridTrack::MinContent,
+                        GridTrack::Percentage(0.25),
+                    ],
+                    ..Default::default()
+                }),
+                vec![]
+            )
+        )
+    }
+
+    #[test]
+    fn test_grid_template_rows() {
+        assert_eq!(
+            build("grid-template-rows: max-content 2fr;"),
+            (
+                Style::View(ViewStyle {
+                    grid_template_rows: &[GridTrack::MaxContent, GridTrack::Fraction(2.0)],
+                    ..Default::default()
+                }),
+                vec![]
+            )
+        )
+    }
+
+    #[test]
+    fn test_grid_area() {
+        assert_eq!(
+            build("grid-area: 2 / span 2 / -1;"),
+            (
+                Style::View(ViewStyle {
+                    grid_area: GridArea {
+                        row: GridLine {
+                            start: GridPlacement::Line(2),
+                            end: GridPlacement::Line(-1),
+                        },
+                        column: GridLine {
+                            start: GridPlacement::Span(2),
+                            end: GridPlacement::Auto,
+                        },
+                    },
+                    ..Default::default()
+                }),
+                vec![]
+            )
+        )
+    }
 }
@@ -5,14 +5,19 @@ use quote::{quote, ToTokens};
 use std::iter::FromIterator;
 
 use super::{Diagnostic, Driver, Error, ParseError};
-use crate::color::Color;
+use crate::color::{Color, ColorComponents};
 use crate::font::{FontFamily, FontSize, FontStyle, FontWeight, GenericFontFamily};
-use crate::geometry::{ByCorner, ByDirection, ByEdge, Dimension, Size};
+use crate::geometry::{ByCorner, ByDirection, ByEdge, Dimension, Point, Size};
 use crate::layout::{LayoutAxisX, LayoutAxisY, LayoutDirection};
 use crate::linalg::Transform3D;
+use crate::physics::Angle;
 use crate::styles::{
-    Absolute, Align, Border, BorderStyle, FlexDirection, ImageStyle, Inherited, Justify, ObjectFit,
-    Overflow, Position, Relative, TextAlign, TextStyle, Transform, ViewStyle, Visibility,
+    Absolute, Align, Border, BorderStyle, BoxShadow, ColorStop, Corner, Display, EllipsisMode,
+    FlexDirection, FlexWrap, Gradient, GradientDirection, GridArea, GridLine, GridPlacement,
+    GridTrack, ImageStyle, Inherited, Justify, LineHeight, LinearGradient, ObjectFit, Overflow,
+    Position, RadialGradient, RadialGradientShape, RadialGradientSize, Relative, TextAlign,
+    TextDecoration, TextDecorationStyle, TextShadow, TextStyle, TextTransform, Transform,
+    ViewStyle, Visibility,
 };
 
 impl<T> ToTokens for Dimension<T>
@@ -100,11 +105,16 @@ impl ToTokens for Relative {
         let flex_basis = &self.flex_basis;
         let flex_grow = &self.flex_grow;
         let flex_shrink = &self.flex_shrink;
+        let align_self = match &self.align_self {
+            Some(align) => quote! { Some(#align) },
+            None => quote! { None },
+        };
 
         tokens.extend(quote! { polyhorn::styles::Relative {
             flex_basis: #flex_basis,
             flex_grow: #flex_grow,
             flex_shrink: #flex_shrink,
+            align_self: #align_self,
         } })
     }
 }
@@ -161,6 +171,81 @@ impl ToTokens for Justify {
     }
 }
 
+impl ToTokens for FlexWrap {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            FlexWrap::NoWrap => quote! { polyhorn::styles::FlexWrap::NoWrap },
+            FlexWrap::Wrap => quote! { polyhorn::styles::FlexWrap::Wrap },
+            FlexWrap::WrapReverse => quote! { polyhorn::styles::FlexWrap::WrapReverse },
+        })
+    }
+}
+
+impl ToTokens for Display {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            Display::Flex => quote! { polyhorn::styles::Display::Flex },
+            Display::Grid => quote! { polyhorn::styles::Display::Grid },
+            Display::None => quote! { polyhorn::styles::Display::None },
+        })
+    }
+}
+
+impl ToTokens for GridTrack {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            GridTrack::Auto => quote! { polyhorn::styles::GridTrack::Auto },
+            GridTrack::Points(points) => quote! { polyhorn::styles::GridTrack::Points(#points) },
+            GridTrack::Percentage(percentage) => {
+                quote! { polyhorn::styles::GridTrack::Percentage(#percentage) }
+            }
+            GridTrack::Fraction(fraction) => {
+                quote! { polyhorn::styles::GridTrack::Fraction(#fraction) }
+            }
+            GridTrack::MinContent => quote! { polyhorn::styles::GridTrack::MinContent },
+            GridTrack::MaxContent => quote! { polyhorn::styles::GridTrack::MaxContent },
+        })
+    }
+}
+
+impl ToTokens for GridPlacement {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            GridPlacement::Auto => quote! { polyhorn::styles::GridPlacement::Auto },
+            GridPlacement::Line(line) => quote! { polyhorn::styles::GridPlacement::Line(#line) },
+            GridPlacement::Span(span) => quote! { polyhorn::styles::GridPlacement::Span(#span) },
+        })
+    }
+}
+
+impl ToTokens for GridLine {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let start = &self.start;
+        let end = &self.end;
+
+        tokens.extend(quote! {
+            polyhorn::styles::GridLine {
+                start: #start,
+                end: #end,
+            }
+        })
+    }
+}
+
+impl ToTokens for GridArea {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let row = &self.row;
+        let column = &self.column;
+
+        tokens.extend(quote! {
+            polyhorn::styles::GridArea {
+                row: #row,
+                column: #column,
+            }
+        })
+    }
+}
+
 impl ToTokens for LayoutDirection {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         tokens.extend(match self {
@@ -172,15 +257,27 @@ impl ToTokens for LayoutDirection {
 
 impl ToTokens for Color {
     fn to_tokens(&self, tokens: &mut TokenStream) {
-        let rgb = self.to_srgb();
+        let alpha = self.alpha();
 
-        let red = (rgb.red * 255.0) as u8;
-        let green = (rgb.green * 255.0) as u8;
-        let blue = (rgb.blue * 255.0) as u8;
-        let alpha = rgb.alpha;
-
-        tokens.extend(quote! {
-            polyhorn::color::Color::rgba(#red, #green, #blue, #alpha)
+        tokens.extend(match self.components {
+            ColorComponents::Transparent => quote! { polyhorn::color::Color::transparent() },
+            ColorComponents::StandardRGB(rgb) => {
+                let (red, green, blue) = (rgb.red, rgb.green, rgb.blue);
+                quote! { polyhorn::color::Color::srgba(#red, #green, #blue, #alpha) }
+            }
+            ColorComponents::DisplayP3(rgb) => {
+                let (red, green, blue) = (rgb.red, rgb.green, rgb.blue);
+                quote! { polyhorn::color::Color::display_p3a(#red, #green, #blue, #alpha) }
+            }
+            ColorComponents::Lab([lightness, a, b]) => {
+                quote! { polyhorn::color::Color::laba(#lightness, #a, #b, #alpha) }
+            }
+            ColorComponents::Oklab([lightness, a, b]) => {
+                quote! { polyhorn::color::Color::oklaba(#lightness, #a, #b, #alpha) }
+            }
+            ColorComponents::Xyz([x, y, z]) => {
+                quote! { polyhorn::color::Color::xyza(#x, #y, #z, #alpha) }
+            }
         });
     }
 }
@@ -276,6 +373,95 @@ impl ToTokens for TextAlign {
             TextAlign::Left => quote! { polyhorn::styles::TextAlign::Left },
             TextAlign::Center => quote! { polyhorn::styles::TextAlign::Center },
             TextAlign::Right => quote! { polyhorn::styles::TextAlign::Right },
+            TextAlign::Justify => quote! { polyhorn::styles::TextAlign::Justify },
+        })
+    }
+}
+
+impl ToTokens for LineHeight {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            LineHeight::Normal => quote! { polyhorn::styles::LineHeight::Normal },
+            LineHeight::Number(number) => quote! { polyhorn::styles::LineHeight::Number(#number) },
+            LineHeight::Dimension(dimension) => {
+                quote! { polyhorn::styles::LineHeight::Dimension(#dimension) }
+            }
+        })
+    }
+}
+
+impl ToTokens for TextDecorationStyle {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            TextDecorationStyle::Solid => quote! { polyhorn::styles::TextDecorationStyle::Solid },
+            TextDecorationStyle::Double => quote! { polyhorn::styles::TextDecorationStyle::Double },
+            TextDecorationStyle::Dotted => quote! { polyhorn::styles::TextDecorationStyle::Dotted },
+            TextDecorationStyle::Dashed => quote! { polyhorn::styles::TextDecorationStyle::Dashed },
+            TextDecorationStyle::Wavy => quote! { polyhorn::styles::TextDecorationStyle::Wavy },
+        })
+    }
+}
+
+impl ToTokens for TextDecoration {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let underline = self.underline;
+        let line_through = self.line_through;
+        let style = &self.style;
+        let color = match &self.color {
+            Some(color) => quote! { Some(#color) },
+            None => quote! { None },
+        };
+
+        tokens.extend(quote! {
+            polyhorn::styles::TextDecoration {
+                underline: #underline,
+                line_through: #line_through,
+                style: #style,
+                color: #color,
+            }
+        })
+    }
+}
+
+impl ToTokens for TextTransform {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            TextTransform::None => quote! { polyhorn::styles::TextTransform::None },
+            TextTransform::Uppercase => quote! { polyhorn::styles::TextTransform::Uppercase },
+            TextTransform::Lowercase => quote! { polyhorn::styles::TextTransform::Lowercase },
+            TextTransform::Capitalize => quote! { polyhorn::styles::TextTransform::Capitalize },
+        })
+    }
+}
+
+impl ToTokens for EllipsisMode {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            EllipsisMode::Clip => quote! { polyhorn::styles::EllipsisMode::Clip },
+            EllipsisMode::Head => quote! { polyhorn::styles::EllipsisMode::Head },
+            EllipsisMode::Middle => quote! { polyhorn::styles::EllipsisMode::Middle },
+            EllipsisMode::Tail => quote! { polyhorn::styles::EllipsisMode::Tail },
+        })
+    }
+}
+
+impl ToTokens for TextShadow {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let offset_x = self.offset_x;
+        let offset_y = self.offset_y;
+        let blur_radius = self.blur_radius;
+        let color = match &self.color {
+            Some(color) => quote! { Some(#color) },
+            None => quote! { None },
+        };
+
+        tokens.extend(quote! {
+            polyhorn::styles::TextShadow {
+                offset_x: #offset_x,
+                offset_y: #offset_y,
+                blur_radius: #blur_radius,
+                color: #color,
+            }
         })
     }
 }
@@ -291,6 +477,21 @@ where
         let font_style = &self.font_style;
         let font_size = &self.font_size;
         let text_align = &self.text_align;
+        let line_height = &self.line_height;
+        let letter_spacing = &self.letter_spacing;
+        let text_decoration = &self.text_decoration;
+        let text_transform = &self.text_transform;
+        let number_of_lines = &self.number_of_lines;
+        let ellipsis_mode = &self.ellipsis_mode;
+        let text_shadow = match &self.text_shadow {
+            Inherited::Inherited => quote! { polyhorn::styles::Inherited::Inherited },
+            Inherited::Specified(Some(shadow)) => {
+                quote! { polyhorn::styles::Inherited::Specified(Some(#shadow)) }
+            }
+            Inherited::Specified(None) => {
+                quote! { polyhorn::styles::Inherited::Specified(None) }
+            }
+        };
 
         tokens.extend(quote! {
             polyhorn::styles::TextStyle {
@@ -300,6 +501,13 @@ where
                 font_style: #font_style,
                 font_size: #font_size,
                 text_align: #text_align,
+                line_height: #line_height,
+                letter_spacing: #letter_spacing,
+                text_decoration: #text_decoration,
+                text_transform: #text_transform,
+                number_of_lines: #number_of_lines,
+                ellipsis_mode: #ellipsis_mode,
+                text_shadow: #text_shadow,
             }
         })
     }
@@ -322,6 +530,33 @@ where
     }
 }
 
+impl<T> ToTokens for Point<T>
+where
+    T: ToTokens,
+{
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let x = &self.x;
+        let y = &self.y;
+
+        tokens.extend(quote! {
+            polyhorn::geometry::Point { x: #x, y: #y }
+        })
+    }
+}
+
+impl<T> ToTokens for Angle<T>
+where
+    T: ToTokens + Float,
+{
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let radians = self.to_radians();
+
+        tokens.extend(quote! {
+            polyhorn::physics::Angle::with_radians(#radians)
+        })
+    }
+}
+
 impl<T> ToTokens for Transform3D<T>
 where
     T: ToTokens + Float,
@@ -440,21 +675,175 @@ where
     }
 }
 
+impl ToTokens for BoxShadow {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let offset_x = &self.offset_x;
+        let offset_y = &self.offset_y;
+        let blur_radius = &self.blur_radius;
+        let spread_radius = &self.spread_radius;
+        let color = &self.color;
+        let inset = &self.inset;
+
+        tokens.extend(quote! {
+            polyhorn::styles::BoxShadow {
+                offset_x: #offset_x,
+                offset_y: #offset_y,
+                blur_radius: #blur_radius,
+                spread_radius: #spread_radius,
+                color: #color,
+                inset: #inset,
+            }
+        });
+    }
+}
+
+impl ToTokens for ColorStop {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let color = &self.color;
+        let position = match &self.position {
+            Some(position) => quote! { Some(#position) },
+            None => quote! { None },
+        };
+
+        tokens.extend(quote! {
+            polyhorn::styles::ColorStop {
+                color: #color,
+                position: #position,
+            }
+        });
+    }
+}
+
+impl ToTokens for Corner {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            Corner::TopLeft => quote! { polyhorn::styles::Corner::TopLeft },
+            Corner::TopRight => quote! { polyhorn::styles::Corner::TopRight },
+            Corner::BottomRight => quote! { polyhorn::styles::Corner::BottomRight },
+            Corner::BottomLeft => quote! { polyhorn::styles::Corner::BottomLeft },
+        })
+    }
+}
+
+impl ToTokens for GradientDirection {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            GradientDirection::Angle(angle) => {
+                quote! { polyhorn::styles::GradientDirection::Angle(#angle) }
+            }
+            GradientDirection::Corner(corner) => {
+                quote! { polyhorn::styles::GradientDirection::Corner(#corner) }
+            }
+        })
+    }
+}
+
+impl ToTokens for RadialGradientShape {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            RadialGradientShape::Circle => quote! { polyhorn::styles::RadialGradientShape::Circle },
+            RadialGradientShape::Ellipse => {
+                quote! { polyhorn::styles::RadialGradientShape::Ellipse }
+            }
+        })
+    }
+}
+
+impl ToTokens for RadialGradientSize {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            RadialGradientSize::ClosestSide => {
+                quote! { polyhorn::styles::RadialGradientSize::ClosestSide }
+            }
+            RadialGradientSize::ClosestCorner => {
+                quote! { polyhorn::styles::RadialGradientSize::ClosestCorner }
+            }
+            RadialGradientSize::FarthestSide => {
+                quote! { polyhorn::styles::RadialGradientSize::FarthestSide }
+            }
+            RadialGradientSize::FarthestCorner => {
+                quote! { polyhorn::styles::RadialGradientSize::FarthestCorner }
+            }
+            RadialGradientSize::Radii(x, y) => {
+                quote! { polyhorn::styles::RadialGradientSize::Radii(#x, #y) }
+            }
+        })
+    }
+}
+
+/// Emits a block that evaluates to a static slice of the given items. Unlike
+/// `&[...]`, this also works for items that are constructed with a `const fn`
+/// (e.g. colors) and are therefore not promoted to static references.
+fn static_slice<T>(ty: TokenStream, items: &[T]) -> TokenStream
+where
+    T: ToTokens,
+{
+    quote! {{
+        const ITEMS: &[#ty] = &[#(#items),*];
+        ITEMS
+    }}
+}
+
+impl ToTokens for Gradient {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let stops = static_slice(quote! { polyhorn::styles::ColorStop }, self.stops());
+
+        tokens.extend(match self {
+            Gradient::Linear(LinearGradient { direction, .. }) => quote! {
+                polyhorn::styles::Gradient::Linear(polyhorn::styles::LinearGradient {
+                    direction: #direction,
+                    stops: #stops,
+                })
+            },
+            Gradient::Radial(RadialGradient {
+                shape,
+                size,
+                position,
+                ..
+            }) => quote! {
+                polyhorn::styles::Gradient::Radial(polyhorn::styles::RadialGradient {
+                    shape: #shape,
+                    size: #size,
+                    position: #position,
+                    stops: #stops,
+                })
+            },
+        })
+    }
+}
+
 impl ToTokens for ViewStyle {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         let position = &self.position;
+        let display = &self.display;
         let direction = &self.direction;
         let size = &self.size;
-        let min_size = &self.size;
+        let min_size = &self.min_size;
         let max_size = &self.max_size;
+        let aspect_ratio = match &self.aspect_ratio {
+            Some(aspect_ratio) => quote! { Some(#aspect_ratio) },
+            None => quote! { None },
+        };
         let flex_direction = &self.flex_direction;
+        let flex_wrap = &self.flex_wrap;
         let align_items = &self.align_items;
         let justify_content = &self.justify_content;
+        let align_content = &self.align_content;
+        let grid_template_rows = self.grid_template_rows;
+        let grid_template_columns = self.grid_template_columns;
+        let grid_area = &self.grid_area;
+        let row_gap = &self.row_gap;
+        let column_gap = &self.column_gap;
         let margin = &self.margin;
         let border = &self.border;
         let border_radius = &self.border_radius;
         let padding = &self.padding;
         let background_color = &self.background_color;
+        let background_image = match &self.background_image {
+            Some(background_image) => quote! { Some(#background_image) },
+            None => quote! { None },
+        };
+        let box_shadow = static_slice(quote! { polyhorn::styles::BoxShadow }, self.box_shadow);
         let opacity = &self.opacity;
         let transform = &self.transform;
         let overflow = &self.overflow;
@@ -463,18 +852,29 @@ impl ToTokens for ViewStyle {
         tokens.extend(quote! {
             polyhorn::styles::ViewStyle {
                 position: #position,
+                display: #display,
                 direction: #direction,
                 size: #size,
                 min_size: #min_size,
                 max_size: #max_size,
+                aspect_ratio: #aspect_ratio,
                 flex_direction: #flex_direction,
+                flex_wrap: #flex_wrap,
                 align_items: #align_items,
                 justify_content: #justify_content,
+                align_content: #align_content,
+                grid_template_rows: &[#(#grid_template_rows),*],
+                grid_template_columns: &[#(#grid_template_columns),*],
+                grid_area: #grid_area,
+                row_gap: #row_gap,
+                column_gap: #column_gap,
                 margin: #margin,
                 border: #border,
                 border_radius: #border_radius,
                 padding: #padding,
                 background_color: #background_color,
+                background_image: #background_image,
+                box_shadow: #box_shadow,
                 opacity: #opacity,
                 transform: [#(#transform),*],
                 overflow: #overflow,
@@ -624,6 +1024,9 @@ impl ToTokens for CascoError {
                         CompileError::new(MultiSpan::single(span), "Unexpected token.")
                             .to_tokens(tokens)
                     }
+                    &ParseError::InvalidColor(span, error) => {
+                        CompileError::new(span, &error.to_string()).to_tokens(tokens)
+                    }
                     &ParseError::UnknownVariant(span) => {
                         CompileError::new(span, "Unknown variant.").to_tokens(tokens)
                     }
@@ -5,8 +5,9 @@ use crate::font::{FontFamily, FontSize, FontStyle, FontWeight};
 use crate::geometry::{ByCorner, ByDirection, ByEdge, Dimension};
 use crate::layout::LayoutDirection;
 use crate::styles::{
-    Align, Border, FlexDirection, Inherited, Justify, ObjectFit, Overflow, TextAlign, Transform,
-    Visibility,
+    Align, Border, BoxShadow, Display, EllipsisMode, FlexDirection, FlexWrap, Gradient, GridArea,
+    GridTrack, Inherited, Justify, LineHeight, ObjectFit, Overflow, TextAlign, TextDecoration,
+    TextShadow, TextTransform, Transform, Visibility,
 };
 
 /// Determines whether this view should be included in calculating the layout of
@@ -29,20 +30,40 @@ pub enum PositionType {
 /// Represents a typed property value.
 #[derive(Debug)]
 pub enum PropertyValue {
+    /// This is the alignment of the lines of a flexbox with multiple lines
+    /// along its cross axis.
+    AlignContent(Align),
+
     /// This is the alignment of items along the main axis of the flexbox. The
     /// default value for this property is `Align::Stretch` which will resize
     /// descendant views along the cross axis to match the relevant dimension of
     /// this view.
     AlignItems(Align),
 
+    /// If present, this overrides the alignment of this view along the cross
+    /// axis of its ancestor. If not present (i.e. `auto`), this view is
+    /// aligned according to the `align-items` property of its ancestor.
+    AlignSelf(Option<Align>),
+
+    /// If present, this property forces the ratio between the width and height
+    /// of this view.
+    AspectRatio(Option<f32>),
+
     /// This is the background color of this view. The default color is
     /// transparent. This property does not affect the layout of this view, its
     /// siblings or its descendants.
     BackgroundColor(Color),
 
+    /// If present, this gradient is drawn on top of the background color of
+    /// this view.
+    BackgroundImage(Option<Gradient>),
+
     /// Provides the distance of this view to the bottom edge of its ancestor.
     Bottom(Dimension<f32>),
 
+    /// These are the shadows of this view.
+    BoxShadow(Vec<BoxShadow>),
+
     /// This is the color that will be used to fill the text outlines. If not
     /// present, the Text component will inherit the text color of its parent.
     /// If the parent does not have a color, the default `Color::canvastext()`
@@ -51,10 +72,21 @@ pub enum PropertyValue {
     /// (i.e. light vs. dark mode).
     Color(Inherited<Color>),
 
+    /// This is the space between adjacent columns of a view.
+    ColumnGap(Dimension<f32>),
+
     /// This field determines the direction in which descendant views are layed
     /// out.
     Direction(Inherited<LayoutDirection>),
 
+    /// This field determines the algorithm that is used to lay out the
+    /// descendant views of this view.
+    Display(Display),
+
+    /// Controls where text is truncated if it does not fit within its maximum
+    /// number of lines.
+    EllipsisMode(Inherited<EllipsisMode>),
+
     /// If present, this property controls the weight of this view in computing
     /// a layout using the flexbox algorithm.
     FlexBasis(Dimension<f32>),
@@ -75,6 +107,10 @@ pub enum PropertyValue {
     /// shrunk.
     FlexShrink(f32),
 
+    /// This property controls whether descendant views that do not fit on a
+    /// single line wrap onto multiple lines.
+    FlexWrap(FlexWrap),
+
     /// This is the font family that will be used to render the text outlines.
     /// If not present, the Text component will inherit its font family from its
     /// parent. If the parent does not have a font family, the default
@@ -102,6 +138,19 @@ pub enum PropertyValue {
     /// system-dependent and can vary depending on a user's preferred font size.
     FontSize(Inherited<FontSize>),
 
+    /// This is the space between adjacent rows and the space between adjacent
+    /// columns (in that order) of a view.
+    Gap(Dimension<f32>, Dimension<f32>),
+
+    /// This is the placement of this view within the grid of its ancestor.
+    GridArea(GridArea),
+
+    /// These are the sizes of the columns of a grid.
+    GridTemplateColumns(Vec<GridTrack>),
+
+    /// These are the sizes of the rows of a grid.
+    GridTemplateRows(Vec<GridTrack>),
+
     /// This is the height of the view.
     Height(Dimension<f32>),
 
@@ -114,6 +163,13 @@ pub enum PropertyValue {
     /// Provides the distance of this view to the left edge of its ancestor.
     Left(Dimension<f32>),
 
+    /// This is the additional space (in points) that is inserted between
+    /// characters.
+    LetterSpacing(Inherited<f32>),
+
+    /// This is the distance between the baselines of successive lines.
+    LineHeight(Inherited<LineHeight>),
+
     /// This is the maximum height of this view. This must not be less than the
     /// `height` field if both fields are present and contain absolute values.
     MaxHeight(Dimension<f32>),
@@ -132,6 +188,10 @@ pub enum PropertyValue {
     /// values.
     MinWidth(Dimension<f32>),
 
+    /// This is the maximum number of lines that text is rendered in. If 0 (i.e.
+    /// `none`), the number of lines is unlimited.
+    NumberOfLines(Inherited<usize>),
+
     /// Controls the method for fitting images that do not match the dimensions
     /// of their container.
     ObjectFit(ObjectFit),
@@ -167,10 +227,22 @@ pub enum PropertyValue {
     /// Provides the distance of this view to the right edge of its ancestor.
     Right(Dimension<f32>),
 
+    /// This is the space between adjacent rows of a view.
+    RowGap(Dimension<f32>),
+
     /// Controls the alignment of text when it is rendered to a container that
     /// is larger than the rendered text.
     TextAlign(Inherited<TextAlign>),
 
+    /// Controls the lines that are drawn under or through text.
+    TextDecoration(Inherited<TextDecoration>),
+
+    /// If present, this shadow is drawn behind the text outlines.
+    TextShadow(Inherited<Option<TextShadow>>),
+
+    /// Controls the capitalization of text.
+    TextTransform(Inherited<TextTransform>),
+
     /// If present, controls the color that this image is rendered in. Only the
     /// alpha channel of the original image is kept: all other channels are
     /// replaced by the given tint color. If this tint color exists in a
@@ -31,6 +31,7 @@ impl<T> Velocity<T> {
 
 /// Type-safe representation for angles.
 #[derive(Copy, Clone, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Angle<T> {
     radians: T,
 }
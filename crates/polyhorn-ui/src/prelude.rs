@@ -1,8 +1,8 @@
 //! This is the shared Polyhorn UI prelude.
 
 pub use polyhorn_core::{
-    use_async, use_channel, use_context, use_effect, use_id, use_layout_effect, use_reference,
-    use_state, ContextProvider,
+    use_async, use_channel, use_context, use_effect, use_history, use_id, use_imperative_handle,
+    use_layout_effect, use_machine, use_reference, use_state, use_worker, ContextProvider,
 };
 
 pub use crate::hooks::use_safe_area_insets;
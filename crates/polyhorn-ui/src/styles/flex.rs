@@ -2,6 +2,7 @@ use strum_macros::EnumString;
 
 /// Controls the direction in which items are layed out.
 #[derive(Copy, Clone, Debug, Eq, PartialEq, EnumString)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum FlexDirection {
     /// Items are layed out vertically from top to bottom.
     #[strum(serialize = "column")]
@@ -24,6 +25,7 @@ pub enum FlexDirection {
 
 /// Controls how items are aligned along the main axis of a flexbox.
 #[derive(Copy, Clone, Debug, Eq, PartialEq, EnumString)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Align {
     /// Items are aligned at the start of the cross axis.
     #[strum(serialize = "flex-start")]
@@ -43,17 +45,23 @@ pub enum Align {
 
     /// Items are spaced evenly between, the first item starts at the start of
     /// the cross axis and the last item ends at the end of the cross axis.
+    /// Distributing space has no effect on the items of a single line, so this
+    /// behaves like `FlexStart` when used to align items (which is also what
+    /// Yoga does).
     #[strum(serialize = "space-between")]
     SpaceBetween,
 
     /// Items are spaced evenly around, the first item starts after the start of
     /// the cross axis and the last item ends before the end of the cross axis.
+    /// Like `SpaceBetween`, this behaves like `FlexStart` when used to align
+    /// items.
     #[strum(serialize = "space-around")]
     SpaceAround,
 }
 
 /// Controls how content is justified along the cross axis of a flexbox.
 #[derive(Copy, Clone, Debug, Eq, PartialEq, EnumString)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Justify {
     /// Content is justified at the start of the main axis.
     #[strum(serialize = "flex-start")]
@@ -84,3 +92,30 @@ pub enum Justify {
     #[strum(serialize = "space-evenly")]
     SpaceEvenly,
 }
+
+/// Controls whether items are forced onto a single line or can wrap onto
+/// multiple lines of a flexbox.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, EnumString)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FlexWrap {
+    /// Items are layed out on a single line, which may cause them to overflow
+    /// the flexbox.
+    #[strum(serialize = "nowrap")]
+    NoWrap,
+
+    /// Items that do not fit on a line are wrapped onto the next line. Lines
+    /// are stacked along the cross axis.
+    #[strum(serialize = "wrap")]
+    Wrap,
+
+    /// Items that do not fit on a line are wrapped onto the next line. Lines
+    /// are stacked along the cross axis in reverse order.
+    #[strum(serialize = "wrap-reverse")]
+    WrapReverse,
+}
+
+impl Default for FlexWrap {
+    fn default() -> Self {
+        FlexWrap::NoWrap
+    }
+}
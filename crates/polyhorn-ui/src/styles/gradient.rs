@@ -0,0 +1,521 @@
+use strum_macros::EnumString;
+
+use crate::color::Color;
+use crate::geometry::{Dimension, Point, Size};
+use crate::physics::Angle;
+
+/// Color at a specific position along the gradient line or ray of a gradient.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ColorStop {
+    /// This is the color of this stop.
+    pub color: Color,
+
+    /// This is the position of this stop along the gradient line or ray, as a
+    /// percentage of its length or in points. If not present, stops without a
+    /// position are distributed evenly between the surrounding stops.
+    pub position: Option<Dimension<f32>>,
+}
+
+/// Corner of a rectangle that a linear gradient can point towards.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Corner {
+    /// This is the top left corner of a rectangle.
+    TopLeft,
+
+    /// This is the top right corner of a rectangle.
+    TopRight,
+
+    /// This is the bottom right corner of a rectangle.
+    BottomRight,
+
+    /// This is the bottom left corner of a rectangle.
+    BottomLeft,
+}
+
+/// Direction of the gradient line of a linear gradient.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum GradientDirection {
+    /// The gradient line points in the direction of the given angle, where 0
+    /// points upwards and positive angles rotate clockwise.
+    Angle(Angle<f32>),
+
+    /// The gradient line points towards the given corner. The angle of the
+    /// line depends on the size of the rectangle: the line that is
+    /// perpendicular to it at its center passes through the other two corners.
+    Corner(Corner),
+}
+
+impl Default for GradientDirection {
+    fn default() -> Self {
+        GradientDirection::Angle(Angle::with_degrees(180.0))
+    }
+}
+
+/// Gradient that progresses along a straight line.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LinearGradient {
+    /// This is the direction of the gradient line. The default direction
+    /// points downwards.
+    pub direction: GradientDirection,
+
+    /// These are the color stops of this gradient.
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::leak::slice"))]
+    pub stops: &'static [ColorStop],
+}
+
+/// Shape of the ending shape of a radial gradient.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, EnumString)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RadialGradientShape {
+    /// The ending shape is a circle.
+    #[strum(serialize = "circle")]
+    Circle,
+
+    /// The ending shape is an axis-aligned ellipse.
+    #[strum(serialize = "ellipse")]
+    Ellipse,
+}
+
+impl Default for RadialGradientShape {
+    fn default() -> Self {
+        RadialGradientShape::Ellipse
+    }
+}
+
+/// Size of the ending shape of a radial gradient.
+#[derive(Copy, Clone, Debug, PartialEq, EnumString)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RadialGradientSize {
+    /// The ending shape touches the side of the rectangle that is closest to
+    /// its center (or the closest sides in both dimensions for ellipses).
+    #[strum(serialize = "closest-side")]
+    ClosestSide,
+
+    /// The ending shape passes through the corner of the rectangle that is
+    /// closest to its center.
+    #[strum(serialize = "closest-corner")]
+    ClosestCorner,
+
+    /// The ending shape touches the side of the rectangle that is farthest
+    /// from its center (or the farthest sides in both dimensions for
+    /// ellipses).
+    #[strum(serialize = "farthest-side")]
+    FarthestSide,
+
+    /// The ending shape passes through the corner of the rectangle that is
+    /// farthest from its center.
+    #[strum(serialize = "farthest-corner")]
+    FarthestCorner,
+
+    /// The ending shape has the given horizontal and vertical radius.
+    /// Percentages are relative to the width and height of the rectangle
+    /// respectively.
+    #[strum(disabled)]
+    Radii(Dimension<f32>, Dimension<f32>),
+}
+
+impl Default for RadialGradientSize {
+    fn default() -> Self {
+        RadialGradientSize::FarthestCorner
+    }
+}
+
+/// Gradient that radiates from a center point.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RadialGradient {
+    /// This is the shape of the gradient.
+    pub shape: RadialGradientShape,
+
+    /// This is the size of the ending shape of the gradient.
+    pub size: RadialGradientSize,
+
+    /// This is the center of the gradient. Percentages are relative to the
+    /// size of the rectangle. The default center is the center of the
+    /// rectangle.
+    pub position: Point<Dimension<f32>>,
+
+    /// These are the color stops of this gradient.
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::leak::slice"))]
+    pub stops: &'static [ColorStop],
+}
+
+impl Default for RadialGradient {
+    fn default() -> Self {
+        RadialGradient {
+            shape: Default::default(),
+            size: Default::default(),
+            position: Point::new(Dimension::Percentage(0.5), Dimension::Percentage(0.5)),
+            stops: &[],
+        }
+    }
+}
+
+/// Image that consists of a smooth transition between colors.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Gradient {
+    /// Gradient that progresses along a straight line.
+    Linear(LinearGradient),
+
+    /// Gradient that radiates from a center point.
+    Radial(RadialGradient),
+}
+
+/// Geometry of a gradient that has been resolved for a rectangle of a
+/// specific size, relative to the top left corner of that rectangle.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum GradientGeometry {
+    /// Linear gradients progress from the start point to the end point.
+    Linear {
+        /// This is the point at which the gradient line starts (i.e. where
+        /// stops at 0% are drawn).
+        start: Point<f32>,
+
+        /// This is the point at which the gradient line ends (i.e. where stops
+        /// at 100% are drawn).
+        end: Point<f32>,
+    },
+
+    /// Radial gradients progress from the center to the ending shape.
+    Radial {
+        /// This is the center of the gradient.
+        center: Point<f32>,
+
+        /// These are the horizontal and vertical radius of the ending shape
+        /// (i.e. where stops at 100% are drawn).
+        radius: Size<f32>,
+    },
+}
+
+/// Gradient that has been resolved for a rectangle of a specific size.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ResolvedGradient {
+    /// This is the geometry of the gradient.
+    pub geometry: GradientGeometry,
+
+    /// These are the offsets of the color stops as a fraction of the length of
+    /// the gradient line or ray, along with their colors. Offsets are
+    /// non-decreasing but can be less than 0 or greater than 1.
+    pub stops: Vec<(f32, Color)>,
+}
+
+impl Gradient {
+    /// Returns the color stops of this gradient.
+    pub fn stops(&self) -> &'static [ColorStop] {
+        match self {
+            Gradient::Linear(linear) => linear.stops,
+            Gradient::Radial(radial) => radial.stops,
+        }
+    }
+
+    /// Resolves the geometry and color stops of this gradient for a rectangle
+    /// of the given size, following the rules of CSS Images.
+    pub fn resolve(&self, size: Size<f32>) -> ResolvedGradient {
+        let (geometry, length) = match self {
+            Gradient::Linear(linear) => resolve_linear(linear, size),
+            Gradient::Radial(radial) => resolve_radial(radial, size),
+        };
+
+        ResolvedGradient {
+            geometry,
+            stops: resolve_stops(self.stops(), length),
+        }
+    }
+}
+
+fn resolve_linear(gradient: &LinearGradient, size: Size<f32>) -> (GradientGeometry, f32) {
+    let (width, height) = (size.width, size.height);
+
+    let angle = match gradient.direction {
+        GradientDirection::Angle(angle) => angle.to_radians(),
+        GradientDirection::Corner(corner) => {
+            let angle = height.atan2(width);
+
+            match corner {
+                Corner::TopRight => angle,
+                Corner::BottomRight => std::f32::consts::PI - angle,
+                Corner::BottomLeft => std::f32::consts::PI + angle,
+                Corner::TopLeft => -angle,
+            }
+        }
+    };
+
+    // The gradient line passes through the center of the rectangle and is
+    // long enough for the perpendicular lines at its ends to pass through the
+    // corners.
+    let (sin, cos) = angle.sin_cos();
+    let length = (width * sin).abs() + (height * cos).abs();
+    let (dx, dy) = (sin * length / 2.0, -cos * length / 2.0);
+    let center = Point::new(width / 2.0, height / 2.0);
+
+    (
+        GradientGeometry::Linear {
+            start: Point::new(center.x - dx, center.y - dy),
+            end: Point::new(center.x + dx, center.y + dy),
+        },
+        length,
+    )
+}
+
+fn resolve_radial(gradient: &RadialGradient, size: Size<f32>) -> (GradientGeometry, f32) {
+    let resolve = |dimension, length: f32| match dimension {
+        Dimension::Points(points) => points,
+        Dimension::Percentage(percentage) => percentage * length,
+        Dimension::Undefined | Dimension::Auto => length / 2.0,
+    };
+
+    let center = Point::new(
+        resolve(gradient.position.x, size.width),
+        resolve(gradient.position.y, size.height),
+    );
+
+    // These are the distances to the closest and farthest sides in both
+    // dimensions.
+    let (left, right) = (center.x.abs(), (size.width - center.x).abs());
+    let (top, bottom) = (center.y.abs(), (size.height - center.y).abs());
+    let closest = Size::new(left.min(right), top.min(bottom));
+    let farthest = Size::new(left.max(right), top.max(bottom));
+
+    let circle = gradient.shape == RadialGradientShape::Circle;
+
+    let radius = match gradient.size {
+        RadialGradientSize::ClosestSide if circle => {
+            let radius = closest.width.min(closest.height);
+            Size::new(radius, radius)
+        }
+        RadialGradientSize::FarthestSide if circle => {
+            let radius = farthest.width.max(farthest.height);
+            Size::new(radius, radius)
+        }
+        RadialGradientSize::ClosestCorner if circle => {
+            let radius = closest.width.hypot(closest.height);
+            Size::new(radius, radius)
+        }
+        RadialGradientSize::FarthestCorner if circle => {
+            let radius = farthest.width.hypot(farthest.height);
+            Size::new(radius, radius)
+        }
+        RadialGradientSize::ClosestSide => closest,
+        RadialGradientSize::FarthestSide => farthest,
+        // An ellipse that passes through a corner keeps the aspect ratio of
+        // the ellipse that touches the corresponding sides.
+        RadialGradientSize::ClosestCorner => Size::new(
+            closest.width * std::f32::consts::SQRT_2,
+            closest.height * std::f32::consts::SQRT_2,
+        ),
+        RadialGradientSize::FarthestCorner => Size::new(
+            farthest.width * std::f32::consts::SQRT_2,
+            farthest.height * std::f32::consts::SQRT_2,
+        ),
+        RadialGradientSize::Radii(x, _) if circle => {
+            let radius = resolve(x, size.width);
+            Size::new(radius, radius)
+        }
+        RadialGradientSize::Radii(x, y) => {
+            Size::new(resolve(x, size.width), resolve(y, size.height))
+        }
+    };
+
+    (GradientGeometry::Radial { center, radius }, radius.width)
+}
+
+fn resolve_stops(stops: &[ColorStop], length: f32) -> Vec<(f32, Color)> {
+    let mut offsets = stops
+        .iter()
+        .map(|stop| match stop.position {
+            Some(Dimension::Percentage(percentage)) => Some(percentage),
+            Some(Dimension::Points(points)) if length != 0.0 => Some(points / length),
+            Some(Dimension::Points(_)) => Some(0.0),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    // The first and last stops default to the start and end of the gradient.
+    if let Some(first) = offsets.first_mut() {
+        first.get_or_insert(0.0);
+    }
+
+    if let Some(last) = offsets.last_mut() {
+        last.get_or_insert(1.0);
+    }
+
+    // Stops are not allowed to precede any of the stops before them.
+    let mut max = f32::NEG_INFINITY;
+
+    for offset in offsets.iter_mut().flatten() {
+        max = max.max(*offset);
+        *offset = max;
+    }
+
+    // Stops without an offset are distributed evenly between the surrounding
+    // stops.
+    let mut index = 0;
+
+    while index < offsets.len() {
+        if offsets[index].is_some() {
+            index += 1;
+            continue;
+        }
+
+        let start = index - 1;
+        let end = (index..offsets.len())
+            .find(|&index| offsets[index].is_some())
+            .unwrap();
+        let (from, to) = (offsets[start].unwrap(), offsets[end].unwrap());
+
+        for (index, offset) in offsets[start + 1..end].iter_mut().enumerate() {
+            let fraction = (index + 1) as f32 / (end - start) as f32;
+            *offset = Some(from + (to - from) * fraction);
+        }
+
+        index = end;
+    }
+
+    offsets
+        .into_iter()
+        .zip(stops)
+        .map(|(offset, stop)| (offset.unwrap(), stop.color))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        ColorStop, Corner, Gradient, GradientDirection, GradientGeometry, LinearGradient,
+        RadialGradient, RadialGradientShape, RadialGradientSize,
+    };
+    use crate::color::{Color, NamedColor};
+    use crate::geometry::{Dimension, Point, Size};
+    use crate::physics::Angle;
+
+    fn stop(color: Color, position: Option<Dimension<f32>>) -> ColorStop {
+        ColorStop { color, position }
+    }
+
+    fn assert_close(a: Point<f32>, b: Point<f32>) {
+        assert!(
+            (a.x - b.x).abs() < 0.001 && (a.y - b.y).abs() < 0.001,
+            "{:?} != {:?}",
+            a,
+            b
+        );
+    }
+
+    #[test]
+    fn test_linear() {
+        let gradient = Gradient::Linear(LinearGradient {
+            direction: GradientDirection::Angle(Angle::with_degrees(90.0)),
+            stops: &[],
+        });
+
+        match gradient.resolve(Size::new(200.0, 100.0)).geometry {
+            GradientGeometry::Linear { start, end } => {
+                assert_close(start, Point::new(0.0, 50.0));
+                assert_close(end, Point::new(200.0, 50.0));
+            }
+            geometry => panic!("Unexpected geometry: {:?}", geometry),
+        }
+
+        // The perpendicular at the end of a line towards a corner passes
+        // through that corner.
+        let gradient = Gradient::Linear(LinearGradient {
+            direction: GradientDirection::Corner(Corner::BottomRight),
+            stops: &[],
+        });
+
+        match gradient.resolve(Size::new(200.0, 100.0)).geometry {
+            GradientGeometry::Linear { start, end } => {
+                let direction = (end.x - start.x, end.y - start.y);
+                let corner = (200.0 - end.x, 100.0 - end.y);
+                assert!((direction.0 * corner.0 + direction.1 * corner.1).abs() < 0.01);
+                assert!(direction.0 > 0.0 && direction.1 > 0.0);
+            }
+            geometry => panic!("Unexpected geometry: {:?}", geometry),
+        }
+    }
+
+    #[test]
+    fn test_radial() {
+        let gradient = Gradient::Radial(RadialGradient {
+            position: Point::new(Dimension::Points(50.0), Dimension::Percentage(0.5)),
+            ..Default::default()
+        });
+
+        assert_eq!(
+            gradient.resolve(Size::new(200.0, 100.0)).geometry,
+            GradientGeometry::Radial {
+                center: Point::new(50.0, 50.0),
+                radius: Size::new(
+                    150.0 * std::f32::consts::SQRT_2,
+                    50.0 * std::f32::consts::SQRT_2
+                ),
+            }
+        );
+
+        let gradient = Gradient::Radial(RadialGradient {
+            shape: RadialGradientShape::Circle,
+            size: RadialGradientSize::ClosestSide,
+            position: Point::new(Dimension::Points(50.0), Dimension::Percentage(0.5)),
+            ..Default::default()
+        });
+
+        assert_eq!(
+            gradient.resolve(Size::new(200.0, 100.0)).geometry,
+            GradientGeometry::Radial {
+                center: Point::new(50.0, 50.0),
+                radius: Size::new(50.0, 50.0),
+            }
+        );
+    }
+
+    #[test]
+    fn test_stops() {
+        let gradient = Gradient::Linear(LinearGradient {
+            direction: GradientDirection::Angle(Angle::with_degrees(90.0)),
+            stops: &[],
+        });
+
+        let stops = |stops: &'static [ColorStop]| {
+            let gradient = match gradient {
+                Gradient::Linear(linear) => Gradient::Linear(LinearGradient { stops, ..linear }),
+                _ => unreachable!(),
+            };
+
+            gradient
+                .resolve(Size::new(200.0, 100.0))
+                .stops
+                .into_iter()
+                .map(|(offset, _)| offset)
+                .collect::<Vec<_>>()
+        };
+
+        // Missing positions are distributed evenly.
+        assert_eq!(
+            stops(Box::leak(Box::new([
+                stop(Color::red(), None),
+                stop(Color::green(), None),
+                stop(Color::blue(), None),
+            ]))),
+            vec![0.0, 0.5, 1.0]
+        );
+
+        // Points are relative to the length of the gradient line and stops
+        // can't precede earlier stops.
+        assert_eq!(
+            stops(Box::leak(Box::new([
+                stop(Color::red(), Some(Dimension::Points(100.0))),
+                stop(Color::green(), None),
+                stop(Color::blue(), Some(Dimension::Percentage(0.25))),
+                stop(Color::black(), None),
+            ]))),
+            vec![0.5, 0.5, 0.5, 1.0]
+        );
+    }
+}
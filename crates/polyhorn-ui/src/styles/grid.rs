@@ -0,0 +1,78 @@
+use strum_macros::EnumString;
+
+/// Controls the size of a single row or column of a grid.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum GridTrack {
+    /// The track is as large as its largest item and grows if there is space
+    /// left after sizing all other tracks.
+    Auto,
+
+    /// This is a track size expressed in absolute units, where each unit
+    /// represents a single pixel.
+    Points(f32),
+
+    /// This is a track size expressed in relative units, where 1.0 represents
+    /// 100% of the grid container.
+    Percentage(f32),
+
+    /// The track takes the given share of the space that is left after sizing
+    /// all other tracks (i.e. the `fr` unit in CSS).
+    Fraction(f32),
+
+    /// The track is as small as possible without overflowing its items (e.g.
+    /// text is wrapped at every opportunity).
+    MinContent,
+
+    /// The track is as large as its items would like to be (e.g. text is not
+    /// wrapped at all).
+    MaxContent,
+}
+
+/// Determines the line at which a grid item starts or ends along one axis.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, EnumString)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum GridPlacement {
+    /// The item is placed by the auto-placement algorithm and spans a single
+    /// track.
+    #[strum(serialize = "auto")]
+    Auto,
+
+    /// The item starts or ends at the line with the given index. Lines are
+    /// numbered from 1 and negative indices count back from the last line.
+    #[strum(disabled)]
+    Line(i16),
+
+    /// The item spans the given number of tracks, starting from its other
+    /// line.
+    #[strum(disabled)]
+    Span(u16),
+}
+
+impl Default for GridPlacement {
+    fn default() -> Self {
+        GridPlacement::Auto
+    }
+}
+
+/// Placement of a grid item along one axis (i.e. its rows or its columns).
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GridLine {
+    /// This is the line at which the grid item starts.
+    pub start: GridPlacement,
+
+    /// This is the line at which the grid item ends.
+    pub end: GridPlacement,
+}
+
+/// Placement of a grid item along both axes of a grid.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GridArea {
+    /// This is the placement of the grid item along the rows of a grid.
+    pub row: GridLine,
+
+    /// This is the placement of the grid item along the columns of a grid.
+    pub column: GridLine,
+}
@@ -6,6 +6,7 @@ use crate::color::Color;
 /// Defines the method for fitting objects that do not match the dimensions of
 /// their container.
 #[derive(Copy, Clone, Debug, Eq, PartialEq, EnumString)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ObjectFit {
     /// Fills the container without respecting the aspect ratio of the object if
     /// the dimensions of the container isn't a multiple of the dimensions of
@@ -41,6 +42,7 @@ impl Default for ObjectFit {
 
 /// Controls the appearance of an Image.
 #[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ImageStyle {
     /// Controls the method for fitting images that do not match the dimensions
     /// of their container.
@@ -56,6 +58,7 @@ pub struct ImageStyle {
 
 /// This is a union style of the Image and View styles.
 #[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ImageViewStyle {
     /// This style contains the properties that are only applicable to Images.
     pub image: ImageStyle,
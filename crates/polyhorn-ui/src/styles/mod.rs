@@ -1,6 +1,8 @@
 //! Styles for each reactive component.
 
 mod flex;
+mod gradient;
+mod grid;
 mod image;
 mod position;
 mod scrollable;
@@ -8,17 +10,26 @@ mod text;
 mod transform;
 mod view;
 
-pub use flex::{Align, FlexDirection, Justify};
+pub use flex::{Align, FlexDirection, FlexWrap, Justify};
+pub use gradient::{
+    ColorStop, Corner, Gradient, GradientDirection, GradientGeometry, LinearGradient,
+    RadialGradient, RadialGradientShape, RadialGradientSize, ResolvedGradient,
+};
+pub use grid::{GridArea, GridLine, GridPlacement, GridTrack};
 pub use image::{ImageStyle, ImageViewStyle, ObjectFit};
 pub use position::{Absolute, Position, Relative};
 pub use scrollable::{ScrollableStyle, ScrollableViewStyle, ScrollbarColor};
-pub use text::{TextAlign, TextStyle};
+pub use text::{
+    EllipsisMode, LineHeight, TextAlign, TextDecoration, TextDecorationStyle, TextShadow,
+    TextStyle, TextTransform,
+};
 pub use transform::{Transform, TransformBuilder};
-pub use view::{Border, BorderStyle, Overflow, ViewStyle, Visibility};
+pub use view::{Border, BorderStyle, BoxShadow, Display, Overflow, ViewStyle, Visibility};
 
 /// Represents a property that can optionally be inherited from a parent
 /// element.
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Inherited<T> {
     /// If inherited, the value of this property is taken from the parent
     /// element (transitively). If none of the parent elements have specified
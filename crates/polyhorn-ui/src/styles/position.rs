@@ -1,7 +1,9 @@
+use super::Align;
 use crate::geometry::{ByEdge, Dimension};
 
 /// Controls the absolute positioning of a view.
 #[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Absolute {
     /// Provides the distance of this view to each of the edges of its ancestor.
     pub distances: ByEdge<Dimension<f32>>,
@@ -9,6 +11,7 @@ pub struct Absolute {
 
 /// Controls the relative positioning of a view.
 #[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Relative {
     /// If present, this property controls the weight of this view in computing
     /// a layout using the flexbox algorithm.
@@ -26,6 +29,11 @@ pub struct Relative {
     /// view is shrunk when necessary. If set to 0.0, this view will not be
     /// shrunk.
     pub flex_shrink: f32,
+
+    /// If present, this property overrides the alignment of this view along
+    /// the cross axis of its ancestor (i.e. the `align_items` field of the
+    /// ancestor's style).
+    pub align_self: Option<Align>,
 }
 
 impl Default for Relative {
@@ -34,12 +42,14 @@ impl Default for Relative {
             flex_basis: Dimension::Undefined,
             flex_grow: 0.0,
             flex_shrink: 1.0,
+            align_self: None,
         }
     }
 }
 
 /// Determines whether a view affects the layout of its ancestor and siblings.
 #[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Position {
     /// If layed out absolutely, this view does not affect the layout of its
     /// ancestor or siblings.
@@ -3,6 +3,7 @@ use crate::geometry::{ByEdge, Dimension};
 
 /// Color of the scroll bars of a Scrollable.
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ScrollbarColor {
     /// This variant lets the OS decide which color to use. The OS may look at
     /// the appearance mode chosen by the user (i.e. light vs. dark mode) and/or
@@ -28,6 +29,7 @@ impl Default for ScrollbarColor {
 
 /// Controls the appearance of a Scrollable.
 #[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ScrollableStyle {
     /// Controls the distance between the scrollable content and each edge of a
     /// rectangle.
@@ -48,6 +50,7 @@ pub struct ScrollableStyle {
 
 /// This is a union style of the Scrollable and View styles.
 #[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ScrollableViewStyle {
     /// This style contains the properties that are only applicable to
     /// Scrollables.
@@ -3,10 +3,12 @@ use strum_macros::EnumString;
 use super::Inherited;
 use crate::color::Color;
 use crate::font::{FontFamily, FontSize, FontStyle, FontWeight};
+use crate::geometry::Dimension;
 
 /// Controls the alignment of text in a container that is larger than the text
 /// itself.
 #[derive(Copy, Clone, Debug, Eq, PartialEq, EnumString)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TextAlign {
     /// Text is aligned to the left edge of the container.
     #[strum(serialize = "left")]
@@ -19,10 +21,220 @@ pub enum TextAlign {
     /// Text is aligned to the right edge of the container.
     #[strum(serialize = "right")]
     Right,
+
+    /// Text is stretched to both edges of the container by widening the
+    /// spaces between words. The last line of a paragraph is aligned to the
+    /// left edge of the container.
+    #[strum(serialize = "justify")]
+    Justify,
+}
+
+/// Controls the distance between the baselines of successive lines of text.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LineHeight {
+    /// The line height is determined by the metrics of the font.
+    Normal,
+
+    /// The line height is a multiple of the font size.
+    Number(f32),
+
+    /// The line height is given in points or as a percentage of the font size.
+    Dimension(Dimension<f32>),
+}
+
+impl LineHeight {
+    /// Returns the line height in points for text with the given font size, or
+    /// `None` if the line height should be determined by the metrics of the
+    /// font.
+    pub fn to_points(&self, font_size: f32) -> Option<f32> {
+        match self {
+            LineHeight::Normal => None,
+            LineHeight::Number(number) => Some(number * font_size),
+            LineHeight::Dimension(Dimension::Points(points)) => Some(*points),
+            LineHeight::Dimension(Dimension::Percentage(percentage)) => {
+                Some(percentage * font_size)
+            }
+            LineHeight::Dimension(_) => None,
+        }
+    }
+}
+
+impl Default for LineHeight {
+    fn default() -> Self {
+        LineHeight::Normal
+    }
+}
+
+/// Controls the appearance of the lines that decorate text.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, EnumString)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TextDecorationStyle {
+    /// Draws a single line.
+    #[strum(serialize = "solid")]
+    Solid,
+
+    /// Draws two parallel lines.
+    #[strum(serialize = "double")]
+    Double,
+
+    /// Draws a dotted line.
+    #[strum(serialize = "dotted")]
+    Dotted,
+
+    /// Draws a dashed line.
+    #[strum(serialize = "dashed")]
+    Dashed,
+
+    /// Draws a wavy line.
+    #[strum(serialize = "wavy")]
+    Wavy,
+}
+
+impl Default for TextDecorationStyle {
+    fn default() -> Self {
+        TextDecorationStyle::Solid
+    }
+}
+
+/// Controls the lines that are drawn under or through text.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TextDecoration {
+    /// If true, a line is drawn under the text.
+    pub underline: bool,
+
+    /// If true, a line is drawn through the middle of the text.
+    pub line_through: bool,
+
+    /// This is the style of the lines.
+    pub style: TextDecorationStyle,
+
+    /// This is the color of the lines. If not present, the lines are drawn in
+    /// the color of the text itself.
+    pub color: Option<Color>,
+}
+
+impl TextDecoration {
+    /// Returns a decoration that does not draw any lines.
+    pub fn none() -> TextDecoration {
+        TextDecoration::default()
+    }
+
+    /// Returns true if this decoration does not draw any lines.
+    pub fn is_none(&self) -> bool {
+        !self.underline && !self.line_through
+    }
+}
+
+/// Controls the capitalization of text.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, EnumString)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TextTransform {
+    /// Text is rendered as is.
+    #[strum(serialize = "none")]
+    None,
+
+    /// All characters are converted to uppercase.
+    #[strum(serialize = "uppercase")]
+    Uppercase,
+
+    /// All characters are converted to lowercase.
+    #[strum(serialize = "lowercase")]
+    Lowercase,
+
+    /// The first character of each word is converted to uppercase.
+    #[strum(serialize = "capitalize")]
+    Capitalize,
+}
+
+impl TextTransform {
+    /// Applies this transform to the given text. The given character is the
+    /// one that precedes the text (if any), which is used to determine whether
+    /// the text starts a new word.
+    pub fn apply(&self, text: &str, previous: Option<char>) -> String {
+        match self {
+            TextTransform::None => text.to_owned(),
+            TextTransform::Uppercase => text.to_uppercase(),
+            TextTransform::Lowercase => text.to_lowercase(),
+            TextTransform::Capitalize => {
+                let mut previous = previous;
+                let mut result = String::with_capacity(text.len());
+
+                for ch in text.chars() {
+                    match previous {
+                        Some(previous) if !previous.is_whitespace() => result.push(ch),
+                        _ => result.extend(ch.to_uppercase()),
+                    }
+
+                    previous = Some(ch);
+                }
+
+                result
+            }
+        }
+    }
+}
+
+impl Default for TextTransform {
+    fn default() -> Self {
+        TextTransform::None
+    }
+}
+
+/// Controls where text is truncated if it does not fit within its maximum
+/// number of lines.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, EnumString)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EllipsisMode {
+    /// Text is cut off without an ellipsis.
+    #[strum(serialize = "clip")]
+    Clip,
+
+    /// The start of the last line is replaced by an ellipsis.
+    #[strum(serialize = "head")]
+    Head,
+
+    /// The middle of the last line is replaced by an ellipsis.
+    #[strum(serialize = "middle")]
+    Middle,
+
+    /// The end of the last line is replaced by an ellipsis.
+    #[strum(serialize = "tail")]
+    Tail,
+}
+
+impl Default for EllipsisMode {
+    fn default() -> Self {
+        EllipsisMode::Tail
+    }
+}
+
+/// Shadow that is drawn behind the outlines of text.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TextShadow {
+    /// This is the horizontal offset of the shadow in points.
+    pub offset_x: f32,
+
+    /// This is the vertical offset of the shadow in points.
+    pub offset_y: f32,
+
+    /// This is the blur radius of the shadow in points.
+    pub blur_radius: f32,
+
+    /// This is the color of the shadow. If not present, the shadow is drawn in
+    /// the color of the text itself.
+    pub color: Option<Color>,
 }
 
 /// Controls the appearance of a Text.
 #[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(deserialize = "S: crate::font::FontName"))
+)]
 pub struct TextStyle<S = &'static str> {
     /// This is the color that will be used to fill the text outlines. If not
     /// present, the Text component will inherit the text color of its parent.
@@ -62,4 +274,36 @@ pub struct TextStyle<S = &'static str> {
     /// Controls the alignment of text when it is rendered to a container that
     /// is larger than the rendered text.
     pub text_align: Inherited<TextAlign>,
+
+    /// This is the distance between the baselines of successive lines. If the
+    /// parent does not have a line height, the default `LineHeight::Normal`
+    /// will be used.
+    pub line_height: Inherited<LineHeight>,
+
+    /// This is the additional space (in points) that is inserted between
+    /// characters. If the parent does not have a letter spacing, no additional
+    /// space is inserted.
+    pub letter_spacing: Inherited<f32>,
+
+    /// Controls the lines that are drawn under or through the text. If the
+    /// parent does not have a text decoration, no lines are drawn.
+    pub text_decoration: Inherited<TextDecoration>,
+
+    /// Controls the capitalization of the text. If the parent does not have a
+    /// text transform, the text is rendered as is.
+    pub text_transform: Inherited<TextTransform>,
+
+    /// This is the maximum number of lines that the text is rendered in. If 0
+    /// (or if the parent does not have a number of lines), the number of lines
+    /// is unlimited.
+    pub number_of_lines: Inherited<usize>,
+
+    /// Controls where text is truncated if it does not fit within its maximum
+    /// number of lines. If the parent does not have an ellipsis mode, the
+    /// default `EllipsisMode::Tail` will be used.
+    pub ellipsis_mode: Inherited<EllipsisMode>,
+
+    /// If present, this shadow is drawn behind the text outlines. If the parent
+    /// does not have a text shadow, no shadow is drawn.
+    pub text_shadow: Inherited<Option<TextShadow>>,
 }
@@ -72,6 +72,7 @@ where
 /// Decomposition of a CSS transform into a constant 3D transform and a relative
 /// 2D translation (i.e. CSS percentage).
 #[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Transform<T>
 where
     T: Float,
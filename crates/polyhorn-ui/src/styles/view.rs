@@ -1,13 +1,17 @@
 use std::fmt::Debug;
 use strum_macros::EnumString;
 
-use super::{Align, FlexDirection, Inherited, Justify, Position, Transform};
-use crate::color::Color;
+use super::{
+    Align, FlexDirection, FlexWrap, Gradient, GridArea, GridTrack, Inherited, Justify, Position,
+    Transform,
+};
+use crate::color::{Color, NamedColor};
 use crate::geometry::{ByCorner, ByDirection, ByEdge, Dimension, Size};
 use crate::layout::LayoutDirection;
 
 /// Controls the style that is used to draw a border.
 #[derive(Copy, Clone, Debug, Eq, PartialEq, EnumString)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum BorderStyle {
     /// Draws a solid line.
     #[strum(serialize = "solid")]
@@ -27,6 +31,7 @@ pub enum BorderStyle {
 
 /// Controls the appearance of a border shown around the dimensions of a view.
 #[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Border {
     /// Controls the thickness of a border rendered around a view. If the
     /// dimension resolves to either undefined or auto, no border will be shown.
@@ -58,9 +63,51 @@ impl Default for Border {
     }
 }
 
+/// Shadow that is drawn outside (or inside) the border box of a view.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BoxShadow {
+    /// This is the horizontal offset of the shadow in points. Positive values
+    /// move the shadow to the right.
+    pub offset_x: f32,
+
+    /// This is the vertical offset of the shadow in points. Positive values
+    /// move the shadow down.
+    pub offset_y: f32,
+
+    /// This is the blur radius of the shadow in points. The default value is
+    /// 0.0, which results in a shadow with sharp edges.
+    pub blur_radius: f32,
+
+    /// This is the distance in points by which the shadow is expanded (or
+    /// contracted if negative) before it is blurred.
+    pub spread_radius: f32,
+
+    /// This is the color of the shadow. The default color is black.
+    pub color: Color,
+
+    /// If true, the shadow is drawn inside the padding box of the view instead
+    /// of outside its border box.
+    pub inset: bool,
+}
+
+impl Default for BoxShadow {
+    fn default() -> Self {
+        BoxShadow {
+            offset_x: 0.0,
+            offset_y: 0.0,
+            blur_radius: 0.0,
+            spread_radius: 0.0,
+            color: Color::black(),
+            inset: false,
+        }
+    }
+}
+
 /// Controls the way dimensions of views are adjusted when their content
 /// overflows their original boundaries.
 #[derive(Copy, Clone, Debug, Eq, PartialEq, EnumString)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Overflow {
     /// If overflow is visible, views are adjusted to accommodate the larger
     /// content size.
@@ -84,8 +131,39 @@ impl Default for Overflow {
     }
 }
 
+/// Controls the algorithm that is used to lay out the descendant views of a
+/// view.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, EnumString)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Display {
+    /// Descendant views are layed out along a single axis using the flexbox
+    /// algorithm.
+    #[strum(serialize = "flex")]
+    Flex,
+
+    /// Descendant views are placed in the rows and columns of a grid. Grids
+    /// are only supported by the Taffy layout algorithm. Other algorithms lay
+    /// out grids as if they were flexboxes.
+    #[strum(serialize = "grid")]
+    Grid,
+
+    /// The view and its descendants are removed from layout entirely: they
+    /// don't take up any space and are not rendered. Unlike views that are
+    /// hidden (see `Visibility::Hidden`), siblings are layed out as if this
+    /// view does not exist.
+    #[strum(serialize = "none")]
+    None,
+}
+
+impl Default for Display {
+    fn default() -> Self {
+        Display::Flex
+    }
+}
+
 /// Controls the visibility of a view.
 #[derive(Copy, Clone, Debug, Eq, PartialEq, EnumString)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Visibility {
     /// If visible, the view is both included in layout calculations and
     /// rendered to the screen, even if its opacity is zero.
@@ -106,11 +184,16 @@ impl Default for Visibility {
 
 /// Controls the appearance of a View.
 #[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ViewStyle {
     /// This field determines whether this view should be included in
     /// calculating the layout of descendant views of the ancestor of this view.
     pub position: Position,
 
+    /// This field determines the algorithm that is used to lay out the
+    /// descendant views of this view. The default value is `Display::Flex`.
+    pub display: Display,
+
     /// This field determines the direction in which descendant views are layed
     /// out.
     pub direction: Inherited<LayoutDirection>,
@@ -128,9 +211,19 @@ pub struct ViewStyle {
     /// one of both dimensions.
     pub max_size: Size<Dimension<f32>>,
 
+    /// If present, this property forces the ratio between the width and height
+    /// of this view. If only one of both dimensions is given (or computed
+    /// by the flexbox algorithm), the other dimension is derived from it.
+    pub aspect_ratio: Option<f32>,
+
     /// This is the main axis along which the flexbox algorithm operates.
     pub flex_direction: FlexDirection,
 
+    /// This property controls whether descendant views that do not fit on a
+    /// single line wrap onto multiple lines. The default value for this
+    /// property is `FlexWrap::NoWrap`.
+    pub flex_wrap: FlexWrap,
+
     /// This is the alignment of items along the main axis of the flexbox. The
     /// default value for this property is `Align::Stretch` which will resize
     /// descendant views along the cross axis to match the relevant dimension of
@@ -143,6 +236,37 @@ pub struct ViewStyle {
     /// main axis.
     pub justify_content: Justify,
 
+    /// This is the alignment of the lines of a flexbox with multiple lines
+    /// along its cross axis. The default value for this property is
+    /// `Align::FlexStart`. This property has no effect if the flexbox does not
+    /// wrap.
+    pub align_content: Align,
+
+    /// These are the sizes of the rows of this view if it is a grid. Rows
+    /// that are not listed here (e.g. because more items are placed in this
+    /// grid) are sized automatically.
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::leak::slice"))]
+    pub grid_template_rows: &'static [GridTrack],
+
+    /// These are the sizes of the columns of this view if it is a grid.
+    /// Columns that are not listed here are sized automatically.
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::leak::slice"))]
+    pub grid_template_columns: &'static [GridTrack],
+
+    /// This is the placement of this view within the grid of its ancestor.
+    /// This property has no effect if the ancestor is not a grid.
+    pub grid_area: GridArea,
+
+    /// This is the space between adjacent rows of this view (e.g. between the
+    /// items of a column flexbox or between the lines of a row flexbox that
+    /// wraps). Gaps are not supported by the Yoga and Stretch layout
+    /// algorithms.
+    pub row_gap: Dimension<f32>,
+
+    /// This is the space between adjacent columns of this view. Gaps are not
+    /// supported by the Yoga and Stretch layout algorithms.
+    pub column_gap: Dimension<f32>,
+
     /// This property controls the margin that is used outside this view.
     pub margin: ByEdge<Dimension<f32>>,
 
@@ -157,6 +281,17 @@ pub struct ViewStyle {
     /// siblings or its descendants.
     pub background_color: Color,
 
+    /// If present, this gradient is drawn on top of the background color of
+    /// this view and is clipped to its corner radius. This property does not
+    /// affect the layout of this view, its siblings or its descendants.
+    pub background_image: Option<Gradient>,
+
+    /// These are the shadows of this view. Shadows are drawn in order, i.e.
+    /// the first shadow is drawn on top. Shadows follow the corner radius of
+    /// this view and do not affect its layout.
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "crate::leak::slice"))]
+    pub box_shadow: &'static [BoxShadow],
+
     /// If not 0.0, this field controls the corner radius of this view. This
     /// property does not affect the layout of this view, its siblings or its
     /// descendants.
@@ -188,14 +323,25 @@ impl Default for ViewStyle {
     fn default() -> Self {
         ViewStyle {
             position: Position::Relative(Default::default()),
+            display: Display::Flex,
             direction: Inherited::Inherited,
             min_size: Size::new(Dimension::Auto, Dimension::Auto),
             max_size: Size::new(Dimension::Auto, Dimension::Auto),
+            aspect_ratio: None,
             flex_direction: FlexDirection::Column,
+            flex_wrap: FlexWrap::NoWrap,
             align_items: Align::Stretch,
             justify_content: Justify::FlexStart,
+            align_content: Align::FlexStart,
+            grid_template_rows: &[],
+            grid_template_columns: &[],
+            grid_area: Default::default(),
+            row_gap: Dimension::Undefined,
+            column_gap: Dimension::Undefined,
             size: Size::new(Dimension::Auto, Dimension::Auto),
             background_color: Color::transparent(),
+            background_image: None,
+            box_shadow: &[],
             margin: Default::default(),
             border: Default::default(),
             border_radius: Default::default(),
@@ -0,0 +1,584 @@
+use polyhorn_core::{Component, Element, Platform};
+
+use crate::color::{Color, NamedColor};
+use crate::components::Text;
+use crate::events::EventListener;
+use crate::font::{Font, FontFamily, FontSize, FontStyle, FontWeight, GenericFontFamily};
+use crate::geometry::Dimension;
+use crate::styles::{
+    EllipsisMode, Inherited, LineHeight, TextAlign, TextDecoration, TextShadow, TextStyle,
+    TextTransform,
+};
+
+/// Text style of which all inherited values have been resolved.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ResolvedTextStyle {
+    /// This is the color that is used to fill the text outlines.
+    pub color: Color,
+
+    /// This is the font family that is used to render the text.
+    pub font_family: FontFamily,
+
+    /// This is the font weight that is used to render the text.
+    pub font_weight: FontWeight,
+
+    /// This is the font style that is used to render the text.
+    pub font_style: FontStyle,
+
+    /// This is the font size in points. Named font sizes are relative to the
+    /// medium font size (16 points) and percentages are relative to the font
+    /// size of the parent.
+    pub font_size: f32,
+
+    /// This is the alignment of the paragraph that the text is part of.
+    pub text_align: TextAlign,
+
+    /// This is the distance between the baselines of successive lines.
+    /// Percentages are resolved to points relative to the font size of the
+    /// text that specifies them, whereas numbers are inherited as is.
+    pub line_height: LineHeight,
+
+    /// This is the additional space (in points) between characters.
+    pub letter_spacing: f32,
+
+    /// These are the lines that are drawn under or through the text.
+    pub text_decoration: TextDecoration,
+
+    /// This is the capitalization of the text. It has already been applied to
+    /// the text of attributed runs.
+    pub text_transform: TextTransform,
+
+    /// This is the maximum number of lines of the paragraph that the text is
+    /// part of, or 0 if the number of lines is unlimited.
+    pub number_of_lines: usize,
+
+    /// This is the location of the ellipsis if the paragraph that the text is
+    /// part of needs to be truncated.
+    pub ellipsis_mode: EllipsisMode,
+
+    /// This is the shadow that is drawn behind the text, if any.
+    pub text_shadow: Option<TextShadow>,
+}
+
+impl ResolvedTextStyle {
+    /// Returns the style of a child that has the given (partial) style and
+    /// inherits its remaining properties from this style.
+    pub fn cascade(&self, style: &TextStyle) -> ResolvedTextStyle {
+        let font_size = match style.font_size {
+            Inherited::Inherited => self.font_size,
+            Inherited::Specified(size) => resolve_font_size(size, self.font_size),
+        };
+
+        ResolvedTextStyle {
+            color: inherit(style.color, self.color),
+            font_family: inherit(style.font_family, self.font_family),
+            font_weight: inherit(style.font_weight, self.font_weight),
+            font_style: inherit(style.font_style, self.font_style),
+            font_size,
+            text_align: inherit(style.text_align, self.text_align),
+            line_height: match style.line_height {
+                Inherited::Specified(LineHeight::Dimension(Dimension::Percentage(percentage))) => {
+                    LineHeight::Dimension(Dimension::Points(percentage * font_size))
+                }
+                line_height => inherit(line_height, self.line_height),
+            },
+            letter_spacing: inherit(style.letter_spacing, self.letter_spacing),
+            text_decoration: inherit(style.text_decoration, self.text_decoration),
+            text_transform: inherit(style.text_transform, self.text_transform),
+            number_of_lines: inherit(style.number_of_lines, self.number_of_lines),
+            ellipsis_mode: inherit(style.ellipsis_mode, self.ellipsis_mode),
+            text_shadow: inherit(style.text_shadow, self.text_shadow),
+        }
+    }
+
+    /// Returns the distance between the baselines of successive lines in
+    /// points, or `None` if it should be determined by the metrics of the
+    /// font.
+    pub fn line_height_points(&self) -> Option<f32> {
+        self.line_height.to_points(self.font_size)
+    }
+
+    /// Returns the font that is used to render text in this style.
+    pub fn font(&self) -> Font {
+        Font {
+            family: self.font_family,
+            weight: self.font_weight,
+            style: self.font_style,
+            size: FontSize::Dimension(Dimension::Points(self.font_size)),
+        }
+    }
+}
+
+impl Default for ResolvedTextStyle {
+    fn default() -> Self {
+        ResolvedTextStyle {
+            color: Color::black(),
+            font_family: FontFamily::Generic(GenericFontFamily::SansSerif),
+            font_weight: FontWeight::Normal,
+            font_style: FontStyle::Normal,
+            font_size: 16.0,
+            text_align: TextAlign::Left,
+            line_height: LineHeight::Normal,
+            letter_spacing: 0.0,
+            text_decoration: TextDecoration::none(),
+            text_transform: TextTransform::None,
+            number_of_lines: 0,
+            ellipsis_mode: EllipsisMode::Tail,
+            text_shadow: None,
+        }
+    }
+}
+
+fn inherit<T>(value: Inherited<T>, parent: T) -> T {
+    match value {
+        Inherited::Inherited => parent,
+        Inherited::Specified(value) => value,
+    }
+}
+
+fn resolve_font_size(size: FontSize, parent: f32) -> f32 {
+    match size {
+        FontSize::ExtraExtraSmall => 16.0 * 3.0 / 5.0,
+        FontSize::ExtraSmall => 16.0 * 3.0 / 4.0,
+        FontSize::Small => 16.0 * 8.0 / 9.0,
+        FontSize::Medium => 16.0,
+        FontSize::Large => 16.0 * 1.2,
+        FontSize::ExtraLarge => 16.0 * 1.5,
+        FontSize::ExtraExtraLarge => 16.0 * 2.0,
+        FontSize::ExtraExtraExtraLarge => 16.0 * 3.0,
+        FontSize::Dimension(Dimension::Points(size)) => size,
+        FontSize::Dimension(Dimension::Percentage(percentage)) => parent * percentage,
+        FontSize::Dimension(_) => 0.0,
+    }
+}
+
+/// Run of text within attributed text that has a single style and press
+/// listener.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AttributedRun<L = EventListener<()>> {
+    /// This is the text of this run.
+    pub text: String,
+
+    /// This is the resolved style of this run.
+    pub style: ResolvedTextStyle,
+
+    /// This is the listener that should be called when the user presses this
+    /// run. Nested texts without a listener of their own inherit the listener
+    /// of their parent.
+    pub on_press: L,
+}
+
+/// Platform-independent model of (rich) text that consists of runs with a
+/// single style each. This is obtained by flattening a tree of nested texts.
+/// Press listeners can't be sent to other threads, so platforms that need to
+/// (e.g. to measure text during layout) use text without listeners (see
+/// [`AttributedText::without_listeners`]).
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AttributedText<L = EventListener<()>> {
+    runs: Vec<AttributedRun<L>>,
+    text_align: TextAlign,
+    number_of_lines: usize,
+    ellipsis_mode: EllipsisMode,
+}
+
+impl AttributedText {
+    /// Flattens the given text and its children into attributed text. Strings
+    /// within nested texts inherit the style of their ancestors. Children
+    /// that are not texts or strings are ignored.
+    pub fn new<P>(text: &Text, children: &Element<P>) -> AttributedText
+    where
+        P: Platform + ?Sized,
+    {
+        AttributedText::with_base(&ResolvedTextStyle::default(), text, children)
+    }
+
+    /// Flattens the given text and its children into attributed text, where
+    /// properties that the outermost text doesn't specify are inherited from
+    /// the given base style rather than resolved to their defaults.
+    pub fn with_base<P>(
+        base: &ResolvedTextStyle,
+        text: &Text,
+        children: &Element<P>,
+    ) -> AttributedText
+    where
+        P: Platform + ?Sized,
+    {
+        fn flatten<P>(builder: &mut AttributedTextBuilder, element: &Element<P>)
+        where
+            P: Platform + ?Sized,
+        {
+            match element {
+                Element::Component(component) => {
+                    if let Some(text) = component.component.downcast_ref::<Text>() {
+                        builder.push_style(&text.style, &text.on_press);
+                        flatten(builder, &component.children);
+                        builder.pop_style();
+                    }
+                }
+                Element::String(string) => builder.push_str(string),
+                Element::Fragment(fragment) => {
+                    for element in &fragment.elements {
+                        flatten(builder, element);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        let mut builder = AttributedTextBuilder::with_base(base, &text.style, &text.on_press);
+        flatten(&mut builder, children);
+        builder.build()
+    }
+
+    /// Returns this text without its press listeners. Adjacent runs that only
+    /// differ in their listeners are merged.
+    pub fn without_listeners(&self) -> AttributedText<()> {
+        let mut runs: Vec<AttributedRun<()>> = vec![];
+
+        for run in &self.runs {
+            match runs.last_mut() {
+                Some(last) if last.style == run.style => last.text.push_str(&run.text),
+                _ => runs.push(AttributedRun {
+                    text: run.text.clone(),
+                    style: run.style,
+                    on_press: (),
+                }),
+            }
+        }
+
+        AttributedText {
+            runs,
+            text_align: self.text_align,
+            number_of_lines: self.number_of_lines,
+            ellipsis_mode: self.ellipsis_mode,
+        }
+    }
+}
+
+impl<L> AttributedText<L> {
+    /// Returns the runs of this text.
+    pub fn runs(&self) -> &[AttributedRun<L>] {
+        &self.runs
+    }
+
+    /// Returns the alignment of this text, which is determined by the
+    /// outermost text.
+    pub fn text_align(&self) -> TextAlign {
+        self.text_align
+    }
+
+    /// Returns the maximum number of lines of this text (or 0 if the number of
+    /// lines is unlimited), which is determined by the outermost text.
+    pub fn number_of_lines(&self) -> usize {
+        self.number_of_lines
+    }
+
+    /// Returns the location of the ellipsis if this text needs to be
+    /// truncated, which is determined by the outermost text.
+    pub fn ellipsis_mode(&self) -> EllipsisMode {
+        self.ellipsis_mode
+    }
+
+    /// Returns the concatenated text of all runs.
+    pub fn to_plain_string(&self) -> String {
+        self.runs.iter().map(|run| run.text.as_str()).collect()
+    }
+}
+
+/// Builder that flattens nested texts into attributed text.
+pub struct AttributedTextBuilder {
+    stack: Vec<(ResolvedTextStyle, EventListener<()>)>,
+    runs: Vec<AttributedRun>,
+}
+
+impl AttributedTextBuilder {
+    /// Returns a new builder for text with the given outermost style and press
+    /// listener. Properties that are not specified by that style are resolved
+    /// to their defaults.
+    pub fn new(style: &TextStyle, on_press: &EventListener<()>) -> AttributedTextBuilder {
+        AttributedTextBuilder::with_base(&ResolvedTextStyle::default(), style, on_press)
+    }
+
+    /// Returns a new builder for text with the given outermost style and press
+    /// listener. Properties that are not specified by that style are inherited
+    /// from the given base style.
+    pub fn with_base(
+        base: &ResolvedTextStyle,
+        style: &TextStyle,
+        on_press: &EventListener<()>,
+    ) -> AttributedTextBuilder {
+        let style = base.cascade(style);
+
+        AttributedTextBuilder {
+            stack: vec![(style, on_press.clone())],
+            runs: vec![],
+        }
+    }
+
+    /// Enters a nested text with the given style and press listener.
+    pub fn push_style(&mut self, style: &TextStyle, on_press: &EventListener<()>) {
+        let (parent, listener) = self.current();
+
+        let style = parent.cascade(style);
+        let on_press = if on_press.is_some() {
+            on_press.clone()
+        } else {
+            listener.clone()
+        };
+
+        self.stack.push((style, on_press));
+    }
+
+    /// Leaves the nested text that was most recently entered.
+    pub fn pop_style(&mut self) {
+        assert!(self.stack.len() > 1, "The outermost text cannot be left.");
+        self.stack.pop();
+    }
+
+    /// Appends the given string in the style of the current text. The text
+    /// transform of that style is applied to the string.
+    pub fn push_str(&mut self, text: &str) {
+        if text.is_empty() {
+            return;
+        }
+
+        let (style, on_press) = self.current().clone();
+
+        // Capitalization depends on whether the previous run ends mid-word.
+        let previous = self.runs.last().and_then(|run| run.text.chars().last());
+        let text = style.text_transform.apply(text, previous);
+
+        // Adjacent runs can only be merged if neither of them can be pressed,
+        // because listeners cannot be compared.
+        if let Some(last) = self.runs.last_mut() {
+            if last.style == style && last.on_press.is_none() && on_press.is_none() {
+                last.text.push_str(&text);
+                return;
+            }
+        }
+
+        self.runs.push(AttributedRun {
+            text,
+            style,
+            on_press,
+        });
+    }
+
+    /// Returns the attributed text that has been built.
+    pub fn build(self) -> AttributedText {
+        let (style, _) = &self.stack[0];
+
+        AttributedText {
+            text_align: style.text_align,
+            number_of_lines: style.number_of_lines,
+            ellipsis_mode: style.ellipsis_mode,
+            runs: self.runs,
+        }
+    }
+
+    fn current(&self) -> &(ResolvedTextStyle, EventListener<()>) {
+        self.stack.last().unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    use super::{AttributedTextBuilder, ResolvedTextStyle};
+    use crate::color::{Color, NamedColor};
+    use crate::events::EventListener;
+    use crate::font::{FontSize, FontWeight};
+    use crate::geometry::Dimension;
+    use crate::styles::{Inherited, LineHeight, TextAlign, TextStyle, TextTransform};
+
+    #[test]
+    fn test_cascade() {
+        let parent = ResolvedTextStyle::default().cascade(&TextStyle {
+            font_size: Inherited::Specified(FontSize::Dimension(Dimension::Points(20.0))),
+            ..Default::default()
+        });
+
+        let child = parent.cascade(&TextStyle {
+            color: Inherited::Specified(Color::red()),
+            font_size: Inherited::Specified(FontSize::Dimension(Dimension::Percentage(0.5))),
+            ..Default::default()
+        });
+
+        assert_eq!(
+            child,
+            ResolvedTextStyle {
+                color: Color::red(),
+                font_size: 10.0,
+                ..Default::default()
+            }
+        );
+
+        assert_eq!(
+            parent.cascade(&TextStyle {
+                font_size: Inherited::Specified(FontSize::Large),
+                ..Default::default()
+            }),
+            ResolvedTextStyle {
+                font_size: 19.2,
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn test_cascade_line_height() {
+        let parent = ResolvedTextStyle::default().cascade(&TextStyle {
+            line_height: Inherited::Specified(LineHeight::Dimension(Dimension::Percentage(1.5))),
+            ..Default::default()
+        });
+
+        let child = parent.cascade(&TextStyle {
+            font_size: Inherited::Specified(FontSize::Dimension(Dimension::Points(32.0))),
+            ..Default::default()
+        });
+
+        // Percentages are resolved against the font size of the parent,
+        // whereas numbers are resolved against the font size of each child.
+        assert_eq!(child.line_height_points(), Some(24.0));
+
+        let parent = ResolvedTextStyle::default().cascade(&TextStyle {
+            line_height: Inherited::Specified(LineHeight::Number(1.5)),
+            ..Default::default()
+        });
+
+        let child = parent.cascade(&TextStyle {
+            font_size: Inherited::Specified(FontSize::Dimension(Dimension::Points(32.0))),
+            ..Default::default()
+        });
+
+        assert_eq!(child.line_height_points(), Some(48.0));
+    }
+
+    #[test]
+    fn test_text_transform() {
+        let mut builder = AttributedTextBuilder::new(
+            &TextStyle {
+                text_transform: Inherited::Specified(TextTransform::Capitalize),
+                ..Default::default()
+            },
+            &Default::default(),
+        );
+
+        builder.push_str("hello wor");
+        builder.push_style(
+            &TextStyle {
+                font_weight: Inherited::Specified(FontWeight::Bold),
+                ..Default::default()
+            },
+            &Default::default(),
+        );
+        builder.push_str("ld, and");
+        builder.pop_style();
+        builder.push_style(
+            &TextStyle {
+                text_transform: Inherited::Specified(TextTransform::Uppercase),
+                ..Default::default()
+            },
+            &Default::default(),
+        );
+        builder.push_str(" goodbye");
+
+        // Words that continue in the next run are not capitalized again.
+        assert_eq!(
+            builder.build().to_plain_string(),
+            "Hello World, And GOODBYE"
+        );
+    }
+
+    #[test]
+    fn test_builder() {
+        let pressed = Rc::new(Cell::new(0));
+        let on_press = EventListener::new({
+            let pressed = pressed.clone();
+            move |_| pressed.set(pressed.get() + 1)
+        });
+
+        let bold = TextStyle {
+            font_weight: Inherited::Specified(FontWeight::Bold),
+            ..Default::default()
+        };
+
+        let mut builder = AttributedTextBuilder::new(
+            &TextStyle {
+                color: Inherited::Specified(Color::blue()),
+                text_align: Inherited::Specified(TextAlign::Center),
+                ..Default::default()
+            },
+            &Default::default(),
+        );
+
+        builder.push_str("Hello ");
+        builder.push_style(&Default::default(), &Default::default());
+        builder.push_str("world, ");
+        builder.pop_style();
+        builder.push_style(&Default::default(), &on_press);
+        builder.push_str("this is ");
+        builder.push_style(&bold, &Default::default());
+        builder.push_str("a link");
+        builder.pop_style();
+        builder.pop_style();
+        builder.push_str("");
+
+        let text = builder.build();
+
+        assert_eq!(text.to_plain_string(), "Hello world, this is a link");
+        assert_eq!(text.text_align(), TextAlign::Center);
+
+        // Runs with the same style are merged unless they can be pressed.
+        let runs = text
+            .runs()
+            .iter()
+            .map(|run| {
+                (
+                    run.text.as_str(),
+                    run.style.font_weight,
+                    run.on_press.is_some(),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            runs,
+            vec![
+                ("Hello world, ", FontWeight::Normal, false),
+                ("this is ", FontWeight::Normal, true),
+                ("a link", FontWeight::Bold, true),
+            ]
+        );
+
+        assert!(text
+            .runs()
+            .iter()
+            .all(|run| run.style.color == Color::blue()));
+
+        // Nested texts inherit the listener of their parent.
+        text.runs()[2].on_press.emit(());
+        assert_eq!(pressed.get(), 1);
+
+        // Runs are merged once their listeners are dropped.
+        let runs = text
+            .without_listeners()
+            .runs()
+            .iter()
+            .map(|run| (run.text.clone(), run.style.font_weight))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            runs,
+            vec![
+                ("Hello world, this is ".to_owned(), FontWeight::Normal),
+                ("a link".to_owned(), FontWeight::Bold),
+            ]
+        );
+    }
+}
@@ -0,0 +1,405 @@
+use ab_glyph::FontArc;
+use std::fmt::{Display, Formatter, Result};
+use std::path::Path;
+use ttf_parser::name_id;
+
+use crate::font::{FontFamily, FontStyle, FontWeight, GenericFontFamily};
+
+/// Error that is returned when font data can't be parsed.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct InvalidFont;
+
+impl Display for InvalidFont {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        f.write_str("invalid font data")
+    }
+}
+
+impl std::error::Error for InvalidFont {}
+
+/// Directories that contain the fonts that are installed on common Linux
+/// distributions, macOS and Windows. Directories that are relative are
+/// relative to the home directory of the user.
+const SYSTEM_DIRECTORIES: &[&str] = &[
+    "/usr/share/fonts",
+    "/usr/local/share/fonts",
+    ".local/share/fonts",
+    ".fonts",
+    "/System/Library/Fonts",
+    "/Library/Fonts",
+    "Library/Fonts",
+    "C:\\Windows\\Fonts",
+];
+
+/// Installed families that the generic families are registered with, in order
+/// of preference.
+const GENERIC_FAMILIES: &[(&str, &[&str])] = &[
+    (
+        "sans-serif",
+        &[
+            "DejaVu Sans",
+            "Liberation Sans",
+            "Noto Sans",
+            "Helvetica",
+            "Arial",
+        ],
+    ),
+    (
+        "serif",
+        &[
+            "DejaVu Serif",
+            "Liberation Serif",
+            "Noto Serif",
+            "Times",
+            "Times New Roman",
+        ],
+    ),
+    (
+        "monospace",
+        &[
+            "DejaVu Sans Mono",
+            "Liberation Mono",
+            "Noto Sans Mono",
+            "Menlo",
+            "Courier New",
+        ],
+    ),
+];
+
+#[derive(Clone)]
+struct Face {
+    family: String,
+    weight: f32,
+    slanted: bool,
+
+    /// This is a boolean that indicates if this face is narrower or wider than
+    /// usual (e.g. condensed), which are only selected as a last resort.
+    stretched: bool,
+
+    font: FontArc,
+}
+
+/// Collection of fonts that text can be measured and painted with. Generic
+/// font families are looked up by their CSS name (e.g. `sans-serif`).
+#[derive(Clone, Default)]
+pub struct Fonts {
+    faces: Vec<Face>,
+}
+
+impl Fonts {
+    /// Returns a new collection without any fonts. Text that is rendered with
+    /// an empty collection has zero size and is not painted.
+    pub fn new() -> Fonts {
+        Default::default()
+    }
+
+    /// Returns a new collection with the fonts that are installed on this
+    /// system (if any). The generic serif, sans-serif and monospace families
+    /// are registered with the first installed family of a few common
+    /// families (e.g. DejaVu, Liberation or Noto). Note that this reads every
+    /// font file that is installed, so the collection is best shared (e.g. in
+    /// an `Arc`) rather than loaded repeatedly.
+    pub fn system() -> Fonts {
+        let mut fonts = Fonts::new();
+        let home = std::env::var_os("HOME").or_else(|| std::env::var_os("USERPROFILE"));
+
+        for directory in SYSTEM_DIRECTORIES {
+            let path = Path::new(directory);
+
+            if path.is_absolute() {
+                fonts.load_directory(path);
+            } else if let Some(home) = &home {
+                fonts.load_directory(Path::new(home).join(path));
+            }
+        }
+
+        for (generic, families) in GENERIC_FAMILIES {
+            for family in families.iter() {
+                if fonts.alias(generic, family) {
+                    break;
+                }
+            }
+        }
+
+        fonts
+    }
+
+    /// Registers the given TrueType or OpenType font data with the given
+    /// family, weight and style.
+    pub fn insert(
+        &mut self,
+        family: &str,
+        weight: FontWeight,
+        style: FontStyle,
+        data: Vec<u8>,
+    ) -> std::result::Result<(), InvalidFont> {
+        let font = FontArc::try_from_vec(data).map_err(|_| InvalidFont)?;
+
+        self.faces.push(Face {
+            family: family.to_owned(),
+            weight: numeric_weight(weight),
+            slanted: style != FontStyle::Normal,
+            stretched: false,
+            font,
+        });
+
+        Ok(())
+    }
+
+    /// Registers the given TrueType or OpenType font data (e.g. a font that is
+    /// bundled with an app) with the family, weight and style that are stored
+    /// in the font itself.
+    pub fn load(&mut self, data: Vec<u8>) -> std::result::Result<(), InvalidFont> {
+        let face = ttf_parser::Face::parse(&data, 0).map_err(|_| InvalidFont)?;
+
+        // The typographic family groups more than 4 styles (e.g. light and
+        // black) into a single family, which is why it takes precedence.
+        let family = [name_id::TYPOGRAPHIC_FAMILY, name_id::FAMILY]
+            .iter()
+            .find_map(|&id| {
+                face.names()
+                    .into_iter()
+                    .find(|name| name.name_id == id && name.is_unicode())
+                    .and_then(|name| name.to_string())
+            })
+            .ok_or(InvalidFont)?;
+
+        let weight = FontWeight::Number(face.weight().to_number() as f32 / 1000.0);
+        let style = if face.is_italic() {
+            FontStyle::Italic
+        } else if face.is_oblique() {
+            FontStyle::Oblique
+        } else {
+            FontStyle::Normal
+        };
+
+        let stretched = face.width() != ttf_parser::Width::Normal;
+
+        self.insert(&family, weight, style, data)?;
+        self.faces.last_mut().unwrap().stretched = stretched;
+
+        Ok(())
+    }
+
+    /// Loads every TrueType and OpenType font in the given directory and its
+    /// subdirectories (e.g. the assets of a package) and returns the number
+    /// of fonts that were loaded. Files that can't be read or parsed are
+    /// skipped.
+    pub fn load_directory(&mut self, path: impl AsRef<Path>) -> usize {
+        let entries = match std::fs::read_dir(path) {
+            Ok(entries) => entries,
+            Err(_) => return 0,
+        };
+
+        let mut paths = entries
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .collect::<Vec<_>>();
+
+        // Faces are selected in the order in which they are registered, so
+        // this needs to be deterministic.
+        paths.sort();
+
+        let mut count = 0;
+
+        for path in paths {
+            if path.is_dir() {
+                count += self.load_directory(&path);
+                continue;
+            }
+
+            let extension = path
+                .extension()
+                .and_then(|extension| extension.to_str())
+                .map(|extension| extension.to_ascii_lowercase());
+
+            if !matches!(extension.as_deref(), Some("ttf") | Some("otf")) {
+                continue;
+            }
+
+            if let Ok(data) = std::fs::read(&path) {
+                if self.load(data).is_ok() {
+                    count += 1;
+                }
+            }
+        }
+
+        count
+    }
+
+    /// Loads every TrueType and OpenType font in the `assets/` folder of the
+    /// package with the given manifest directory (i.e.
+    /// `env!("CARGO_MANIFEST_DIR")`) and returns the number of fonts that were
+    /// loaded.
+    pub fn load_assets(&mut self, manifest_dir: impl AsRef<Path>) -> usize {
+        self.load_directory(manifest_dir.as_ref().join("assets"))
+    }
+
+    /// Registers every face of the given family with the given alias as well
+    /// (e.g. to use an installed family as a generic family) and returns a
+    /// boolean that indicates if the family contains any faces.
+    pub fn alias(&mut self, alias: &str, family: &str) -> bool {
+        let faces = self
+            .faces
+            .iter()
+            .filter(|face| face.family.eq_ignore_ascii_case(family))
+            .map(|face| Face {
+                family: alias.to_owned(),
+                ..face.clone()
+            })
+            .collect::<Vec<_>>();
+
+        let found = !faces.is_empty();
+        self.faces.extend(faces);
+        found
+    }
+
+    /// Returns a boolean that indicates if this collection does not contain
+    /// any fonts.
+    pub fn is_empty(&self) -> bool {
+        self.faces.is_empty()
+    }
+
+    /// Returns the font that best matches the given family, weight and style.
+    /// If the family is not available, this falls back to the generic
+    /// sans-serif family and finally to any available family.
+    pub fn select(
+        &self,
+        family: FontFamily,
+        weight: FontWeight,
+        style: FontStyle,
+    ) -> Option<&FontArc> {
+        let name = match family {
+            FontFamily::Generic(GenericFontFamily::Serif) => "serif",
+            FontFamily::Generic(GenericFontFamily::SansSerif) => "sans-serif",
+            FontFamily::Generic(GenericFontFamily::Monospace) => "monospace",
+            FontFamily::Named(name) => name,
+        };
+
+        let weight = numeric_weight(weight);
+        let slanted = style != FontStyle::Normal;
+
+        let candidates = [name, "sans-serif"]
+            .iter()
+            .map(|name| {
+                self.faces
+                    .iter()
+                    .filter(|face| face.family.eq_ignore_ascii_case(name))
+                    .collect::<Vec<_>>()
+            })
+            .find(|faces| !faces.is_empty())
+            .unwrap_or_else(|| self.faces.iter().collect());
+
+        // A face with the right style is always preferred over a face with
+        // the right weight, and any face with a normal width is preferred over
+        // a face that is stretched.
+        candidates
+            .into_iter()
+            .min_by(|a, b| {
+                let cost = |face: &Face| {
+                    (face.weight - weight).abs()
+                        + if face.slanted != slanted { 1000.0 } else { 0.0 }
+                        + if face.stretched { 2000.0 } else { 0.0 }
+                };
+
+                cost(a).partial_cmp(&cost(b)).unwrap()
+            })
+            .map(|face| &face.font)
+    }
+}
+
+/// Returns the CSS numeric value of the given font weight.
+fn numeric_weight(weight: FontWeight) -> f32 {
+    match weight {
+        FontWeight::Number(weight) => (weight * 1000.0).clamp(1.0, 1000.0),
+        FontWeight::Thin => 100.0,
+        FontWeight::ExtraLight => 200.0,
+        FontWeight::Light => 300.0,
+        FontWeight::Normal => 400.0,
+        FontWeight::Medium => 500.0,
+        FontWeight::SemiBold => 600.0,
+        FontWeight::Bold => 700.0,
+        FontWeight::ExtraBold => 800.0,
+        FontWeight::Black => 900.0,
+    }
+}
+
+/// Returns a collection with the test fonts, which contain a rectangular glyph
+/// for each printable ASCII character and kern "AV".
+#[cfg(test)]
+pub(crate) fn test_fonts() -> Fonts {
+    let mut fonts = Fonts::new();
+    let regular = include_bytes!("../../tests/fonts/PolyhornTest-Regular.ttf");
+    let bold = include_bytes!("../../tests/fonts/PolyhornTest-Bold.ttf");
+    fonts.load(regular.to_vec()).unwrap();
+    fonts.load(bold.to_vec()).unwrap();
+    fonts
+}
+
+#[cfg(test)]
+mod tests {
+    use ab_glyph::Font;
+
+    use super::{test_fonts, Fonts};
+    use crate::font::{FontFamily, FontStyle, FontWeight, GenericFontFamily};
+
+    #[test]
+    fn test_select() {
+        let fonts = test_fonts();
+
+        let sans = FontFamily::Generic(GenericFontFamily::SansSerif);
+        let regular = fonts.select(sans, FontWeight::Normal, FontStyle::Normal);
+        let bold = fonts.select(sans, FontWeight::SemiBold, FontStyle::Normal);
+        let missing = fonts.select(
+            FontFamily::Named("Missing"),
+            FontWeight::Normal,
+            FontStyle::Normal,
+        );
+
+        let advance = |font: Option<&ab_glyph::FontArc>| {
+            let font = font.unwrap();
+            font.h_advance_unscaled(font.glyph_id('m'))
+        };
+
+        // The bold face is wider than the regular face.
+        assert_eq!(advance(regular), 900.0);
+        assert_eq!(advance(bold), 1000.0);
+        assert_eq!(advance(missing), advance(regular));
+    }
+
+    #[test]
+    fn test_load_directory() {
+        let mut fonts = Fonts::new();
+
+        let count = fonts.load_directory(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fonts"));
+        assert_eq!(count, 2);
+
+        // Fonts are registered with their own family name, weight and style.
+        let family = FontFamily::Named("Polyhorn Test");
+        let regular = fonts.select(family, FontWeight::Normal, FontStyle::Normal);
+        let bold = fonts.select(family, FontWeight::Bold, FontStyle::Normal);
+
+        let advance = |font: Option<&ab_glyph::FontArc>| {
+            let font = font.unwrap();
+            font.h_advance_unscaled(font.glyph_id('m'))
+        };
+
+        assert!(advance(bold) > advance(regular));
+        assert!(fonts.load(vec![0; 16]).is_err());
+    }
+
+    #[test]
+    fn test_alias() {
+        let mut fonts = test_fonts();
+
+        assert!(fonts.alias("serif", "polyhorn test"));
+        assert!(!fonts.alias("monospace", "Missing"));
+
+        let serif = FontFamily::Generic(GenericFontFamily::Serif);
+        let bold = fonts.select(serif, FontWeight::Bold, FontStyle::Normal);
+        assert_eq!(
+            bold.unwrap()
+                .h_advance_unscaled(bold.unwrap().glyph_id('m')),
+            1000.0
+        );
+    }
+}
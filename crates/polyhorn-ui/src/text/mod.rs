@@ -0,0 +1,23 @@
+//! Platform-independent model of (rich) text. With the `text` feature, this
+//! also implements measurement and line breaking of text. This makes it
+//! possible to lay out text without asking the platform (e.g. in headless
+//! renderers and tests).
+//!
+//! Note that text is not shaped: every character maps to a single glyph of its
+//! font, which is kerned with the previous glyph. This means that ligatures,
+//! complex scripts and bidirectional text are not supported.
+
+mod attributed;
+#[cfg(feature = "text")]
+mod fonts;
+#[cfg(feature = "text")]
+mod paragraph;
+
+#[cfg(feature = "text")]
+pub use ab_glyph;
+
+pub use attributed::{AttributedRun, AttributedText, AttributedTextBuilder, ResolvedTextStyle};
+#[cfg(feature = "text")]
+pub use fonts::{Fonts, InvalidFont};
+#[cfg(feature = "text")]
+pub use paragraph::{measure_func, Line, Paragraph, PositionedGlyph};
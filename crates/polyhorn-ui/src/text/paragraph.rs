@@ -0,0 +1,656 @@
+use ab_glyph::{Font, FontArc, GlyphId, PxScale, ScaleFont};
+use std::sync::Arc;
+
+use super::{AttributedText, Fonts, ResolvedTextStyle};
+use crate::color::Color;
+use crate::geometry::{Dimension, Point, Size};
+use crate::layout::MeasureFunc;
+use crate::styles::{EllipsisMode, TextAlign};
+
+/// Lines are allowed to exceed the maximum width by this amount to account for
+/// rounding errors between measuring and painting a paragraph.
+const EPSILON: f32 = 0.01;
+
+/// Run of text with a single font and color.
+struct Run {
+    font: Option<FontArc>,
+    scale: PxScale,
+    color: Color,
+    letter_spacing: f32,
+    line_height: Option<f32>,
+}
+
+impl Run {
+    fn new(fonts: &Fonts, style: &ResolvedTextStyle) -> Run {
+        let font = fonts
+            .select(style.font_family, style.font_weight, style.font_style)
+            .cloned();
+
+        // A CSS font size refers to the size of the em square, whereas the
+        // scale of a font refers to the distance between its ascent and
+        // descent.
+        let scale = match &font {
+            Some(font) => {
+                style.font_size * font.height_unscaled() / font.units_per_em().unwrap_or(1000.0)
+            }
+            None => 0.0,
+        };
+
+        Run {
+            font,
+            scale: PxScale::from(scale),
+            color: style.color,
+            letter_spacing: style.letter_spacing,
+            line_height: style.line_height_points(),
+        }
+    }
+
+    /// Returns the distance that this run occupies above and below the
+    /// baseline. Like CSS, the difference between the line height and the
+    /// height of the font is split evenly between both sides.
+    fn metrics(&self) -> (f32, f32) {
+        let (ascent, descent, line_gap) = match &self.font {
+            Some(font) => {
+                let font = font.as_scaled(self.scale);
+                (font.ascent(), font.descent(), font.line_gap())
+            }
+            None => (0.0, 0.0, 0.0),
+        };
+
+        let height = ascent - descent;
+        let leading = self.line_height.unwrap_or(height + line_gap) - height;
+
+        (ascent + leading / 2.0, -descent + leading / 2.0)
+    }
+
+    /// Returns the item for the given character in this run, which is kerned
+    /// with the given previous glyph (if any). Every character maps to a
+    /// single glyph (see the `text` module).
+    fn item(&self, index: usize, character: char, previous: Option<GlyphId>) -> Item {
+        let (glyph, advance) = match &self.font {
+            Some(font) if !character.is_control() => {
+                let font = font.as_scaled(self.scale);
+                let glyph = font.glyph_id(character);
+                let kerning = previous.map_or(0.0, |previous| font.kern(previous, glyph));
+                (
+                    Some(glyph),
+                    font.h_advance(glyph) + kerning + self.letter_spacing,
+                )
+            }
+            _ => (None, 0.0),
+        };
+
+        Item {
+            run: index,
+            character,
+            glyph,
+            advance,
+        }
+    }
+}
+
+/// Character within a paragraph along with its run and horizontal advance.
+struct Item {
+    run: usize,
+    character: char,
+    glyph: Option<GlyphId>,
+    advance: f32,
+}
+
+/// Glyph that has been positioned horizontally within a line.
+struct Positioned {
+    run: usize,
+    glyph: GlyphId,
+    x: f32,
+
+    /// This is the number of gaps between words that precede this glyph,
+    /// which is used to justify the line.
+    gaps: usize,
+}
+
+/// Single line of a paragraph.
+pub struct Line {
+    glyphs: Vec<Positioned>,
+    width: f32,
+    ascent: f32,
+    height: f32,
+    gaps: usize,
+    wrapped: bool,
+}
+
+impl Line {
+    /// Returns the width of this line, excluding trailing whitespace.
+    pub fn width(&self) -> f32 {
+        self.width
+    }
+
+    /// Returns the height of this line, including its line gap.
+    pub fn height(&self) -> f32 {
+        self.height
+    }
+
+    /// Returns true if this line ends because the next word did not fit
+    /// (rather than because of a line break or the end of the paragraph).
+    pub fn is_wrapped(&self) -> bool {
+        self.wrapped
+    }
+}
+
+/// Paragraph of styled text that has been broken into lines.
+pub struct Paragraph {
+    runs: Vec<Run>,
+    lines: Vec<Line>,
+    align: TextAlign,
+}
+
+impl Paragraph {
+    /// Lays out the given text with the given fonts. If a maximum width is
+    /// given, lines are broken at whitespace so that they do not exceed that
+    /// width (unless a single word is wider). If the text limits the number of
+    /// lines, the remaining lines are dropped and the last line is truncated
+    /// according to its ellipsis mode.
+    pub fn new<L>(fonts: &Fonts, text: &AttributedText<L>, max_width: Option<f32>) -> Paragraph {
+        let mut runs = vec![];
+        let mut items = vec![];
+
+        for attributed in text.runs() {
+            let index = runs.len();
+            let run = Run::new(fonts, &attributed.style);
+            let mut previous = None;
+
+            for character in attributed.text.chars() {
+                let item = run.item(index, character, previous);
+                previous = item.glyph;
+                items.push(item);
+            }
+
+            runs.push(run);
+        }
+
+        let ellipsis;
+        let max_width = max_width.map(|width| width + EPSILON);
+        let mut lines = break_lines(&items, max_width);
+
+        let number_of_lines = text.number_of_lines();
+
+        if number_of_lines != 0 && lines.len() > number_of_lines {
+            lines.truncate(number_of_lines);
+
+            let (line, fallback, wrapped) = lines.last_mut().unwrap();
+            let run = line.last().map_or(*fallback, |item| item.run);
+            *wrapped = false;
+
+            if text.ellipsis_mode() != EllipsisMode::Clip {
+                ellipsis = runs[run].item(run, '\u{2026}', None);
+                truncate(line, &ellipsis, text.ellipsis_mode(), max_width);
+            }
+        }
+
+        let lines = lines
+            .iter()
+            .map(|(line, fallback, wrapped)| layout_line(&runs, line, *fallback, *wrapped))
+            .collect();
+
+        Paragraph {
+            runs,
+            lines,
+            align: text.text_align(),
+        }
+    }
+
+    /// Returns the lines of this paragraph.
+    pub fn lines(&self) -> &[Line] {
+        &self.lines
+    }
+
+    /// Returns the size of the smallest box that contains all lines.
+    pub fn size(&self) -> Size<f32> {
+        Size::new(
+            self.lines.iter().map(Line::width).fold(0.0, f32::max),
+            self.lines.iter().map(Line::height).sum(),
+        )
+    }
+
+    /// Returns all glyphs in this paragraph if the paragraph is aligned within
+    /// a box of the given width.
+    pub fn glyphs(&self, width: f32) -> Vec<PositionedGlyph<'_>> {
+        let mut glyphs = vec![];
+        let mut top = 0.0;
+
+        for line in &self.lines {
+            let left = match self.align {
+                TextAlign::Left | TextAlign::Justify => 0.0,
+                TextAlign::Center => (width - line.width) / 2.0,
+                TextAlign::Right => width - line.width,
+            };
+
+            // Only lines that wrap are justified.
+            let spacing = match self.align {
+                TextAlign::Justify if line.wrapped && line.gaps != 0 => {
+                    (width - line.width).max(0.0) / line.gaps as f32
+                }
+                _ => 0.0,
+            };
+
+            let baseline = top + line.ascent;
+
+            for positioned in &line.glyphs {
+                let run = &self.runs[positioned.run];
+
+                if let Some(font) = &run.font {
+                    glyphs.push(PositionedGlyph {
+                        font,
+                        scale: run.scale,
+                        id: positioned.glyph,
+                        color: run.color,
+                        origin: Point {
+                            x: left + positioned.x + positioned.gaps as f32 * spacing,
+                            y: baseline,
+                        },
+                    });
+                }
+            }
+
+            top += line.height;
+        }
+
+        glyphs
+    }
+}
+
+/// Glyph within a paragraph that has been positioned relative to the top left
+/// corner of that paragraph.
+#[derive(Copy, Clone, Debug)]
+pub struct PositionedGlyph<'a> {
+    /// This is the font that this glyph should be drawn with.
+    pub font: &'a FontArc,
+
+    /// This is the scale at which this glyph should be drawn.
+    pub scale: PxScale,
+
+    /// This is the ID of this glyph within its font.
+    pub id: GlyphId,
+
+    /// This is the color of the run that this glyph is part of.
+    pub color: Color,
+
+    /// This is the position of the origin of this glyph, which lies on the
+    /// baseline of its line.
+    pub origin: Point<f32>,
+}
+
+/// Returns a measure function for the given text. Text is measured with the
+/// width that it's given (if any), rounded up so that it fits when it's
+/// painted.
+pub fn measure_func(fonts: Arc<Fonts>, text: AttributedText<()>) -> MeasureFunc {
+    MeasureFunc::Boxed(Arc::new(move |size| {
+        let max_width = match size.width {
+            Dimension::Points(width) => Some(width),
+            _ => None,
+        };
+
+        let size = Paragraph::new(&fonts, &text, max_width).size();
+        Size::new(size.width.ceil(), size.height.ceil())
+    }))
+}
+
+/// Breaks the given items into lines. Each line is returned along with the run
+/// that provides its metrics if it's empty and whether it wraps.
+fn break_lines(items: &[Item], max_width: Option<f32>) -> Vec<(Vec<&Item>, usize, bool)> {
+    let mut lines = vec![];
+    let mut line: Vec<&Item> = vec![];
+    let mut index = 0;
+
+    fn finish<'a>(
+        line: &mut Vec<&'a Item>,
+        lines: &mut Vec<(Vec<&'a Item>, usize, bool)>,
+        fallback: usize,
+        wrapped: bool,
+    ) {
+        lines.push((std::mem::take(line), fallback, wrapped));
+    }
+
+    while index < items.len() {
+        let item = &items[index];
+
+        if item.character == '\n' {
+            finish(&mut line, &mut lines, item.run, false);
+            index += 1;
+            continue;
+        }
+
+        // A chunk is either a sequence of whitespace or a word.
+        let whitespace = item.character.is_whitespace();
+        let end = items[index..]
+            .iter()
+            .position(|item| item.character == '\n' || item.character.is_whitespace() != whitespace)
+            .map_or(items.len(), |offset| index + offset);
+        let chunk = &items[index..end];
+
+        // Words that don't fit move to the next line. Whitespace before the
+        // break stays at the end of the previous line, where it doesn't count
+        // towards its width.
+        if !whitespace {
+            let width = line.iter().map(|item| item.advance).sum::<f32>();
+            let advance = chunk.iter().map(|item| item.advance).sum::<f32>();
+            let occupied = line.iter().any(|item| !item.character.is_whitespace());
+
+            if let Some(max_width) = max_width {
+                if occupied && width + advance > max_width {
+                    finish(&mut line, &mut lines, item.run, true);
+                }
+            }
+        }
+
+        line.extend(chunk);
+        index = end;
+    }
+
+    if !line.is_empty() || matches!(items.last(), Some(item) if item.character == '\n') {
+        let fallback = items.last().map_or(0, |item| item.run);
+        finish(&mut line, &mut lines, fallback, false);
+    }
+
+    lines
+}
+
+/// Removes items from the given line until the ellipsis fits within the
+/// maximum width and inserts the ellipsis where the items were removed.
+fn truncate<'a>(
+    line: &mut Vec<&'a Item>,
+    ellipsis: &'a Item,
+    mode: EllipsisMode,
+    max_width: Option<f32>,
+) {
+    let available = max_width.map_or(f32::INFINITY, |width| width - ellipsis.advance);
+    let width = |line: &Vec<&Item>| line.iter().map(|item| item.advance).sum::<f32>();
+
+    let trim_end = |line: &mut Vec<&Item>| {
+        while matches!(line.last(), Some(item) if item.character.is_whitespace()) {
+            line.pop();
+        }
+    };
+
+    trim_end(line);
+
+    match mode {
+        EllipsisMode::Clip => {}
+        EllipsisMode::Head => {
+            while !line.is_empty() && width(line) > available {
+                line.remove(0);
+            }
+
+            while matches!(line.first(), Some(item) if item.character.is_whitespace()) {
+                line.remove(0);
+            }
+
+            line.insert(0, ellipsis);
+        }
+        EllipsisMode::Middle => {
+            while !line.is_empty() && width(line) > available {
+                line.remove(line.len() / 2);
+            }
+
+            line.insert(line.len() / 2, ellipsis);
+        }
+        EllipsisMode::Tail => {
+            while !line.is_empty() && width(line) > available {
+                line.pop();
+            }
+
+            trim_end(line);
+            line.push(ellipsis);
+        }
+    }
+}
+
+fn layout_line(runs: &[Run], items: &[&Item], fallback: usize, wrapped: bool) -> Line {
+    let mut glyphs = vec![];
+    let mut x = 0.0;
+    let mut width = 0.0;
+    let mut gaps = 0;
+    let mut previous: Option<&Item> = None;
+
+    for item in items {
+        let whitespace = item.character.is_whitespace();
+
+        // A gap is whitespace between two words. Leading and trailing
+        // whitespace does not count.
+        if !whitespace && width > 0.0 {
+            if let Some(previous) = previous {
+                if previous.character.is_whitespace() {
+                    gaps += 1;
+                }
+            }
+        }
+
+        if let Some(glyph) = item.glyph {
+            glyphs.push(Positioned {
+                run: item.run,
+                glyph,
+                x,
+                gaps,
+            });
+        }
+
+        x += item.advance;
+
+        if !whitespace {
+            width = x;
+        }
+
+        previous = Some(item);
+    }
+
+    // Empty lines have the metrics of the run that they're part of.
+    let mut metrics = items
+        .iter()
+        .map(|item| runs[item.run].metrics())
+        .collect::<Vec<_>>();
+
+    if metrics.is_empty() {
+        metrics.extend(runs.get(fallback).map(Run::metrics));
+    }
+
+    let above = metrics.iter().map(|metrics| metrics.0).fold(0.0, f32::max);
+    let below = metrics.iter().map(|metrics| metrics.1).fold(0.0, f32::max);
+
+    Line {
+        glyphs,
+        width,
+        ascent: above,
+        height: above + below,
+        gaps,
+        wrapped,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::{measure_func, Paragraph};
+    use crate::geometry::{Dimension, Size};
+    use crate::layout::MeasureFunc;
+    use crate::styles::{EllipsisMode, Inherited, LineHeight, TextAlign, TextStyle};
+    use crate::text::fonts::test_fonts;
+    use crate::text::{AttributedText, AttributedTextBuilder};
+
+    fn text(style: &TextStyle, string: &str) -> AttributedText {
+        let mut builder = AttributedTextBuilder::new(style, &Default::default());
+        builder.push_str(string);
+        builder.build()
+    }
+
+    #[test]
+    fn test_paragraph() {
+        let fonts = test_fonts();
+
+        let string = "Hello world, this wraps";
+        let style = TextStyle::default();
+
+        let single = Paragraph::new(&fonts, &text(&style, string), None);
+        assert_eq!(single.lines().len(), 1);
+
+        let size = single.size();
+        assert!(size.width > 100.0 && size.height > 16.0 && size.height < 24.0);
+
+        // A narrower width wraps the text without exceeding that width.
+        let wrapped = Paragraph::new(&fonts, &text(&style, string), Some(size.width / 2.0));
+        assert!(wrapped.lines().len() > 1);
+        assert!(wrapped.size().width <= size.width / 2.0);
+        assert_eq!(
+            wrapped.size().height,
+            size.height * wrapped.lines().len() as f32
+        );
+
+        // Hard line breaks are retained.
+        let string = "a\n\nb";
+        assert_eq!(
+            Paragraph::new(&fonts, &text(&style, string), None)
+                .lines()
+                .len(),
+            3
+        );
+    }
+
+    #[test]
+    fn test_glyphs() {
+        let fonts = test_fonts();
+
+        let style = TextStyle {
+            text_align: Inherited::Specified(TextAlign::Right),
+            ..Default::default()
+        };
+
+        let string = "I I";
+        let paragraph = Paragraph::new(&fonts, &text(&style, string), None);
+        let glyphs = paragraph.glyphs(200.0);
+
+        // Whitespace is positioned too.
+        assert_eq!(glyphs.len(), 3);
+
+        let last = glyphs[2];
+        assert!(last.origin.x < 200.0 && last.origin.x > 190.0);
+        assert!(last.origin.y > 0.0 && last.origin.y < paragraph.size().height);
+    }
+
+    #[test]
+    fn test_line_height() {
+        let fonts = test_fonts();
+
+        let style = TextStyle {
+            line_height: Inherited::Specified(LineHeight::Number(2.0)),
+            ..Default::default()
+        };
+
+        let string = "a\nb";
+        let paragraph = Paragraph::new(&fonts, &text(&style, string), None);
+        assert_eq!(paragraph.size().height, 64.0);
+
+        // The baseline is centered within the line height.
+        let glyphs = paragraph.glyphs(100.0);
+        assert!(glyphs[0].origin.y > 16.0 && glyphs[0].origin.y < 32.0);
+        assert_eq!(glyphs[1].origin.y - glyphs[0].origin.y, 32.0);
+    }
+
+    #[test]
+    fn test_letter_spacing() {
+        let fonts = test_fonts();
+
+        let string = "abc";
+        let normal = Paragraph::new(&fonts, &text(&TextStyle::default(), string), None);
+
+        let style = TextStyle {
+            letter_spacing: Inherited::Specified(2.0),
+            ..Default::default()
+        };
+
+        let spaced = Paragraph::new(&fonts, &text(&style, string), None);
+        assert!((spaced.size().width - normal.size().width - 6.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_kerning() {
+        let fonts = test_fonts();
+        let style = TextStyle::default();
+
+        // The test fonts kern "AV" by a tenth of an em.
+        let plain = Paragraph::new(&fonts, &text(&style, "AA"), None);
+        let kerned = Paragraph::new(&fonts, &text(&style, "AV"), None);
+        assert!((plain.size().width - kerned.size().width - 1.6).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_number_of_lines() {
+        let fonts = test_fonts();
+
+        let string = "Hello world, this wraps";
+        let single = Paragraph::new(&fonts, &text(&TextStyle::default(), string), None);
+        let max_width = single.size().width / 2.0;
+
+        for mode in [EllipsisMode::Clip, EllipsisMode::Head, EllipsisMode::Tail] {
+            let style = TextStyle {
+                number_of_lines: Inherited::Specified(1),
+                ellipsis_mode: Inherited::Specified(mode),
+                ..Default::default()
+            };
+
+            let paragraph = Paragraph::new(&fonts, &text(&style, string), Some(max_width));
+            assert_eq!(paragraph.lines().len(), 1);
+            assert!(paragraph.size().width <= max_width);
+            assert!(!paragraph.lines()[0].is_wrapped());
+        }
+    }
+
+    #[test]
+    fn test_justify() {
+        let fonts = test_fonts();
+
+        let style = TextStyle {
+            text_align: Inherited::Specified(TextAlign::Justify),
+            ..Default::default()
+        };
+
+        let string = "I I I I";
+        let single = Paragraph::new(&fonts, &text(&style, string), None);
+        let width = single.size().width * 0.75;
+
+        let paragraph = Paragraph::new(&fonts, &text(&style, string), Some(width));
+        assert_eq!(paragraph.lines().len(), 2);
+        assert!(paragraph.lines()[0].is_wrapped());
+
+        // The last glyph on the first line is moved to the right edge, whereas
+        // the last line is aligned to the left.
+        let glyphs = paragraph.glyphs(width);
+        let first = glyphs
+            .iter()
+            .rev()
+            .find(|glyph| glyph.origin.y == glyphs[0].origin.y)
+            .unwrap();
+        assert!(first.origin.x > width * 0.9);
+        assert_eq!(glyphs.last().unwrap().origin.x, glyphs[0].origin.x);
+    }
+
+    #[test]
+    fn test_measure_func() {
+        let fonts = Arc::new(test_fonts());
+
+        let string = "Hello world, this wraps";
+        let MeasureFunc::Boxed(measure) = measure_func(
+            fonts,
+            text(&TextStyle::default(), string).without_listeners(),
+        );
+
+        let single = measure(Size::new(Dimension::Undefined, Dimension::Undefined));
+        let wrapped = measure(Size::new(
+            Dimension::Points(single.width / 2.0),
+            Dimension::Undefined,
+        ));
+
+        // Sizes are rounded up to whole points.
+        assert_eq!(single.width, single.width.ceil());
+        assert!(wrapped.width <= single.width / 2.0);
+        assert!(wrapped.height > single.height);
+    }
+}